@@ -612,11 +612,83 @@ internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
     fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultVoid.UniffiByValue,)
 }
 internal interface UniffiCallbackInterfaceAudioErrorCallbackMethod0 : com.sun.jna.Callback {
-    fun callback(`uniffiHandle`: Long,`code`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+    fun callback(`uniffiHandle`: Long,`code`: Int,`count`: Int,`firstTimestampMs`: Long,`lastTimestampMs`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceAudioLevelCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`levels`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceAudioLevelCallbackMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`level`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceAudioLoopCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`loopedId`: Int,`originalId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceAudioRouteCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`route`: RustBuffer.ByValue,`deviceId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceBluetoothScoCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`needed`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceDspParamCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`name`: RustBuffer.ByValue,`value`: Float,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceDspParamCallbackMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`stalls`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceEmergencyCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`nodeId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceFloorCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceFloorCallbackMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`holderId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceFloorCallbackMethod2 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`holderId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceOutputHealthCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`reason`: RustBuffer.ByValue,`toneChecked`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
 }
 internal interface UniffiCallbackInterfacePacketTransportMethod0 : com.sun.jna.Callback {
     fun callback(`uniffiHandle`: Long,`data`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
 }
+internal interface UniffiCallbackInterfacePeerEventCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfacePeerEventCallbackMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfacePeerEventCallbackMethod2 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfacePeerKeyCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`peerId`: Int,`identityKey`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfacePeerPositionCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`peerId`: Int,`position`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceRosterCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`roster`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceScanCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`channel`: Short,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceScanCallbackMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`channel`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceTransmitCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceTransmitGateMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`meta`: RustBuffer.ByValue,`uniffiOutReturn`: ByteByReference,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceTransportHealthCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`stallMs`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceVoiceActivityCallbackMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`speaking`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
 @Structure.FieldOrder("uniffiFree", "uniffiClone", "onEngineError")
 internal open class UniffiVTableCallbackInterfaceAudioErrorCallback(
     @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
@@ -635,6 +707,170 @@ internal open class UniffiVTableCallbackInterfaceAudioErrorCallback(
         `onEngineError` = other.`onEngineError`
     }
 
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onPeerLevels", "onMicLevel")
+internal open class UniffiVTableCallbackInterfaceAudioLevelCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onPeerLevels`: UniffiCallbackInterfaceAudioLevelCallbackMethod0? = null,
+    @JvmField internal var `onMicLevel`: UniffiCallbackInterfaceAudioLevelCallbackMethod1? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onPeerLevels`: UniffiCallbackInterfaceAudioLevelCallbackMethod0? = null,
+        `onMicLevel`: UniffiCallbackInterfaceAudioLevelCallbackMethod1? = null,
+    ): UniffiVTableCallbackInterfaceAudioLevelCallback(`uniffiFree`,`uniffiClone`,`onPeerLevels`,`onMicLevel`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceAudioLevelCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onPeerLevels` = other.`onPeerLevels`
+        `onMicLevel` = other.`onMicLevel`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onAudioLoopDetected")
+internal open class UniffiVTableCallbackInterfaceAudioLoopCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onAudioLoopDetected`: UniffiCallbackInterfaceAudioLoopCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onAudioLoopDetected`: UniffiCallbackInterfaceAudioLoopCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceAudioLoopCallback(`uniffiFree`,`uniffiClone`,`onAudioLoopDetected`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceAudioLoopCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onAudioLoopDetected` = other.`onAudioLoopDetected`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onAudioRouteChanged")
+internal open class UniffiVTableCallbackInterfaceAudioRouteCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onAudioRouteChanged`: UniffiCallbackInterfaceAudioRouteCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onAudioRouteChanged`: UniffiCallbackInterfaceAudioRouteCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceAudioRouteCallback(`uniffiFree`,`uniffiClone`,`onAudioRouteChanged`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceAudioRouteCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onAudioRouteChanged` = other.`onAudioRouteChanged`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onBluetoothScoNeeded")
+internal open class UniffiVTableCallbackInterfaceBluetoothScoCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onBluetoothScoNeeded`: UniffiCallbackInterfaceBluetoothScoCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onBluetoothScoNeeded`: UniffiCallbackInterfaceBluetoothScoCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceBluetoothScoCallback(`uniffiFree`,`uniffiClone`,`onBluetoothScoNeeded`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceBluetoothScoCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onBluetoothScoNeeded` = other.`onBluetoothScoNeeded`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onDspParamChanged", "onParamSnapshotRolledBack")
+internal open class UniffiVTableCallbackInterfaceDspParamCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onDspParamChanged`: UniffiCallbackInterfaceDspParamCallbackMethod0? = null,
+    @JvmField internal var `onParamSnapshotRolledBack`: UniffiCallbackInterfaceDspParamCallbackMethod1? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onDspParamChanged`: UniffiCallbackInterfaceDspParamCallbackMethod0? = null,
+        `onParamSnapshotRolledBack`: UniffiCallbackInterfaceDspParamCallbackMethod1? = null,
+    ): UniffiVTableCallbackInterfaceDspParamCallback(`uniffiFree`,`uniffiClone`,`onDspParamChanged`,`onParamSnapshotRolledBack`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceDspParamCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onDspParamChanged` = other.`onDspParamChanged`
+        `onParamSnapshotRolledBack` = other.`onParamSnapshotRolledBack`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onEmergencyReceived")
+internal open class UniffiVTableCallbackInterfaceEmergencyCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onEmergencyReceived`: UniffiCallbackInterfaceEmergencyCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onEmergencyReceived`: UniffiCallbackInterfaceEmergencyCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceEmergencyCallback(`uniffiFree`,`uniffiClone`,`onEmergencyReceived`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceEmergencyCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onEmergencyReceived` = other.`onEmergencyReceived`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onFloorGranted", "onFloorDenied", "onFloorRevoked")
+internal open class UniffiVTableCallbackInterfaceFloorCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onFloorGranted`: UniffiCallbackInterfaceFloorCallbackMethod0? = null,
+    @JvmField internal var `onFloorDenied`: UniffiCallbackInterfaceFloorCallbackMethod1? = null,
+    @JvmField internal var `onFloorRevoked`: UniffiCallbackInterfaceFloorCallbackMethod2? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onFloorGranted`: UniffiCallbackInterfaceFloorCallbackMethod0? = null,
+        `onFloorDenied`: UniffiCallbackInterfaceFloorCallbackMethod1? = null,
+        `onFloorRevoked`: UniffiCallbackInterfaceFloorCallbackMethod2? = null,
+    ): UniffiVTableCallbackInterfaceFloorCallback(`uniffiFree`,`uniffiClone`,`onFloorGranted`,`onFloorDenied`,`onFloorRevoked`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceFloorCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onFloorGranted` = other.`onFloorGranted`
+        `onFloorDenied` = other.`onFloorDenied`
+        `onFloorRevoked` = other.`onFloorRevoked`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onOutputSilentSuspected")
+internal open class UniffiVTableCallbackInterfaceOutputHealthCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onOutputSilentSuspected`: UniffiCallbackInterfaceOutputHealthCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onOutputSilentSuspected`: UniffiCallbackInterfaceOutputHealthCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceOutputHealthCallback(`uniffiFree`,`uniffiClone`,`onOutputSilentSuspected`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceOutputHealthCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onOutputSilentSuspected` = other.`onOutputSilentSuspected`
+    }
+
 }
 @Structure.FieldOrder("uniffiFree", "uniffiClone", "sendPacket")
 internal open class UniffiVTableCallbackInterfacePacketTransport(
@@ -654,6 +890,186 @@ internal open class UniffiVTableCallbackInterfacePacketTransport(
         `sendPacket` = other.`sendPacket`
     }
 
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onPeerStartedTalking", "onPeerStoppedTalking", "onPeerTimedOut")
+internal open class UniffiVTableCallbackInterfacePeerEventCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onPeerStartedTalking`: UniffiCallbackInterfacePeerEventCallbackMethod0? = null,
+    @JvmField internal var `onPeerStoppedTalking`: UniffiCallbackInterfacePeerEventCallbackMethod1? = null,
+    @JvmField internal var `onPeerTimedOut`: UniffiCallbackInterfacePeerEventCallbackMethod2? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onPeerStartedTalking`: UniffiCallbackInterfacePeerEventCallbackMethod0? = null,
+        `onPeerStoppedTalking`: UniffiCallbackInterfacePeerEventCallbackMethod1? = null,
+        `onPeerTimedOut`: UniffiCallbackInterfacePeerEventCallbackMethod2? = null,
+    ): UniffiVTableCallbackInterfacePeerEventCallback(`uniffiFree`,`uniffiClone`,`onPeerStartedTalking`,`onPeerStoppedTalking`,`onPeerTimedOut`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfacePeerEventCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onPeerStartedTalking` = other.`onPeerStartedTalking`
+        `onPeerStoppedTalking` = other.`onPeerStoppedTalking`
+        `onPeerTimedOut` = other.`onPeerTimedOut`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onPeerKeyEstablished")
+internal open class UniffiVTableCallbackInterfacePeerKeyCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onPeerKeyEstablished`: UniffiCallbackInterfacePeerKeyCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onPeerKeyEstablished`: UniffiCallbackInterfacePeerKeyCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfacePeerKeyCallback(`uniffiFree`,`uniffiClone`,`onPeerKeyEstablished`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfacePeerKeyCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onPeerKeyEstablished` = other.`onPeerKeyEstablished`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onPeerPosition")
+internal open class UniffiVTableCallbackInterfacePeerPositionCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onPeerPosition`: UniffiCallbackInterfacePeerPositionCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onPeerPosition`: UniffiCallbackInterfacePeerPositionCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfacePeerPositionCallback(`uniffiFree`,`uniffiClone`,`onPeerPosition`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfacePeerPositionCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onPeerPosition` = other.`onPeerPosition`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onRosterUpdated")
+internal open class UniffiVTableCallbackInterfaceRosterCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onRosterUpdated`: UniffiCallbackInterfaceRosterCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onRosterUpdated`: UniffiCallbackInterfaceRosterCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceRosterCallback(`uniffiFree`,`uniffiClone`,`onRosterUpdated`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceRosterCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onRosterUpdated` = other.`onRosterUpdated`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onScanActivity", "onScanChannelChanged")
+internal open class UniffiVTableCallbackInterfaceScanCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onScanActivity`: UniffiCallbackInterfaceScanCallbackMethod0? = null,
+    @JvmField internal var `onScanChannelChanged`: UniffiCallbackInterfaceScanCallbackMethod1? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onScanActivity`: UniffiCallbackInterfaceScanCallbackMethod0? = null,
+        `onScanChannelChanged`: UniffiCallbackInterfaceScanCallbackMethod1? = null,
+    ): UniffiVTableCallbackInterfaceScanCallback(`uniffiFree`,`uniffiClone`,`onScanActivity`,`onScanChannelChanged`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceScanCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onScanActivity` = other.`onScanActivity`
+        `onScanChannelChanged` = other.`onScanChannelChanged`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onTransmitStarted")
+internal open class UniffiVTableCallbackInterfaceTransmitCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onTransmitStarted`: UniffiCallbackInterfaceTransmitCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onTransmitStarted`: UniffiCallbackInterfaceTransmitCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceTransmitCallback(`uniffiFree`,`uniffiClone`,`onTransmitStarted`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceTransmitCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onTransmitStarted` = other.`onTransmitStarted`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "allowFrame")
+internal open class UniffiVTableCallbackInterfaceTransmitGate(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `allowFrame`: UniffiCallbackInterfaceTransmitGateMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `allowFrame`: UniffiCallbackInterfaceTransmitGateMethod0? = null,
+    ): UniffiVTableCallbackInterfaceTransmitGate(`uniffiFree`,`uniffiClone`,`allowFrame`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceTransmitGate) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `allowFrame` = other.`allowFrame`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onTransportStalled")
+internal open class UniffiVTableCallbackInterfaceTransportHealthCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onTransportStalled`: UniffiCallbackInterfaceTransportHealthCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onTransportStalled`: UniffiCallbackInterfaceTransportHealthCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceTransportHealthCallback(`uniffiFree`,`uniffiClone`,`onTransportStalled`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceTransportHealthCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onTransportStalled` = other.`onTransportStalled`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onVoiceActivityChanged")
+internal open class UniffiVTableCallbackInterfaceVoiceActivityCallback(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onVoiceActivityChanged`: UniffiCallbackInterfaceVoiceActivityCallbackMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onVoiceActivityChanged`: UniffiCallbackInterfaceVoiceActivityCallbackMethod0? = null,
+    ): UniffiVTableCallbackInterfaceVoiceActivityCallback(`uniffiFree`,`uniffiClone`,`onVoiceActivityChanged`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceVoiceActivityCallback) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onVoiceActivityChanged` = other.`onVoiceActivityChanged`
+    }
+
 }
 
 // A JNA Library to expose the extern-C FFI definitions.
@@ -678,34 +1094,338 @@ internal object IntegrityCheckingUniffiLib {
         uniffiCheckContractApiVersion(this)
         uniffiCheckApiChecksums(this)
     }
-    external fun uniffi_walkie_talkie_engine_checksum_func_init_logger(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_is_session_active(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_push_incoming_packet(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_release_resources(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_mic_enabled(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_input_stream(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_output_stream(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_session(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_session(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_constructor_audioengine_new(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_audioerrorcallback_on_engine_error(
-    ): Short
-    external fun uniffi_walkie_talkie_engine_checksum_method_packettransport_send_packet(
-    ): Short
-    external fun ffi_walkie_talkie_engine_uniffi_contract_version(
-    ): Int
+    external fun uniffi_walkie_talkie_engine_checksum_func_generate_identity_key(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_func_init_logger(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_func_set_locale(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_add_attenuation_window(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_apply_param_snapshot(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_block_peer(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_clear_keys(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_clear_transmit_gate(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_clip_cache_bytes(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_debug_set_peer_delay(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_describe_effective_config(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_describe_pipeline(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_export_archive_clip(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_export_session_token(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_finish_voice_message(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_flush_peer(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_bandwidth_usage(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_dsp_param(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_floor_holder(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_input_gain_report(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_mic_level(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_peer_capabilities(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_peer_levels(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_replay(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_resource_report(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_roster(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_get_stats(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_is_privacy_mode(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_is_session_active(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_mute_peer(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_notify_network_change(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_notify_route_changed(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_on_accessory_ptt(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_play_alert(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_play_replay(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_play_test_tone(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_play_voice_message(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_preload_clip(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_publish_roster(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_push_incoming_packet(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_read_render_frames(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_release_resources(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_remove_attenuation_window(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_reopen_input_stream(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_reopen_output_stream(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_resume_session(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_run_benchmark(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_search_archive(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_active_channel(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_audio_level_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_audio_loop_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_audio_route_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_bandwidth_budget_kbps(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_bluetooth_sco_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_dsp_param(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_dsp_param_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_emergency_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_encoder_bitrate(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_floor_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_group_key(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_identity_key(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_input_device(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_input_gain(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_local_position(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_local_priority(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_loopback_enabled(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_mic_enabled(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_output_device(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_output_health_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_output_volume(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_concealment(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_event_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_key_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_position_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_priority(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_priority_channel(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_privacy_mode(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_receive_muted(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_roster_admin(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_roster_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_route_preference(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_scan_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_scan_channels(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_sidetone_gain(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transmit_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transmit_gate(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transmit_quota(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transport_health_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_set_voice_activity_callback(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_shutdown(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_archive(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_emergency_transmission(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_input_stream(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_mic_recording(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_output_stream(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_pull_session(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_recording(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_secondary_output_stream(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_session(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_start_voice_message(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_archive(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_emergency_transmission(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_mic_recording(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_recording(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_secondary_output_stream(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_session(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_submit_capture_frames(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_unblock_peer(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_unload_clip(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_unmute_peer(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioengine_update_config(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_agc_enabled(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_bitrate_bps(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_build(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_comfort_noise_dbfs(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_describe_effective_config(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_devices(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_echo_cancellation(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_floor_control(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_frame_size_ms(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_jitter_buffer_ms(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_max_concurrent_speakers(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_noise_suppression(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_output_watchdog(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_pad_packets(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_ptt_pre_roll_ms(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_ptt_toggle(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_redundancy(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_roger_beep(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_routing_mode(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_sample_rate(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_secondary_output(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_talk_permit_tone(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_talker_id_chirp(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_vad(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_configbuilder_validate(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_constructor_audioengine_new(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_constructor_configbuilder_new(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioerrorcallback_on_engine_error(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audiolevelcallback_on_peer_levels(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audiolevelcallback_on_mic_level(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioloopcallback_on_audio_loop_detected(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_audioroutecallback_on_audio_route_changed(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_bluetoothscocallback_on_bluetooth_sco_needed(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_dspparamcallback_on_dsp_param_changed(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_dspparamcallback_on_param_snapshot_rolled_back(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_emergencycallback_on_emergency_received(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_floorcallback_on_floor_granted(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_floorcallback_on_floor_denied(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_floorcallback_on_floor_revoked(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_outputhealthcallback_on_output_silent_suspected(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_packettransport_send_packet(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_peereventcallback_on_peer_started_talking(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_peereventcallback_on_peer_stopped_talking(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_peereventcallback_on_peer_timed_out(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_peerkeycallback_on_peer_key_established(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_peerpositioncallback_on_peer_position(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_rostercallback_on_roster_updated(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_scancallback_on_scan_activity(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_scancallback_on_scan_channel_changed(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_transmitcallback_on_transmit_started(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_transmitgate_allow_frame(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_transporthealthcallback_on_transport_stalled(
+): Short
+external fun uniffi_walkie_talkie_engine_checksum_method_voiceactivitycallback_on_voice_activity_changed(
+): Short
+external fun ffi_walkie_talkie_engine_uniffi_contract_version(
+): Int
+
     
-        
 }
 
 internal object UniffiLib {
@@ -719,143 +1439,454 @@ internal object UniffiLib {
     init {
         Native.register(UniffiLib::class.java, findLibraryName(componentName = "walkie_talkie_engine"))
         uniffiCallbackInterfaceAudioErrorCallback.register(this)
+        uniffiCallbackInterfaceAudioLevelCallback.register(this)
+        uniffiCallbackInterfaceAudioLoopCallback.register(this)
+        uniffiCallbackInterfaceAudioRouteCallback.register(this)
+        uniffiCallbackInterfaceBluetoothScoCallback.register(this)
+        uniffiCallbackInterfaceDspParamCallback.register(this)
+        uniffiCallbackInterfaceEmergencyCallback.register(this)
+        uniffiCallbackInterfaceFloorCallback.register(this)
+        uniffiCallbackInterfaceOutputHealthCallback.register(this)
         uniffiCallbackInterfacePacketTransport.register(this)
+        uniffiCallbackInterfacePeerEventCallback.register(this)
+        uniffiCallbackInterfacePeerKeyCallback.register(this)
+        uniffiCallbackInterfacePeerPositionCallback.register(this)
+        uniffiCallbackInterfaceRosterCallback.register(this)
+        uniffiCallbackInterfaceScanCallback.register(this)
+        uniffiCallbackInterfaceTransmitCallback.register(this)
+        uniffiCallbackInterfaceTransmitGate.register(this)
+        uniffiCallbackInterfaceTransportHealthCallback.register(this)
+        uniffiCallbackInterfaceVoiceActivityCallback.register(this)
         
     }
     external fun uniffi_walkie_talkie_engine_fn_clone_audioengine(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_walkie_talkie_engine_fn_free_audioengine(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_constructor_audioengine_new(`config`: RustBuffer.ByValue,`transport`: Long,`callback`: Long,`ownNodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_is_session_active(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Byte
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_push_incoming_packet(`ptr`: Long,`data`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_release_resources(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_mic_enabled(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_input_stream(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_output_stream(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_session(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_session(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioerrorcallback(`vtable`: UniffiVTableCallbackInterfaceAudioErrorCallback,
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_packettransport(`vtable`: UniffiVTableCallbackInterfacePacketTransport,
-    ): Unit
-    external fun uniffi_walkie_talkie_engine_fn_func_init_logger(uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_walkie_talkie_engine_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_walkie_talkie_engine_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_walkie_talkie_engine_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_u8(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_u8(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Byte
-    external fun ffi_walkie_talkie_engine_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_i8(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_i8(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Byte
-    external fun ffi_walkie_talkie_engine_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_u16(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_u16(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Short
-    external fun ffi_walkie_talkie_engine_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_i16(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_i16(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Short
-    external fun ffi_walkie_talkie_engine_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_u32(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_u32(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Int
-    external fun ffi_walkie_talkie_engine_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_i32(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_i32(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Int
-    external fun ffi_walkie_talkie_engine_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_u64(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_u64(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun ffi_walkie_talkie_engine_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_i64(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_i64(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun ffi_walkie_talkie_engine_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_f32(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_f32(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Float
-    external fun ffi_walkie_talkie_engine_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_f64(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_f64(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Double
-    external fun ffi_walkie_talkie_engine_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_rust_buffer(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_rust_buffer(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_walkie_talkie_engine_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_cancel_void(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_free_void(`handle`: Long,
-    ): Unit
-    external fun ffi_walkie_talkie_engine_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
+): Long
+external fun uniffi_walkie_talkie_engine_fn_free_audioengine(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_constructor_audioengine_new(`config`: RustBuffer.ByValue,`transport`: Long,`callback`: Long,`ownNodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_add_attenuation_window(`ptr`: Long,`depthDb`: Float,`startInMs`: Int,`durationMs`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_apply_param_snapshot(`ptr`: Long,`snapshot`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_block_peer(`ptr`: Long,`nodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_clear_keys(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_clear_transmit_gate(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_clip_cache_bytes(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_debug_set_peer_delay(`ptr`: Long,`nodeId`: Int,`ms`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_describe_effective_config(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_describe_pipeline(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_export_archive_clip(`ptr`: Long,`segment`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_export_session_token(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_finish_voice_message(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_flush_peer(`ptr`: Long,`nodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_bandwidth_usage(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_dsp_param(`ptr`: Long,`name`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_floor_holder(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_input_gain_report(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_mic_level(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_peer_capabilities(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_peer_levels(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_replay(`ptr`: Long,`seconds`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_resource_report(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_roster(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_get_stats(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_is_privacy_mode(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_is_session_active(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_mute_peer(`ptr`: Long,`nodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_notify_network_change(`ptr`: Long,`kind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_notify_route_changed(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_on_accessory_ptt(`ptr`: Long,`pressed`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_play_alert(`ptr`: Long,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_play_replay(`ptr`: Long,`seconds`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_play_test_tone(`ptr`: Long,`route`: RustBuffer.ByValue,`freqHz`: Float,`durationMs`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_play_voice_message(`ptr`: Long,`oggBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_preload_clip(`ptr`: Long,`id`: RustBuffer.ByValue,`oggBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_publish_roster(`ptr`: Long,`version`: Int,`keys`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_push_incoming_packet(`ptr`: Long,`data`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_read_render_frames(`ptr`: Long,`numFrames`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_release_resources(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_remove_attenuation_window(`ptr`: Long,`windowId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_reopen_input_stream(`ptr`: Long,`reason`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_reopen_output_stream(`ptr`: Long,`reason`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_resume_session(`ptr`: Long,`token`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_run_benchmark(`ptr`: Long,`peers`: Int,`seconds`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_search_archive(`ptr`: Long,`peerId`: RustBuffer.ByValue,`fromMs`: Long,`toMs`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_active_channel(`ptr`: Long,`channel`: Short,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_audio_level_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_audio_loop_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_audio_route_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_bandwidth_budget_kbps(`ptr`: Long,`totalKbps`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_bluetooth_sco_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_dsp_param(`ptr`: Long,`name`: RustBuffer.ByValue,`value`: Float,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_dsp_param_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_emergency_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_encoder_bitrate(`ptr`: Long,`bitsPerSec`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_floor_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_group_key(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_identity_key(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_input_device(`ptr`: Long,`deviceId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_input_gain(`ptr`: Long,`db`: Float,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_local_position(`ptr`: Long,`position`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_local_priority(`ptr`: Long,`priority`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_loopback_enabled(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_mic_enabled(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_output_device(`ptr`: Long,`deviceId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_output_health_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_output_volume(`ptr`: Long,`volume`: Float,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_concealment(`ptr`: Long,`nodeId`: Int,`mode`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_event_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_key_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_position_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_priority(`ptr`: Long,`nodeId`: Int,`priority`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_priority_channel(`ptr`: Long,`channel`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_privacy_mode(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_receive_muted(`ptr`: Long,`muted`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_roster_admin(`ptr`: Long,`adminIdentityKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_roster_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_route_preference(`ptr`: Long,`order`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_scan_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_scan_channels(`ptr`: Long,`channels`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_sidetone_gain(`ptr`: Long,`gain`: Float,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_transmit_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_transmit_gate(`ptr`: Long,`gate`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_transmit_quota(`ptr`: Long,`seconds`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_transport_health_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_set_voice_activity_callback(`ptr`: Long,`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_shutdown(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_archive(`ptr`: Long,`dir`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_emergency_transmission(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_input_stream(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_mic_recording(`ptr`: Long,`path`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_output_stream(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_pull_session(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_recording(`ptr`: Long,`path`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_secondary_output_stream(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_session(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_start_voice_message(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_archive(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_emergency_transmission(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_mic_recording(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_recording(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_secondary_output_stream(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_stop_session(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_submit_capture_frames(`ptr`: Long,`frames`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_unblock_peer(`ptr`: Long,`nodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_unload_clip(`ptr`: Long,`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_unmute_peer(`ptr`: Long,`nodeId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_method_audioengine_update_config(`ptr`: Long,`config`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_clone_configbuilder(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_free_configbuilder(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_constructor_configbuilder_new(`profile`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_agc_enabled(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_bitrate_bps(`ptr`: Long,`bps`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_build(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_comfort_noise_dbfs(`ptr`: Long,`level`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_describe_effective_config(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_devices(`ptr`: Long,`inputDeviceId`: Int,`outputDeviceId`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_echo_cancellation(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_floor_control(`ptr`: Long,`policy`: RustBuffer.ByValue,`priority`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_frame_size_ms(`ptr`: Long,`ms`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_jitter_buffer_ms(`ptr`: Long,`ms`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_max_concurrent_speakers(`ptr`: Long,`speakers`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_noise_suppression(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_output_watchdog(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_pad_packets(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_ptt_pre_roll_ms(`ptr`: Long,`ms`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_ptt_toggle(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_redundancy(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_roger_beep(`ptr`: Long,`beep`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_routing_mode(`ptr`: Long,`mode`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_sample_rate(`ptr`: Long,`hz`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_secondary_output(`ptr`: Long,`output`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_talk_permit_tone(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_talker_id_chirp(`ptr`: Long,`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_vad(`ptr`: Long,`enabled`: Byte,`gateTransmit`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_walkie_talkie_engine_fn_method_configbuilder_validate(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioerrorcallback(`vtable`: UniffiVTableCallbackInterfaceAudioErrorCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_audiolevelcallback(`vtable`: UniffiVTableCallbackInterfaceAudioLevelCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioloopcallback(`vtable`: UniffiVTableCallbackInterfaceAudioLoopCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioroutecallback(`vtable`: UniffiVTableCallbackInterfaceAudioRouteCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_bluetoothscocallback(`vtable`: UniffiVTableCallbackInterfaceBluetoothScoCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_dspparamcallback(`vtable`: UniffiVTableCallbackInterfaceDspParamCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_emergencycallback(`vtable`: UniffiVTableCallbackInterfaceEmergencyCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_floorcallback(`vtable`: UniffiVTableCallbackInterfaceFloorCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_outputhealthcallback(`vtable`: UniffiVTableCallbackInterfaceOutputHealthCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_packettransport(`vtable`: UniffiVTableCallbackInterfacePacketTransport,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_peereventcallback(`vtable`: UniffiVTableCallbackInterfacePeerEventCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_peerkeycallback(`vtable`: UniffiVTableCallbackInterfacePeerKeyCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_peerpositioncallback(`vtable`: UniffiVTableCallbackInterfacePeerPositionCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_rostercallback(`vtable`: UniffiVTableCallbackInterfaceRosterCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_scancallback(`vtable`: UniffiVTableCallbackInterfaceScanCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_transmitcallback(`vtable`: UniffiVTableCallbackInterfaceTransmitCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_transmitgate(`vtable`: UniffiVTableCallbackInterfaceTransmitGate,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_transporthealthcallback(`vtable`: UniffiVTableCallbackInterfaceTransportHealthCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_init_callback_vtable_voiceactivitycallback(`vtable`: UniffiVTableCallbackInterfaceVoiceActivityCallback,
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_func_generate_identity_key(uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_walkie_talkie_engine_fn_func_init_logger(uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_walkie_talkie_engine_fn_func_set_locale(`tag`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun ffi_walkie_talkie_engine_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_walkie_talkie_engine_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_walkie_talkie_engine_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun ffi_walkie_talkie_engine_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_walkie_talkie_engine_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_u8(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_u8(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun ffi_walkie_talkie_engine_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_i8(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_i8(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun ffi_walkie_talkie_engine_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_u16(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_u16(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+external fun ffi_walkie_talkie_engine_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_i16(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_i16(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+external fun ffi_walkie_talkie_engine_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_u32(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_u32(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun ffi_walkie_talkie_engine_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_i32(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_i32(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun ffi_walkie_talkie_engine_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_u64(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_u64(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun ffi_walkie_talkie_engine_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_i64(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_i64(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun ffi_walkie_talkie_engine_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_f32(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_f32(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Float
+external fun ffi_walkie_talkie_engine_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_f64(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_f64(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Double
+external fun ffi_walkie_talkie_engine_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_rust_buffer(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_rust_buffer(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_walkie_talkie_engine_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_cancel_void(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_free_void(`handle`: Long,
+): Unit
+external fun ffi_walkie_talkie_engine_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+
     
-        
 }
 
 private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
@@ -869,978 +1900,9405 @@ private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
 }
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_func_generate_identity_key() != 56498.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_walkie_talkie_engine_checksum_func_init_logger() != 21797.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_is_session_active() != 47020.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_func_set_locale() != 8100.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_push_incoming_packet() != 14335.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_add_attenuation_window() != 57494.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_release_resources() != 56445.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_apply_param_snapshot() != 24968.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_mic_enabled() != 33852.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_block_peer() != 56942.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_input_stream() != 21684.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_clear_keys() != 60704.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_output_stream() != 45253.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_clear_transmit_gate() != 19596.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_session() != 51869.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_clip_cache_bytes() != 50848.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_session() != 5573.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_debug_set_peer_delay() != 43577.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_constructor_audioengine_new() != 24249.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_describe_effective_config() != 16950.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioerrorcallback_on_engine_error() != 55915.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_describe_pipeline() != 34147.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_walkie_talkie_engine_checksum_method_packettransport_send_packet() != 58023.toShort()) {
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_export_archive_clip() != 38441.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-}
-
-/**
- * @suppress
- */
-public fun uniffiEnsureInitialized() {
-    IntegrityCheckingUniffiLib
-    // UniffiLib() initialized as objects are used, but we still need to explicitly
-    // reference it so initialization across crates works as expected.
-    UniffiLib
-}
-
-// Async support
-
-// Public interface members begin here.
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_export_session_token() != 8468.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_finish_voice_message() != 51304.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_flush_peer() != 40474.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_bandwidth_usage() != 24416.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_dsp_param() != 4485.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_floor_holder() != 39237.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_input_gain_report() != 2556.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_mic_level() != 40413.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_peer_capabilities() != 26889.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_peer_levels() != 5822.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_replay() != 34220.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_resource_report() != 53617.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_roster() != 29751.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_get_stats() != 37379.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_is_privacy_mode() != 45941.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_is_session_active() != 47020.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_mute_peer() != 56125.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_notify_network_change() != 18121.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_notify_route_changed() != 62235.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_on_accessory_ptt() != 9745.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_play_alert() != 23360.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_play_replay() != 55195.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_play_test_tone() != 25456.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_play_voice_message() != 1098.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_preload_clip() != 24029.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_publish_roster() != 22167.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_push_incoming_packet() != 14335.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_read_render_frames() != 9689.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_release_resources() != 56445.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_remove_attenuation_window() != 9673.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_reopen_input_stream() != 33081.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_reopen_output_stream() != 4297.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_resume_session() != 32698.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_run_benchmark() != 16256.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_search_archive() != 45555.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_active_channel() != 15520.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_audio_level_callback() != 48620.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_audio_loop_callback() != 44699.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_audio_route_callback() != 44766.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_bandwidth_budget_kbps() != 28838.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_bluetooth_sco_callback() != 52422.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_dsp_param() != 33082.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_dsp_param_callback() != 39521.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_emergency_callback() != 47331.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_encoder_bitrate() != 42470.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_floor_callback() != 62887.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_group_key() != 34575.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_identity_key() != 10124.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_input_device() != 5618.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_input_gain() != 20880.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_local_position() != 19120.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_local_priority() != 44114.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_loopback_enabled() != 22765.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_mic_enabled() != 4096.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_output_device() != 36133.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_output_health_callback() != 49577.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_output_volume() != 10892.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_concealment() != 3968.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_event_callback() != 30968.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_key_callback() != 50090.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_position_callback() != 1854.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_peer_priority() != 35079.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_priority_channel() != 31643.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_privacy_mode() != 63199.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_receive_muted() != 18093.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_roster_admin() != 58361.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_roster_callback() != 49128.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_route_preference() != 21151.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_scan_callback() != 50369.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_scan_channels() != 59018.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_sidetone_gain() != 23022.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transmit_callback() != 41284.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transmit_gate() != 63293.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transmit_quota() != 18194.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_transport_health_callback() != 62444.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_set_voice_activity_callback() != 56041.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_shutdown() != 40579.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_archive() != 8952.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_emergency_transmission() != 11451.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_input_stream() != 21684.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_mic_recording() != 58646.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_output_stream() != 45253.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_pull_session() != 47844.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_recording() != 44988.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_secondary_output_stream() != 36611.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_session() != 51869.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_start_voice_message() != 54612.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_archive() != 12660.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_emergency_transmission() != 43426.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_mic_recording() != 44821.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_recording() != 31057.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_secondary_output_stream() != 52177.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_stop_session() != 13645.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_submit_capture_frames() != 54327.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_unblock_peer() != 38098.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_unload_clip() != 58207.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_unmute_peer() != 27344.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioengine_update_config() != 30123.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_agc_enabled() != 18296.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_bitrate_bps() != 39952.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_build() != 236.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_comfort_noise_dbfs() != 33777.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_describe_effective_config() != 9619.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_devices() != 2054.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_echo_cancellation() != 23981.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_floor_control() != 56149.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_frame_size_ms() != 14316.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_jitter_buffer_ms() != 54155.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_max_concurrent_speakers() != 13559.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_noise_suppression() != 23858.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_output_watchdog() != 50163.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_pad_packets() != 23408.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_ptt_pre_roll_ms() != 12798.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_ptt_toggle() != 9983.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_redundancy() != 53468.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_roger_beep() != 57846.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_routing_mode() != 17787.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_sample_rate() != 33873.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_secondary_output() != 24065.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_talk_permit_tone() != 35778.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_talker_id_chirp() != 44443.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_vad() != 11493.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_configbuilder_validate() != 47127.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_constructor_audioengine_new() != 64097.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_constructor_configbuilder_new() != 34428.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioerrorcallback_on_engine_error() != 56123.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audiolevelcallback_on_peer_levels() != 22513.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audiolevelcallback_on_mic_level() != 38679.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioloopcallback_on_audio_loop_detected() != 35616.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_audioroutecallback_on_audio_route_changed() != 14956.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_bluetoothscocallback_on_bluetooth_sco_needed() != 6414.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_dspparamcallback_on_dsp_param_changed() != 33501.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_dspparamcallback_on_param_snapshot_rolled_back() != 55111.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_emergencycallback_on_emergency_received() != 51811.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_floorcallback_on_floor_granted() != 15369.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_floorcallback_on_floor_denied() != 55938.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_floorcallback_on_floor_revoked() != 8155.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_outputhealthcallback_on_output_silent_suspected() != 54680.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_packettransport_send_packet() != 58023.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_peereventcallback_on_peer_started_talking() != 38652.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_peereventcallback_on_peer_stopped_talking() != 31394.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_peereventcallback_on_peer_timed_out() != 6100.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_peerkeycallback_on_peer_key_established() != 4270.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_peerpositioncallback_on_peer_position() != 19257.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_rostercallback_on_roster_updated() != 29056.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_scancallback_on_scan_activity() != 39917.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_scancallback_on_scan_channel_changed() != 60752.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_transmitcallback_on_transmit_started() != 57334.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_transmitgate_allow_frame() != 4400.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_transporthealthcallback_on_transport_stalled() != 3596.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_walkie_talkie_engine_checksum_method_voiceactivitycallback_on_voice_activity_changed() != 62991.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+/**
+ * @suppress
+ */
+public fun uniffiEnsureInitialized() {
+    IntegrityCheckingUniffiLib
+    // UniffiLib() initialized as objects are used, but we still need to explicitly
+    // reference it so initialization across crates works as expected.
+    UniffiLib
+}
+
+// Async support
+
+// Public interface members begin here.
+
+
+// Interface implemented by anything that can contain an object reference.
+//
+// Such types expose a `destroy()` method that must be called to cleanly
+// dispose of the contained objects. Failure to call this method may result
+// in memory leaks.
+//
+// The easiest way to ensure this method is called is to use the `.use`
+// helper method to execute a block and destroy the object at the end.
+interface Disposable {
+    fun destroy()
+    companion object {
+        fun destroy(vararg args: Any?) {
+            for (arg in args) {
+                when (arg) {
+                    is Disposable -> arg.destroy()
+                    is ArrayList<*> -> {
+                        for (idx in arg.indices) {
+                            val element = arg[idx]
+                            if (element is Disposable) {
+                                element.destroy()
+                            }
+                        }
+                    }
+                    is Map<*, *> -> {
+                        for (element in arg.values) {
+                            if (element is Disposable) {
+                                element.destroy()
+                            }
+                        }
+                    }
+                    is Iterable<*> -> {
+                        for (element in arg) {
+                            if (element is Disposable) {
+                                element.destroy()
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+ * @suppress
+ */
+inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
+    try {
+        block(this)
+    } finally {
+        try {
+            // N.B. our implementation is on the nullable type `Disposable?`.
+            this?.destroy()
+        } catch (e: Throwable) {
+            // swallow
+        }
+    }
+
+/** 
+ * Placeholder object used to signal that we're constructing an interface with a FFI handle.
+ *
+ * This is the first argument for interface constructors that input a raw handle. It exists is that
+ * so we can avoid signature conflicts when an interface has a regular constructor than inputs a
+ * Long.
+ *
+ * @suppress
+ * */
+object UniffiWithHandle
+
+/** 
+ * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
+ *
+ * @suppress
+ * */
+object NoHandle// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+internal const val IDX_CALLBACK_FREE = 0
+// Callback return codes
+internal const val UNIFFI_CALLBACK_SUCCESS = 0
+internal const val UNIFFI_CALLBACK_ERROR = 1
+internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+/**
+ * @suppress
+ */
+public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
+    internal val handleMap = UniffiHandleMap<CallbackInterface>()
+
+    internal fun drop(handle: Long) {
+        handleMap.remove(handle)
+    }
+
+    override fun lift(value: Long): CallbackInterface {
+        return handleMap.get(value)
+    }
+
+    override fun read(buf: ByteBuffer) = lift(buf.getLong())
+
+    override fun lower(value: CallbackInterface) = handleMap.insert(value)
+
+    override fun allocationSize(value: CallbackInterface) = 8UL
+
+    override fun write(value: CallbackInterface, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+/**
+ * The cleaner interface for Object finalization code to run.
+ * This is the entry point to any implementation that we're using.
+ *
+ * The cleaner registers objects and returns cleanables, so now we are
+ * defining a `UniffiCleaner` with a `UniffiClenaer.Cleanable` to abstract the
+ * different implmentations available at compile time.
+ *
+ * @suppress
+ */
+interface UniffiCleaner {
+    interface Cleanable {
+        fun clean()
+    }
+
+    fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable
+
+    companion object
+}
+
+// The fallback Jna cleaner, which is available for both Android, and the JVM.
+private class UniffiJnaCleaner : UniffiCleaner {
+    private val cleaner = com.sun.jna.internal.Cleaner.getCleaner()
+
+    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
+        UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
+}
+
+private class UniffiJnaCleanable(
+    private val cleanable: com.sun.jna.internal.Cleaner.Cleanable,
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+
+// We decide at uniffi binding generation time whether we were
+// using Android or not.
+// There are further runtime checks to chose the correct implementation
+// of the cleaner.
+private fun UniffiCleaner.Companion.create(): UniffiCleaner =
+    try {
+        // For safety's sake: if the library hasn't been run in android_cleaner = true
+        // mode, but is being run on Android, then we still need to think about
+        // Android API versions.
+        // So we check if java.lang.ref.Cleaner is there, and use that…
+        java.lang.Class.forName("java.lang.ref.Cleaner")
+        JavaLangRefCleaner()
+    } catch (e: ClassNotFoundException) {
+        // … otherwise, fallback to the JNA cleaner.
+        UniffiJnaCleaner()
+    }
+
+private class JavaLangRefCleaner : UniffiCleaner {
+    val cleaner = java.lang.ref.Cleaner.create()
+
+    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
+        JavaLangRefCleanable(cleaner.register(value, cleanUpTask))
+}
+
+private class JavaLangRefCleanable(
+    val cleanable: java.lang.ref.Cleaner.Cleanable
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUByte: FfiConverter<UByte, Byte> {
+    override fun lift(value: Byte): UByte {
+        return value.toUByte()
+    }
+
+    override fun read(buf: ByteBuffer): UByte {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: UByte): Byte {
+        return value.toByte()
+    }
+
+    override fun allocationSize(value: UByte) = 1UL
+
+    override fun write(value: UByte, buf: ByteBuffer) {
+        buf.put(value.toByte())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUShort: FfiConverter<UShort, Short> {
+    override fun lift(value: Short): UShort {
+        return value.toUShort()
+    }
+
+    override fun read(buf: ByteBuffer): UShort {
+        return lift(buf.getShort())
+    }
+
+    override fun lower(value: UShort): Short {
+        return value.toShort()
+    }
+
+    override fun allocationSize(value: UShort) = 2UL
+
+    override fun write(value: UShort, buf: ByteBuffer) {
+        buf.putShort(value.toShort())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterShort: FfiConverter<Short, Short> {
+    override fun lift(value: Short): Short {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Short {
+        return buf.getShort()
+    }
+
+    override fun lower(value: Short): Short {
+        return value
+    }
+
+    override fun allocationSize(value: Short) = 2UL
+
+    override fun write(value: Short, buf: ByteBuffer) {
+        buf.putShort(value)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterInt: FfiConverter<Int, Int> {
+    override fun lift(value: Int): Int {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Int {
+        return buf.getInt()
+    }
+
+    override fun lower(value: Int): Int {
+        return value
+    }
+
+    override fun allocationSize(value: Int) = 4UL
+
+    override fun write(value: Int, buf: ByteBuffer) {
+        buf.putInt(value)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterFloat: FfiConverter<Float, Float> {
+    override fun lift(value: Float): Float {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Float {
+        return buf.getFloat()
+    }
+
+    override fun lower(value: Float): Float {
+        return value
+    }
+
+    override fun allocationSize(value: Float) = 4UL
+
+    override fun write(value: Float, buf: ByteBuffer) {
+        buf.putFloat(value)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterDouble: FfiConverter<Double, Double> {
+    override fun lift(value: Double): Double {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Double {
+        return buf.getDouble()
+    }
+
+    override fun lower(value: Double): Double {
+        return value
+    }
+
+    override fun allocationSize(value: Double) = 8UL
+
+    override fun write(value: Double, buf: ByteBuffer) {
+        buf.putDouble(value)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
+
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
+
+    override fun allocationSize(value: Boolean) = 1UL
+
+    override fun write(value: Boolean, buf: ByteBuffer) {
+        buf.put(lower(value))
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
+    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
+    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
+    // store our length and avoid writing it out to the buffer.
+    override fun lift(value: RustBuffer.ByValue): String {
+        try {
+            val byteArr = ByteArray(value.len.toInt())
+            value.asByteBuffer()!!.get(byteArr)
+            return byteArr.toString(Charsets.UTF_8)
+        } finally {
+            RustBuffer.free(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr.toString(Charsets.UTF_8)
+    }
+
+    fun toUtf8(value: String): ByteBuffer {
+        // Make sure we don't have invalid UTF-16, check for lone surrogates.
+        return Charsets.UTF_8.newEncoder().run {
+            onMalformedInput(CodingErrorAction.REPORT)
+            encode(CharBuffer.wrap(value))
+        }
+    }
+
+    override fun lower(value: String): RustBuffer.ByValue {
+        val byteBuf = toUtf8(value)
+        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
+        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
+        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
+        rbuf.asByteBuffer()!!.put(byteBuf)
+        return rbuf
+    }
+
+    // We aren't sure exactly how many bytes our string will be once it's UTF-8
+    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
+    // enough.
+    override fun allocationSize(value: String): ULong {
+        val sizeForLength = 4UL
+        val sizeForString = value.length.toULong() * 3UL
+        return sizeForLength + sizeForString
+    }
+
+    override fun write(value: String, buf: ByteBuffer) {
+        val byteBuf = toUtf8(value)
+        buf.putInt(byteBuf.limit())
+        buf.put(byteBuf)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+//
+public interface AudioEngineInterface {
+    
+    /**
+     * Dips the channel audio by `depth_db` for `duration_ms`, starting `start_in_ms` from now
+     * (e.g. while the app plays a navigation prompt). A `duration_ms` of 0 keeps the window
+     * open until `remove_attenuation_window`. Returns a handle for the window.
+     */
+    fun `addAttenuationWindow`(`depthDb`: kotlin.Float, `startInMs`: kotlin.UInt, `durationMs`: kotlin.UInt): kotlin.UInt
+    
+    /**
+     * Applies a whole set of DSP tunables atomically: either every value is valid and
+     * all are applied, or nothing changes. The snapshot then runs on probation and is
+     * rolled back automatically if playout stalls spike.
+     */
+    fun `applyParamSnapshot`(`snapshot`: Map<kotlin.String, kotlin.Float>)
+    
+    /**
+     * Drops audio from `node_id` as it arrives, before it is decrypted or takes
+     * jitter buffer memory, and flushes what is already buffered. Its control
+     * messages (floor, keys, roster) still get through. Counted in
+     * `EngineStats.packets_blocked`. Kept across sessions.
+     */
+    fun `blockPeer`(`nodeId`: kotlin.UInt)
+    
+    /**
+     * Wipes all key material: the group key, our identity key with every key agreed
+     * with peers, and the roster with its admin. Audio goes out unencrypted until
+     * keys are set again. `stop_session` does this by itself in privacy mode.
+     */
+    fun `clearKeys`()
+    
+    /**
+     * Removes the transmit policy: every encoded frame is sent again.
+     */
+    fun `clearTransmitGate`()
+    
+    /**
+     * Memory currently held by pre-decoded clips, in bytes.
+     */
+    fun `clipCacheBytes`(): kotlin.ULong
+    
+    /**
+     * DEBUG: holds back `node_id`'s packets for `ms` before they reach its jitter
+     * buffer, to demo one participant on a terrible link. 0 removes the delay.
+     */
+    fun `debugSetPeerDelay`(`nodeId`: kotlin.UInt, `ms`: kotlin.UInt)
+    
+    /**
+     * The current config as the engine applies it, including which profile it came from.
+     */
+    fun `describeEffectiveConfig`(): EffectiveConfig
+    
+    /**
+     * Every processing stage in signal order with its live settings, for tooling
+     * and support to see exactly what runs on this device.
+     */
+    fun `describePipeline`(): PipelineGraph
+    
+    /**
+     * Cuts a transmission found by `search_archive` out of its segment, as an
+     * Ogg/Opus file like a voice message (`play_voice_message` plays it).
+     */
+    fun `exportArchiveClip`(`segment`: ArchiveSegment): kotlin.ByteArray
+    
+    /**
+     * Snapshot of the state a restarted process needs to carry on as the same node:
+     * node ID, sequence counter, encryption epoch (no key material), and the active,
+     * scanned and priority channels. Refused in privacy mode, since the app would
+     * have to persist it.
+     */
+    fun `exportSessionToken`(): kotlin.ByteArray
+    
+    /**
+     * Ends the voice message and returns it as an Ogg/Opus file, ready for
+     * `play_voice_message` on the recipient's side.
+     */
+    fun `finishVoiceMessage`(): kotlin.ByteArray
+    
+    /**
+     * Discards the packets buffered for `node_id` and restarts its playout, e.g.
+     * when signaling says the peer restarted or seeked, instead of playing out
+     * stale audio. Its next packet starts a new talk burst. Stats are kept.
+     */
+    fun `flushPeer`(`nodeId`: kotlin.UInt)
+    
+    /**
+     * Current consumption and what the budget leaves the sender. Rates are
+     * measured between calls, so poll this (every second or so) for a live figure.
+     */
+    fun `getBandwidthUsage`(): BandwidthUsage
+    
+    /**
+     * Returns the current value of a DSP tunable, or `None` if the name is unknown.
+     */
+    fun `getDspParam`(`name`: kotlin.String): kotlin.Float?
+    
+    /**
+     * The node holding the floor (us included), if any (`AudioConfig.floor_control`).
+     */
+    fun `getFloorHolder`(): kotlin.UInt?
+    
+    /**
+     * Reports our software gain next to what the platform does to the mic signal
+     * before we get it, which `set_input_gain` can't see or undo.
+     */
+    fun `getInputGainReport`(): InputGainReport
+    
+    /**
+     * The latest mic level, for polling instead of `AudioLevelCallback`. None
+     * until a session has captured some audio.
+     */
+    fun `getMicLevel`(): MicLevel?
+    
+    /**
+     * What each peer announced it supports (announced at session start, so
+     * features can be turned on only once everyone understands them). Peers
+     * silent for 3s are left out until they are heard again.
+     */
+    fun `getPeerCapabilities`(): List<PeerCapabilities>
+    
+    /**
+     * The latest per-peer levels, for polling instead of `AudioLevelCallback`.
+     */
+    fun `getPeerLevels`(): List<PeerLevel>
+    
+    /**
+     * Up to the last `seconds` of the received mix (see `AudioConfig.replay_buffer_secs`),
+     * as mono PCM at the session sample rate. Taken before receive mute and ducking.
+     */
+    fun `getReplay`(`seconds`: kotlin.UInt): List<kotlin.Short>
+    
+    /**
+     * Counts of what the engines in this process hold (see `ResourceReport`), so
+     * long-running deployments can check for slow leaks across sessions.
+     */
+    fun `getResourceReport`(): ResourceReport
+    
+    /**
+     * The current roster (version 0 and no keys until one is published or received).
+     */
+    fun `getRoster`(): PeerRoster
+    
+    /**
+     * Connection quality: per-peer loss/jitter/PLC plus global traffic totals.
+     */
+    fun `getStats`(): EngineStats
+    
+    fun `isPrivacyMode`(): kotlin.Boolean
+    
+    fun `isSessionActive`(): kotlin.Boolean
+    
+    /**
+     * Silences `node_id` in our mix. Its audio is still received and decoded
+     * (talk events and stats keep coming), so unmuting is instant. Kept across
+     * sessions; see `block_peer` to drop it instead.
+     */
+    fun `mutePeer`(`nodeId`: kotlin.UInt)
+    
+    /**
+     * Call when the device switches networks (e.g. Wi-Fi to cellular). For a grace
+     * period the jitter buffer aims deeper and the encoder adds more in-band FEC,
+     * so the burst of late and lost packets around the switch doesn't break up the
+     * audio; afterwards both go back to normal and the buffer drains back to its
+     * usual depth. A jitter target changed by the app meanwhile is left alone.
+     */
+    fun `notifyNetworkChange`(`kind`: NetworkKind)
+    
+    /**
+     * Call when Android reports audio devices added or removed. Re-walks the route
+     * preference and reopens the streams if a different device is now preferred.
+     */
+    fun `notifyRouteChanged`()
+    
+    /**
+     * A PTT key edge from an accessory (wired headset button, Bluetooth remote)
+     * or the app's own button, so every entry point behaves alike. Bounces and
+     * key repeats are filtered out, and `AudioConfig.ptt_toggle` turns taps into
+     * on/off toggles. Drives `set_mic_enabled`, whose errors it returns (an edge
+     * that arrives during the debounce window is applied later, and its errors
+     * are only logged).
+     */
+    fun `onAccessoryPtt`(`pressed`: kotlin.Boolean)
+    
+    /**
+     * Plays a preloaded clip on top of the channel mix (not affected by ducking).
+     */
+    fun `playAlert`(`id`: kotlin.String)
+    
+    /**
+     * Plays back up to the last `seconds` of the received mix, like an alert.
+     */
+    fun `playReplay`(`seconds`: kotlin.UInt)
+    
+    /**
+     * Plays a `freq_hz` tone for `duration_ms` (up to TEST_TONE_MAX_MS), so a settings
+     * screen can let the user confirm which speaker or headset the engine uses. With
+     * no `route` it goes where the session plays: through the running output stream,
+     * or outside a session to the device the route preference picks. A given `route`
+     * must be connected. Returns the route the tone plays on, if known.
+     */
+    fun `playTestTone`(`route`: AudioRoute?, `freqHz`: kotlin.Float, `durationMs`: kotlin.UInt): AudioRoute?
+    
+    /**
+     * Decodes a voice message and plays it on top of the channel mix, like an alert.
+     * The channel is ducked by `AudioConfig.voice_message_duck_db` for exactly as
+     * long as the message plays.
+     */
+    fun `playVoiceMessage`(`oggBytes`: kotlin.ByteArray)
+    
+    /**
+     * Decodes an Ogg/Opus alert clip and caches its PCM so `play_alert` starts instantly.
+     * Re-using an `id` replaces the previous clip. Older clips are evicted if the cache is full.
+     */
+    fun `preloadClip`(`id`: kotlin.String, `oggBytes`: kotlin.ByteArray)
+    
+    /**
+     * Admin only (our identity key must be the roster admin's): publishes a new
+     * roster of trusted identity keys, at most 40 of 32 bytes each. Every peer we
+     * have agreed keys with gets it now, later ones once key agreement completes.
+     * `version` must be higher than the current one.
+     */
+    fun `publishRoster`(`version`: kotlin.UInt, `keys`: List<kotlin.ByteArray>)
+    
+    fun `pushIncomingPacket`(`data`: kotlin.ByteArray)
+    
+    /**
+     * Pull mode: renders the next `num_frames` samples of the receive mix.
+     * Returns silence if no pull session is active.
+     */
+    fun `readRenderFrames`(`numFrames`: kotlin.UInt): List<kotlin.Short>
+    
+    fun `releaseResources`()
+    
+    /**
+     * Ends an attenuation window early (or closes an open-ended one). The gain ramps back up.
+     */
+    fun `removeAttenuationWindow`(`windowId`: kotlin.UInt)
+    
+    /**
+     * Same as `reopen_output_stream` for the input stream.
+     */
+    fun `reopenInputStream`(`reason`: kotlin.String): kotlin.Boolean
+    
+    /**
+     * Closes and reopens the output stream, if one is running (false if not).
+     * Everything else in the session carries on.
+     */
+    fun `reopenOutputStream`(`reason`: kotlin.String): kotlin.Boolean
+    
+    /**
+     * Restores a token from `export_session_token` (call before starting a session).
+     * The sequence number skips ahead and the epoch is bumped, so packets sent after
+     * the export are never repeated and no nonce is reused. The channels are
+     * restored as `set_active_channel`, `set_scan_channels` and
+     * `set_priority_channel` would.
+     */
+    fun `resumeSession`(`token`: kotlin.ByteArray)
+    
+    /**
+     * Runs the pipeline offline as fast as the CPU allows: our capture chain with the
+     * current DSP settings plus decoding, resampling and mixing of `peers` (1-64)
+     * synthetic talkers, for `seconds` (1-60) of audio. Blocks the caller until done,
+     * so call it off the UI thread; a running session is not touched. Apps can run
+     * it once per device to pick safe peer caps and DSP settings.
+     */
+    fun `runBenchmark`(`peers`: kotlin.UInt, `seconds`: kotlin.UInt): BenchmarkReport
+    
+    /**
+     * The transmissions in the archive of the last `start_archive` that overlap
+     * `from_ms..to_ms` (Unix time), from `peer_id` or anyone, oldest first.
+     */
+    fun `searchArchive`(`peerId`: kotlin.UInt?, `fromMs`: kotlin.ULong, `toMs`: kotlin.ULong): List<ArchiveSegment>
+    
+    /**
+     * Moves us to talkgroup `channel`, so several groups can share one transport.
+     * Our audio is tagged with it, and audio tagged with another channel is dropped
+     * on arrival (unless scanned, see `set_scan_channels`). Channel 0 is the
+     * default and goes out with the v2 header, so builds without channels still
+     * hear it. Control messages are shared by all channels, except that each
+     * channel has its own floor: one we hold is released, and one is requested on
+     * the new channel while PTT is held.
+     */
+    fun `setActiveChannel`(`channel`: kotlin.UShort)
+    
+    /**
+     * Registers the listener for per-peer levels, for speaking indicators.
+     */
+    fun `setAudioLevelCallback`(`callback`: AudioLevelCallback)
+    
+    fun `setAudioLoopCallback`(`callback`: AudioLoopCallback)
+    
+    /**
+     * Registers the listener told which route the engine picked.
+     */
+    fun `setAudioRouteCallback`(`callback`: AudioRouteCallback)
+    
+    /**
+     * Data saver: caps what we send to `total_kbps`, packet overhead included.
+     * Redundant frames go first, then the bitrate comes down (to at least 6kbps),
+     * and in-band FEC goes once the bitrate is too low to spare it. Emergency
+     * transmissions ignore the budget. What we receive can't be capped from
+     * here, but is reported alongside (`get_bandwidth_usage`). 0 removes the budget.
+     */
+    fun `setBandwidthBudgetKbps`(`totalKbps`: kotlin.UInt)
+    
+    /**
+     * Registers the listener told when to start and stop Bluetooth SCO.
+     */
+    fun `setBluetoothScoCallback`(`callback`: BluetoothScoCallback)
+    
+    /**
+     * Changes a DSP tunable on the fly (e.g. "agc.target_level_db", "jitter.max_packets").
+     * Fails with `InvalidParameter` for unknown names or out-of-range values.
+     */
+    fun `setDspParam`(`name`: kotlin.String, `value`: kotlin.Float)
+    
+    /**
+     * Registers the listener notified after every successful `set_dsp_param`.
+     */
+    fun `setDspParamCallback`(`callback`: DspParamCallback)
+    
+    fun `setEmergencyCallback`(`callback`: EmergencyCallback)
+    
+    /**
+     * Changes the Opus bitrate of the running encoder, e.g. 8-12 kbps on a poor link
+     * or 32 kbps on Wi-Fi, without restarting the session. Takes effect from the next
+     * frame and is kept in the config. 0 lets the encoder choose.
+     */
+    fun `setEncoderBitrate`(`bitsPerSec`: kotlin.Int)
+    
+    /**
+     * Registers the listener told when we get, miss or lose the floor.
+     */
+    fun `setFloorCallback`(`callback`: FloorCallback)
+    
+    /**
+     * Sets the 32-byte group key: audio is encrypted end-to-end (ChaCha20-Poly1305)
+     * and unencrypted packets are rejected. An empty key turns encryption off.
+     */
+    fun `setGroupKey`(`key`: kotlin.ByteArray)
+    
+    /**
+     * Loads our long-term X25519 identity key (from `generate_identity_key`) and
+     * announces it. Peers then agree on per-peer keys and our audio is encrypted
+     * under a fresh session key only they receive; audio from peers we have no
+     * key for yet is dropped. Returns our public identity key. An empty key
+     * turns key agreement off (the group key, if any, applies again).
+     */
+    fun `setIdentityKey`(`key`: kotlin.ByteArray): kotlin.ByteArray
+    
+    /**
+     * Same as `set_output_device` for the mic: only the input stream is
+     * reopened, so peers keep playing.
+     */
+    fun `setInputDevice`(`deviceId`: kotlin.Int)
+    
+    /**
+     * Gain applied to our outgoing audio right before encoding (after AGC), in dB,
+     * clamped to MIN_INPUT_GAIN_DB..=MAX_INPUT_GAIN_DB. 0 = unchanged. Lets
+     * operators even out transmit loudness across different devices.
+     */
+    fun `setInputGain`(`db`: kotlin.Float)
+    
+    /**
+     * Sets the position attached to our transmissions (None stops sending it).
+     */
+    fun `setLocalPosition`(`position`: PeerPosition?)
+    
+    /**
+     * Our priority, sent with our audio (0, the default, is left out). Receivers
+     * with `AudioConfig.preemption` duck or mute lower-priority speakers while we
+     * talk, so e.g. a dispatcher always gets through.
+     */
+    fun `setLocalPriority`(`priority`: kotlin.UByte)
+    
+    /**
+     * Self-monitor: our own encoded packets are also fed straight into the receive
+     * pipeline, where they play out as a peer with our own node ID. Checks mic,
+     * codec, encryption and playout end-to-end without a second device (use a
+     * headset, or the speaker feeds back into the mic). Turning it off flushes
+     * what is still buffered.
+     */
+    fun `setLoopbackEnabled`(`enabled`: kotlin.Boolean)
+    
+    /**
+     * Fails with `QuotaExceeded` (leaving the mic off) once the transmit quota is used up.
+     */
+    fun `setMicEnabled`(`enabled`: kotlin.Boolean)
+    
+    /**
+     * Moves playback to device `device_id` (0 = back to the route
+     * preference / system default) without stopping the session: only the
+     * output stream is reopened, so the mic keeps sending. If the new device
+     * fails to open, the previous one is restored and `DeviceError` returned.
+     */
+    fun `setOutputDevice`(`deviceId`: kotlin.Int)
+    
+    /**
+     * Registers the listener for the output watchdog (see `AudioConfig.output_watchdog`).
+     */
+    fun `setOutputHealthCallback`(`callback`: OutputHealthCallback)
+    
+    /**
+     * Master volume for everything we play (peers and alerts). 1.0 = unity.
+     */
+    fun `setOutputVolume`(`volume`: kotlin.Float)
+    
+    /**
+     * How `node_id`'s lost frames are filled in. `Repeat` or `Silence` keep
+     * background channels from spending decoder time the current speaker needs.
+     * Kept across sessions; `Auto` (the default) clears it.
+     */
+    fun `setPeerConcealment`(`nodeId`: kotlin.UInt, `mode`: ConcealmentMode)
+    
+    /**
+     * Registers the listener for peer talk/timeout events (who is speaking).
+     */
+    fun `setPeerEventCallback`(`callback`: PeerEventCallback)
+    
+    /**
+     * Registers the listener told when a peer's identity key has been verified.
+     */
+    fun `setPeerKeyCallback`(`callback`: PeerKeyCallback)
+    
+    /**
+     * Registers the listener for peer positions, delivered in sync with their audio.
+     */
+    fun `setPeerPositionCallback`(`callback`: PeerPositionCallback)
+    
+    /**
+     * Overrides the priority `node_id` announces, for our own mix only. `None`
+     * goes back to the announced one. Kept across sessions.
+     */
+    fun `setPeerPriority`(`nodeId`: kotlin.UInt, `priority`: kotlin.UByte?)
+    
+    /**
+     * Scans like a radio with a priority channel: the other channels we listen to
+     * (the active one and the scan list) rank equally, so the mixer locks onto
+     * whichever one someone starts talking on and stays there until it goes quiet,
+     * but `channel` takes over whenever someone talks on it. It must be the active
+     * channel or a scanned one to be heard. `None` goes back to the scan list's order.
+     * `ScanCallback.on_scan_channel_changed` reports what is being played.
+     */
+    fun `setPriorityChannel`(`channel`: kotlin.UShort?)
+    
+    /**
+     * Engine-wide data minimization. While enabled the engine attaches no
+     * position metadata to outgoing audio, keeps no talk history, wipes keys when
+     * the session stops, and only logs warnings and errors (its own lines only; the
+     * process-wide log level is left to the app).
+     * Anything that would persist or record activity must check `is_privacy_mode`.
+     */
+    fun `setPrivacyMode`(`enabled`: kotlin.Boolean)
+    
+    /**
+     * Silences incoming peer audio without stopping the output stream.
+     * Alerts still play; peers keep being decoded so unmuting is instant.
+     */
+    fun `setReceiveMuted`(`muted`: kotlin.Boolean)
+    
+    /**
+     * Sets the identity key of the admin node, the only one allowed to publish the
+     * trusted-peer roster. An empty key clears it. Changing the admin drops the
+     * current roster.
+     */
+    fun `setRosterAdmin`(`adminIdentityKey`: kotlin.ByteArray)
+    
+    /**
+     * Registers the listener told when a newer roster arrives.
+     */
+    fun `setRosterCallback`(`callback`: RosterCallback)
+    
+    /**
+     * Order in which the engine tries routes when picking devices (first connected
+     * one wins, the system default if none is). A nonzero `input_device_id` /
+     * `output_device_id` in the config still overrides it. Applies immediately.
+     */
+    fun `setRoutePreference`(`order`: List<AudioRoute>)
+    
+    fun `setScanCallback`(`callback`: ScanCallback)
+    
+    /**
+     * Listens to `channels` besides the active one, in priority order (after the
+     * active channel, the first is highest). Audio from the highest-ranked channel
+     * where someone is talking plays; the others are monitored but muted, and keep
+     * decoding so a channel that takes over comes in mid-sentence.
+     * `ScanCallback.on_scan_activity` reports when a scanned channel becomes active.
+     * We still transmit on the active channel only. An empty list stops scanning.
+     */
+    fun `setScanChannels`(`channels`: List<kotlin.UShort>)
+    
+    /**
+     * Lets the user hear their own mic while transmitting (headset users
+     * otherwise hear nothing of themselves). `gain` is relative to the captured
+     * level, 0.0-1.0; 0 turns the sidetone off. It bypasses the send pipeline's
+     * frame assembly and DSP, so it lags by about one audio buffer.
+     */
+    fun `setSidetoneGain`(`gain`: kotlin.Float)
+    
+    /**
+     * Registers the listener told when a transmission actually starts.
+     */
+    fun `setTransmitCallback`(`callback`: TransmitCallback)
+    
+    /**
+     * Installs the app's per-frame transmit policy, replacing any previous one.
+     * Frames it rejects are dropped before they get a sequence number.
+     */
+    fun `setTransmitGate`(`gate`: TransmitGate)
+    
+    /**
+     * Caps how much audio this session may transmit, counted like
+     * `EngineStats.session_transmit_seconds` (frames actually sent). Once it is used up
+     * the engine stops transmitting and `set_mic_enabled(true)` fails with
+     * `QuotaExceeded`. Usage starts over with every session, so apps on a metered
+     * plan set what is left of it. 0 removes the quota.
+     */
+    fun `setTransmitQuota`(`seconds`: kotlin.UInt)
+    
+    /**
+     * Registers the listener told when the app's `send_packet` blocks.
+     */
+    fun `setTransportHealthCallback`(`callback`: TransportHealthCallback)
+    
+    /**
+     * Registers the listener for local speaking/silent transitions (requires `vad_enabled`).
+     */
+    fun `setVoiceActivityCallback`(`callback`: VoiceActivityCallback)
+    
+    /**
+     * Ends the session and stops the engine's own threads: the transport thread,
+     * the UDP receive thread and the callback thread. Each gets up to 1s; a
+     * `send_packet` or callback still running after that is left to finish on its
+     * own. Nothing is sent afterwards, and starting a session fails with `ShutDown`.
+     * Dropping the engine does the same; this releases the threads at a known point
+     * while the app may still hold a reference.
+     */
+    fun `shutdown`()
+    
+    /**
+     * Starts archiving the channel to the directory `dir`, which is created if
+     * needed. Only voice activity is kept: the received mix, taken as for
+     * `start_recording`, goes to a new Ogg/Opus segment file whenever a peer
+     * starts talking while nobody else does, and the segment ends when the last
+     * talker stops. Each transmission is indexed for `search_archive`; an
+     * archive from an earlier session is continued. The same privacy rules as
+     * `start_recording` apply.
+     */
+    fun `startArchive`(`dir`: kotlin.String)
+    
+    /**
+     * Starts transmitting an emergency at once: the mic opens without a floor
+     * request or talk permit tone and stays open regardless of floor control and
+     * the transmit quota. The encoder switches to its highest bitrate and most FEC,
+     * and every packet carries the emergency flag, so receivers fire
+     * `EmergencyCallback.on_emergency_received` and play it above every priority.
+     * Lasts until `stop_emergency_transmission`.
+     */
+    fun `startEmergencyTransmission`()
+    
+    fun `startInputStream`()
+    
+    /**
+     * Starts capturing our own outgoing audio to a 16-bit WAV file at `path`, as
+     * it goes into the encoder (after echo cancellation, noise suppression and AGC;
+     * only while transmitting). Replaces any running mic recording. Written on a
+     * writer thread. The same privacy rules as `start_recording` apply.
+     */
+    fun `startMicRecording`(`path`: kotlin.String)
+    
+    fun `startOutputStream`()
+    
+    /**
+     * Starts the session WITHOUT opening any audio device.
+     * The host owns the audio graph and drives I/O through
+     * `submit_capture_frames` and `read_render_frames`.
+     */
+    fun `startPullSession`()
+    
+    /**
+     * Starts archiving the received mix (all peers, before receive mute, ducking
+     * and alerts) to an Ogg/Opus file at `path`, replacing any running recording.
+     * Encoding happens on a writer thread. Refused in privacy mode; enabling it,
+     * stopping the session or changing the sample rate ends the recording.
+     */
+    fun `startRecording`(`path`: kotlin.String)
+    
+    /**
+     * Opens the second output (`AudioConfig.secondary_output`), if configured.
+     * A device that fails to open is logged and left out: the main output and
+     * the rest of the session don't depend on it.
+     */
+    fun `startSecondaryOutputStream`()
+    
+    /**
+     * Starts BOTH Input and Output streams.
+     * Call this when joining a group.
+     */
+    fun `startSession`()
+    
+    /**
+     * Starts capturing a voice message, for when the recipient is offline. The mic
+     * is processed as for transmission (AEC, NS, AGC) whether or not it is enabled,
+     * but nothing is sent. A writer thread encodes it into an in-memory Ogg/Opus
+     * blob of at most VOICE_MESSAGE_MAX_MS. Discards a message already being
+     * captured. The same privacy rules as `start_recording` apply.
+     */
+    fun `startVoiceMessage`()
+    
+    /**
+     * Closes the segment being written and indexes it (returns once that is done).
+     */
+    fun `stopArchive`()
+    
+    /**
+     * Ends an emergency transmission and turns the mic off, like releasing PTT.
+     */
+    fun `stopEmergencyTransmission`()
+    
+    /**
+     * Finishes the file of a running mic recording (returns once it is complete).
+     */
+    fun `stopMicRecording`()
+    
+    /**
+     * Finishes the file of a running recording (returns once it is complete).
+     */
+    fun `stopRecording`()
+    
+    fun `stopSecondaryOutputStream`()
+    
+    /**
+     * Stops BOTH streams.
+     * Call this when leaving a group. In privacy mode all keys are wiped as well
+     * (see `clear_keys`).
+     */
+    fun `stopSession`()
+    
+    /**
+     * Pull mode: feeds captured mono PCM (at `config.sample_rate`) into the send path.
+     */
+    fun `submitCaptureFrames`(`frames`: List<kotlin.Short>)
+    
+    fun `unblockPeer`(`nodeId`: kotlin.UInt)
+    
+    fun `unloadClip`(`id`: kotlin.String)
+    
+    fun `unmutePeer`(`nodeId`: kotlin.UInt)
+    
+    /**
+     * Applies a new config to the running session without stopping it.
+     * Encoder settings (bitrate, frame size), DSP toggles and jitter targets are
+     * swapped in by the audio callbacks themselves. Only a sample rate or device
+     * change reopens the affected stream(s), which is briefly audible.
+     * A config `new` would refuse is refused here too, leaving the old one in place.
+     */
+    fun `updateConfig`(`config`: AudioConfig)
+    
+    companion object
+}
+
+open class AudioEngine: Disposable, AutoCloseable, AudioEngineInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+    /**
+     * Fails with `InvalidParameter` (reason logged) for a config the engine
+     * can't run, see `ConfigBuilder::validate`.
+     */
+    constructor(`config`: AudioConfig, `transport`: PacketTransport, `callback`: AudioErrorCallback, `ownNodeId`: kotlin.UInt) :
+        this(UniffiWithHandle, 
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_constructor_audioengine_new(
+    
+        FfiConverterTypeAudioConfig.lower(`config`),FfiConverterTypePacketTransport.lower(`transport`),FfiConverterTypeAudioErrorCallback.lower(`callback`),FfiConverterUInt.lower(`ownNodeId`),_status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_walkie_talkie_engine_fn_free_audioengine(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_walkie_talkie_engine_fn_clone_audioengine(handle, status)
+        }
+    }
+
+    
+    /**
+     * Dips the channel audio by `depth_db` for `duration_ms`, starting `start_in_ms` from now
+     * (e.g. while the app plays a navigation prompt). A `duration_ms` of 0 keeps the window
+     * open until `remove_attenuation_window`. Returns a handle for the window.
+     */override fun `addAttenuationWindow`(`depthDb`: kotlin.Float, `startInMs`: kotlin.UInt, `durationMs`: kotlin.UInt): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_add_attenuation_window(
+        it,
+        FfiConverterFloat.lower(`depthDb`),FfiConverterUInt.lower(`startInMs`),FfiConverterUInt.lower(`durationMs`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Applies a whole set of DSP tunables atomically: either every value is valid and
+     * all are applied, or nothing changes. The snapshot then runs on probation and is
+     * rolled back automatically if playout stalls spike.
+     */
+    @Throws(AudioException::class)override fun `applyParamSnapshot`(`snapshot`: Map<kotlin.String, kotlin.Float>)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_apply_param_snapshot(
+        it,
+        FfiConverterMapStringFloat.lower(`snapshot`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Drops audio from `node_id` as it arrives, before it is decrypted or takes
+     * jitter buffer memory, and flushes what is already buffered. Its control
+     * messages (floor, keys, roster) still get through. Counted in
+     * `EngineStats.packets_blocked`. Kept across sessions.
+     */override fun `blockPeer`(`nodeId`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_block_peer(
+        it,
+        FfiConverterUInt.lower(`nodeId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Wipes all key material: the group key, our identity key with every key agreed
+     * with peers, and the roster with its admin. Audio goes out unencrypted until
+     * keys are set again. `stop_session` does this by itself in privacy mode.
+     */override fun `clearKeys`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_clear_keys(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Removes the transmit policy: every encoded frame is sent again.
+     */override fun `clearTransmitGate`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_clear_transmit_gate(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Memory currently held by pre-decoded clips, in bytes.
+     */override fun `clipCacheBytes`(): kotlin.ULong {
+            return FfiConverterULong.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_clip_cache_bytes(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * DEBUG: holds back `node_id`'s packets for `ms` before they reach its jitter
+     * buffer, to demo one participant on a terrible link. 0 removes the delay.
+     */override fun `debugSetPeerDelay`(`nodeId`: kotlin.UInt, `ms`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_debug_set_peer_delay(
+        it,
+        FfiConverterUInt.lower(`nodeId`),FfiConverterUInt.lower(`ms`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * The current config as the engine applies it, including which profile it came from.
+     */override fun `describeEffectiveConfig`(): EffectiveConfig {
+            return FfiConverterTypeEffectiveConfig.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_describe_effective_config(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Every processing stage in signal order with its live settings, for tooling
+     * and support to see exactly what runs on this device.
+     */override fun `describePipeline`(): PipelineGraph {
+            return FfiConverterTypePipelineGraph.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_describe_pipeline(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Cuts a transmission found by `search_archive` out of its segment, as an
+     * Ogg/Opus file like a voice message (`play_voice_message` plays it).
+     */
+    @Throws(AudioException::class)override fun `exportArchiveClip`(`segment`: ArchiveSegment): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_export_archive_clip(
+        it,
+        FfiConverterTypeArchiveSegment.lower(`segment`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Snapshot of the state a restarted process needs to carry on as the same node:
+     * node ID, sequence counter, encryption epoch (no key material), and the active,
+     * scanned and priority channels. Refused in privacy mode, since the app would
+     * have to persist it.
+     */
+    @Throws(AudioException::class)override fun `exportSessionToken`(): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_export_session_token(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Ends the voice message and returns it as an Ogg/Opus file, ready for
+     * `play_voice_message` on the recipient's side.
+     */
+    @Throws(AudioException::class)override fun `finishVoiceMessage`(): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_finish_voice_message(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Discards the packets buffered for `node_id` and restarts its playout, e.g.
+     * when signaling says the peer restarted or seeked, instead of playing out
+     * stale audio. Its next packet starts a new talk burst. Stats are kept.
+     */override fun `flushPeer`(`nodeId`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_flush_peer(
+        it,
+        FfiConverterUInt.lower(`nodeId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Current consumption and what the budget leaves the sender. Rates are
+     * measured between calls, so poll this (every second or so) for a live figure.
+     */override fun `getBandwidthUsage`(): BandwidthUsage {
+            return FfiConverterTypeBandwidthUsage.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_bandwidth_usage(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Returns the current value of a DSP tunable, or `None` if the name is unknown.
+     */override fun `getDspParam`(`name`: kotlin.String): kotlin.Float? {
+            return FfiConverterOptionalFloat.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_dsp_param(
+        it,
+        FfiConverterString.lower(`name`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * The node holding the floor (us included), if any (`AudioConfig.floor_control`).
+     */override fun `getFloorHolder`(): kotlin.UInt? {
+            return FfiConverterOptionalUInt.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_floor_holder(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Reports our software gain next to what the platform does to the mic signal
+     * before we get it, which `set_input_gain` can't see or undo.
+     */override fun `getInputGainReport`(): InputGainReport {
+            return FfiConverterTypeInputGainReport.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_input_gain_report(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * The latest mic level, for polling instead of `AudioLevelCallback`. None
+     * until a session has captured some audio.
+     */override fun `getMicLevel`(): MicLevel? {
+            return FfiConverterOptionalTypeMicLevel.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_mic_level(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * What each peer announced it supports (announced at session start, so
+     * features can be turned on only once everyone understands them). Peers
+     * silent for 3s are left out until they are heard again.
+     */override fun `getPeerCapabilities`(): List<PeerCapabilities> {
+            return FfiConverterSequenceTypePeerCapabilities.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_peer_capabilities(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * The latest per-peer levels, for polling instead of `AudioLevelCallback`.
+     */override fun `getPeerLevels`(): List<PeerLevel> {
+            return FfiConverterSequenceTypePeerLevel.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_peer_levels(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Up to the last `seconds` of the received mix (see `AudioConfig.replay_buffer_secs`),
+     * as mono PCM at the session sample rate. Taken before receive mute and ducking.
+     */override fun `getReplay`(`seconds`: kotlin.UInt): List<kotlin.Short> {
+            return FfiConverterSequenceShort.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_replay(
+        it,
+        FfiConverterUInt.lower(`seconds`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Counts of what the engines in this process hold (see `ResourceReport`), so
+     * long-running deployments can check for slow leaks across sessions.
+     */override fun `getResourceReport`(): ResourceReport {
+            return FfiConverterTypeResourceReport.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_resource_report(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * The current roster (version 0 and no keys until one is published or received).
+     */override fun `getRoster`(): PeerRoster {
+            return FfiConverterTypePeerRoster.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_roster(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Connection quality: per-peer loss/jitter/PLC plus global traffic totals.
+     */override fun `getStats`(): EngineStats {
+            return FfiConverterTypeEngineStats.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_get_stats(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `isPrivacyMode`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_is_privacy_mode(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `isSessionActive`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_is_session_active(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Silences `node_id` in our mix. Its audio is still received and decoded
+     * (talk events and stats keep coming), so unmuting is instant. Kept across
+     * sessions; see `block_peer` to drop it instead.
+     */override fun `mutePeer`(`nodeId`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_mute_peer(
+        it,
+        FfiConverterUInt.lower(`nodeId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Call when the device switches networks (e.g. Wi-Fi to cellular). For a grace
+     * period the jitter buffer aims deeper and the encoder adds more in-band FEC,
+     * so the burst of late and lost packets around the switch doesn't break up the
+     * audio; afterwards both go back to normal and the buffer drains back to its
+     * usual depth. A jitter target changed by the app meanwhile is left alone.
+     */override fun `notifyNetworkChange`(`kind`: NetworkKind)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_notify_network_change(
+        it,
+        FfiConverterTypeNetworkKind.lower(`kind`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Call when Android reports audio devices added or removed. Re-walks the route
+     * preference and reopens the streams if a different device is now preferred.
+     */
+    @Throws(AudioException::class)override fun `notifyRouteChanged`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_notify_route_changed(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * A PTT key edge from an accessory (wired headset button, Bluetooth remote)
+     * or the app's own button, so every entry point behaves alike. Bounces and
+     * key repeats are filtered out, and `AudioConfig.ptt_toggle` turns taps into
+     * on/off toggles. Drives `set_mic_enabled`, whose errors it returns (an edge
+     * that arrives during the debounce window is applied later, and its errors
+     * are only logged).
+     */
+    @Throws(AudioException::class)override fun `onAccessoryPtt`(`pressed`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_on_accessory_ptt(
+        it,
+        FfiConverterBoolean.lower(`pressed`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Plays a preloaded clip on top of the channel mix (not affected by ducking).
+     */
+    @Throws(AudioException::class)override fun `playAlert`(`id`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_play_alert(
+        it,
+        FfiConverterString.lower(`id`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Plays back up to the last `seconds` of the received mix, like an alert.
+     */
+    @Throws(AudioException::class)override fun `playReplay`(`seconds`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_play_replay(
+        it,
+        FfiConverterUInt.lower(`seconds`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Plays a `freq_hz` tone for `duration_ms` (up to TEST_TONE_MAX_MS), so a settings
+     * screen can let the user confirm which speaker or headset the engine uses. With
+     * no `route` it goes where the session plays: through the running output stream,
+     * or outside a session to the device the route preference picks. A given `route`
+     * must be connected. Returns the route the tone plays on, if known.
+     */
+    @Throws(AudioException::class)override fun `playTestTone`(`route`: AudioRoute?, `freqHz`: kotlin.Float, `durationMs`: kotlin.UInt): AudioRoute? {
+            return FfiConverterOptionalTypeAudioRoute.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_play_test_tone(
+        it,
+        FfiConverterOptionalTypeAudioRoute.lower(`route`),FfiConverterFloat.lower(`freqHz`),FfiConverterUInt.lower(`durationMs`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Decodes a voice message and plays it on top of the channel mix, like an alert.
+     * The channel is ducked by `AudioConfig.voice_message_duck_db` for exactly as
+     * long as the message plays.
+     */
+    @Throws(AudioException::class)override fun `playVoiceMessage`(`oggBytes`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_play_voice_message(
+        it,
+        FfiConverterByteArray.lower(`oggBytes`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Decodes an Ogg/Opus alert clip and caches its PCM so `play_alert` starts instantly.
+     * Re-using an `id` replaces the previous clip. Older clips are evicted if the cache is full.
+     */
+    @Throws(AudioException::class)override fun `preloadClip`(`id`: kotlin.String, `oggBytes`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_preload_clip(
+        it,
+        FfiConverterString.lower(`id`),FfiConverterByteArray.lower(`oggBytes`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Admin only (our identity key must be the roster admin's): publishes a new
+     * roster of trusted identity keys, at most 40 of 32 bytes each. Every peer we
+     * have agreed keys with gets it now, later ones once key agreement completes.
+     * `version` must be higher than the current one.
+     */
+    @Throws(AudioException::class)override fun `publishRoster`(`version`: kotlin.UInt, `keys`: List<kotlin.ByteArray>)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_publish_roster(
+        it,
+        FfiConverterUInt.lower(`version`),FfiConverterSequenceByteArray.lower(`keys`),_status)
+}
+    }
+    
+    
+
+    override fun `pushIncomingPacket`(`data`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_push_incoming_packet(
+        it,
+        FfiConverterByteArray.lower(`data`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Pull mode: renders the next `num_frames` samples of the receive mix.
+     * Returns silence if no pull session is active.
+     */override fun `readRenderFrames`(`numFrames`: kotlin.UInt): List<kotlin.Short> {
+            return FfiConverterSequenceShort.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_read_render_frames(
+        it,
+        FfiConverterUInt.lower(`numFrames`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `releaseResources`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_release_resources(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Ends an attenuation window early (or closes an open-ended one). The gain ramps back up.
+     */override fun `removeAttenuationWindow`(`windowId`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_remove_attenuation_window(
+        it,
+        FfiConverterUInt.lower(`windowId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Same as `reopen_output_stream` for the input stream.
+     */
+    @Throws(AudioException::class)override fun `reopenInputStream`(`reason`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_reopen_input_stream(
+        it,
+        FfiConverterString.lower(`reason`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Closes and reopens the output stream, if one is running (false if not).
+     * Everything else in the session carries on.
+     */
+    @Throws(AudioException::class)override fun `reopenOutputStream`(`reason`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_reopen_output_stream(
+        it,
+        FfiConverterString.lower(`reason`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Restores a token from `export_session_token` (call before starting a session).
+     * The sequence number skips ahead and the epoch is bumped, so packets sent after
+     * the export are never repeated and no nonce is reused. The channels are
+     * restored as `set_active_channel`, `set_scan_channels` and
+     * `set_priority_channel` would.
+     */
+    @Throws(AudioException::class)override fun `resumeSession`(`token`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_resume_session(
+        it,
+        FfiConverterByteArray.lower(`token`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Runs the pipeline offline as fast as the CPU allows: our capture chain with the
+     * current DSP settings plus decoding, resampling and mixing of `peers` (1-64)
+     * synthetic talkers, for `seconds` (1-60) of audio. Blocks the caller until done,
+     * so call it off the UI thread; a running session is not touched. Apps can run
+     * it once per device to pick safe peer caps and DSP settings.
+     */
+    @Throws(AudioException::class)override fun `runBenchmark`(`peers`: kotlin.UInt, `seconds`: kotlin.UInt): BenchmarkReport {
+            return FfiConverterTypeBenchmarkReport.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_run_benchmark(
+        it,
+        FfiConverterUInt.lower(`peers`),FfiConverterUInt.lower(`seconds`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * The transmissions in the archive of the last `start_archive` that overlap
+     * `from_ms..to_ms` (Unix time), from `peer_id` or anyone, oldest first.
+     */override fun `searchArchive`(`peerId`: kotlin.UInt?, `fromMs`: kotlin.ULong, `toMs`: kotlin.ULong): List<ArchiveSegment> {
+            return FfiConverterSequenceTypeArchiveSegment.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_search_archive(
+        it,
+        FfiConverterOptionalUInt.lower(`peerId`),FfiConverterULong.lower(`fromMs`),FfiConverterULong.lower(`toMs`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Moves us to talkgroup `channel`, so several groups can share one transport.
+     * Our audio is tagged with it, and audio tagged with another channel is dropped
+     * on arrival (unless scanned, see `set_scan_channels`). Channel 0 is the
+     * default and goes out with the v2 header, so builds without channels still
+     * hear it. Control messages are shared by all channels, except that each
+     * channel has its own floor: one we hold is released, and one is requested on
+     * the new channel while PTT is held.
+     */override fun `setActiveChannel`(`channel`: kotlin.UShort)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_active_channel(
+        it,
+        FfiConverterUShort.lower(`channel`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener for per-peer levels, for speaking indicators.
+     */override fun `setAudioLevelCallback`(`callback`: AudioLevelCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_audio_level_callback(
+        it,
+        FfiConverterTypeAudioLevelCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    override fun `setAudioLoopCallback`(`callback`: AudioLoopCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_audio_loop_callback(
+        it,
+        FfiConverterTypeAudioLoopCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told which route the engine picked.
+     */override fun `setAudioRouteCallback`(`callback`: AudioRouteCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_audio_route_callback(
+        it,
+        FfiConverterTypeAudioRouteCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Data saver: caps what we send to `total_kbps`, packet overhead included.
+     * Redundant frames go first, then the bitrate comes down (to at least 6kbps),
+     * and in-band FEC goes once the bitrate is too low to spare it. Emergency
+     * transmissions ignore the budget. What we receive can't be capped from
+     * here, but is reported alongside (`get_bandwidth_usage`). 0 removes the budget.
+     */override fun `setBandwidthBudgetKbps`(`totalKbps`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_bandwidth_budget_kbps(
+        it,
+        FfiConverterUInt.lower(`totalKbps`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told when to start and stop Bluetooth SCO.
+     */override fun `setBluetoothScoCallback`(`callback`: BluetoothScoCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_bluetooth_sco_callback(
+        it,
+        FfiConverterTypeBluetoothScoCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Changes a DSP tunable on the fly (e.g. "agc.target_level_db", "jitter.max_packets").
+     * Fails with `InvalidParameter` for unknown names or out-of-range values.
+     */
+    @Throws(AudioException::class)override fun `setDspParam`(`name`: kotlin.String, `value`: kotlin.Float)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_dsp_param(
+        it,
+        FfiConverterString.lower(`name`),FfiConverterFloat.lower(`value`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener notified after every successful `set_dsp_param`.
+     */override fun `setDspParamCallback`(`callback`: DspParamCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_dsp_param_callback(
+        it,
+        FfiConverterTypeDspParamCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    override fun `setEmergencyCallback`(`callback`: EmergencyCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_emergency_callback(
+        it,
+        FfiConverterTypeEmergencyCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Changes the Opus bitrate of the running encoder, e.g. 8-12 kbps on a poor link
+     * or 32 kbps on Wi-Fi, without restarting the session. Takes effect from the next
+     * frame and is kept in the config. 0 lets the encoder choose.
+     */
+    @Throws(AudioException::class)override fun `setEncoderBitrate`(`bitsPerSec`: kotlin.Int)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_encoder_bitrate(
+        it,
+        FfiConverterInt.lower(`bitsPerSec`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told when we get, miss or lose the floor.
+     */override fun `setFloorCallback`(`callback`: FloorCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_floor_callback(
+        it,
+        FfiConverterTypeFloorCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Sets the 32-byte group key: audio is encrypted end-to-end (ChaCha20-Poly1305)
+     * and unencrypted packets are rejected. An empty key turns encryption off.
+     */
+    @Throws(AudioException::class)override fun `setGroupKey`(`key`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_group_key(
+        it,
+        FfiConverterByteArray.lower(`key`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Loads our long-term X25519 identity key (from `generate_identity_key`) and
+     * announces it. Peers then agree on per-peer keys and our audio is encrypted
+     * under a fresh session key only they receive; audio from peers we have no
+     * key for yet is dropped. Returns our public identity key. An empty key
+     * turns key agreement off (the group key, if any, applies again).
+     */
+    @Throws(AudioException::class)override fun `setIdentityKey`(`key`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_identity_key(
+        it,
+        FfiConverterByteArray.lower(`key`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Same as `set_output_device` for the mic: only the input stream is
+     * reopened, so peers keep playing.
+     */
+    @Throws(AudioException::class)override fun `setInputDevice`(`deviceId`: kotlin.Int)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_input_device(
+        it,
+        FfiConverterInt.lower(`deviceId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Gain applied to our outgoing audio right before encoding (after AGC), in dB,
+     * clamped to MIN_INPUT_GAIN_DB..=MAX_INPUT_GAIN_DB. 0 = unchanged. Lets
+     * operators even out transmit loudness across different devices.
+     */override fun `setInputGain`(`db`: kotlin.Float)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_input_gain(
+        it,
+        FfiConverterFloat.lower(`db`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Sets the position attached to our transmissions (None stops sending it).
+     */override fun `setLocalPosition`(`position`: PeerPosition?)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_local_position(
+        it,
+        FfiConverterOptionalTypePeerPosition.lower(`position`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Our priority, sent with our audio (0, the default, is left out). Receivers
+     * with `AudioConfig.preemption` duck or mute lower-priority speakers while we
+     * talk, so e.g. a dispatcher always gets through.
+     */override fun `setLocalPriority`(`priority`: kotlin.UByte)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_local_priority(
+        it,
+        FfiConverterUByte.lower(`priority`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Self-monitor: our own encoded packets are also fed straight into the receive
+     * pipeline, where they play out as a peer with our own node ID. Checks mic,
+     * codec, encryption and playout end-to-end without a second device (use a
+     * headset, or the speaker feeds back into the mic). Turning it off flushes
+     * what is still buffered.
+     */override fun `setLoopbackEnabled`(`enabled`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_loopback_enabled(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Fails with `QuotaExceeded` (leaving the mic off) once the transmit quota is used up.
+     */
+    @Throws(AudioException::class)override fun `setMicEnabled`(`enabled`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_mic_enabled(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Moves playback to device `device_id` (0 = back to the route
+     * preference / system default) without stopping the session: only the
+     * output stream is reopened, so the mic keeps sending. If the new device
+     * fails to open, the previous one is restored and `DeviceError` returned.
+     */
+    @Throws(AudioException::class)override fun `setOutputDevice`(`deviceId`: kotlin.Int)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_output_device(
+        it,
+        FfiConverterInt.lower(`deviceId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener for the output watchdog (see `AudioConfig.output_watchdog`).
+     */override fun `setOutputHealthCallback`(`callback`: OutputHealthCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_output_health_callback(
+        it,
+        FfiConverterTypeOutputHealthCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Master volume for everything we play (peers and alerts). 1.0 = unity.
+     */override fun `setOutputVolume`(`volume`: kotlin.Float)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_output_volume(
+        it,
+        FfiConverterFloat.lower(`volume`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * How `node_id`'s lost frames are filled in. `Repeat` or `Silence` keep
+     * background channels from spending decoder time the current speaker needs.
+     * Kept across sessions; `Auto` (the default) clears it.
+     */override fun `setPeerConcealment`(`nodeId`: kotlin.UInt, `mode`: ConcealmentMode)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_concealment(
+        it,
+        FfiConverterUInt.lower(`nodeId`),FfiConverterTypeConcealmentMode.lower(`mode`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener for peer talk/timeout events (who is speaking).
+     */override fun `setPeerEventCallback`(`callback`: PeerEventCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_event_callback(
+        it,
+        FfiConverterTypePeerEventCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told when a peer's identity key has been verified.
+     */override fun `setPeerKeyCallback`(`callback`: PeerKeyCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_key_callback(
+        it,
+        FfiConverterTypePeerKeyCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener for peer positions, delivered in sync with their audio.
+     */override fun `setPeerPositionCallback`(`callback`: PeerPositionCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_position_callback(
+        it,
+        FfiConverterTypePeerPositionCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Overrides the priority `node_id` announces, for our own mix only. `None`
+     * goes back to the announced one. Kept across sessions.
+     */override fun `setPeerPriority`(`nodeId`: kotlin.UInt, `priority`: kotlin.UByte?)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_peer_priority(
+        it,
+        FfiConverterUInt.lower(`nodeId`),FfiConverterOptionalUByte.lower(`priority`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Scans like a radio with a priority channel: the other channels we listen to
+     * (the active one and the scan list) rank equally, so the mixer locks onto
+     * whichever one someone starts talking on and stays there until it goes quiet,
+     * but `channel` takes over whenever someone talks on it. It must be the active
+     * channel or a scanned one to be heard. `None` goes back to the scan list's order.
+     * `ScanCallback.on_scan_channel_changed` reports what is being played.
+     */override fun `setPriorityChannel`(`channel`: kotlin.UShort?)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_priority_channel(
+        it,
+        FfiConverterOptionalUShort.lower(`channel`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Engine-wide data minimization. While enabled the engine attaches no
+     * position metadata to outgoing audio, keeps no talk history, wipes keys when
+     * the session stops, and only logs warnings and errors (its own lines only; the
+     * process-wide log level is left to the app).
+     * Anything that would persist or record activity must check `is_privacy_mode`.
+     */override fun `setPrivacyMode`(`enabled`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_privacy_mode(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Silences incoming peer audio without stopping the output stream.
+     * Alerts still play; peers keep being decoded so unmuting is instant.
+     */override fun `setReceiveMuted`(`muted`: kotlin.Boolean)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_receive_muted(
+        it,
+        FfiConverterBoolean.lower(`muted`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Sets the identity key of the admin node, the only one allowed to publish the
+     * trusted-peer roster. An empty key clears it. Changing the admin drops the
+     * current roster.
+     */
+    @Throws(AudioException::class)override fun `setRosterAdmin`(`adminIdentityKey`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_roster_admin(
+        it,
+        FfiConverterByteArray.lower(`adminIdentityKey`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told when a newer roster arrives.
+     */override fun `setRosterCallback`(`callback`: RosterCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_roster_callback(
+        it,
+        FfiConverterTypeRosterCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Order in which the engine tries routes when picking devices (first connected
+     * one wins, the system default if none is). A nonzero `input_device_id` /
+     * `output_device_id` in the config still overrides it. Applies immediately.
+     */
+    @Throws(AudioException::class)override fun `setRoutePreference`(`order`: List<AudioRoute>)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_route_preference(
+        it,
+        FfiConverterSequenceTypeAudioRoute.lower(`order`),_status)
+}
+    }
+    
+    
+
+    override fun `setScanCallback`(`callback`: ScanCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_scan_callback(
+        it,
+        FfiConverterTypeScanCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Listens to `channels` besides the active one, in priority order (after the
+     * active channel, the first is highest). Audio from the highest-ranked channel
+     * where someone is talking plays; the others are monitored but muted, and keep
+     * decoding so a channel that takes over comes in mid-sentence.
+     * `ScanCallback.on_scan_activity` reports when a scanned channel becomes active.
+     * We still transmit on the active channel only. An empty list stops scanning.
+     */override fun `setScanChannels`(`channels`: List<kotlin.UShort>)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_scan_channels(
+        it,
+        FfiConverterSequenceUShort.lower(`channels`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Lets the user hear their own mic while transmitting (headset users
+     * otherwise hear nothing of themselves). `gain` is relative to the captured
+     * level, 0.0-1.0; 0 turns the sidetone off. It bypasses the send pipeline's
+     * frame assembly and DSP, so it lags by about one audio buffer.
+     */override fun `setSidetoneGain`(`gain`: kotlin.Float)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_sidetone_gain(
+        it,
+        FfiConverterFloat.lower(`gain`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told when a transmission actually starts.
+     */override fun `setTransmitCallback`(`callback`: TransmitCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_transmit_callback(
+        it,
+        FfiConverterTypeTransmitCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Installs the app's per-frame transmit policy, replacing any previous one.
+     * Frames it rejects are dropped before they get a sequence number.
+     */override fun `setTransmitGate`(`gate`: TransmitGate)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_transmit_gate(
+        it,
+        FfiConverterTypeTransmitGate.lower(`gate`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Caps how much audio this session may transmit, counted like
+     * `EngineStats.session_transmit_seconds` (frames actually sent). Once it is used up
+     * the engine stops transmitting and `set_mic_enabled(true)` fails with
+     * `QuotaExceeded`. Usage starts over with every session, so apps on a metered
+     * plan set what is left of it. 0 removes the quota.
+     */override fun `setTransmitQuota`(`seconds`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_transmit_quota(
+        it,
+        FfiConverterUInt.lower(`seconds`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener told when the app's `send_packet` blocks.
+     */override fun `setTransportHealthCallback`(`callback`: TransportHealthCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_transport_health_callback(
+        it,
+        FfiConverterTypeTransportHealthCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Registers the listener for local speaking/silent transitions (requires `vad_enabled`).
+     */override fun `setVoiceActivityCallback`(`callback`: VoiceActivityCallback)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_voice_activity_callback(
+        it,
+        FfiConverterTypeVoiceActivityCallback.lower(`callback`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Ends the session and stops the engine's own threads: the transport thread,
+     * the UDP receive thread and the callback thread. Each gets up to 1s; a
+     * `send_packet` or callback still running after that is left to finish on its
+     * own. Nothing is sent afterwards, and starting a session fails with `ShutDown`.
+     * Dropping the engine does the same; this releases the threads at a known point
+     * while the app may still hold a reference.
+     */override fun `shutdown`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_shutdown(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts archiving the channel to the directory `dir`, which is created if
+     * needed. Only voice activity is kept: the received mix, taken as for
+     * `start_recording`, goes to a new Ogg/Opus segment file whenever a peer
+     * starts talking while nobody else does, and the segment ends when the last
+     * talker stops. Each transmission is indexed for `search_archive`; an
+     * archive from an earlier session is continued. The same privacy rules as
+     * `start_recording` apply.
+     */
+    @Throws(AudioException::class)override fun `startArchive`(`dir`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_archive(
+        it,
+        FfiConverterString.lower(`dir`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts transmitting an emergency at once: the mic opens without a floor
+     * request or talk permit tone and stays open regardless of floor control and
+     * the transmit quota. The encoder switches to its highest bitrate and most FEC,
+     * and every packet carries the emergency flag, so receivers fire
+     * `EmergencyCallback.on_emergency_received` and play it above every priority.
+     * Lasts until `stop_emergency_transmission`.
+     */override fun `startEmergencyTransmission`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_emergency_transmission(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    @Throws(AudioException::class)override fun `startInputStream`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_input_stream(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts capturing our own outgoing audio to a 16-bit WAV file at `path`, as
+     * it goes into the encoder (after echo cancellation, noise suppression and AGC;
+     * only while transmitting). Replaces any running mic recording. Written on a
+     * writer thread. The same privacy rules as `start_recording` apply.
+     */
+    @Throws(AudioException::class)override fun `startMicRecording`(`path`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_mic_recording(
+        it,
+        FfiConverterString.lower(`path`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(AudioException::class)override fun `startOutputStream`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_output_stream(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts the session WITHOUT opening any audio device.
+     * The host owns the audio graph and drives I/O through
+     * `submit_capture_frames` and `read_render_frames`.
+     */
+    @Throws(AudioException::class)override fun `startPullSession`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_pull_session(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts archiving the received mix (all peers, before receive mute, ducking
+     * and alerts) to an Ogg/Opus file at `path`, replacing any running recording.
+     * Encoding happens on a writer thread. Refused in privacy mode; enabling it,
+     * stopping the session or changing the sample rate ends the recording.
+     */
+    @Throws(AudioException::class)override fun `startRecording`(`path`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_recording(
+        it,
+        FfiConverterString.lower(`path`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Opens the second output (`AudioConfig.secondary_output`), if configured.
+     * A device that fails to open is logged and left out: the main output and
+     * the rest of the session don't depend on it.
+     */override fun `startSecondaryOutputStream`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_secondary_output_stream(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts BOTH Input and Output streams.
+     * Call this when joining a group.
+     */
+    @Throws(AudioException::class)override fun `startSession`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_session(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Starts capturing a voice message, for when the recipient is offline. The mic
+     * is processed as for transmission (AEC, NS, AGC) whether or not it is enabled,
+     * but nothing is sent. A writer thread encodes it into an in-memory Ogg/Opus
+     * blob of at most VOICE_MESSAGE_MAX_MS. Discards a message already being
+     * captured. The same privacy rules as `start_recording` apply.
+     */
+    @Throws(AudioException::class)override fun `startVoiceMessage`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_voice_message(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Closes the segment being written and indexes it (returns once that is done).
+     */override fun `stopArchive`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_archive(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Ends an emergency transmission and turns the mic off, like releasing PTT.
+     */
+    @Throws(AudioException::class)override fun `stopEmergencyTransmission`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_emergency_transmission(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Finishes the file of a running mic recording (returns once it is complete).
+     */override fun `stopMicRecording`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_mic_recording(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Finishes the file of a running recording (returns once it is complete).
+     */override fun `stopRecording`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_recording(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    override fun `stopSecondaryOutputStream`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_secondary_output_stream(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Stops BOTH streams.
+     * Call this when leaving a group. In privacy mode all keys are wiped as well
+     * (see `clear_keys`).
+     */
+    @Throws(AudioException::class)override fun `stopSession`()
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_session(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Pull mode: feeds captured mono PCM (at `config.sample_rate`) into the send path.
+     */override fun `submitCaptureFrames`(`frames`: List<kotlin.Short>)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_submit_capture_frames(
+        it,
+        FfiConverterSequenceShort.lower(`frames`),_status)
+}
+    }
+    
+    
+
+    override fun `unblockPeer`(`nodeId`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_unblock_peer(
+        it,
+        FfiConverterUInt.lower(`nodeId`),_status)
+}
+    }
+    
+    
+
+    override fun `unloadClip`(`id`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_unload_clip(
+        it,
+        FfiConverterString.lower(`id`),_status)
+}
+    }
+    
+    
+
+    override fun `unmutePeer`(`nodeId`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_unmute_peer(
+        it,
+        FfiConverterUInt.lower(`nodeId`),_status)
+}
+    }
+    
+    
+
+    
+    /**
+     * Applies a new config to the running session without stopping it.
+     * Encoder settings (bitrate, frame size), DSP toggles and jitter targets are
+     * swapped in by the audio callbacks themselves. Only a sample rate or device
+     * change reopens the affected stream(s), which is briefly audible.
+     * A config `new` would refuse is refused here too, leaving the old one in place.
+     */
+    @Throws(AudioException::class)override fun `updateConfig`(`config`: AudioConfig)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_update_config(
+        it,
+        FfiConverterTypeAudioConfig.lower(`config`),_status)
+}
+    }
+    
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAudioEngine: FfiConverter<AudioEngine, Long> {
+    override fun lower(value: AudioEngine): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): AudioEngine {
+        return AudioEngine(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): AudioEngine {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: AudioEngine) = 8UL
+
+    override fun write(value: AudioEngine, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+//
+/**
+ * Builds an `AudioConfig` from a profile plus overrides:
+ * `ConfigBuilder(ConfigProfile.CELLULAR).echoCancellation(true).build()`.
+ */
+public interface ConfigBuilderInterface {
+    
+    fun `agcEnabled`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `bitrateBps`(`bps`: kotlin.Int): ConfigBuilder
+    
+    /**
+     * The finished config, or `InvalidParameter` (with the reason logged) if the
+     * engine couldn't run it. `validate` says why.
+     */
+    fun `build`(): AudioConfig
+    
+    fun `comfortNoiseDbfs`(`level`: kotlin.Float?): ConfigBuilder
+    
+    /**
+     * What the config built so far resolves to, see `AudioEngine::describe_effective_config`.
+     */
+    fun `describeEffectiveConfig`(): EffectiveConfig
+    
+    /**
+     * 0 lets the route preference (or the system) pick.
+     */
+    fun `devices`(`inputDeviceId`: kotlin.Int, `outputDeviceId`: kotlin.Int): ConfigBuilder
+    
+    fun `echoCancellation`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `floorControl`(`policy`: FloorPolicy, `priority`: kotlin.UByte): ConfigBuilder
+    
+    fun `frameSizeMs`(`ms`: kotlin.Int): ConfigBuilder
+    
+    fun `jitterBufferMs`(`ms`: kotlin.Int): ConfigBuilder
+    
+    fun `maxConcurrentSpeakers`(`speakers`: kotlin.UInt): ConfigBuilder
+    
+    fun `noiseSuppression`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `outputWatchdog`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `padPackets`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `pttPreRollMs`(`ms`: kotlin.UInt): ConfigBuilder
+    
+    fun `pttToggle`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `redundancy`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `rogerBeep`(`beep`: RogerBeep?): ConfigBuilder
+    
+    fun `routingMode`(`mode`: AudioRoutingMode): ConfigBuilder
+    
+    fun `sampleRate`(`hz`: kotlin.Int): ConfigBuilder
+    
+    /**
+     * A second device playing the channel, with its own gain. None = off.
+     */
+    fun `secondaryOutput`(`output`: SecondaryOutput?): ConfigBuilder
+    
+    fun `talkPermitTone`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `talkerIdChirp`(`enabled`: kotlin.Boolean): ConfigBuilder
+    
+    fun `vad`(`enabled`: kotlin.Boolean, `gateTransmit`: kotlin.Boolean): ConfigBuilder
+    
+    /**
+     * Why `build` would fail, as an ID to show the user in their own language.
+     */
+    fun `validate`(): ConfigIssue?
+    
+    companion object
+}
+
+/**
+ * Builds an `AudioConfig` from a profile plus overrides:
+ * `ConfigBuilder(ConfigProfile.CELLULAR).echoCancellation(true).build()`.
+ */
+open class ConfigBuilder: Disposable, AutoCloseable, ConfigBuilderInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+    /**
+     * Starts from `profile`, or from the engine defaults without one.
+     */
+    constructor(`profile`: ConfigProfile?) :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_constructor_configbuilder_new(
+    
+        FfiConverterOptionalTypeConfigProfile.lower(`profile`),_status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_walkie_talkie_engine_fn_free_configbuilder(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_walkie_talkie_engine_fn_clone_configbuilder(handle, status)
+        }
+    }
+
+    override fun `agcEnabled`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_agc_enabled(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `bitrateBps`(`bps`: kotlin.Int): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_bitrate_bps(
+        it,
+        FfiConverterInt.lower(`bps`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * The finished config, or `InvalidParameter` (with the reason logged) if the
+     * engine couldn't run it. `validate` says why.
+     */
+    @Throws(AudioException::class)override fun `build`(): AudioConfig {
+            return FfiConverterTypeAudioConfig.lift(
+    callWithHandle {
+    uniffiRustCallWithError(AudioException) { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_build(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `comfortNoiseDbfs`(`level`: kotlin.Float?): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_comfort_noise_dbfs(
+        it,
+        FfiConverterOptionalFloat.lower(`level`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * What the config built so far resolves to, see `AudioEngine::describe_effective_config`.
+     */override fun `describeEffectiveConfig`(): EffectiveConfig {
+            return FfiConverterTypeEffectiveConfig.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_describe_effective_config(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * 0 lets the route preference (or the system) pick.
+     */override fun `devices`(`inputDeviceId`: kotlin.Int, `outputDeviceId`: kotlin.Int): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_devices(
+        it,
+        FfiConverterInt.lower(`inputDeviceId`),FfiConverterInt.lower(`outputDeviceId`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `echoCancellation`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_echo_cancellation(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `floorControl`(`policy`: FloorPolicy, `priority`: kotlin.UByte): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_floor_control(
+        it,
+        FfiConverterTypeFloorPolicy.lower(`policy`),FfiConverterUByte.lower(`priority`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `frameSizeMs`(`ms`: kotlin.Int): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_frame_size_ms(
+        it,
+        FfiConverterInt.lower(`ms`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `jitterBufferMs`(`ms`: kotlin.Int): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_jitter_buffer_ms(
+        it,
+        FfiConverterInt.lower(`ms`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `maxConcurrentSpeakers`(`speakers`: kotlin.UInt): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_max_concurrent_speakers(
+        it,
+        FfiConverterUInt.lower(`speakers`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `noiseSuppression`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_noise_suppression(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `outputWatchdog`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_output_watchdog(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `padPackets`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_pad_packets(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `pttPreRollMs`(`ms`: kotlin.UInt): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_ptt_pre_roll_ms(
+        it,
+        FfiConverterUInt.lower(`ms`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `pttToggle`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_ptt_toggle(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `redundancy`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_redundancy(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `rogerBeep`(`beep`: RogerBeep?): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_roger_beep(
+        it,
+        FfiConverterOptionalTypeRogerBeep.lower(`beep`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `routingMode`(`mode`: AudioRoutingMode): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_routing_mode(
+        it,
+        FfiConverterTypeAudioRoutingMode.lower(`mode`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `sampleRate`(`hz`: kotlin.Int): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_sample_rate(
+        it,
+        FfiConverterInt.lower(`hz`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * A second device playing the channel, with its own gain. None = off.
+     */override fun `secondaryOutput`(`output`: SecondaryOutput?): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_secondary_output(
+        it,
+        FfiConverterOptionalTypeSecondaryOutput.lower(`output`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `talkPermitTone`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_talk_permit_tone(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `talkerIdChirp`(`enabled`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_talker_id_chirp(
+        it,
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `vad`(`enabled`: kotlin.Boolean, `gateTransmit`: kotlin.Boolean): ConfigBuilder {
+            return FfiConverterTypeConfigBuilder.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_vad(
+        it,
+        FfiConverterBoolean.lower(`enabled`),FfiConverterBoolean.lower(`gateTransmit`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    /**
+     * Why `build` would fail, as an ID to show the user in their own language.
+     */override fun `validate`(): ConfigIssue? {
+            return FfiConverterOptionalTypeConfigIssue.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_method_configbuilder_validate(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeConfigBuilder: FfiConverter<ConfigBuilder, Long> {
+    override fun lower(value: ConfigBuilder): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): ConfigBuilder {
+        return ConfigBuilder(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): ConfigBuilder {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: ConfigBuilder) = 8UL
+
+    override fun write(value: ConfigBuilder, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+
+/**
+ * One transmission in the channel archive (see `start_archive`), as found by
+ * `search_archive`. Pass it to `export_archive_clip` to get the audio.
+ */
+data class ArchiveSegment (
+    /**
+     * The segment file it is in.
+     */
+    var `segment`: kotlin.UInt
+    , 
+    var `peerId`: kotlin.UInt
+    , 
+    /**
+     * Unix time in ms.
+     */
+    var `startMs`: kotlin.ULong
+    , 
+    var `durationMs`: kotlin.UInt
+    , 
+    /**
+     * Where it starts in the segment file.
+     */
+    var `offsetMs`: kotlin.UInt
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeArchiveSegment: FfiConverterRustBuffer<ArchiveSegment> {
+    override fun read(buf: ByteBuffer): ArchiveSegment {
+        return ArchiveSegment(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ArchiveSegment) = (
+            FfiConverterUInt.allocationSize(value.`segment`) +
+            FfiConverterUInt.allocationSize(value.`peerId`) +
+            FfiConverterULong.allocationSize(value.`startMs`) +
+            FfiConverterUInt.allocationSize(value.`durationMs`) +
+            FfiConverterUInt.allocationSize(value.`offsetMs`)
+    )
+
+    override fun write(value: ArchiveSegment, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`segment`, buf)
+            FfiConverterUInt.write(value.`peerId`, buf)
+            FfiConverterULong.write(value.`startMs`, buf)
+            FfiConverterUInt.write(value.`durationMs`, buf)
+            FfiConverterUInt.write(value.`offsetMs`, buf)
+    }
+}
+
+
+
+data class AudioConfig (
+    var `sampleRate`: kotlin.Int
+    , 
+    var `frameSizeMs`: kotlin.Int
+    , 
+    var `jitterBufferMs`: kotlin.Int
+    , 
+    /**
+     * Android's device ID (`AudioDeviceInfo.getId()`), or on iOS and desktops the
+     * ID the engine logs for each device. 0 = the system default.
+     */
+    var `inputDeviceId`: kotlin.Int
+    , 
+    var `outputDeviceId`: kotlin.Int
+    , 
+    /**
+     * Cancel our own speaker output from the mic signal (needed on loudspeaker).
+     */
+    var `echoCancellation`: kotlin.Boolean = false 
+    , 
+    /**
+     * RNNoise suppression of background noise (wind, traffic) before encoding.
+     * Requires 48kHz and a frame size that is a multiple of 10ms.
+     */
+    var `noiseSuppression`: kotlin.Boolean = false 
+    , 
+    /**
+     * Normalize the mic level before encoding so quiet and loud talkers sound alike.
+     */
+    var `agcEnabled`: kotlin.Boolean = false 
+    , 
+    /**
+     * AGC target level in dB below full scale (18 => -18 dBFS).
+     */
+    var `agcTargetLevelDb`: kotlin.Int = 18 
+    , 
+    /**
+     * Upper bound for the AGC boost, in dB.
+     */
+    var `agcMaxGainDb`: kotlin.Int = 24 
+    , 
+    /**
+     * Run voice activity detection on the mic while PTT is held (drives `VoiceActivityCallback`).
+     */
+    var `vadEnabled`: kotlin.Boolean = false 
+    , 
+    /**
+     * With VAD enabled: do not encode/send frames classified as silence, even with PTT held.
+     */
+    var `vadGateTransmit`: kotlin.Boolean = false 
+    , 
+    /**
+     * How long playout of a peer pauses for a missing packet (while later ones are
+     * already buffered) before it is declared lost. 0 = conceal immediately.
+     * Raise on links with heavy reordering (e.g. bonded cellular); costs latency.
+     */
+    var `reorderWaitMs`: kotlin.Int = 0 
+    , 
+    /**
+     * A hole smaller than this many packets is concealed packet-by-packet (PLC);
+     * a larger one makes the peer resync to the next buffered packet. Counted in
+     * packets of our own `frame_size_ms`, like the jitter limits.
+     */
+    var `resyncGapPackets`: kotlin.Int = 10 
+    , 
+    /**
+     * Opus target bitrate in bits per second. 0 lets the encoder choose.
+     */
+    var `bitrateBps`: kotlin.Int = 0 
+    , 
+    /**
+     * Opus encoder complexity, 0-10 (higher values are clamped). Lower values save
+     * CPU on low-end devices at some cost in quality.
+     */
+    var `opusComplexity`: kotlin.Int = 10 
+    , 
+    /**
+     * Variable bitrate. Turn off for constant-bitrate packets under a strict
+     * bandwidth budget.
+     */
+    var `useVbr`: kotlin.Boolean = true 
+    , 
+    /**
+     * Send standard RTP instead of the engine's own format, for SIP/RTP tooling and
+     * Wireshark. Drops position metadata; ignored while encryption is on.
+     */
+    var `rtpPacketization`: kotlin.Boolean = false 
+    , 
+    /**
+     * Repeat the previous frame in every packet, so a single lost packet is filled
+     * from the next one. Roughly doubles the bitrate; meant for lossy mesh links.
+     */
+    var `redundancy`: kotlin.Boolean = false 
+    , 
+    /**
+     * Pad encrypted packets to a fixed size bucket, so their sizes don't reveal when
+     * someone is speaking. Costs bandwidth; best combined with `use_vbr = false`.
+     * No effect without encryption.
+     */
+    var `padPackets`: kotlin.Boolean = false 
+    , 
+    /**
+     * Deliver all callbacks from one dedicated engine thread (bounded queue) instead of
+     * the audio/transport threads, so a slow handler can never cause dropouts.
+     */
+    var `dedicatedCallbackThread`: kotlin.Boolean = false 
+    , 
+    /**
+     * Drop outgoing audio that waited longer than `jitter_buffer_ms` for the transport
+     * (e.g. while `send_packet` was blocked) instead of sending it late, so a stall
+     * doesn't leave peers lagging behind afterwards. Control messages always go out.
+     */
+    var `dropStaleAudio`: kotlin.Boolean = false 
+    , 
+    /**
+     * Keep the last this many seconds of the received mix in memory (at most 60),
+     * so `get_replay` / `play_replay` can bring back a missed transmission. 0 = off.
+     * Nothing is kept in privacy mode.
+     */
+    var `replayBufferSecs`: kotlin.UInt = 0u 
+    , 
+    /**
+     * Watch for an output stream that runs but plays nothing (device routing bugs)
+     * and report it through `OutputHealthCallback`. Device sessions only (not pull mode).
+     */
+    var `outputWatchdog`: kotlin.Boolean = false 
+    , 
+    /**
+     * Incoming packets larger than this are dropped unparsed and counted in
+     * `EngineStats.packets_oversized`, whichever way they arrive. At least 576.
+     */
+    var `maxPacketBytes`: kotlin.UInt = 1500u 
+    , 
+    /**
+     * How far the channel is ducked while `play_voice_message` plays, in dB.
+     * 0 = not at all.
+     */
+    var `voiceMessageDuckDb`: kotlin.Float = 12.0f 
+    , 
+    /**
+     * `set_mic_enabled(true)` first plays a short confirm tone locally and opens the
+     * mic once it has finished, like a radio's talk permit tone. `TransmitCallback`
+     * reports when sending actually starts.
+     */
+    var `talkPermitTone`: kotlin.Boolean = false 
+    , 
+    /**
+     * Keeps this much of the mic signal while PTT is up and sends it at the start
+     * of the next transmission, so the first syllables spoken while pressing the
+     * button aren't lost. Opt-in: the mic is then processed all the time, though
+     * nothing leaves the device until PTT is pressed. Up to 1000ms; 0 = off.
+     */
+    var `pttPreRollMs`: kotlin.UInt = 0u 
+    , 
+    /**
+     * `on_accessory_ptt` toggles the mic on a tap instead of keeping it on while
+     * the key is held. A long press still works as momentary.
+     */
+    var `pttToggle`: kotlin.Boolean = false 
+    , 
+    /**
+     * Before a peer's transmission plays, a short chirp derived from its node ID, so
+     * listeners can tell who is talking without looking at the screen.
+     */
+    var `talkerIdChirp`: kotlin.Boolean = false 
+    , 
+    /**
+     * Tone sent after our transmission when PTT is released. None = off.
+     */
+    var `rogerBeep`: RogerBeep? = null 
+    , 
+    /**
+     * Half-duplex floor control: only the node holding the floor transmits, and
+     * `set_mic_enabled(true)` asks for it first (see `FloorCallback`). Every node
+     * should use the same policy. None = off.
+     */
+    var `floorControl`: FloorPolicy? = null 
+    , 
+    /**
+     * Our priority under `FloorPolicy::Priority` (higher wins).
+     */
+    var `floorPriority`: kotlin.UByte = 0u 
+    , 
+    /**
+     * What happens to peers with a lower priority (`set_local_priority`) than the
+     * highest one currently talking, e.g. everyone else while a dispatcher speaks.
+     * None = all peers mix equally.
+     */
+    var `preemption`: PreemptionPolicy? = null 
+    , 
+    /**
+     * How far `PreemptionPolicy::Duck` lowers them, in dB.
+     */
+    var `preemptionDuckDb`: kotlin.Float = 12.0f 
+    , 
+    /**
+     * Faint noise at this level (dBFS, -90 to -20) while a peer's transmission
+     * underruns or stalls, so the gap doesn't sound like a dropped link. None = off.
+     */
+    var `comfortNoiseDbfs`: kotlin.Float? = null 
+    , 
+    /**
+     * Most peers mixed at once. Past it, the highest-priority speakers keep their
+     * turn (those already talking first) and the rest are held in their jitter
+     * buffers, losing the oldest audio, until one stops. Muted peers don't count.
+     * 0 = no limit.
+     */
+    var `maxConcurrentSpeakers`: kotlin.UInt = 0u 
+    , 
+    /**
+     * Also play the channel on a second device, e.g. a wired line-out feeding a
+     * vehicle PA while the user listens on a Bluetooth headset. Device sessions
+     * only (not pull mode). None = off.
+     */
+    var `secondaryOutput`: SecondaryOutput? = null 
+    , 
+    /**
+     * Where audio goes when no device ID is set. None is `Auto`.
+     */
+    var `routingMode`: AudioRoutingMode? = null 
+    , 
+    /**
+     * The profile this config was built from (see `ConfigBuilder`). Informational:
+     * the engine only looks at the fields above.
+     */
+    var `profile`: ConfigProfile? = null 
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAudioConfig: FfiConverterRustBuffer<AudioConfig> {
+    override fun read(buf: ByteBuffer): AudioConfig {
+        return AudioConfig(
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalTypeRogerBeep.read(buf),
+            FfiConverterOptionalTypeFloorPolicy.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterOptionalTypePreemptionPolicy.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterOptionalFloat.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterOptionalTypeSecondaryOutput.read(buf),
+            FfiConverterOptionalTypeAudioRoutingMode.read(buf),
+            FfiConverterOptionalTypeConfigProfile.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AudioConfig) = (
+            FfiConverterInt.allocationSize(value.`sampleRate`) +
+            FfiConverterInt.allocationSize(value.`frameSizeMs`) +
+            FfiConverterInt.allocationSize(value.`jitterBufferMs`) +
+            FfiConverterInt.allocationSize(value.`inputDeviceId`) +
+            FfiConverterInt.allocationSize(value.`outputDeviceId`) +
+            FfiConverterBoolean.allocationSize(value.`echoCancellation`) +
+            FfiConverterBoolean.allocationSize(value.`noiseSuppression`) +
+            FfiConverterBoolean.allocationSize(value.`agcEnabled`) +
+            FfiConverterInt.allocationSize(value.`agcTargetLevelDb`) +
+            FfiConverterInt.allocationSize(value.`agcMaxGainDb`) +
+            FfiConverterBoolean.allocationSize(value.`vadEnabled`) +
+            FfiConverterBoolean.allocationSize(value.`vadGateTransmit`) +
+            FfiConverterInt.allocationSize(value.`reorderWaitMs`) +
+            FfiConverterInt.allocationSize(value.`resyncGapPackets`) +
+            FfiConverterInt.allocationSize(value.`bitrateBps`) +
+            FfiConverterInt.allocationSize(value.`opusComplexity`) +
+            FfiConverterBoolean.allocationSize(value.`useVbr`) +
+            FfiConverterBoolean.allocationSize(value.`rtpPacketization`) +
+            FfiConverterBoolean.allocationSize(value.`redundancy`) +
+            FfiConverterBoolean.allocationSize(value.`padPackets`) +
+            FfiConverterBoolean.allocationSize(value.`dedicatedCallbackThread`) +
+            FfiConverterBoolean.allocationSize(value.`dropStaleAudio`) +
+            FfiConverterUInt.allocationSize(value.`replayBufferSecs`) +
+            FfiConverterBoolean.allocationSize(value.`outputWatchdog`) +
+            FfiConverterUInt.allocationSize(value.`maxPacketBytes`) +
+            FfiConverterFloat.allocationSize(value.`voiceMessageDuckDb`) +
+            FfiConverterBoolean.allocationSize(value.`talkPermitTone`) +
+            FfiConverterUInt.allocationSize(value.`pttPreRollMs`) +
+            FfiConverterBoolean.allocationSize(value.`pttToggle`) +
+            FfiConverterBoolean.allocationSize(value.`talkerIdChirp`) +
+            FfiConverterOptionalTypeRogerBeep.allocationSize(value.`rogerBeep`) +
+            FfiConverterOptionalTypeFloorPolicy.allocationSize(value.`floorControl`) +
+            FfiConverterUByte.allocationSize(value.`floorPriority`) +
+            FfiConverterOptionalTypePreemptionPolicy.allocationSize(value.`preemption`) +
+            FfiConverterFloat.allocationSize(value.`preemptionDuckDb`) +
+            FfiConverterOptionalFloat.allocationSize(value.`comfortNoiseDbfs`) +
+            FfiConverterUInt.allocationSize(value.`maxConcurrentSpeakers`) +
+            FfiConverterOptionalTypeSecondaryOutput.allocationSize(value.`secondaryOutput`) +
+            FfiConverterOptionalTypeAudioRoutingMode.allocationSize(value.`routingMode`) +
+            FfiConverterOptionalTypeConfigProfile.allocationSize(value.`profile`)
+    )
+
+    override fun write(value: AudioConfig, buf: ByteBuffer) {
+            FfiConverterInt.write(value.`sampleRate`, buf)
+            FfiConverterInt.write(value.`frameSizeMs`, buf)
+            FfiConverterInt.write(value.`jitterBufferMs`, buf)
+            FfiConverterInt.write(value.`inputDeviceId`, buf)
+            FfiConverterInt.write(value.`outputDeviceId`, buf)
+            FfiConverterBoolean.write(value.`echoCancellation`, buf)
+            FfiConverterBoolean.write(value.`noiseSuppression`, buf)
+            FfiConverterBoolean.write(value.`agcEnabled`, buf)
+            FfiConverterInt.write(value.`agcTargetLevelDb`, buf)
+            FfiConverterInt.write(value.`agcMaxGainDb`, buf)
+            FfiConverterBoolean.write(value.`vadEnabled`, buf)
+            FfiConverterBoolean.write(value.`vadGateTransmit`, buf)
+            FfiConverterInt.write(value.`reorderWaitMs`, buf)
+            FfiConverterInt.write(value.`resyncGapPackets`, buf)
+            FfiConverterInt.write(value.`bitrateBps`, buf)
+            FfiConverterInt.write(value.`opusComplexity`, buf)
+            FfiConverterBoolean.write(value.`useVbr`, buf)
+            FfiConverterBoolean.write(value.`rtpPacketization`, buf)
+            FfiConverterBoolean.write(value.`redundancy`, buf)
+            FfiConverterBoolean.write(value.`padPackets`, buf)
+            FfiConverterBoolean.write(value.`dedicatedCallbackThread`, buf)
+            FfiConverterBoolean.write(value.`dropStaleAudio`, buf)
+            FfiConverterUInt.write(value.`replayBufferSecs`, buf)
+            FfiConverterBoolean.write(value.`outputWatchdog`, buf)
+            FfiConverterUInt.write(value.`maxPacketBytes`, buf)
+            FfiConverterFloat.write(value.`voiceMessageDuckDb`, buf)
+            FfiConverterBoolean.write(value.`talkPermitTone`, buf)
+            FfiConverterUInt.write(value.`pttPreRollMs`, buf)
+            FfiConverterBoolean.write(value.`pttToggle`, buf)
+            FfiConverterBoolean.write(value.`talkerIdChirp`, buf)
+            FfiConverterOptionalTypeRogerBeep.write(value.`rogerBeep`, buf)
+            FfiConverterOptionalTypeFloorPolicy.write(value.`floorControl`, buf)
+            FfiConverterUByte.write(value.`floorPriority`, buf)
+            FfiConverterOptionalTypePreemptionPolicy.write(value.`preemption`, buf)
+            FfiConverterFloat.write(value.`preemptionDuckDb`, buf)
+            FfiConverterOptionalFloat.write(value.`comfortNoiseDbfs`, buf)
+            FfiConverterUInt.write(value.`maxConcurrentSpeakers`, buf)
+            FfiConverterOptionalTypeSecondaryOutput.write(value.`secondaryOutput`, buf)
+            FfiConverterOptionalTypeAudioRoutingMode.write(value.`routingMode`, buf)
+            FfiConverterOptionalTypeConfigProfile.write(value.`profile`, buf)
+    }
+}
+
+
+
+/**
+ * Data saver status (`get_bandwidth_usage`).
+ */
+data class BandwidthUsage (
+    /**
+     * `None` without a budget.
+     */
+    var `budgetKbps`: kotlin.UInt?
+    , 
+    /**
+     * Audio and control traffic, averaged over the last few seconds.
+     */
+    var `sendKbps`: kotlin.Float
+    , 
+    var `receiveKbps`: kotlin.Float
+    , 
+    /**
+     * What the encoder is set to while transmitting; 0 = Opus picks.
+     */
+    var `bitrateBps`: kotlin.Int
+    , 
+    /**
+     * Whether redundant frames and in-band FEC still fit.
+     */
+    var `redundancy`: kotlin.Boolean
+    , 
+    var `fec`: kotlin.Boolean
+    , 
+    /**
+     * The budget is holding transmissions below `AudioConfig`.
+     */
+    var `capped`: kotlin.Boolean
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBandwidthUsage: FfiConverterRustBuffer<BandwidthUsage> {
+    override fun read(buf: ByteBuffer): BandwidthUsage {
+        return BandwidthUsage(
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BandwidthUsage) = (
+            FfiConverterOptionalUInt.allocationSize(value.`budgetKbps`) +
+            FfiConverterFloat.allocationSize(value.`sendKbps`) +
+            FfiConverterFloat.allocationSize(value.`receiveKbps`) +
+            FfiConverterInt.allocationSize(value.`bitrateBps`) +
+            FfiConverterBoolean.allocationSize(value.`redundancy`) +
+            FfiConverterBoolean.allocationSize(value.`fec`) +
+            FfiConverterBoolean.allocationSize(value.`capped`)
+    )
+
+    override fun write(value: BandwidthUsage, buf: ByteBuffer) {
+            FfiConverterOptionalUInt.write(value.`budgetKbps`, buf)
+            FfiConverterFloat.write(value.`sendKbps`, buf)
+            FfiConverterFloat.write(value.`receiveKbps`, buf)
+            FfiConverterInt.write(value.`bitrateBps`, buf)
+            FfiConverterBoolean.write(value.`redundancy`, buf)
+            FfiConverterBoolean.write(value.`fec`, buf)
+            FfiConverterBoolean.write(value.`capped`, buf)
+    }
+}
+
+
+
+/**
+ * Result of `run_benchmark`.
+ */
+data class BenchmarkReport (
+    var `peers`: kotlin.UInt
+    , 
+    var `sampleRate`: kotlin.Int
+    , 
+    var `frameSizeMs`: kotlin.Int
+    , 
+    /**
+     * Audio processed, in seconds.
+     */
+    var `audioSeconds`: kotlin.Double
+    , 
+    /**
+     * Time spent processing (all stages together), in seconds.
+     */
+    var `processingSeconds`: kotlin.Double
+    , 
+    /**
+     * How many times faster than realtime the pipeline ran. The audio thread shares
+     * the CPU with everything else, so leave generous headroom above 1.
+     */
+    var `realtimeFactor`: kotlin.Double
+    , 
+    var `stages`: List<BenchmarkStage>
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBenchmarkReport: FfiConverterRustBuffer<BenchmarkReport> {
+    override fun read(buf: ByteBuffer): BenchmarkReport {
+        return BenchmarkReport(
+            FfiConverterUInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterSequenceTypeBenchmarkStage.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BenchmarkReport) = (
+            FfiConverterUInt.allocationSize(value.`peers`) +
+            FfiConverterInt.allocationSize(value.`sampleRate`) +
+            FfiConverterInt.allocationSize(value.`frameSizeMs`) +
+            FfiConverterDouble.allocationSize(value.`audioSeconds`) +
+            FfiConverterDouble.allocationSize(value.`processingSeconds`) +
+            FfiConverterDouble.allocationSize(value.`realtimeFactor`) +
+            FfiConverterSequenceTypeBenchmarkStage.allocationSize(value.`stages`)
+    )
+
+    override fun write(value: BenchmarkReport, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`peers`, buf)
+            FfiConverterInt.write(value.`sampleRate`, buf)
+            FfiConverterInt.write(value.`frameSizeMs`, buf)
+            FfiConverterDouble.write(value.`audioSeconds`, buf)
+            FfiConverterDouble.write(value.`processingSeconds`, buf)
+            FfiConverterDouble.write(value.`realtimeFactor`, buf)
+            FfiConverterSequenceTypeBenchmarkStage.write(value.`stages`, buf)
+    }
+}
+
+
+
+/**
+ * Time spent in one stage of `run_benchmark`.
+ */
+data class BenchmarkStage (
+    var `name`: kotlin.String
+    , 
+    var `totalMs`: kotlin.Double
+    , 
+    /**
+     * Average per audio frame (all peers together for the receive stages).
+     */
+    var `perFrameUs`: kotlin.Double
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBenchmarkStage: FfiConverterRustBuffer<BenchmarkStage> {
+    override fun read(buf: ByteBuffer): BenchmarkStage {
+        return BenchmarkStage(
+            FfiConverterString.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BenchmarkStage) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterDouble.allocationSize(value.`totalMs`) +
+            FfiConverterDouble.allocationSize(value.`perFrameUs`)
+    )
+
+    override fun write(value: BenchmarkStage, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterDouble.write(value.`totalMs`, buf)
+            FfiConverterDouble.write(value.`perFrameUs`, buf)
+    }
+}
+
+
+
+/**
+ * The CPU as detected at startup, and which optimized code paths that selected.
+ */
+data class CpuFeatures (
+    /**
+     * Architecture of this build of the library: "aarch64", "arm", "x86_64", ...
+     */
+    var `arch`: kotlin.String
+    , 
+    var `neon`: kotlin.Boolean
+    , 
+    var `cores`: kotlin.UInt
+    , 
+    /**
+     * Mixing and resampling kernels in use: "neon" or "scalar".
+     */
+    var `kernels`: kotlin.String
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCpuFeatures: FfiConverterRustBuffer<CpuFeatures> {
+    override fun read(buf: ByteBuffer): CpuFeatures {
+        return CpuFeatures(
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CpuFeatures) = (
+            FfiConverterString.allocationSize(value.`arch`) +
+            FfiConverterBoolean.allocationSize(value.`neon`) +
+            FfiConverterUInt.allocationSize(value.`cores`) +
+            FfiConverterString.allocationSize(value.`kernels`)
+    )
+
+    override fun write(value: CpuFeatures, buf: ByteBuffer) {
+            FfiConverterString.write(value.`arch`, buf)
+            FfiConverterBoolean.write(value.`neon`, buf)
+            FfiConverterUInt.write(value.`cores`, buf)
+            FfiConverterString.write(value.`kernels`, buf)
+    }
+}
+
+
+
+/**
+ * A config as the engine applies it, see `describe_effective_config`.
+ */
+data class EffectiveConfig (
+    var `profile`: ConfigProfile?
+    , 
+    /**
+     * Every setting by field name, after the engine's clamps and requirements
+     * (e.g. noise suppression shows as off below 48kHz). "valid" says whether
+     * `ConfigBuilder::build` would accept it. Values are stable tokens for
+     * tooling; `messages` is what to show users.
+     */
+    var `settings`: Map<kotlin.String, kotlin.String>
+    , 
+    /**
+     * Why it isn't valid, if it isn't.
+     */
+    var `issue`: ConfigIssue? = null 
+    , 
+    /**
+     * The issue, then any settings the engine changes, for the user.
+     */
+    var `messages`: List<Message>
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeEffectiveConfig: FfiConverterRustBuffer<EffectiveConfig> {
+    override fun read(buf: ByteBuffer): EffectiveConfig {
+        return EffectiveConfig(
+            FfiConverterOptionalTypeConfigProfile.read(buf),
+            FfiConverterMapStringString.read(buf),
+            FfiConverterOptionalTypeConfigIssue.read(buf),
+            FfiConverterSequenceTypeMessage.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: EffectiveConfig) = (
+            FfiConverterOptionalTypeConfigProfile.allocationSize(value.`profile`) +
+            FfiConverterMapStringString.allocationSize(value.`settings`) +
+            FfiConverterOptionalTypeConfigIssue.allocationSize(value.`issue`) +
+            FfiConverterSequenceTypeMessage.allocationSize(value.`messages`)
+    )
+
+    override fun write(value: EffectiveConfig, buf: ByteBuffer) {
+            FfiConverterOptionalTypeConfigProfile.write(value.`profile`, buf)
+            FfiConverterMapStringString.write(value.`settings`, buf)
+            FfiConverterOptionalTypeConfigIssue.write(value.`issue`, buf)
+            FfiConverterSequenceTypeMessage.write(value.`messages`, buf)
+    }
+}
+
+
+
+data class EngineStats (
+    /**
+     * Peers currently in the mixer (as of the last publish, at most 250ms old).
+     */
+    var `peers`: List<PeerStats>
+    , 
+    /**
+     * Talk time per peer heard this session, including peers that have left.
+     */
+    var `talk`: List<PeerTalkStats>
+    , 
+    var `packetsSent`: kotlin.ULong
+    , 
+    var `bytesSent`: kotlin.ULong
+    , 
+    var `packetsReceived`: kotlin.ULong
+    , 
+    var `bytesReceived`: kotlin.ULong
+    , 
+    /**
+     * Mid-transmission playout underruns since the engine was created.
+     */
+    var `playoutStalls`: kotlin.ULong
+    , 
+    /**
+     * Times the mic disappeared mid-session and the engine switched to the default one.
+     */
+    var `inputFailovers`: kotlin.ULong
+    , 
+    /**
+     * Callbacks dropped because the dedicated callback thread fell behind.
+     */
+    var `callbacksDropped`: kotlin.ULong
+    , 
+    /**
+     * Packets rejected as replays or duplicates.
+     */
+    var `packetsReplayed`: kotlin.ULong
+    , 
+    /**
+     * Packets dropped because their checksum did not match (damaged in transit).
+     */
+    var `packetsCorrupted`: kotlin.ULong
+    , 
+    /**
+     * Looped copies of audio we already sent or heard, dropped (see `AudioLoopCallback`).
+     */
+    var `packetsLooped`: kotlin.ULong
+    , 
+    /**
+     * Audio from peers we blocked (`block_peer`), dropped on arrival.
+     */
+    var `packetsBlocked`: kotlin.ULong
+    , 
+    /**
+     * Packets dropped for exceeding `AudioConfig.max_packet_bytes`.
+     */
+    var `packetsOversized`: kotlin.ULong
+    , 
+    /**
+     * Audio dropped because its Opus payload was malformed (RFC 6716 §3.4).
+     */
+    var `packetsMalformed`: kotlin.ULong
+    , 
+    /**
+     * Audio from builds before protocol versions (v0), played only while no key is set.
+     */
+    var `packetsLegacy`: kotlin.ULong
+    , 
+    /**
+     * Outgoing audio dropped as stale after a transport stall (`drop_stale_audio`).
+     */
+    var `packetsDroppedStale`: kotlin.ULong
+    , 
+    /**
+     * Encoded frames the app's `TransmitGate` held back.
+     */
+    var `framesGated`: kotlin.ULong
+    , 
+    /**
+     * Silent frames not sent because the encoder's DTX had nothing new to say.
+     */
+    var `framesDtx`: kotlin.ULong
+    , 
+    /**
+     * Audio we transmitted this session (frames actually sent), for usage accounting.
+     */
+    var `sessionTransmitSeconds`: kotlin.Double
+    , 
+    /**
+     * Bytes of audio packets sent this session.
+     */
+    var `sessionTransmitBytes`: kotlin.ULong
+    , 
+    /**
+     * Transmit time left under `set_transmit_quota`; `None` without a quota.
+     */
+    var `transmitQuotaRemainingSeconds`: kotlin.Double?
+    , 
+    var `privacyMode`: kotlin.Boolean
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeEngineStats: FfiConverterRustBuffer<EngineStats> {
+    override fun read(buf: ByteBuffer): EngineStats {
+        return EngineStats(
+            FfiConverterSequenceTypePeerStats.read(buf),
+            FfiConverterSequenceTypePeerTalkStats.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterOptionalDouble.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: EngineStats) = (
+            FfiConverterSequenceTypePeerStats.allocationSize(value.`peers`) +
+            FfiConverterSequenceTypePeerTalkStats.allocationSize(value.`talk`) +
+            FfiConverterULong.allocationSize(value.`packetsSent`) +
+            FfiConverterULong.allocationSize(value.`bytesSent`) +
+            FfiConverterULong.allocationSize(value.`packetsReceived`) +
+            FfiConverterULong.allocationSize(value.`bytesReceived`) +
+            FfiConverterULong.allocationSize(value.`playoutStalls`) +
+            FfiConverterULong.allocationSize(value.`inputFailovers`) +
+            FfiConverterULong.allocationSize(value.`callbacksDropped`) +
+            FfiConverterULong.allocationSize(value.`packetsReplayed`) +
+            FfiConverterULong.allocationSize(value.`packetsCorrupted`) +
+            FfiConverterULong.allocationSize(value.`packetsLooped`) +
+            FfiConverterULong.allocationSize(value.`packetsBlocked`) +
+            FfiConverterULong.allocationSize(value.`packetsOversized`) +
+            FfiConverterULong.allocationSize(value.`packetsMalformed`) +
+            FfiConverterULong.allocationSize(value.`packetsLegacy`) +
+            FfiConverterULong.allocationSize(value.`packetsDroppedStale`) +
+            FfiConverterULong.allocationSize(value.`framesGated`) +
+            FfiConverterULong.allocationSize(value.`framesDtx`) +
+            FfiConverterDouble.allocationSize(value.`sessionTransmitSeconds`) +
+            FfiConverterULong.allocationSize(value.`sessionTransmitBytes`) +
+            FfiConverterOptionalDouble.allocationSize(value.`transmitQuotaRemainingSeconds`) +
+            FfiConverterBoolean.allocationSize(value.`privacyMode`)
+    )
+
+    override fun write(value: EngineStats, buf: ByteBuffer) {
+            FfiConverterSequenceTypePeerStats.write(value.`peers`, buf)
+            FfiConverterSequenceTypePeerTalkStats.write(value.`talk`, buf)
+            FfiConverterULong.write(value.`packetsSent`, buf)
+            FfiConverterULong.write(value.`bytesSent`, buf)
+            FfiConverterULong.write(value.`packetsReceived`, buf)
+            FfiConverterULong.write(value.`bytesReceived`, buf)
+            FfiConverterULong.write(value.`playoutStalls`, buf)
+            FfiConverterULong.write(value.`inputFailovers`, buf)
+            FfiConverterULong.write(value.`callbacksDropped`, buf)
+            FfiConverterULong.write(value.`packetsReplayed`, buf)
+            FfiConverterULong.write(value.`packetsCorrupted`, buf)
+            FfiConverterULong.write(value.`packetsLooped`, buf)
+            FfiConverterULong.write(value.`packetsBlocked`, buf)
+            FfiConverterULong.write(value.`packetsOversized`, buf)
+            FfiConverterULong.write(value.`packetsMalformed`, buf)
+            FfiConverterULong.write(value.`packetsLegacy`, buf)
+            FfiConverterULong.write(value.`packetsDroppedStale`, buf)
+            FfiConverterULong.write(value.`framesGated`, buf)
+            FfiConverterULong.write(value.`framesDtx`, buf)
+            FfiConverterDouble.write(value.`sessionTransmitSeconds`, buf)
+            FfiConverterULong.write(value.`sessionTransmitBytes`, buf)
+            FfiConverterOptionalDouble.write(value.`transmitQuotaRemainingSeconds`, buf)
+            FfiConverterBoolean.write(value.`privacyMode`, buf)
+    }
+}
+
+
+
+/**
+ * What shapes the transmit level, see `get_input_gain_report`.
+ */
+data class InputGainReport (
+    /**
+     * Gain we apply in software right before encoding (`set_input_gain`).
+     */
+    var `softwareGainDb`: kotlin.Float
+    , 
+    /**
+     * Our own AGC is levelling the signal (`AudioConfig.agc_enabled`).
+     */
+    var `softwareAgc`: kotlin.Boolean
+    , 
+    /**
+     * Input preset the platform opened the mic with; `None` outside a session.
+     */
+    var `platformPreset`: kotlin.String?
+    , 
+    /**
+     * The platform may apply its own mic gain and preprocessing (AGC, NS, AEC) before
+     * we see the audio. Which effects run depends on the device; only the
+     * unprocessed presets rule them out.
+     */
+    var `platformProcessing`: kotlin.Boolean
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeInputGainReport: FfiConverterRustBuffer<InputGainReport> {
+    override fun read(buf: ByteBuffer): InputGainReport {
+        return InputGainReport(
+            FfiConverterFloat.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: InputGainReport) = (
+            FfiConverterFloat.allocationSize(value.`softwareGainDb`) +
+            FfiConverterBoolean.allocationSize(value.`softwareAgc`) +
+            FfiConverterOptionalString.allocationSize(value.`platformPreset`) +
+            FfiConverterBoolean.allocationSize(value.`platformProcessing`)
+    )
+
+    override fun write(value: InputGainReport, buf: ByteBuffer) {
+            FfiConverterFloat.write(value.`softwareGainDb`, buf)
+            FfiConverterBoolean.write(value.`softwareAgc`, buf)
+            FfiConverterOptionalString.write(value.`platformPreset`, buf)
+            FfiConverterBoolean.write(value.`platformProcessing`, buf)
+    }
+}
+
+
+
+/**
+ * User-facing text: an ID with its arguments, and the engine's own wording in the
+ * language set with `set_locale`. Apps with their own translations use `id` and
+ * `args`; the arguments are already formatted for the locale.
+ */
+data class Message (
+    var `id`: MessageId
+    , 
+    var `args`: List<kotlin.String>
+    , 
+    var `text`: kotlin.String
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMessage: FfiConverterRustBuffer<Message> {
+    override fun read(buf: ByteBuffer): Message {
+        return Message(
+            FfiConverterTypeMessageId.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Message) = (
+            FfiConverterTypeMessageId.allocationSize(value.`id`) +
+            FfiConverterSequenceString.allocationSize(value.`args`) +
+            FfiConverterString.allocationSize(value.`text`)
+    )
+
+    override fun write(value: Message, buf: ByteBuffer) {
+            FfiConverterTypeMessageId.write(value.`id`, buf)
+            FfiConverterSequenceString.write(value.`args`, buf)
+            FfiConverterString.write(value.`text`, buf)
+    }
+}
+
+
+
+/**
+ * Our own mic level over the last `AudioLevelCallback` period, as it goes to the
+ * encoder (after AEC, NS, AGC and input gain) while transmitting. Otherwise as
+ * captured (unless the pre-roll or a voice message has it processed anyway), so it
+ * also helps position the mic before pressing PTT.
+ */
+data class MicLevel (
+    var `rmsDbfs`: kotlin.Float
+    , 
+    var `peakDbfs`: kotlin.Float
+    , 
+    var `transmitting`: kotlin.Boolean
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMicLevel: FfiConverterRustBuffer<MicLevel> {
+    override fun read(buf: ByteBuffer): MicLevel {
+        return MicLevel(
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: MicLevel) = (
+            FfiConverterFloat.allocationSize(value.`rmsDbfs`) +
+            FfiConverterFloat.allocationSize(value.`peakDbfs`) +
+            FfiConverterBoolean.allocationSize(value.`transmitting`)
+    )
+
+    override fun write(value: MicLevel, buf: ByteBuffer) {
+            FfiConverterFloat.write(value.`rmsDbfs`, buf)
+            FfiConverterFloat.write(value.`peakDbfs`, buf)
+            FfiConverterBoolean.write(value.`transmitting`, buf)
+    }
+}
+
+
+
+/**
+ * What a peer announced it supports. Bits: 1 = position, 2 = encryption,
+ * 4 = key agreement, 8 = checksum, 16 = redundancy, 32 = padding, 64 = priority; unknown bits belong
+ * to newer versions.
+ */
+data class PeerCapabilities (
+    var `peerId`: kotlin.UInt
+    , 
+    var `minVersion`: kotlin.UByte
+    , 
+    var `maxVersion`: kotlin.UByte
+    , 
+    var `capabilities`: kotlin.UInt
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePeerCapabilities: FfiConverterRustBuffer<PeerCapabilities> {
+    override fun read(buf: ByteBuffer): PeerCapabilities {
+        return PeerCapabilities(
+            FfiConverterUInt.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PeerCapabilities) = (
+            FfiConverterUInt.allocationSize(value.`peerId`) +
+            FfiConverterUByte.allocationSize(value.`minVersion`) +
+            FfiConverterUByte.allocationSize(value.`maxVersion`) +
+            FfiConverterUInt.allocationSize(value.`capabilities`)
+    )
+
+    override fun write(value: PeerCapabilities, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`peerId`, buf)
+            FfiConverterUByte.write(value.`minVersion`, buf)
+            FfiConverterUByte.write(value.`maxVersion`, buf)
+            FfiConverterUInt.write(value.`capabilities`, buf)
+    }
+}
+
+
+
+/**
+ * A peer's loudness over the last `AudioLevelCallback` period, as decoded (before
+ * volume, ducking, preemption and mute), for speaking indicators.
+ */
+data class PeerLevel (
+    var `peerId`: kotlin.UInt
+    , 
+    /**
+     * -100 when the peer played nothing.
+     */
+    var `rmsDbfs`: kotlin.Float
+    , 
+    var `peakDbfs`: kotlin.Float
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePeerLevel: FfiConverterRustBuffer<PeerLevel> {
+    override fun read(buf: ByteBuffer): PeerLevel {
+        return PeerLevel(
+            FfiConverterUInt.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PeerLevel) = (
+            FfiConverterUInt.allocationSize(value.`peerId`) +
+            FfiConverterFloat.allocationSize(value.`rmsDbfs`) +
+            FfiConverterFloat.allocationSize(value.`peakDbfs`)
+    )
+
+    override fun write(value: PeerLevel, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`peerId`, buf)
+            FfiConverterFloat.write(value.`rmsDbfs`, buf)
+            FfiConverterFloat.write(value.`peakDbfs`, buf)
+    }
+}
+
+
+
+/**
+ * Where a peer is, as reported by the peer itself. Opaque to the engine:
+ * it is only carried alongside the audio and handed back on playout.
+ */
+data class PeerPosition (
+    var `latitudeDeg`: kotlin.Double
+    , 
+    var `longitudeDeg`: kotlin.Double
+    , 
+    var `altitudeM`: kotlin.Float
+    , 
+    /**
+     * Direction the speaker is facing (0 = north, clockwise).
+     */
+    var `headingDeg`: kotlin.Float
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePeerPosition: FfiConverterRustBuffer<PeerPosition> {
+    override fun read(buf: ByteBuffer): PeerPosition {
+        return PeerPosition(
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PeerPosition) = (
+            FfiConverterDouble.allocationSize(value.`latitudeDeg`) +
+            FfiConverterDouble.allocationSize(value.`longitudeDeg`) +
+            FfiConverterFloat.allocationSize(value.`altitudeM`) +
+            FfiConverterFloat.allocationSize(value.`headingDeg`)
+    )
+
+    override fun write(value: PeerPosition, buf: ByteBuffer) {
+            FfiConverterDouble.write(value.`latitudeDeg`, buf)
+            FfiConverterDouble.write(value.`longitudeDeg`, buf)
+            FfiConverterFloat.write(value.`altitudeM`, buf)
+            FfiConverterFloat.write(value.`headingDeg`, buf)
+    }
+}
+
+
+
+/**
+ * The trusted-peer roster published by the admin node (see `publish_roster`).
+ */
+data class PeerRoster (
+    /**
+     * 0 until a roster has been published or received.
+     */
+    var `version`: kotlin.UInt
+    , 
+    /**
+     * Identity public keys (32 bytes each) the app should trust.
+     */
+    var `keys`: List<kotlin.ByteArray>
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePeerRoster: FfiConverterRustBuffer<PeerRoster> {
+    override fun read(buf: ByteBuffer): PeerRoster {
+        return PeerRoster(
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PeerRoster) = (
+            FfiConverterUInt.allocationSize(value.`version`) +
+            FfiConverterSequenceByteArray.allocationSize(value.`keys`)
+    )
+
+    override fun write(value: PeerRoster, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`version`, buf)
+            FfiConverterSequenceByteArray.write(value.`keys`, buf)
+    }
+}
+
+
+
+data class PeerStats (
+    var `peerId`: kotlin.UInt
+    , 
+    var `packetsReceived`: kotlin.ULong
+    , 
+    /**
+     * Sequence numbers that never arrived in time (concealed or skipped on resync).
+     */
+    var `packetsLost`: kotlin.ULong
+    , 
+    var `packetLossPercent`: kotlin.Float
+    , 
+    /**
+     * Packets currently waiting in this peer's jitter buffer.
+     */
+    var `jitterBufferDepth`: kotlin.UInt
+    , 
+    /**
+     * Frames synthesized by Opus packet loss concealment.
+     */
+    var `packetsConcealed`: kotlin.ULong
+    , 
+    /**
+     * Lost frames rebuilt from the packet after them (in-band FEC or redundancy).
+     */
+    var `packetsRecovered`: kotlin.ULong
+    , 
+    var `decodeErrors`: kotlin.ULong
+    , 
+    var `bytesReceived`: kotlin.ULong
+    , 
+    var `talking`: kotlin.Boolean
+    , 
+    /**
+     * Capture-to-playout delay (smoothed), from the sender's capture timestamps.
+     * Includes any offset between the two devices' clocks. None for v1 senders.
+     */
+    var `endToEndDelayMs`: kotlin.Int?
+    , 
+    /**
+     * Mixer callbacks this peer sat out because decoding ran over budget, or
+     * because `AudioConfig.max_concurrent_speakers` were already talking.
+     */
+    var `callbacksDeferred`: kotlin.ULong
+    , 
+    /**
+     * Of `packets_concealed`, those filled in without Opus PLC (see `ConcealmentMode`).
+     */
+    var `packetsConcealedCheaply`: kotlin.ULong
+    , 
+    /**
+     * What the peer announced (see `set_local_priority`), or our override of it.
+     */
+    var `priority`: kotlin.UByte
+    , 
+    /**
+     * Duration of the peer's packets, from their Opus TOC. Senders pick their own
+     * `frame_size_ms`, so this may differ from ours.
+     */
+    var `frameSizeMs`: kotlin.Float
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePeerStats: FfiConverterRustBuffer<PeerStats> {
+    override fun read(buf: ByteBuffer): PeerStats {
+        return PeerStats(
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PeerStats) = (
+            FfiConverterUInt.allocationSize(value.`peerId`) +
+            FfiConverterULong.allocationSize(value.`packetsReceived`) +
+            FfiConverterULong.allocationSize(value.`packetsLost`) +
+            FfiConverterFloat.allocationSize(value.`packetLossPercent`) +
+            FfiConverterUInt.allocationSize(value.`jitterBufferDepth`) +
+            FfiConverterULong.allocationSize(value.`packetsConcealed`) +
+            FfiConverterULong.allocationSize(value.`packetsRecovered`) +
+            FfiConverterULong.allocationSize(value.`decodeErrors`) +
+            FfiConverterULong.allocationSize(value.`bytesReceived`) +
+            FfiConverterBoolean.allocationSize(value.`talking`) +
+            FfiConverterOptionalInt.allocationSize(value.`endToEndDelayMs`) +
+            FfiConverterULong.allocationSize(value.`callbacksDeferred`) +
+            FfiConverterULong.allocationSize(value.`packetsConcealedCheaply`) +
+            FfiConverterUByte.allocationSize(value.`priority`) +
+            FfiConverterFloat.allocationSize(value.`frameSizeMs`)
+    )
+
+    override fun write(value: PeerStats, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`peerId`, buf)
+            FfiConverterULong.write(value.`packetsReceived`, buf)
+            FfiConverterULong.write(value.`packetsLost`, buf)
+            FfiConverterFloat.write(value.`packetLossPercent`, buf)
+            FfiConverterUInt.write(value.`jitterBufferDepth`, buf)
+            FfiConverterULong.write(value.`packetsConcealed`, buf)
+            FfiConverterULong.write(value.`packetsRecovered`, buf)
+            FfiConverterULong.write(value.`decodeErrors`, buf)
+            FfiConverterULong.write(value.`bytesReceived`, buf)
+            FfiConverterBoolean.write(value.`talking`, buf)
+            FfiConverterOptionalInt.write(value.`endToEndDelayMs`, buf)
+            FfiConverterULong.write(value.`callbacksDeferred`, buf)
+            FfiConverterULong.write(value.`packetsConcealedCheaply`, buf)
+            FfiConverterUByte.write(value.`priority`, buf)
+            FfiConverterFloat.write(value.`frameSizeMs`, buf)
+    }
+}
+
+
+
+/**
+ * A peer's participation over the current session (kept after the peer times out,
+ * reset when a new session starts). Measured on the audio actually played.
+ */
+data class PeerTalkStats (
+    var `peerId`: kotlin.UInt
+    , 
+    var `talkSeconds`: kotlin.Float
+    , 
+    /**
+     * Average level of the peer's speech in dBFS.
+     */
+    var `averageLevelDbfs`: kotlin.Float
+    , 
+    /**
+     * Talk seconds per hour of day, 24 entries (UTC: index 0 is 00:00-01:00).
+     */
+    var `talkSecondsByHour`: List<kotlin.Float>
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePeerTalkStats: FfiConverterRustBuffer<PeerTalkStats> {
+    override fun read(buf: ByteBuffer): PeerTalkStats {
+        return PeerTalkStats(
+            FfiConverterUInt.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterSequenceFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PeerTalkStats) = (
+            FfiConverterUInt.allocationSize(value.`peerId`) +
+            FfiConverterFloat.allocationSize(value.`talkSeconds`) +
+            FfiConverterFloat.allocationSize(value.`averageLevelDbfs`) +
+            FfiConverterSequenceFloat.allocationSize(value.`talkSecondsByHour`)
+    )
+
+    override fun write(value: PeerTalkStats, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`peerId`, buf)
+            FfiConverterFloat.write(value.`talkSeconds`, buf)
+            FfiConverterFloat.write(value.`averageLevelDbfs`, buf)
+            FfiConverterSequenceFloat.write(value.`talkSecondsByHour`, buf)
+    }
+}
+
+
+
+/**
+ * The processing chain in signal order, as currently configured.
+ */
+data class PipelineGraph (
+    /**
+     * The audio backend ("oboe" or "cpal"), "pull" or "stopped".
+     */
+    var `mode`: kotlin.String
+    , 
+    var `sampleRate`: kotlin.Int
+    , 
+    var `capture`: List<PipelineNode>
+    , 
+    var `playback`: List<PipelineNode>
+    , 
+    var `cpu`: CpuFeatures
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePipelineGraph: FfiConverterRustBuffer<PipelineGraph> {
+    override fun read(buf: ByteBuffer): PipelineGraph {
+        return PipelineGraph(
+            FfiConverterString.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterSequenceTypePipelineNode.read(buf),
+            FfiConverterSequenceTypePipelineNode.read(buf),
+            FfiConverterTypeCpuFeatures.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PipelineGraph) = (
+            FfiConverterString.allocationSize(value.`mode`) +
+            FfiConverterInt.allocationSize(value.`sampleRate`) +
+            FfiConverterSequenceTypePipelineNode.allocationSize(value.`capture`) +
+            FfiConverterSequenceTypePipelineNode.allocationSize(value.`playback`) +
+            FfiConverterTypeCpuFeatures.allocationSize(value.`cpu`)
+    )
+
+    override fun write(value: PipelineGraph, buf: ByteBuffer) {
+            FfiConverterString.write(value.`mode`, buf)
+            FfiConverterInt.write(value.`sampleRate`, buf)
+            FfiConverterSequenceTypePipelineNode.write(value.`capture`, buf)
+            FfiConverterSequenceTypePipelineNode.write(value.`playback`, buf)
+            FfiConverterTypeCpuFeatures.write(value.`cpu`, buf)
+    }
+}
+
+
+
+/**
+ * One processing stage and its current settings (values formatted for display).
+ */
+data class PipelineNode (
+    var `name`: kotlin.String
+    , 
+    /**
+     * Inactive stages are listed too, so the graph shape is the same on every device.
+     */
+    var `active`: kotlin.Boolean
+    , 
+    var `params`: Map<kotlin.String, kotlin.String>
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePipelineNode: FfiConverterRustBuffer<PipelineNode> {
+    override fun read(buf: ByteBuffer): PipelineNode {
+        return PipelineNode(
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterMapStringString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: PipelineNode) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterBoolean.allocationSize(value.`active`) +
+            FfiConverterMapStringString.allocationSize(value.`params`)
+    )
+
+    override fun write(value: PipelineNode, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterBoolean.write(value.`active`, buf)
+            FfiConverterMapStringString.write(value.`params`, buf)
+    }
+}
+
+
+
+/**
+ * What the engines in this process hold right now (`get_resource_report`). After
+ * `stop_session`, `pipelines`, `peer_streams` and `encoders` are back to 0; threads
+ * are the engine's own (transport, callbacks, UDP receive) plus short-lived timers.
+ */
+data class ResourceReport (
+    var `engines`: kotlin.UInt
+    , 
+    /**
+     * Input and output callbacks, i.e. open audio streams or pull-mode pipelines.
+     */
+    var `pipelines`: kotlin.UInt
+    , 
+    /**
+     * Peers being played, each with its decoder and jitter buffer.
+     */
+    var `peerStreams`: kotlin.UInt
+    , 
+    var `encoders`: kotlin.UInt
+    , 
+    var `threads`: kotlin.UInt
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeResourceReport: FfiConverterRustBuffer<ResourceReport> {
+    override fun read(buf: ByteBuffer): ResourceReport {
+        return ResourceReport(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ResourceReport) = (
+            FfiConverterUInt.allocationSize(value.`engines`) +
+            FfiConverterUInt.allocationSize(value.`pipelines`) +
+            FfiConverterUInt.allocationSize(value.`peerStreams`) +
+            FfiConverterUInt.allocationSize(value.`encoders`) +
+            FfiConverterUInt.allocationSize(value.`threads`)
+    )
+
+    override fun write(value: ResourceReport, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`engines`, buf)
+            FfiConverterUInt.write(value.`pipelines`, buf)
+            FfiConverterUInt.write(value.`peerStreams`, buf)
+            FfiConverterUInt.write(value.`encoders`, buf)
+            FfiConverterUInt.write(value.`threads`, buf)
+    }
+}
+
+
+
+/**
+ * The end-of-transmission tone (`AudioConfig.roger_beep`), so listeners know
+ * the channel is clear.
+ */
+data class RogerBeep (
+    var `frequencyHz`: kotlin.Float = 1000.0f 
+    , 
+    /**
+     * Up to 1000ms.
+     */
+    var `durationMs`: kotlin.UInt = 150u 
+    , 
+    var `levelDbfs`: kotlin.Float = -12.0f 
+    , 
+    /**
+     * Also play it on our own output, as confirmation that PTT was released.
+     */
+    var `playLocally`: kotlin.Boolean = false 
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRogerBeep: FfiConverterRustBuffer<RogerBeep> {
+    override fun read(buf: ByteBuffer): RogerBeep {
+        return RogerBeep(
+            FfiConverterFloat.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: RogerBeep) = (
+            FfiConverterFloat.allocationSize(value.`frequencyHz`) +
+            FfiConverterUInt.allocationSize(value.`durationMs`) +
+            FfiConverterFloat.allocationSize(value.`levelDbfs`) +
+            FfiConverterBoolean.allocationSize(value.`playLocally`)
+    )
+
+    override fun write(value: RogerBeep, buf: ByteBuffer) {
+            FfiConverterFloat.write(value.`frequencyHz`, buf)
+            FfiConverterUInt.write(value.`durationMs`, buf)
+            FfiConverterFloat.write(value.`levelDbfs`, buf)
+            FfiConverterBoolean.write(value.`playLocally`, buf)
+    }
+}
+
+
+
+/**
+ * A second output device fed from the same mix (`AudioConfig.secondary_output`).
+ *
+ * It gets the peers, ducking and alerts, but not `set_output_volume`, the sidetone
+ * or the echo canceller's reference: those belong to the main output. If the
+ * device can't be opened, the session carries on without it.
+ */
+data class SecondaryOutput (
+    var `deviceId`: kotlin.Int
+    , 
+    /**
+     * Its own volume, 0.0-2.0 like `set_output_volume`. Changes apply live.
+     */
+    var `gain`: kotlin.Float = 1.0f 
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSecondaryOutput: FfiConverterRustBuffer<SecondaryOutput> {
+    override fun read(buf: ByteBuffer): SecondaryOutput {
+        return SecondaryOutput(
+            FfiConverterInt.read(buf),
+            FfiConverterFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SecondaryOutput) = (
+            FfiConverterInt.allocationSize(value.`deviceId`) +
+            FfiConverterFloat.allocationSize(value.`gain`)
+    )
+
+    override fun write(value: SecondaryOutput, buf: ByteBuffer) {
+            FfiConverterInt.write(value.`deviceId`, buf)
+            FfiConverterFloat.write(value.`gain`, buf)
+    }
+}
+
+
+
+/**
+ * An encoded frame about to be sent, as shown to `TransmitGate`.
+ */
+data class TransmitFrameMeta (
+    /**
+     * Sequence number the frame goes out with.
+     */
+    var `seq`: kotlin.UInt
+    , 
+    var `frameSizeMs`: kotlin.Int
+    , 
+    /**
+     * First frame of a transmission (PTT pressed, or speech after VAD silence).
+     */
+    var `burstStart`: kotlin.Boolean
+    , 
+    /**
+     * Level of the frame as encoded, in dBFS.
+     */
+    var `levelDbfs`: kotlin.Float
+    , 
+    var `encodedBytes`: kotlin.UInt
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeTransmitFrameMeta: FfiConverterRustBuffer<TransmitFrameMeta> {
+    override fun read(buf: ByteBuffer): TransmitFrameMeta {
+        return TransmitFrameMeta(
+            FfiConverterUInt.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: TransmitFrameMeta) = (
+            FfiConverterUInt.allocationSize(value.`seq`) +
+            FfiConverterInt.allocationSize(value.`frameSizeMs`) +
+            FfiConverterBoolean.allocationSize(value.`burstStart`) +
+            FfiConverterFloat.allocationSize(value.`levelDbfs`) +
+            FfiConverterUInt.allocationSize(value.`encodedBytes`)
+    )
+
+    override fun write(value: TransmitFrameMeta, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`seq`, buf)
+            FfiConverterInt.write(value.`frameSizeMs`, buf)
+            FfiConverterBoolean.write(value.`burstStart`, buf)
+            FfiConverterFloat.write(value.`levelDbfs`, buf)
+            FfiConverterUInt.write(value.`encodedBytes`, buf)
+    }
+}
+
+
+
+
+
+sealed class AudioException(message: String): kotlin.Exception(message) {
+        
+        class DeviceException(message: String) : AudioException(message)
+        
+        class EncoderException(message: String) : AudioException(message)
+        
+        class DecoderException(message: String) : AudioException(message)
+        
+        class InvalidParameter(message: String) : AudioException(message)
+        
+        class TransportException(message: String) : AudioException(message)
+        
+        class RecordingException(message: String) : AudioException(message)
+        
+        class QuotaExceeded(message: String) : AudioException(message)
+        
+        class ShutDown(message: String) : AudioException(message)
+        
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<AudioException> {
+        override fun lift(error_buf: RustBuffer.ByValue): AudioException = FfiConverterTypeAudioError.lift(error_buf)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAudioError : FfiConverterRustBuffer<AudioException> {
+    override fun read(buf: ByteBuffer): AudioException {
+        
+            return when(buf.getInt()) {
+            1 -> AudioException.DeviceException(FfiConverterString.read(buf))
+            2 -> AudioException.EncoderException(FfiConverterString.read(buf))
+            3 -> AudioException.DecoderException(FfiConverterString.read(buf))
+            4 -> AudioException.InvalidParameter(FfiConverterString.read(buf))
+            5 -> AudioException.TransportException(FfiConverterString.read(buf))
+            6 -> AudioException.RecordingException(FfiConverterString.read(buf))
+            7 -> AudioException.QuotaExceeded(FfiConverterString.read(buf))
+            8 -> AudioException.ShutDown(FfiConverterString.read(buf))
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+        
+    }
+
+    override fun allocationSize(value: AudioException): ULong {
+        return 4UL
+    }
+
+    override fun write(value: AudioException, buf: ByteBuffer) {
+        when(value) {
+            is AudioException.DeviceException -> {
+                buf.putInt(1)
+                Unit
+            }
+            is AudioException.EncoderException -> {
+                buf.putInt(2)
+                Unit
+            }
+            is AudioException.DecoderException -> {
+                buf.putInt(3)
+                Unit
+            }
+            is AudioException.InvalidParameter -> {
+                buf.putInt(4)
+                Unit
+            }
+            is AudioException.TransportException -> {
+                buf.putInt(5)
+                Unit
+            }
+            is AudioException.RecordingException -> {
+                buf.putInt(6)
+                Unit
+            }
+            is AudioException.QuotaExceeded -> {
+                buf.putInt(7)
+                Unit
+            }
+            is AudioException.ShutDown -> {
+                buf.putInt(8)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+/**
+ * Kinds of output device the engine can pick by itself.
+ */
+
+enum class AudioRoute {
+    
+    BLUETOOTH_SCO,
+    /**
+     * Wired or USB headset/headphones.
+     */
+    WIRED,
+    EARPIECE,
+    SPEAKER;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAudioRoute: FfiConverterRustBuffer<AudioRoute> {
+    override fun read(buf: ByteBuffer) = try {
+        AudioRoute.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: AudioRoute) = 4UL
+
+    override fun write(value: AudioRoute, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * How the engine picks its devices (`AudioConfig.routing_mode`).
+ */
+
+enum class AudioRoutingMode {
+    
+    /**
+     * Walk the route preference list (see `set_route_preference`).
+     */
+    AUTO,
+    EARPIECE,
+    SPEAKER,
+    /**
+     * A Bluetooth headset's voice link, mic included. The engine asks the app to
+     * start SCO through `BluetoothScoCallback`, and falls back to the preference
+     * list while no headset is connected.
+     */
+    BLUETOOTH_SCO;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAudioRoutingMode: FfiConverterRustBuffer<AudioRoutingMode> {
+    override fun read(buf: ByteBuffer) = try {
+        AudioRoutingMode.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: AudioRoutingMode) = 4UL
+
+    override fun write(value: AudioRoutingMode, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * How a peer's lost frames are filled in (`set_peer_concealment`).
+ */
+
+enum class ConcealmentMode {
+    
+    /**
+     * Opus PLC, except that peers other than the current speaker repeat their last
+     * frame once the mixer callback is short on CPU time.
+     */
+    AUTO,
+    /**
+     * Always Opus PLC: the best sound, at the cost of a decode per lost frame.
+     */
+    FULL,
+    /**
+     * The last frame again, quieter each time. No decoder work.
+     */
+    REPEAT,
+    /**
+     * Silence. No decoder work.
+     */
+    SILENCE;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeConcealmentMode: FfiConverterRustBuffer<ConcealmentMode> {
+    override fun read(buf: ByteBuffer) = try {
+        ConcealmentMode.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ConcealmentMode) = 4UL
+
+    override fun write(value: ConcealmentMode, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Why the engine can't run a config (`ConfigBuilder::validate`). A stable ID with
+ * the limits as plain numbers, so apps word and format it in the user's language
+ * instead of parsing English from the engine (see also `EffectiveConfig.messages`).
+ */
+sealed class ConfigIssue {
+    
+    data class SampleRateUnsupported(
+        val `supportedHz`: List<kotlin.Int>) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class FrameSizeUnsupported(
+        val `supportedMs`: List<kotlin.Int>) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * The jitter buffer must hold at least one frame.
+     */
+    data class JitterBufferTooShort(
+        val `minMs`: kotlin.Int) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * 0 (auto) is allowed too.
+     */
+    data class BitrateOutOfRange(
+        val `minBps`: kotlin.Int, 
+        val `maxBps`: kotlin.Int) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * `reorder_wait_ms`, `agc_target_level_db` or `agc_max_gain_db`.
+     */
+    object NegativeValue : ConfigIssue()
+    
+    
+    data class NoiseSuppressionNeedsSampleRate(
+        val `sampleRateHz`: kotlin.Int) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    object VadGateNeedsVad : ConfigIssue()
+    
+    
+    data class MaxPacketBytesTooSmall(
+        val `minBytes`: kotlin.UInt) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    object NegativeDuck : ConfigIssue()
+    
+    
+    data class RogerBeepOutOfRange(
+        val `maxMs`: kotlin.UInt, 
+        val `maxFrequencyHz`: kotlin.Float) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class ComfortNoiseOutOfRange(
+        val `minDbfs`: kotlin.Float, 
+        val `maxDbfs`: kotlin.Float) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class PttPreRollTooLong(
+        val `maxMs`: kotlin.UInt) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * Needs a device ID and a gain within range.
+     */
+    data class SecondaryOutputInvalid(
+        val `maxGain`: kotlin.Float) : ConfigIssue()
+        
+    {
+        
+
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeConfigIssue : FfiConverterRustBuffer<ConfigIssue>{
+    override fun read(buf: ByteBuffer): ConfigIssue {
+        return when(buf.getInt()) {
+            1 -> ConfigIssue.SampleRateUnsupported(
+                FfiConverterSequenceInt.read(buf),
+                )
+            2 -> ConfigIssue.FrameSizeUnsupported(
+                FfiConverterSequenceInt.read(buf),
+                )
+            3 -> ConfigIssue.JitterBufferTooShort(
+                FfiConverterInt.read(buf),
+                )
+            4 -> ConfigIssue.BitrateOutOfRange(
+                FfiConverterInt.read(buf),
+                FfiConverterInt.read(buf),
+                )
+            5 -> ConfigIssue.NegativeValue
+            6 -> ConfigIssue.NoiseSuppressionNeedsSampleRate(
+                FfiConverterInt.read(buf),
+                )
+            7 -> ConfigIssue.VadGateNeedsVad
+            8 -> ConfigIssue.MaxPacketBytesTooSmall(
+                FfiConverterUInt.read(buf),
+                )
+            9 -> ConfigIssue.NegativeDuck
+            10 -> ConfigIssue.RogerBeepOutOfRange(
+                FfiConverterUInt.read(buf),
+                FfiConverterFloat.read(buf),
+                )
+            11 -> ConfigIssue.ComfortNoiseOutOfRange(
+                FfiConverterFloat.read(buf),
+                FfiConverterFloat.read(buf),
+                )
+            12 -> ConfigIssue.PttPreRollTooLong(
+                FfiConverterUInt.read(buf),
+                )
+            13 -> ConfigIssue.SecondaryOutputInvalid(
+                FfiConverterFloat.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: ConfigIssue) = when(value) {
+        is ConfigIssue.SampleRateUnsupported -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceInt.allocationSize(value.`supportedHz`)
+            )
+        }
+        is ConfigIssue.FrameSizeUnsupported -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceInt.allocationSize(value.`supportedMs`)
+            )
+        }
+        is ConfigIssue.JitterBufferTooShort -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterInt.allocationSize(value.`minMs`)
+            )
+        }
+        is ConfigIssue.BitrateOutOfRange -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterInt.allocationSize(value.`minBps`)
+                + FfiConverterInt.allocationSize(value.`maxBps`)
+            )
+        }
+        is ConfigIssue.NegativeValue -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is ConfigIssue.NoiseSuppressionNeedsSampleRate -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterInt.allocationSize(value.`sampleRateHz`)
+            )
+        }
+        is ConfigIssue.VadGateNeedsVad -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is ConfigIssue.MaxPacketBytesTooSmall -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterUInt.allocationSize(value.`minBytes`)
+            )
+        }
+        is ConfigIssue.NegativeDuck -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is ConfigIssue.RogerBeepOutOfRange -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterUInt.allocationSize(value.`maxMs`)
+                + FfiConverterFloat.allocationSize(value.`maxFrequencyHz`)
+            )
+        }
+        is ConfigIssue.ComfortNoiseOutOfRange -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`minDbfs`)
+                + FfiConverterFloat.allocationSize(value.`maxDbfs`)
+            )
+        }
+        is ConfigIssue.PttPreRollTooLong -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterUInt.allocationSize(value.`maxMs`)
+            )
+        }
+        is ConfigIssue.SecondaryOutputInvalid -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`maxGain`)
+            )
+        }
+    }
+
+    override fun write(value: ConfigIssue, buf: ByteBuffer) {
+        when(value) {
+            is ConfigIssue.SampleRateUnsupported -> {
+                buf.putInt(1)
+                FfiConverterSequenceInt.write(value.`supportedHz`, buf)
+                Unit
+            }
+            is ConfigIssue.FrameSizeUnsupported -> {
+                buf.putInt(2)
+                FfiConverterSequenceInt.write(value.`supportedMs`, buf)
+                Unit
+            }
+            is ConfigIssue.JitterBufferTooShort -> {
+                buf.putInt(3)
+                FfiConverterInt.write(value.`minMs`, buf)
+                Unit
+            }
+            is ConfigIssue.BitrateOutOfRange -> {
+                buf.putInt(4)
+                FfiConverterInt.write(value.`minBps`, buf)
+                FfiConverterInt.write(value.`maxBps`, buf)
+                Unit
+            }
+            is ConfigIssue.NegativeValue -> {
+                buf.putInt(5)
+                Unit
+            }
+            is ConfigIssue.NoiseSuppressionNeedsSampleRate -> {
+                buf.putInt(6)
+                FfiConverterInt.write(value.`sampleRateHz`, buf)
+                Unit
+            }
+            is ConfigIssue.VadGateNeedsVad -> {
+                buf.putInt(7)
+                Unit
+            }
+            is ConfigIssue.MaxPacketBytesTooSmall -> {
+                buf.putInt(8)
+                FfiConverterUInt.write(value.`minBytes`, buf)
+                Unit
+            }
+            is ConfigIssue.NegativeDuck -> {
+                buf.putInt(9)
+                Unit
+            }
+            is ConfigIssue.RogerBeepOutOfRange -> {
+                buf.putInt(10)
+                FfiConverterUInt.write(value.`maxMs`, buf)
+                FfiConverterFloat.write(value.`maxFrequencyHz`, buf)
+                Unit
+            }
+            is ConfigIssue.ComfortNoiseOutOfRange -> {
+                buf.putInt(11)
+                FfiConverterFloat.write(value.`minDbfs`, buf)
+                FfiConverterFloat.write(value.`maxDbfs`, buf)
+                Unit
+            }
+            is ConfigIssue.PttPreRollTooLong -> {
+                buf.putInt(12)
+                FfiConverterUInt.write(value.`maxMs`, buf)
+                Unit
+            }
+            is ConfigIssue.SecondaryOutputInvalid -> {
+                buf.putInt(13)
+                FfiConverterFloat.write(value.`maxGain`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+/**
+ * Named starting points for `ConfigBuilder`, each a full parameter set for one kind of link.
+ */
+
+enum class ConfigProfile {
+    
+    /**
+     * Wi-Fi or wired LAN: 20ms frames, a short jitter buffer.
+     */
+    LOW_LATENCY_LAN,
+    /**
+     * Mobile data: redundancy, some reorder tolerance, stale audio dropped after stalls.
+     */
+    CELLULAR,
+    /**
+     * High-latency, metered links: 60ms frames, 12 kbps, a deep jitter buffer.
+     */
+    SATELLITE,
+    /**
+     * Least CPU and radio time: 16kHz, a cheap encoder, silence not transmitted.
+     */
+    BATTERY;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeConfigProfile: FfiConverterRustBuffer<ConfigProfile> {
+    override fun read(buf: ByteBuffer) = try {
+        ConfigProfile.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ConfigProfile) = 4UL
+
+    override fun write(value: ConfigProfile, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Who gets the floor when it is taken (`AudioConfig.floor_control`).
+ */
+
+enum class FloorPolicy {
+    
+    /**
+     * The holder keeps the floor until it lets go.
+     */
+    FIRST_COME,
+    /**
+     * A request with a higher `floor_priority` than the holder's takes the floor over.
+     */
+    PRIORITY;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFloorPolicy: FfiConverterRustBuffer<FloorPolicy> {
+    override fun read(buf: ByteBuffer) = try {
+        FloorPolicy.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: FloorPolicy) = 4UL
+
+    override fun write(value: FloorPolicy, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Text the engine has for users, by ID. The arguments are listed with each ID.
+ */
+
+enum class MessageId {
+    
+    /**
+     * The supported rates (Hz).
+     */
+    SAMPLE_RATE_UNSUPPORTED,
+    /**
+     * The supported frame sizes (ms).
+     */
+    FRAME_SIZE_UNSUPPORTED,
+    /**
+     * The shortest jitter buffer (ms).
+     */
+    JITTER_BUFFER_TOO_SHORT,
+    /**
+     * The lowest and highest bitrate (bps).
+     */
+    BITRATE_OUT_OF_RANGE,
+    NEGATIVE_VALUE,
+    /**
+     * The sample rate needed (Hz).
+     */
+    NOISE_SUPPRESSION_NEEDS_SAMPLE_RATE,
+    VAD_GATE_NEEDS_VAD,
+    /**
+     * The smallest limit (bytes).
+     */
+    MAX_PACKET_BYTES_TOO_SMALL,
+    NEGATIVE_DUCK,
+    /**
+     * The longest beep (ms) and the highest frequency (Hz).
+     */
+    ROGER_BEEP_OUT_OF_RANGE,
+    /**
+     * The quietest and loudest level (dBFS).
+     */
+    COMFORT_NOISE_OUT_OF_RANGE,
+    /**
+     * The longest pre-roll (ms).
+     */
+    PTT_PRE_ROLL_TOO_LONG,
+    /**
+     * The highest gain.
+     */
+    SECONDARY_OUTPUT_INVALID,
+    /**
+     * The complexity asked for, and the one used.
+     */
+    OPUS_COMPLEXITY_CLAMPED,
+    /**
+     * The gap asked for, and the one used (packets).
+     */
+    RESYNC_GAP_CLAMPED,
+    /**
+     * The longest replay buffer (s).
+     */
+    REPLAY_BUFFER_CAPPED,
+    /**
+     * The loudest beep (dBFS).
+     */
+    ROGER_BEEP_LEVEL_CAPPED;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMessageId: FfiConverterRustBuffer<MessageId> {
+    override fun read(buf: ByteBuffer) = try {
+        MessageId.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: MessageId) = 4UL
+
+    override fun write(value: MessageId, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * The network the device just moved to (`notify_network_change`).
+ */
+
+enum class NetworkKind {
+    
+    WIFI,
+    CELLULAR,
+    ETHERNET,
+    OTHER;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeNetworkKind: FfiConverterRustBuffer<NetworkKind> {
+    override fun read(buf: ByteBuffer) = try {
+        NetworkKind.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: NetworkKind) = 4UL
+
+    override fun write(value: NetworkKind, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Why the output watchdog suspects the device plays nothing.
+ */
+
+enum class OutputSilentReason {
+    
+    /**
+     * We keep rendering, but the frame position the device presents doesn't move.
+     */
+    POSITION_STALLED,
+    /**
+     * The stream under-ran on every check: the device isn't pulling audio in time.
+     */
+    CONTINUOUS_XRUNS;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeOutputSilentReason: FfiConverterRustBuffer<OutputSilentReason> {
+    override fun read(buf: ByteBuffer) = try {
+        OutputSilentReason.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: OutputSilentReason) = 4UL
+
+    override fun write(value: OutputSilentReason, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * What happens to lower-priority speakers (`AudioConfig.preemption`).
+ */
+
+enum class PreemptionPolicy {
+    
+    /**
+     * Turned down by `AudioConfig.preemption_duck_db`.
+     */
+    DUCK,
+    /**
+     * Muted (still decoded, so they come back without a gap in their stream).
+     */
+    SUPPRESS;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePreemptionPolicy: FfiConverterRustBuffer<PreemptionPolicy> {
+    override fun read(buf: ByteBuffer) = try {
+        PreemptionPolicy.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: PreemptionPolicy) = 4UL
+
+    override fun write(value: PreemptionPolicy, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+public interface AudioErrorCallback {
+    
+    /**
+     * `count` occurrences of error `code` between the two timestamps (ms since the Unix epoch).
+     * The first occurrence is reported on its own right away; repeats within the following
+     * second are summarized in a single call.
+     */
+    fun `onEngineError`(`code`: kotlin.Int, `count`: kotlin.UInt, `firstTimestampMs`: kotlin.ULong, `lastTimestampMs`: kotlin.ULong)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceAudioErrorCallback {
+    internal object `onEngineError`: UniffiCallbackInterfaceAudioErrorCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`code`: Int,`count`: Int,`firstTimestampMs`: Long,`lastTimestampMs`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeAudioErrorCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onEngineError`(
+                    FfiConverterInt.lift(`code`),
+                    FfiConverterUInt.lift(`count`),
+                    FfiConverterULong.lift(`firstTimestampMs`),
+                    FfiConverterULong.lift(`lastTimestampMs`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeAudioErrorCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeAudioErrorCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceAudioErrorCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onEngineError`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioerrorcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeAudioErrorCallback: FfiConverterCallbackInterface<AudioErrorCallback>()
+
+
+
+
+
+public interface AudioLevelCallback {
+    
+    /**
+     * Every peer in the mix, about every 50ms while there are any.
+     */
+    fun `onPeerLevels`(`levels`: List<PeerLevel>)
+    
+    /**
+     * Our mic, about every 50ms while a session runs.
+     */
+    fun `onMicLevel`(`level`: MicLevel)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceAudioLevelCallback {
+    internal object `onPeerLevels`: UniffiCallbackInterfaceAudioLevelCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`levels`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeAudioLevelCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onPeerLevels`(
+                    FfiConverterSequenceTypePeerLevel.lift(`levels`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onMicLevel`: UniffiCallbackInterfaceAudioLevelCallbackMethod1 {
+        override fun callback(`uniffiHandle`: Long,`level`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeAudioLevelCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onMicLevel`(
+                    FfiConverterTypeMicLevel.lift(`level`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeAudioLevelCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeAudioLevelCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceAudioLevelCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onPeerLevels`,
+        `onMicLevel`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_audiolevelcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeAudioLevelCallback: FfiConverterCallbackInterface<AudioLevelCallback>()
+
+
+
+
+
+public interface AudioLoopCallback {
+    
+    /**
+     * Audio from `looped_id` is a copy of `original_id`'s (our own node ID when we
+     * hear ourselves), so two bridges forward the same channel in a loop. The copies
+     * are dropped. Fired when the loop is found, and every 30s while it lasts.
+     */
+    fun `onAudioLoopDetected`(`loopedId`: kotlin.UInt, `originalId`: kotlin.UInt)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceAudioLoopCallback {
+    internal object `onAudioLoopDetected`: UniffiCallbackInterfaceAudioLoopCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`loopedId`: Int,`originalId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeAudioLoopCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onAudioLoopDetected`(
+                    FfiConverterUInt.lift(`loopedId`),
+                    FfiConverterUInt.lift(`originalId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeAudioLoopCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeAudioLoopCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceAudioLoopCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onAudioLoopDetected`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioloopcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeAudioLoopCallback: FfiConverterCallbackInterface<AudioLoopCallback>()
+
+
+
+
+
+public interface AudioRouteCallback {
+    
+    /**
+     * The engine switched to `route` (device `device_id`), at session start or
+     * after `notify_route_changed`. Not fired when an explicit device ID is configured.
+     */
+    fun `onAudioRouteChanged`(`route`: AudioRoute, `deviceId`: kotlin.Int)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceAudioRouteCallback {
+    internal object `onAudioRouteChanged`: UniffiCallbackInterfaceAudioRouteCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`route`: RustBuffer.ByValue,`deviceId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeAudioRouteCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onAudioRouteChanged`(
+                    FfiConverterTypeAudioRoute.lift(`route`),
+                    FfiConverterInt.lift(`deviceId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeAudioRouteCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeAudioRouteCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceAudioRouteCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onAudioRouteChanged`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioroutecallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeAudioRouteCallback: FfiConverterCallbackInterface<AudioRouteCallback>()
+
+
+
+
+
+public interface BluetoothScoCallback {
+    
+    /**
+     * The output stream moved onto (true) or off (false) a Bluetooth SCO device:
+     * the app should call `startBluetoothSco` / `setCommunicationDevice`, or
+     * release it. Also fired with false when the session stops.
+     */
+    fun `onBluetoothScoNeeded`(`needed`: kotlin.Boolean)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceBluetoothScoCallback {
+    internal object `onBluetoothScoNeeded`: UniffiCallbackInterfaceBluetoothScoCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`needed`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeBluetoothScoCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onBluetoothScoNeeded`(
+                    FfiConverterBoolean.lift(`needed`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeBluetoothScoCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeBluetoothScoCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceBluetoothScoCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onBluetoothScoNeeded`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_bluetoothscocallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeBluetoothScoCallback: FfiConverterCallbackInterface<BluetoothScoCallback>()
+
+
+
+
+
+public interface DspParamCallback {
+    
+    fun `onDspParamChanged`(`name`: kotlin.String, `value`: kotlin.Float)
+    
+    /**
+     * A snapshot applied via `apply_param_snapshot` was reverted because playout became unstable.
+     */
+    fun `onParamSnapshotRolledBack`(`stalls`: kotlin.UInt)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceDspParamCallback {
+    internal object `onDspParamChanged`: UniffiCallbackInterfaceDspParamCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`name`: RustBuffer.ByValue,`value`: Float,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeDspParamCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onDspParamChanged`(
+                    FfiConverterString.lift(`name`),
+                    FfiConverterFloat.lift(`value`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onParamSnapshotRolledBack`: UniffiCallbackInterfaceDspParamCallbackMethod1 {
+        override fun callback(`uniffiHandle`: Long,`stalls`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeDspParamCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onParamSnapshotRolledBack`(
+                    FfiConverterUInt.lift(`stalls`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeDspParamCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeDspParamCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceDspParamCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onDspParamChanged`,
+        `onParamSnapshotRolledBack`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_dspparamcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeDspParamCallback: FfiConverterCallbackInterface<DspParamCallback>()
+
+
+
+
+
+public interface EmergencyCallback {
+    
+    /**
+     * `node_id` started an emergency transmission. Fired once per emergency, on the
+     * first packet that carries the flag; its audio plays above every priority.
+     */
+    fun `onEmergencyReceived`(`nodeId`: kotlin.UInt)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceEmergencyCallback {
+    internal object `onEmergencyReceived`: UniffiCallbackInterfaceEmergencyCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`nodeId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeEmergencyCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onEmergencyReceived`(
+                    FfiConverterUInt.lift(`nodeId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeEmergencyCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeEmergencyCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceEmergencyCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onEmergencyReceived`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_emergencycallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeEmergencyCallback: FfiConverterCallbackInterface<EmergencyCallback>()
+
+
+
+
+
+public interface FloorCallback {
+    
+    /**
+     * We hold the floor and the mic gate is open.
+     */
+    fun `onFloorGranted`()
+    
+    /**
+     * Our request lost to `holder_id`. The mic is turned off.
+     */
+    fun `onFloorDenied`(`holderId`: kotlin.UInt)
+    
+    /**
+     * `holder_id` took the floor over from us (`FloorPolicy::Priority`). The mic is turned off.
+     */
+    fun `onFloorRevoked`(`holderId`: kotlin.UInt)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceFloorCallback {
+    internal object `onFloorGranted`: UniffiCallbackInterfaceFloorCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeFloorCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onFloorGranted`(
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onFloorDenied`: UniffiCallbackInterfaceFloorCallbackMethod1 {
+        override fun callback(`uniffiHandle`: Long,`holderId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeFloorCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onFloorDenied`(
+                    FfiConverterUInt.lift(`holderId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onFloorRevoked`: UniffiCallbackInterfaceFloorCallbackMethod2 {
+        override fun callback(`uniffiHandle`: Long,`holderId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeFloorCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onFloorRevoked`(
+                    FfiConverterUInt.lift(`holderId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeFloorCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeFloorCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceFloorCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onFloorGranted`,
+        `onFloorDenied`,
+        `onFloorRevoked`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_floorcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeFloorCallback: FfiConverterCallbackInterface<FloorCallback>()
+
+
+
+
+
+public interface OutputHealthCallback {
+    
+    /**
+     * OutputSilentSuspected: the output stream claims to be running but probably makes
+     * no sound, so the app can suggest switching routes. `tone_checked` is true if an
+     * inaudible test tone was played and not heard on the mic. Fired once per episode.
+     */
+    fun `onOutputSilentSuspected`(`reason`: OutputSilentReason, `toneChecked`: kotlin.Boolean)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceOutputHealthCallback {
+    internal object `onOutputSilentSuspected`: UniffiCallbackInterfaceOutputHealthCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`reason`: RustBuffer.ByValue,`toneChecked`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeOutputHealthCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onOutputSilentSuspected`(
+                    FfiConverterTypeOutputSilentReason.lift(`reason`),
+                    FfiConverterBoolean.lift(`toneChecked`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeOutputHealthCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeOutputHealthCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceOutputHealthCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onOutputSilentSuspected`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_outputhealthcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeOutputHealthCallback: FfiConverterCallbackInterface<OutputHealthCallback>()
+
+
+
+
+
+public interface PacketTransport {
+    
+    fun `sendPacket`(`data`: kotlin.ByteArray)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfacePacketTransport {
+    internal object `sendPacket`: UniffiCallbackInterfacePacketTransportMethod0 {
+        override fun callback(`uniffiHandle`: Long,`data`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypePacketTransport.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`sendPacket`(
+                    FfiConverterByteArray.lift(`data`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypePacketTransport.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypePacketTransport.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfacePacketTransport.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `sendPacket`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_packettransport(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypePacketTransport: FfiConverterCallbackInterface<PacketTransport>()
+
+
+
+
+
+public interface PeerEventCallback {
+    
+    /**
+     * A peer's audio starts playing (new peer, or a new talk burst from a known one).
+     */
+    fun `onPeerStartedTalking`(`peerId`: kotlin.UInt)
+    
+    /**
+     * A peer's talk burst has finished playing.
+     */
+    fun `onPeerStoppedTalking`(`peerId`: kotlin.UInt)
+    
+    /**
+     * A peer was silent long enough to be dropped from the mixer.
+     */
+    fun `onPeerTimedOut`(`peerId`: kotlin.UInt)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfacePeerEventCallback {
+    internal object `onPeerStartedTalking`: UniffiCallbackInterfacePeerEventCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypePeerEventCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onPeerStartedTalking`(
+                    FfiConverterUInt.lift(`peerId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onPeerStoppedTalking`: UniffiCallbackInterfacePeerEventCallbackMethod1 {
+        override fun callback(`uniffiHandle`: Long,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypePeerEventCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onPeerStoppedTalking`(
+                    FfiConverterUInt.lift(`peerId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onPeerTimedOut`: UniffiCallbackInterfacePeerEventCallbackMethod2 {
+        override fun callback(`uniffiHandle`: Long,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypePeerEventCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onPeerTimedOut`(
+                    FfiConverterUInt.lift(`peerId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypePeerEventCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypePeerEventCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfacePeerEventCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onPeerStartedTalking`,
+        `onPeerStoppedTalking`,
+        `onPeerTimedOut`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_peereventcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypePeerEventCallback: FfiConverterCallbackInterface<PeerEventCallback>()
+
+
+
+
+
+public interface PeerKeyCallback {
+    
+    /**
+     * A peer proved it holds `identity_key` and its audio can now be decrypted.
+     * The app decides whether it trusts that key (e.g. against a contact list).
+     */
+    fun `onPeerKeyEstablished`(`peerId`: kotlin.UInt, `identityKey`: kotlin.ByteArray)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfacePeerKeyCallback {
+    internal object `onPeerKeyEstablished`: UniffiCallbackInterfacePeerKeyCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`peerId`: Int,`identityKey`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypePeerKeyCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onPeerKeyEstablished`(
+                    FfiConverterUInt.lift(`peerId`),
+                    FfiConverterByteArray.lift(`identityKey`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypePeerKeyCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypePeerKeyCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfacePeerKeyCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onPeerKeyEstablished`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_peerkeycallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypePeerKeyCallback: FfiConverterCallbackInterface<PeerKeyCallback>()
+
+
+
+
+
+public interface PeerPositionCallback {
+    
+    /**
+     * Fired from the audio thread when audio carrying this position starts playing,
+     * so overlays line up with what the user hears.
+     */
+    fun `onPeerPosition`(`peerId`: kotlin.UInt, `position`: PeerPosition)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfacePeerPositionCallback {
+    internal object `onPeerPosition`: UniffiCallbackInterfacePeerPositionCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`peerId`: Int,`position`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypePeerPositionCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onPeerPosition`(
+                    FfiConverterUInt.lift(`peerId`),
+                    FfiConverterTypePeerPosition.lift(`position`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypePeerPositionCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypePeerPositionCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfacePeerPositionCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onPeerPosition`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_peerpositioncallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypePeerPositionCallback: FfiConverterCallbackInterface<PeerPositionCallback>()
+
+
+
+
+
+public interface RosterCallback {
+    
+    /**
+     * The admin node's newer roster arrived (or we published one as the admin).
+     */
+    fun `onRosterUpdated`(`roster`: PeerRoster)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceRosterCallback {
+    internal object `onRosterUpdated`: UniffiCallbackInterfaceRosterCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`roster`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeRosterCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onRosterUpdated`(
+                    FfiConverterTypePeerRoster.lift(`roster`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeRosterCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeRosterCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceRosterCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onRosterUpdated`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_rostercallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeRosterCallback: FfiConverterCallbackInterface<RosterCallback>()
+
+
+
+
+
+public interface ScanCallback {
+    
+    /**
+     * `peer_id` started talking on scanned `channel` (`set_scan_channels`) after it
+     * had been quiet. Whether it is heard depends on the channels ranked above it.
+     */
+    fun `onScanActivity`(`channel`: kotlin.UShort, `peerId`: kotlin.UInt)
+    
+    /**
+     * The channel being played changed: the mixer locked onto `channel`, or went
+     * back to scanning (`None`) once every channel it listens to is quiet.
+     */
+    fun `onScanChannelChanged`(`channel`: kotlin.UShort?)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceScanCallback {
+    internal object `onScanActivity`: UniffiCallbackInterfaceScanCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`channel`: Short,`peerId`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeScanCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onScanActivity`(
+                    FfiConverterUShort.lift(`channel`),
+                    FfiConverterUInt.lift(`peerId`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+    internal object `onScanChannelChanged`: UniffiCallbackInterfaceScanCallbackMethod1 {
+        override fun callback(`uniffiHandle`: Long,`channel`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeScanCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onScanChannelChanged`(
+                    FfiConverterOptionalUShort.lift(`channel`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
 
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeScanCallback.handleMap.remove(handle)
+        }
+    }
 
-// Interface implemented by anything that can contain an object reference.
-//
-// Such types expose a `destroy()` method that must be called to cleanly
-// dispose of the contained objects. Failure to call this method may result
-// in memory leaks.
-//
-// The easiest way to ensure this method is called is to use the `.use`
-// helper method to execute a block and destroy the object at the end.
-interface Disposable {
-    fun destroy()
-    companion object {
-        fun destroy(vararg args: Any?) {
-            for (arg in args) {
-                when (arg) {
-                    is Disposable -> arg.destroy()
-                    is ArrayList<*> -> {
-                        for (idx in arg.indices) {
-                            val element = arg[idx]
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Map<*, *> -> {
-                        for (element in arg.values) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Iterable<*> -> {
-                        for (element in arg) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                }
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeScanCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceScanCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onScanActivity`,
+        `onScanChannelChanged`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_scancallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeScanCallback: FfiConverterCallbackInterface<ScanCallback>()
+
+
+
+
+
+public interface TransmitCallback {
+    
+    /**
+     * Our first frame after PTT was pressed is going out (after the talk permit
+     * tone, if there is one).
+     */
+    fun `onTransmitStarted`()
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceTransmitCallback {
+    internal object `onTransmitStarted`: UniffiCallbackInterfaceTransmitCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeTransmitCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onTransmitStarted`(
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeTransmitCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeTransmitCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceTransmitCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onTransmitStarted`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_transmitcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeTransmitCallback: FfiConverterCallbackInterface<TransmitCallback>()
+
+
+
+
+
+/**
+ * App policy on what we transmit (e.g. nothing while a driving UI is shown, or
+ * external moderation), decided per frame without routing the PCM out and back.
+ */
+public interface TransmitGate {
+    
+    /**
+     * Called on the audio thread for every encoded frame before it is sent; `false`
+     * drops the frame. Runs inside the capture callback, so it must return quickly
+     * and never block.
+     */
+    fun `allowFrame`(`meta`: TransmitFrameMeta): kotlin.Boolean
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceTransmitGate {
+    internal object `allowFrame`: UniffiCallbackInterfaceTransmitGateMethod0 {
+        override fun callback(`uniffiHandle`: Long,`meta`: RustBuffer.ByValue,`uniffiOutReturn`: ByteByReference,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeTransmitGate.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`allowFrame`(
+                    FfiConverterTypeTransmitFrameMeta.lift(`meta`),
+                )
+            }
+            val writeReturn = { value: kotlin.Boolean -> uniffiOutReturn.setValue(FfiConverterBoolean.lower(value)) }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeTransmitGate.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeTransmitGate.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceTransmitGate.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `allowFrame`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_transmitgate(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeTransmitGate: FfiConverterCallbackInterface<TransmitGate>()
+
+
+
+
+
+public interface TransportHealthCallback {
+    
+    /**
+     * The app's `send_packet` blocked for `stall_ms` before returning; packets queued
+     * up behind it in the meantime. Fired from the transport thread after each slow call.
+     */
+    fun `onTransportStalled`(`stallMs`: kotlin.UInt)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceTransportHealthCallback {
+    internal object `onTransportStalled`: UniffiCallbackInterfaceTransportHealthCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`stallMs`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeTransportHealthCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onTransportStalled`(
+                    FfiConverterUInt.lift(`stallMs`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeTransportHealthCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeTransportHealthCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceTransportHealthCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onTransportStalled`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_transporthealthcallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeTransportHealthCallback: FfiConverterCallbackInterface<TransportHealthCallback>()
+
+
+
+
+
+public interface VoiceActivityCallback {
+    
+    /**
+     * Fired on transitions only: `true` when the local user starts speaking while transmitting.
+     */
+    fun `onVoiceActivityChanged`(`speaking`: kotlin.Boolean)
+    
+    companion object
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceVoiceActivityCallback {
+    internal object `onVoiceActivityChanged`: UniffiCallbackInterfaceVoiceActivityCallbackMethod0 {
+        override fun callback(`uniffiHandle`: Long,`speaking`: Byte,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeVoiceActivityCallback.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onVoiceActivityChanged`(
+                    FfiConverterBoolean.lift(`speaking`),
+                )
             }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeVoiceActivityCallback.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeVoiceActivityCallback.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceVoiceActivityCallback.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onVoiceActivityChanged`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_voiceactivitycallback(vtable)
+    }
+}
+
+/**
+ * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
+ *
+ * @suppress
+ */
+public object FfiConverterTypeVoiceActivityCallback: FfiConverterCallbackInterface<VoiceActivityCallback>()
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalUByte: FfiConverterRustBuffer<kotlin.UByte?> {
+    override fun read(buf: ByteBuffer): kotlin.UByte? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterUByte.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.UByte?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterUByte.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.UByte?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterUByte.write(value, buf)
         }
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
-    try {
-        block(this)
-    } finally {
-        try {
-            // N.B. our implementation is on the nullable type `Disposable?`.
-            this?.destroy()
-        } catch (e: Throwable) {
-            // swallow
+public object FfiConverterOptionalUShort: FfiConverterRustBuffer<kotlin.UShort?> {
+    override fun read(buf: ByteBuffer): kotlin.UShort? {
+        if (buf.get().toInt() == 0) {
+            return null
         }
+        return FfiConverterUShort.read(buf)
     }
 
-/** 
- * Placeholder object used to signal that we're constructing an interface with a FFI handle.
- *
- * This is the first argument for interface constructors that input a raw handle. It exists is that
- * so we can avoid signature conflicts when an interface has a regular constructor than inputs a
- * Long.
- *
- * @suppress
- * */
-object UniffiWithHandle
+    override fun allocationSize(value: kotlin.UShort?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterUShort.allocationSize(value)
+        }
+    }
 
-/** 
- * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
- *
+    override fun write(value: kotlin.UShort?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterUShort.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
  * @suppress
- * */
-object NoHandle// Magic number for the Rust proxy to call using the same mechanism as every other method,
-// to free the callback once it's dropped by Rust.
-internal const val IDX_CALLBACK_FREE = 0
-// Callback return codes
-internal const val UNIFFI_CALLBACK_SUCCESS = 0
-internal const val UNIFFI_CALLBACK_ERROR = 1
-internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+ */
+public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
+    override fun read(buf: ByteBuffer): kotlin.UInt? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterUInt.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.UInt?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterUInt.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterUInt.write(value, buf)
+        }
+    }
+}
+
+
+
 
 /**
  * @suppress
  */
-public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
-    internal val handleMap = UniffiHandleMap<CallbackInterface>()
+public object FfiConverterOptionalInt: FfiConverterRustBuffer<kotlin.Int?> {
+    override fun read(buf: ByteBuffer): kotlin.Int? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterInt.read(buf)
+    }
 
-    internal fun drop(handle: Long) {
-        handleMap.remove(handle)
+    override fun allocationSize(value: kotlin.Int?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterInt.allocationSize(value)
+        }
     }
 
-    override fun lift(value: Long): CallbackInterface {
-        return handleMap.get(value)
+    override fun write(value: kotlin.Int?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterInt.write(value, buf)
+        }
     }
+}
 
-    override fun read(buf: ByteBuffer) = lift(buf.getLong())
 
-    override fun lower(value: CallbackInterface) = handleMap.insert(value)
 
-    override fun allocationSize(value: CallbackInterface) = 8UL
 
-    override fun write(value: CallbackInterface, buf: ByteBuffer) {
-        buf.putLong(lower(value))
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalFloat: FfiConverterRustBuffer<kotlin.Float?> {
+    override fun read(buf: ByteBuffer): kotlin.Float? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterFloat.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Float?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterFloat.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Float?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterFloat.write(value, buf)
+        }
     }
 }
+
+
+
+
 /**
- * The cleaner interface for Object finalization code to run.
- * This is the entry point to any implementation that we're using.
- *
- * The cleaner registers objects and returns cleanables, so now we are
- * defining a `UniffiCleaner` with a `UniffiClenaer.Cleanable` to abstract the
- * different implmentations available at compile time.
- *
  * @suppress
  */
-interface UniffiCleaner {
-    interface Cleanable {
-        fun clean()
+public object FfiConverterOptionalDouble: FfiConverterRustBuffer<kotlin.Double?> {
+    override fun read(buf: ByteBuffer): kotlin.Double? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterDouble.read(buf)
     }
 
-    fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable
+    override fun allocationSize(value: kotlin.Double?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterDouble.allocationSize(value)
+        }
+    }
 
-    companion object
+    override fun write(value: kotlin.Double?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterDouble.write(value, buf)
+        }
+    }
 }
 
-// The fallback Jna cleaner, which is available for both Android, and the JVM.
-private class UniffiJnaCleaner : UniffiCleaner {
-    private val cleaner = com.sun.jna.internal.Cleaner.getCleaner()
 
-    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
-        UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
+    override fun read(buf: ByteBuffer): kotlin.String? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterString.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.String?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterString.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterString.write(value, buf)
+        }
+    }
 }
 
-private class UniffiJnaCleanable(
-    private val cleanable: com.sun.jna.internal.Cleaner.Cleanable,
-) : UniffiCleaner.Cleanable {
-    override fun clean() = cleanable.clean()
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeMicLevel: FfiConverterRustBuffer<MicLevel?> {
+    override fun read(buf: ByteBuffer): MicLevel? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeMicLevel.read(buf)
+    }
+
+    override fun allocationSize(value: MicLevel?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeMicLevel.allocationSize(value)
+        }
+    }
+
+    override fun write(value: MicLevel?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeMicLevel.write(value, buf)
+        }
+    }
 }
 
 
-// We decide at uniffi binding generation time whether we were
-// using Android or not.
-// There are further runtime checks to chose the correct implementation
-// of the cleaner.
-private fun UniffiCleaner.Companion.create(): UniffiCleaner =
-    try {
-        // For safety's sake: if the library hasn't been run in android_cleaner = true
-        // mode, but is being run on Android, then we still need to think about
-        // Android API versions.
-        // So we check if java.lang.ref.Cleaner is there, and use that…
-        java.lang.Class.forName("java.lang.ref.Cleaner")
-        JavaLangRefCleaner()
-    } catch (e: ClassNotFoundException) {
-        // … otherwise, fallback to the JNA cleaner.
-        UniffiJnaCleaner()
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypePeerPosition: FfiConverterRustBuffer<PeerPosition?> {
+    override fun read(buf: ByteBuffer): PeerPosition? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypePeerPosition.read(buf)
     }
 
-private class JavaLangRefCleaner : UniffiCleaner {
-    val cleaner = java.lang.ref.Cleaner.create()
+    override fun allocationSize(value: PeerPosition?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypePeerPosition.allocationSize(value)
+        }
+    }
 
-    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
-        JavaLangRefCleanable(cleaner.register(value, cleanUpTask))
+    override fun write(value: PeerPosition?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypePeerPosition.write(value, buf)
+        }
+    }
 }
 
-private class JavaLangRefCleanable(
-    val cleanable: java.lang.ref.Cleaner.Cleanable
-) : UniffiCleaner.Cleanable {
-    override fun clean() = cleanable.clean()
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeRogerBeep: FfiConverterRustBuffer<RogerBeep?> {
+    override fun read(buf: ByteBuffer): RogerBeep? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeRogerBeep.read(buf)
+    }
+
+    override fun allocationSize(value: RogerBeep?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeRogerBeep.allocationSize(value)
+        }
+    }
+
+    override fun write(value: RogerBeep?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeRogerBeep.write(value, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterUInt: FfiConverter<UInt, Int> {
-    override fun lift(value: Int): UInt {
-        return value.toUInt()
+public object FfiConverterOptionalTypeSecondaryOutput: FfiConverterRustBuffer<SecondaryOutput?> {
+    override fun read(buf: ByteBuffer): SecondaryOutput? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeSecondaryOutput.read(buf)
     }
 
-    override fun read(buf: ByteBuffer): UInt {
-        return lift(buf.getInt())
+    override fun allocationSize(value: SecondaryOutput?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSecondaryOutput.allocationSize(value)
+        }
     }
 
-    override fun lower(value: UInt): Int {
-        return value.toInt()
+    override fun write(value: SecondaryOutput?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSecondaryOutput.write(value, buf)
+        }
     }
+}
 
-    override fun allocationSize(value: UInt) = 4UL
 
-    override fun write(value: UInt, buf: ByteBuffer) {
-        buf.putInt(value.toInt())
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeAudioRoute: FfiConverterRustBuffer<AudioRoute?> {
+    override fun read(buf: ByteBuffer): AudioRoute? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeAudioRoute.read(buf)
+    }
+
+    override fun allocationSize(value: AudioRoute?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeAudioRoute.allocationSize(value)
+        }
+    }
+
+    override fun write(value: AudioRoute?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeAudioRoute.write(value, buf)
+        }
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterInt: FfiConverter<Int, Int> {
-    override fun lift(value: Int): Int {
-        return value
+public object FfiConverterOptionalTypeAudioRoutingMode: FfiConverterRustBuffer<AudioRoutingMode?> {
+    override fun read(buf: ByteBuffer): AudioRoutingMode? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeAudioRoutingMode.read(buf)
     }
 
-    override fun read(buf: ByteBuffer): Int {
-        return buf.getInt()
+    override fun allocationSize(value: AudioRoutingMode?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeAudioRoutingMode.allocationSize(value)
+        }
     }
 
-    override fun lower(value: Int): Int {
-        return value
+    override fun write(value: AudioRoutingMode?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeAudioRoutingMode.write(value, buf)
+        }
     }
+}
+
 
-    override fun allocationSize(value: Int) = 4UL
 
-    override fun write(value: Int, buf: ByteBuffer) {
-        buf.putInt(value)
-    }
-}
 
 /**
  * @suppress
  */
-public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean {
-        return value.toInt() != 0
+public object FfiConverterOptionalTypeConfigIssue: FfiConverterRustBuffer<ConfigIssue?> {
+    override fun read(buf: ByteBuffer): ConfigIssue? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeConfigIssue.read(buf)
     }
 
-    override fun read(buf: ByteBuffer): Boolean {
-        return lift(buf.get())
+    override fun allocationSize(value: ConfigIssue?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeConfigIssue.allocationSize(value)
+        }
     }
 
-    override fun lower(value: Boolean): Byte {
-        return if (value) 1.toByte() else 0.toByte()
+    override fun write(value: ConfigIssue?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeConfigIssue.write(value, buf)
+        }
     }
+}
+
 
-    override fun allocationSize(value: Boolean) = 1UL
 
-    override fun write(value: Boolean, buf: ByteBuffer) {
-        buf.put(lower(value))
-    }
-}
 
 /**
  * @suppress
  */
-public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
-    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
-    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
-    // store our length and avoid writing it out to the buffer.
-    override fun lift(value: RustBuffer.ByValue): String {
-        try {
-            val byteArr = ByteArray(value.len.toInt())
-            value.asByteBuffer()!!.get(byteArr)
-            return byteArr.toString(Charsets.UTF_8)
-        } finally {
-            RustBuffer.free(value)
+public object FfiConverterOptionalTypeConfigProfile: FfiConverterRustBuffer<ConfigProfile?> {
+    override fun read(buf: ByteBuffer): ConfigProfile? {
+        if (buf.get().toInt() == 0) {
+            return null
         }
+        return FfiConverterTypeConfigProfile.read(buf)
     }
 
-    override fun read(buf: ByteBuffer): String {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr.toString(Charsets.UTF_8)
+    override fun allocationSize(value: ConfigProfile?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeConfigProfile.allocationSize(value)
+        }
     }
 
-    fun toUtf8(value: String): ByteBuffer {
-        // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        return Charsets.UTF_8.newEncoder().run {
-            onMalformedInput(CodingErrorAction.REPORT)
-            encode(CharBuffer.wrap(value))
+    override fun write(value: ConfigProfile?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeConfigProfile.write(value, buf)
         }
     }
+}
 
-    override fun lower(value: String): RustBuffer.ByValue {
-        val byteBuf = toUtf8(value)
-        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
-        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
-        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
-        rbuf.asByteBuffer()!!.put(byteBuf)
-        return rbuf
-    }
 
-    // We aren't sure exactly how many bytes our string will be once it's UTF-8
-    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
-    // enough.
-    override fun allocationSize(value: String): ULong {
-        val sizeForLength = 4UL
-        val sizeForString = value.length.toULong() * 3UL
-        return sizeForLength + sizeForString
-    }
 
-    override fun write(value: String, buf: ByteBuffer) {
-        val byteBuf = toUtf8(value)
-        buf.putInt(byteBuf.limit())
-        buf.put(byteBuf)
-    }
-}
 
 /**
  * @suppress
  */
-public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
-    override fun read(buf: ByteBuffer): ByteArray {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr
+public object FfiConverterOptionalTypeFloorPolicy: FfiConverterRustBuffer<FloorPolicy?> {
+    override fun read(buf: ByteBuffer): FloorPolicy? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeFloorPolicy.read(buf)
     }
-    override fun allocationSize(value: ByteArray): ULong {
-        return 4UL + value.size.toULong()
+
+    override fun allocationSize(value: FloorPolicy?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeFloorPolicy.allocationSize(value)
+        }
     }
-    override fun write(value: ByteArray, buf: ByteBuffer) {
-        buf.putInt(value.size)
-        buf.put(value)
+
+    override fun write(value: FloorPolicy?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeFloorPolicy.write(value, buf)
+        }
     }
 }
 
 
-// This template implements a class for working with a Rust struct via a handle
-// to the live Rust struct on the other side of the FFI.
-//
-// There's some subtlety here, because we have to be careful not to operate on a Rust
-// struct after it has been dropped, and because we must expose a public API for freeing
-// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
-//
-//   * Each instance holds an opaque handle to the underlying Rust struct.
-//     Method calls need to read this handle from the object's state and pass it in to
-//     the Rust FFI.
-//
-//   * When an instance is no longer needed, its handle should be passed to a
-//     special destructor function provided by the Rust FFI, which will drop the
-//     underlying Rust struct.
-//
-//   * Given an instance, calling code is expected to call the special
-//     `destroy` method in order to free it after use, either by calling it explicitly
-//     or by using a higher-level helper like the `use` method. Failing to do so risks
-//     leaking the underlying Rust struct.
-//
-//   * We can't assume that calling code will do the right thing, and must be prepared
-//     to handle Kotlin method calls executing concurrently with or even after a call to
-//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
-//
-//   * We must never allow Rust code to operate on the underlying Rust struct after
-//     the destructor has been called, and must never call the destructor more than once.
-//     Doing so may trigger memory unsafety.
-//
-//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
-//     is implemented to call the destructor when the Kotlin object becomes unreachable.
-//     This is done in a background thread. This is not a panacea, and client code should be aware that
-//      1. the thread may starve if some there are objects that have poorly performing
-//     `drop` methods or do significant work in their `drop` methods.
-//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
-//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
-//
-// If we try to implement this with mutual exclusion on access to the handle, there is the
-// possibility of a race between a method call and a concurrent call to `destroy`:
-//
-//    * Thread A starts a method call, reads the value of the handle, but is interrupted
-//      before it can pass the handle over the FFI to Rust.
-//    * Thread B calls `destroy` and frees the underlying Rust struct.
-//    * Thread A resumes, passing the already-read handle value to Rust and triggering
-//      a use-after-free.
-//
-// One possible solution would be to use a `ReadWriteLock`, with each method call taking
-// a read lock (and thus allowed to run concurrently) and the special `destroy` method
-// taking a write lock (and thus blocking on live method calls). However, we aim not to
-// generate methods with any hidden blocking semantics, and a `destroy` method that might
-// block if called incorrectly seems to meet that bar.
-//
-// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
-// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
-// has been called. These are updated according to the following rules:
-//
-//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
-//      The initial value for the flag is false.
-//
-//    * At the start of each method call, we atomically check the counter.
-//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
-//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
-//
-//    * At the end of each method call, we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-//    * When `destroy` is called, we atomically flip the flag from false to true.
-//      If the flag was already true we silently fail.
-//      Otherwise we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
-// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
-//
-// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
-// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
-// of the underlying Rust code.
-//
-// This makes a cleaner a better alternative to _not_ calling `destroy()` as
-// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
-// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
-// thread may be starved, and the app will leak memory.
-//
-// In this case, `destroy`ing manually may be a better solution.
-//
-// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
-// with Rust peers are reclaimed:
-//
-// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
-// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
-// 3. The memory is reclaimed when the process terminates.
-//
-// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
-//
-
 
-//
-public interface AudioEngineInterface {
-    
-    fun `isSessionActive`(): kotlin.Boolean
-    
-    fun `pushIncomingPacket`(`data`: kotlin.ByteArray)
-    
-    fun `releaseResources`()
-    
-    fun `setMicEnabled`(`enabled`: kotlin.Boolean)
-    
-    fun `startInputStream`()
-    
-    fun `startOutputStream`()
-    
-    /**
-     * Starts BOTH Input and Output streams.
-     * Call this when joining a group.
-     */
-    fun `startSession`()
-    
-    /**
-     * Stops BOTH streams.
-     * Call this when leaving a group.
-     */
-    fun `stopSession`()
-    
-    companion object
-}
 
-open class AudioEngine: Disposable, AutoCloseable, AudioEngineInterface
-{
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypePreemptionPolicy: FfiConverterRustBuffer<PreemptionPolicy?> {
+    override fun read(buf: ByteBuffer): PreemptionPolicy? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypePreemptionPolicy.read(buf)
+    }
 
-    @Suppress("UNUSED_PARAMETER")
-    /**
-     * @suppress
-     */
-    constructor(withHandle: UniffiWithHandle, handle: Long) {
-        this.handle = handle
-        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    override fun allocationSize(value: PreemptionPolicy?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypePreemptionPolicy.allocationSize(value)
+        }
     }
 
-    /**
-     * @suppress
-     *
-     * This constructor can be used to instantiate a fake object. Only used for tests. Any
-     * attempt to actually use an object constructed this way will fail as there is no
-     * connected Rust object.
-     */
-    @Suppress("UNUSED_PARAMETER")
-    constructor(noHandle: NoHandle) {
-        this.handle = 0
-        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    override fun write(value: PreemptionPolicy?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypePreemptionPolicy.write(value, buf)
+        }
     }
-    constructor(`config`: AudioConfig, `transport`: PacketTransport, `callback`: AudioErrorCallback, `ownNodeId`: kotlin.UInt) :
-        this(UniffiWithHandle, 
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_constructor_audioengine_new(
-    
-        FfiConverterTypeAudioConfig.lower(`config`),FfiConverterTypePacketTransport.lower(`transport`),FfiConverterTypeAudioErrorCallback.lower(`callback`),FfiConverterUInt.lower(`ownNodeId`),_status)
 }
-    )
 
-    protected val handle: Long
-    protected val cleanable: UniffiCleaner.Cleanable
 
-    private val wasDestroyed = AtomicBoolean(false)
-    private val callCounter = AtomicLong(1)
 
-    override fun destroy() {
-        // Only allow a single call to this method.
-        // TODO: maybe we should log a warning if called more than once?
-        if (this.wasDestroyed.compareAndSet(false, true)) {
-            // This decrement always matches the initial count of 1 given at creation time.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable.clean()
-            }
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceUShort: FfiConverterRustBuffer<List<kotlin.UShort>> {
+    override fun read(buf: ByteBuffer): List<kotlin.UShort> {
+        val len = buf.getInt()
+        return List<kotlin.UShort>(len) {
+            FfiConverterUShort.read(buf)
         }
     }
 
-    @Synchronized
-    override fun close() {
-        this.destroy()
+    override fun allocationSize(value: List<kotlin.UShort>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterUShort.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
-        // Check and increment the call counter, to keep the object alive.
-        // This needs a compare-and-set retry loop in case of concurrent updates.
-        do {
-            val c = this.callCounter.get()
-            if (c == 0L) {
-                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
-            }
-            if (c == Long.MAX_VALUE) {
-                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
-            }
-        } while (! this.callCounter.compareAndSet(c, c + 1L))
-        // Now we can safely do the method call without the handle being freed concurrently.
-        try {
-            return block(this.uniffiCloneHandle())
-        } finally {
-            // This decrement always matches the increment we performed above.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable.clean()
-            }
+    override fun write(value: List<kotlin.UShort>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterUShort.write(it, buf)
         }
     }
+}
 
-    // Use a static inner class instead of a closure so as not to accidentally
-    // capture `this` as part of the cleanable's action.
-    private class UniffiCleanAction(private val handle: Long) : Runnable {
-        override fun run() {
-            if (handle == 0.toLong()) {
-                // Fake object created with `NoHandle`, don't try to free.
-                return;
-            }
-            uniffiRustCall { status ->
-                UniffiLib.uniffi_walkie_talkie_engine_fn_free_audioengine(handle, status)
-            }
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceShort: FfiConverterRustBuffer<List<kotlin.Short>> {
+    override fun read(buf: ByteBuffer): List<kotlin.Short> {
+        val len = buf.getInt()
+        return List<kotlin.Short>(len) {
+            FfiConverterShort.read(buf)
         }
     }
 
-    /**
-     * @suppress
-     */
-    fun uniffiCloneHandle(): Long {
-        if (handle == 0.toLong()) {
-            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+    override fun allocationSize(value: List<kotlin.Short>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterShort.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.Short>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterShort.write(it, buf)
         }
-        return uniffiRustCall() { status ->
-            UniffiLib.uniffi_walkie_talkie_engine_fn_clone_audioengine(handle, status)
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceInt: FfiConverterRustBuffer<List<kotlin.Int>> {
+    override fun read(buf: ByteBuffer): List<kotlin.Int> {
+        val len = buf.getInt()
+        return List<kotlin.Int>(len) {
+            FfiConverterInt.read(buf)
         }
     }
 
-    override fun `isSessionActive`(): kotlin.Boolean {
-            return FfiConverterBoolean.lift(
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_is_session_active(
-        it,
-        _status)
+    override fun allocationSize(value: List<kotlin.Int>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterInt.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.Int>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterInt.write(it, buf)
+        }
+    }
 }
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceFloat: FfiConverterRustBuffer<List<kotlin.Float>> {
+    override fun read(buf: ByteBuffer): List<kotlin.Float> {
+        val len = buf.getInt()
+        return List<kotlin.Float>(len) {
+            FfiConverterFloat.read(buf)
+        }
     }
-    )
+
+    override fun allocationSize(value: List<kotlin.Float>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterFloat.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
 
-    override fun `pushIncomingPacket`(`data`: kotlin.ByteArray)
-        = 
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_push_incoming_packet(
-        it,
-        FfiConverterByteArray.lower(`data`),_status)
+    override fun write(value: List<kotlin.Float>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterFloat.write(it, buf)
+        }
+    }
 }
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
+    override fun read(buf: ByteBuffer): List<kotlin.String> {
+        val len = buf.getInt()
+        return List<kotlin.String>(len) {
+            FfiConverterString.read(buf)
+        }
     }
-    
-    
 
-    override fun `releaseResources`()
-        = 
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_release_resources(
-        it,
-        _status)
-}
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
-    
 
-    override fun `setMicEnabled`(`enabled`: kotlin.Boolean)
-        = 
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_set_mic_enabled(
-        it,
-        FfiConverterBoolean.lower(`enabled`),_status)
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
+        }
+    }
 }
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceByteArray: FfiConverterRustBuffer<List<kotlin.ByteArray>> {
+    override fun read(buf: ByteBuffer): List<kotlin.ByteArray> {
+        val len = buf.getInt()
+        return List<kotlin.ByteArray>(len) {
+            FfiConverterByteArray.read(buf)
+        }
     }
-    
-    
 
-    
-    @Throws(AudioException::class)override fun `startInputStream`()
-        = 
-    callWithHandle {
-    uniffiRustCallWithError(AudioException) { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_input_stream(
-        it,
-        _status)
-}
+    override fun allocationSize(value: List<kotlin.ByteArray>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterByteArray.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
-    
 
-    
-    @Throws(AudioException::class)override fun `startOutputStream`()
-        = 
-    callWithHandle {
-    uniffiRustCallWithError(AudioException) { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_output_stream(
-        it,
-        _status)
+    override fun write(value: List<kotlin.ByteArray>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterByteArray.write(it, buf)
+        }
+    }
 }
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeArchiveSegment: FfiConverterRustBuffer<List<ArchiveSegment>> {
+    override fun read(buf: ByteBuffer): List<ArchiveSegment> {
+        val len = buf.getInt()
+        return List<ArchiveSegment>(len) {
+            FfiConverterTypeArchiveSegment.read(buf)
+        }
     }
-    
-    
 
-    
-    /**
-     * Starts BOTH Input and Output streams.
-     * Call this when joining a group.
-     */
-    @Throws(AudioException::class)override fun `startSession`()
-        = 
-    callWithHandle {
-    uniffiRustCallWithError(AudioException) { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_start_session(
-        it,
-        _status)
-}
+    override fun allocationSize(value: List<ArchiveSegment>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeArchiveSegment.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
-    
-    
 
-    
-    /**
-     * Stops BOTH streams.
-     * Call this when leaving a group.
-     */
-    @Throws(AudioException::class)override fun `stopSession`()
-        = 
-    callWithHandle {
-    uniffiRustCallWithError(AudioException) { _status ->
-    UniffiLib.uniffi_walkie_talkie_engine_fn_method_audioengine_stop_session(
-        it,
-        _status)
-}
+    override fun write(value: List<ArchiveSegment>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeArchiveSegment.write(it, buf)
+        }
     }
-    
-    
+}
 
-    
 
-    
 
 
-    
-    
-    /**
-     * @suppress
-     */
-    companion object
-    
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeBenchmarkStage: FfiConverterRustBuffer<List<BenchmarkStage>> {
+    override fun read(buf: ByteBuffer): List<BenchmarkStage> {
+        val len = buf.getInt()
+        return List<BenchmarkStage>(len) {
+            FfiConverterTypeBenchmarkStage.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<BenchmarkStage>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBenchmarkStage.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<BenchmarkStage>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBenchmarkStage.write(it, buf)
+        }
+    }
 }
 
 
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeAudioEngine: FfiConverter<AudioEngine, Long> {
-    override fun lower(value: AudioEngine): Long {
-        return value.uniffiCloneHandle()
-    }
-
-    override fun lift(value: Long): AudioEngine {
-        return AudioEngine(UniffiWithHandle, value)
+public object FfiConverterSequenceTypeMessage: FfiConverterRustBuffer<List<Message>> {
+    override fun read(buf: ByteBuffer): List<Message> {
+        val len = buf.getInt()
+        return List<Message>(len) {
+            FfiConverterTypeMessage.read(buf)
+        }
     }
 
-    override fun read(buf: ByteBuffer): AudioEngine {
-        return lift(buf.getLong())
+    override fun allocationSize(value: List<Message>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeMessage.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun allocationSize(value: AudioEngine) = 8UL
-
-    override fun write(value: AudioEngine, buf: ByteBuffer) {
-        buf.putLong(lower(value))
+    override fun write(value: List<Message>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeMessage.write(it, buf)
+        }
     }
 }
 
 
 
-data class AudioConfig (
-    var `sampleRate`: kotlin.Int
-    , 
-    var `frameSizeMs`: kotlin.Int
-    , 
-    var `jitterBufferMs`: kotlin.Int
-    , 
-    var `inputDeviceId`: kotlin.Int
-    , 
-    var `outputDeviceId`: kotlin.Int
-    
-){
-    
-
-    
-    companion object
-}
 
 /**
  * @suppress
  */
-public object FfiConverterTypeAudioConfig: FfiConverterRustBuffer<AudioConfig> {
-    override fun read(buf: ByteBuffer): AudioConfig {
-        return AudioConfig(
-            FfiConverterInt.read(buf),
-            FfiConverterInt.read(buf),
-            FfiConverterInt.read(buf),
-            FfiConverterInt.read(buf),
-            FfiConverterInt.read(buf),
-        )
+public object FfiConverterSequenceTypePeerCapabilities: FfiConverterRustBuffer<List<PeerCapabilities>> {
+    override fun read(buf: ByteBuffer): List<PeerCapabilities> {
+        val len = buf.getInt()
+        return List<PeerCapabilities>(len) {
+            FfiConverterTypePeerCapabilities.read(buf)
+        }
     }
 
-    override fun allocationSize(value: AudioConfig) = (
-            FfiConverterInt.allocationSize(value.`sampleRate`) +
-            FfiConverterInt.allocationSize(value.`frameSizeMs`) +
-            FfiConverterInt.allocationSize(value.`jitterBufferMs`) +
-            FfiConverterInt.allocationSize(value.`inputDeviceId`) +
-            FfiConverterInt.allocationSize(value.`outputDeviceId`)
-    )
+    override fun allocationSize(value: List<PeerCapabilities>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypePeerCapabilities.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
 
-    override fun write(value: AudioConfig, buf: ByteBuffer) {
-            FfiConverterInt.write(value.`sampleRate`, buf)
-            FfiConverterInt.write(value.`frameSizeMs`, buf)
-            FfiConverterInt.write(value.`jitterBufferMs`, buf)
-            FfiConverterInt.write(value.`inputDeviceId`, buf)
-            FfiConverterInt.write(value.`outputDeviceId`, buf)
+    override fun write(value: List<PeerCapabilities>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypePeerCapabilities.write(it, buf)
+        }
     }
 }
 
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypePeerLevel: FfiConverterRustBuffer<List<PeerLevel>> {
+    override fun read(buf: ByteBuffer): List<PeerLevel> {
+        val len = buf.getInt()
+        return List<PeerLevel>(len) {
+            FfiConverterTypePeerLevel.read(buf)
+        }
+    }
 
-sealed class AudioException(message: String): kotlin.Exception(message) {
-        
-        class DeviceException(message: String) : AudioException(message)
-        
-        class EncoderException(message: String) : AudioException(message)
-        
-        class DecoderException(message: String) : AudioException(message)
-        
+    override fun allocationSize(value: List<PeerLevel>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypePeerLevel.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<AudioException> {
-        override fun lift(error_buf: RustBuffer.ByValue): AudioException = FfiConverterTypeAudioError.lift(error_buf)
+    override fun write(value: List<PeerLevel>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypePeerLevel.write(it, buf)
+        }
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeAudioError : FfiConverterRustBuffer<AudioException> {
-    override fun read(buf: ByteBuffer): AudioException {
-        
-            return when(buf.getInt()) {
-            1 -> AudioException.DeviceException(FfiConverterString.read(buf))
-            2 -> AudioException.EncoderException(FfiConverterString.read(buf))
-            3 -> AudioException.DecoderException(FfiConverterString.read(buf))
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+public object FfiConverterSequenceTypePeerStats: FfiConverterRustBuffer<List<PeerStats>> {
+    override fun read(buf: ByteBuffer): List<PeerStats> {
+        val len = buf.getInt()
+        return List<PeerStats>(len) {
+            FfiConverterTypePeerStats.read(buf)
         }
-        
     }
 
-    override fun allocationSize(value: AudioException): ULong {
-        return 4UL
+    override fun allocationSize(value: List<PeerStats>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypePeerStats.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    override fun write(value: AudioException, buf: ByteBuffer) {
-        when(value) {
-            is AudioException.DeviceException -> {
-                buf.putInt(1)
-                Unit
-            }
-            is AudioException.EncoderException -> {
-                buf.putInt(2)
-                Unit
-            }
-            is AudioException.DecoderException -> {
-                buf.putInt(3)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    override fun write(value: List<PeerStats>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypePeerStats.write(it, buf)
+        }
     }
-
 }
 
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypePeerTalkStats: FfiConverterRustBuffer<List<PeerTalkStats>> {
+    override fun read(buf: ByteBuffer): List<PeerTalkStats> {
+        val len = buf.getInt()
+        return List<PeerTalkStats>(len) {
+            FfiConverterTypePeerTalkStats.read(buf)
+        }
+    }
 
-public interface AudioErrorCallback {
-    
-    fun `onEngineError`(`code`: kotlin.Int)
-    
-    companion object
+    override fun allocationSize(value: List<PeerTalkStats>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypePeerTalkStats.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<PeerTalkStats>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypePeerTalkStats.write(it, buf)
+        }
+    }
 }
 
 
 
-// Put the implementation in an object so we don't pollute the top-level namespace
-internal object uniffiCallbackInterfaceAudioErrorCallback {
-    internal object `onEngineError`: UniffiCallbackInterfaceAudioErrorCallbackMethod0 {
-        override fun callback(`uniffiHandle`: Long,`code`: Int,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
-            val uniffiObj = FfiConverterTypeAudioErrorCallback.handleMap.get(uniffiHandle)
-            val makeCall = { ->
-                uniffiObj.`onEngineError`(
-                    FfiConverterInt.lift(`code`),
-                )
-            }
-            val writeReturn = { _: Unit -> Unit }
-            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypePipelineNode: FfiConverterRustBuffer<List<PipelineNode>> {
+    override fun read(buf: ByteBuffer): List<PipelineNode> {
+        val len = buf.getInt()
+        return List<PipelineNode>(len) {
+            FfiConverterTypePipelineNode.read(buf)
         }
     }
 
-    internal object uniffiFree: UniffiCallbackInterfaceFree {
-        override fun callback(handle: Long) {
-            FfiConverterTypeAudioErrorCallback.handleMap.remove(handle)
-        }
+    override fun allocationSize(value: List<PipelineNode>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypePipelineNode.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
     }
 
-    internal object uniffiClone: UniffiCallbackInterfaceClone {
-        override fun callback(handle: Long): Long {
-            return FfiConverterTypeAudioErrorCallback.handleMap.clone(handle)
+    override fun write(value: List<PipelineNode>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypePipelineNode.write(it, buf)
         }
     }
+}
+
 
-    internal var vtable = UniffiVTableCallbackInterfaceAudioErrorCallback.UniffiByValue(
-        uniffiFree,
-        uniffiClone,
-        `onEngineError`,
-    )
 
-    // Registers the foreign callback with the Rust side.
-    // This method is generated for each callback interface.
-    internal fun register(lib: UniffiLib) {
-        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_audioerrorcallback(vtable)
-    }
-}
 
 /**
- * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
- *
  * @suppress
  */
-public object FfiConverterTypeAudioErrorCallback: FfiConverterCallbackInterface<AudioErrorCallback>()
-
-
-
+public object FfiConverterSequenceTypeAudioRoute: FfiConverterRustBuffer<List<AudioRoute>> {
+    override fun read(buf: ByteBuffer): List<AudioRoute> {
+        val len = buf.getInt()
+        return List<AudioRoute>(len) {
+            FfiConverterTypeAudioRoute.read(buf)
+        }
+    }
 
+    override fun allocationSize(value: List<AudioRoute>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeAudioRoute.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
 
-public interface PacketTransport {
-    
-    fun `sendPacket`(`data`: kotlin.ByteArray)
-    
-    companion object
+    override fun write(value: List<AudioRoute>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeAudioRoute.write(it, buf)
+        }
+    }
 }
 
 
 
-// Put the implementation in an object so we don't pollute the top-level namespace
-internal object uniffiCallbackInterfacePacketTransport {
-    internal object `sendPacket`: UniffiCallbackInterfacePacketTransportMethod0 {
-        override fun callback(`uniffiHandle`: Long,`data`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
-            val uniffiObj = FfiConverterTypePacketTransport.handleMap.get(uniffiHandle)
-            val makeCall = { ->
-                uniffiObj.`sendPacket`(
-                    FfiConverterByteArray.lift(`data`),
-                )
+
+/**
+ * @suppress
+ */
+public object FfiConverterMapStringFloat: FfiConverterRustBuffer<Map<kotlin.String, kotlin.Float>> {
+    override fun read(buf: ByteBuffer): Map<kotlin.String, kotlin.Float> {
+        val len = buf.getInt()
+        return buildMap<kotlin.String, kotlin.Float>(len) {
+            repeat(len) {
+                val k = FfiConverterString.read(buf)
+                val v = FfiConverterFloat.read(buf)
+                this[k] = v
             }
-            val writeReturn = { _: Unit -> Unit }
-            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
         }
     }
 
-    internal object uniffiFree: UniffiCallbackInterfaceFree {
-        override fun callback(handle: Long) {
-            FfiConverterTypePacketTransport.handleMap.remove(handle)
-        }
+    override fun allocationSize(value: Map<kotlin.String, kotlin.Float>): ULong {
+        val spaceForMapSize = 4UL
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterFloat.allocationSize(v)
+        }.sum()
+        return spaceForMapSize + spaceForChildren
     }
 
-    internal object uniffiClone: UniffiCallbackInterfaceClone {
-        override fun callback(handle: Long): Long {
-            return FfiConverterTypePacketTransport.handleMap.clone(handle)
+    override fun write(value: Map<kotlin.String, kotlin.Float>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        // The parens on `(k, v)` here ensure we're calling the right method,
+        // which is important for compatibility with older android devices.
+        // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
+        value.forEach { (k, v) ->
+            FfiConverterString.write(k, buf)
+            FfiConverterFloat.write(v, buf)
         }
     }
+}
+
 
-    internal var vtable = UniffiVTableCallbackInterfacePacketTransport.UniffiByValue(
-        uniffiFree,
-        uniffiClone,
-        `sendPacket`,
-    )
 
-    // Registers the foreign callback with the Rust side.
-    // This method is generated for each callback interface.
-    internal fun register(lib: UniffiLib) {
-        lib.uniffi_walkie_talkie_engine_fn_init_callback_vtable_packettransport(vtable)
-    }
-}
 
 /**
- * The ffiConverter which transforms the Callbacks in to handles to pass to Rust.
- *
  * @suppress
  */
-public object FfiConverterTypePacketTransport: FfiConverterCallbackInterface<PacketTransport>() fun `initLogger`()
+public object FfiConverterMapStringString: FfiConverterRustBuffer<Map<kotlin.String, kotlin.String>> {
+    override fun read(buf: ByteBuffer): Map<kotlin.String, kotlin.String> {
+        val len = buf.getInt()
+        return buildMap<kotlin.String, kotlin.String>(len) {
+            repeat(len) {
+                val k = FfiConverterString.read(buf)
+                val v = FfiConverterString.read(buf)
+                this[k] = v
+            }
+        }
+    }
+
+    override fun allocationSize(value: Map<kotlin.String, kotlin.String>): ULong {
+        val spaceForMapSize = 4UL
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterString.allocationSize(v)
+        }.sum()
+        return spaceForMapSize + spaceForChildren
+    }
+
+    override fun write(value: Map<kotlin.String, kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        // The parens on `(k, v)` here ensure we're calling the right method,
+        // which is important for compatibility with older android devices.
+        // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
+        value.forEach { (k, v) ->
+            FfiConverterString.write(k, buf)
+            FfiConverterString.write(v, buf)
+        }
+    }
+}
+        /**
+         * Creates a random X25519 identity key for `AudioEngine::set_identity_key`.
+         * The app stores it; the engine derives everything else per session.
+         */ fun `generateIdentityKey`(): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_func_generate_identity_key(
+    
+        _status)
+}
+    )
+    }
+    
+ fun `initLogger`()
         = 
     uniffiRustCall() { _status ->
     UniffiLib.uniffi_walkie_talkie_engine_fn_func_init_logger(
@@ -1850,4 +11308,17 @@ public object FfiConverterTypePacketTransport: FfiConverterCallbackInterface<Pac
     
     
 
+        /**
+         * Sets the language of `Message.text` and the number format of `Message.args`,
+         * from a BCP 47 tag such as "de-AT". Languages without a catalog get English.
+         */ fun `setLocale`(`tag`: kotlin.String)
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_walkie_talkie_engine_fn_func_set_locale(
+    
+        FfiConverterString.lower(`tag`),_status)
+}
+    
+    
+
 
@@ -27,6 +27,7 @@ import kotlinx.coroutines.launch
 import uniffi.walkie_talkie_engine.AudioConfig
 import uniffi.walkie_talkie_engine.AudioEngine
 import uniffi.walkie_talkie_engine.AudioErrorCallback
+import uniffi.walkie_talkie_engine.AudioException
 import uniffi.walkie_talkie_engine.PacketTransport
 import java.util.concurrent.atomic.AtomicReference
 
@@ -69,8 +70,8 @@ class VoiceManager(
         }
     }
     private val engineErrorCallback = object : AudioErrorCallback {
-        override fun onEngineError(code: Int) {
-            Log.e("VoiceManager", "CRITICAL: Native Engine Error $code. Restarting...")
+        override fun onEngineError(code: Int, count: UInt, firstTimestampMs: ULong, lastTimestampMs: ULong) {
+            Log.e("VoiceManager", "CRITICAL: Native Engine Error $code (x$count). Restarting...")
             // Trigger reactive restart
             _isSessionActive.value = false
             _isSessionActive.value = true
@@ -142,7 +143,13 @@ class VoiceManager(
         // Optimization: Don't spam the engine/logs if state hasn't changed
         if (_isMicEnabled.value == enabled) return
 
-        activeEngine.get()?.setMicEnabled(enabled)
+        try {
+            activeEngine.get()?.setMicEnabled(enabled)
+        } catch (e: AudioException) {
+            // Refused (e.g. transmit quota used up): the mic stays as it was
+            Log.w("VoiceManager", "Mic change refused", e)
+            return
+        }
         _isMicEnabled.value = enabled
     }
 
@@ -3,4 +3,12 @@ fn main() {
     // This fixes the "cannot locate symbol __cxa_pure_virtual" error.
     #[cfg(target_os = "android")]
     println!("cargo:rustc-link-lib=dylib=c++_shared");
-}
\ No newline at end of file
+
+    // `audio_backend`: the real engine is built (Oboe on Android, cpal on iOS
+    // and with the `desktop` feature). Everywhere else the stub stands in.
+    println!("cargo:rustc-check-cfg=cfg(audio_backend)");
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os == "android" || target_os == "ios" || std::env::var_os("CARGO_FEATURE_DESKTOP").is_some() {
+        println!("cargo:rustc-cfg=audio_backend");
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * Walkie-talkie engine: C ABI for game engines (Cargo feature `game-abi`).
+ *
+ * The game owns the audio device and the network. The engine runs in pull
+ * mode: feed it mic PCM with wt_capture, fill the voice output with wt_render,
+ * send what send_packet hands you and pass received packets to
+ * wt_receive_packet. All PCM is mono 16-bit at the engine's sample rate.
+ *
+ * Every function may be called from any thread. Status codes are 0 on
+ * success or a negative WT_ERROR_*.
+ */
+#ifndef WALKIE_TALKIE_H
+#define WALKIE_TALKIE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define WT_ERROR_DEVICE            (-1)
+#define WT_ERROR_ENCODER           (-2)
+#define WT_ERROR_DECODER           (-3)
+#define WT_ERROR_INVALID_PARAMETER (-4)
+#define WT_ERROR_TRANSPORT         (-5)
+#define WT_ERROR_RECORDING         (-6)
+#define WT_ERROR_QUOTA_EXCEEDED    (-7)
+#define WT_ERROR_SHUT_DOWN         (-8)
+
+#define WT_PROFILE_DEFAULT          (-1)
+#define WT_PROFILE_LOW_LATENCY_LAN  0
+#define WT_PROFILE_CELLULAR         1
+#define WT_PROFILE_SATELLITE        2
+#define WT_PROFILE_BATTERY          3
+
+typedef struct WtEngine WtEngine;
+
+/* Called with every packet to send, from engine threads. `data` is only
+ * valid during the call. */
+typedef void (*WtSendPacket)(void *user_data, const uint8_t *data, size_t len);
+
+/* NULL for an unknown profile or a config the engine can't run. A
+ * sample_rate of 0 keeps the profile's. send_packet must stay callable with
+ * user_data until wt_engine_destroy returns. */
+WtEngine *wt_engine_create(int32_t profile, int32_t sample_rate, uint32_t node_id,
+                           WtSendPacket send_packet, void *user_data);
+/* Stops and frees the engine; no other call on it may run or follow. */
+void wt_engine_destroy(WtEngine *engine);
+
+int32_t wt_start(const WtEngine *engine);
+/* wt_render plays silence until the next wt_start. */
+int32_t wt_stop(const WtEngine *engine);
+
+void wt_capture(const WtEngine *engine, const int16_t *frames, size_t len);
+void wt_render(const WtEngine *engine, int16_t *frames, size_t len);
+void wt_receive_packet(const WtEngine *engine, const uint8_t *data, size_t len);
+
+/* Push-to-talk. WT_ERROR_QUOTA_EXCEEDED once the transmit quota is used up. */
+int32_t wt_set_mic_enabled(const WtEngine *engine, bool enabled);
+/* Talkgroup, e.g. the player's team. */
+void wt_set_active_channel(const WtEngine *engine, uint16_t channel);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* WALKIE_TALKIE_H */
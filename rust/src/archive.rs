@@ -0,0 +1,206 @@
+// ===========================================================================
+// CHANNEL ARCHIVE
+// ===========================================================================
+// `start_archive` keeps the received mix only while someone is talking. Each
+// stretch of voice activity (from the first peer starting to the last one
+// stopping) becomes its own Ogg/Opus segment file, and every transmission in
+// it an entry in the archive index: peer, start time, duration and where it
+// sits in the segment. The writer thread drives a `Segmenter` with the talk
+// events and the PCM, and appends entries to the index file as segments close.
+// `search_archive` filters the index; a hit can be cut out as a clip.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::ArchiveSegment;
+
+pub(crate) const INDEX_FILE: &str = "index.tsv";
+
+pub(crate) fn segment_file(segment: u32) -> String {
+    format!("segment-{:06}.opus", segment)
+}
+
+/// The segment being written.
+struct Open {
+    segment: u32,
+    start_ms: u64,
+    samples: u64,
+    talking: BTreeMap<u32, u64>, // Talkers and the sample they started at
+    talks: Vec<ArchiveSegment>,
+}
+
+/// Turns talk events into segments and their index entries. Times inside a
+/// segment come from the samples written, so they line up with the file.
+pub(crate) struct Segmenter {
+    sample_rate: u64,
+    next: u32,
+    open: Option<Open>,
+}
+
+impl Segmenter {
+    /// `next` is the number of the first segment to write.
+    pub(crate) fn new(sample_rate: u32, next: u32) -> Self {
+        Self { sample_rate: sample_rate as u64, next, open: None }
+    }
+
+    /// A peer started talking. Returns the number of the segment this opens, if any.
+    pub(crate) fn started(&mut self, peer_id: u32, now_ms: u64) -> Option<u32> {
+        if let Some(open) = &mut self.open {
+            open.talking.entry(peer_id).or_insert(open.samples);
+            return None;
+        }
+        let segment = self.next;
+        self.next += 1;
+        self.open = Some(Open {
+            segment,
+            start_ms: now_ms,
+            samples: 0,
+            talking: BTreeMap::from([(peer_id, 0)]),
+            talks: Vec::new(),
+        });
+        Some(segment)
+    }
+
+    /// Whether a segment is open, i.e. PCM should be written.
+    pub(crate) fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    /// Counts PCM written to the open segment.
+    pub(crate) fn advance(&mut self, samples: usize) {
+        if let Some(open) = &mut self.open {
+            open.samples += samples as u64;
+        }
+    }
+
+    /// A peer stopped talking. Once nobody talks any more the segment closes:
+    /// returns its transmissions for the index.
+    pub(crate) fn stopped(&mut self, peer_id: u32) -> Option<Vec<ArchiveSegment>> {
+        if !self.end(peer_id) { return None; }
+        if self.open.as_ref()?.talking.is_empty() { self.close() } else { None }
+    }
+
+    /// Ends the open segment with whoever is still talking (the archive stopped).
+    pub(crate) fn close(&mut self) -> Option<Vec<ArchiveSegment>> {
+        let talking: Vec<u32> = self.open.as_ref()?.talking.keys().copied().collect();
+        for peer_id in talking {
+            self.end(peer_id);
+        }
+        let mut talks = self.open.take()?.talks;
+        talks.sort_by_key(|talk| talk.offset_ms);
+        Some(talks)
+    }
+
+    /// Ends a talker's transmission; false if it wasn't talking.
+    fn end(&mut self, peer_id: u32) -> bool {
+        let sample_rate = self.sample_rate.max(1);
+        let Some(open) = &mut self.open else { return false };
+        let Some(started) = open.talking.remove(&peer_id) else { return false };
+        let to_ms = |samples: u64| samples * 1000 / sample_rate;
+        let offset_ms = to_ms(started);
+        open.talks.push(ArchiveSegment {
+            segment: open.segment,
+            peer_id,
+            start_ms: open.start_ms + offset_ms,
+            duration_ms: (to_ms(open.samples) - offset_ms).min(u32::MAX as u64) as u32,
+            offset_ms: offset_ms.min(u32::MAX as u64) as u32,
+        });
+        true
+    }
+}
+
+/// One index line: segment, peer, start (Unix ms), duration and offset (ms).
+pub(crate) fn format_entry(entry: &ArchiveSegment) -> String {
+    format!("{}\t{}\t{}\t{}\t{}\n", entry.segment, entry.peer_id, entry.start_ms, entry.duration_ms, entry.offset_ms)
+}
+
+/// The entries of an index file. Lines that don't parse (a torn last write) are skipped.
+pub(crate) fn parse_index(text: &str) -> Vec<ArchiveSegment> {
+    text.lines().filter_map(|line| {
+        let mut fields = line.split('\t');
+        let entry = ArchiveSegment {
+            segment: fields.next()?.parse().ok()?,
+            peer_id: fields.next()?.parse().ok()?,
+            start_ms: fields.next()?.parse().ok()?,
+            duration_ms: fields.next()?.parse().ok()?,
+            offset_ms: fields.next()?.parse().ok()?,
+        };
+        fields.next().is_none().then_some(entry)
+    }).collect()
+}
+
+/// Reads the index of the archive in `dir`; an archive without one is empty.
+pub(crate) fn read_index(dir: &Path) -> Vec<ArchiveSegment> {
+    std::fs::read_to_string(dir.join(INDEX_FILE)).map(|text| parse_index(&text)).unwrap_or_default()
+}
+
+/// The transmissions of `peer_id` (or anyone) overlapping `from_ms..to_ms`, oldest first.
+pub(crate) fn search(entries: Vec<ArchiveSegment>, peer_id: Option<u32>, from_ms: u64, to_ms: u64) -> Vec<ArchiveSegment> {
+    let mut hits: Vec<ArchiveSegment> = entries.into_iter()
+        .filter(|entry| peer_id.is_none_or(|id| entry.peer_id == id))
+        .filter(|entry| entry.start_ms < to_ms && entry.start_ms + entry.duration_ms as u64 > from_ms)
+        .collect();
+    hits.sort_by_key(|entry| entry.start_ms);
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_talkers_share_a_segment() {
+        let mut segmenter = Segmenter::new(1000, 7);
+        assert_eq!(segmenter.started(1, 50_000), Some(7));
+        segmenter.advance(300);
+        assert_eq!(segmenter.started(2, 50_300), None);
+        segmenter.advance(200);
+        assert_eq!(segmenter.stopped(1), None);
+        segmenter.advance(100);
+        let talks = segmenter.stopped(2).unwrap();
+        assert!(!segmenter.is_open());
+
+        assert_eq!(talks.len(), 2);
+        assert_eq!((talks[0].peer_id, talks[0].start_ms, talks[0].duration_ms, talks[0].offset_ms), (1, 50_000, 500, 0));
+        assert_eq!((talks[1].peer_id, talks[1].start_ms, talks[1].duration_ms, talks[1].offset_ms), (2, 50_300, 300, 300));
+        assert!(talks.iter().all(|talk| talk.segment == 7));
+
+        // Silence in between is not kept: the next talk opens a new segment at 0
+        assert_eq!(segmenter.started(1, 90_000), Some(8));
+        segmenter.advance(100);
+        let talks = segmenter.close().unwrap();
+        assert_eq!((talks[0].segment, talks[0].offset_ms, talks[0].duration_ms), (8, 0, 100));
+    }
+
+    #[test]
+    fn unknown_talkers_are_ignored() {
+        let mut segmenter = Segmenter::new(1000, 0);
+        assert_eq!(segmenter.stopped(3), None);
+        segmenter.started(1, 0);
+        assert_eq!(segmenter.started(1, 10), None);
+        assert_eq!(segmenter.stopped(3), None);
+        assert!(segmenter.is_open());
+        assert_eq!(segmenter.stopped(1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn the_index_round_trips_and_skips_torn_lines() {
+        let entry = ArchiveSegment { segment: 2, peer_id: 9, start_ms: 1_700_000_000_000, duration_ms: 1500, offset_ms: 40 };
+        let text = format_entry(&entry) + "3\t9\t17";
+        let entries = parse_index(&text);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0], entry);
+    }
+
+    #[test]
+    fn search_filters_by_peer_and_overlap() {
+        let entry = |peer_id, start_ms, duration_ms| ArchiveSegment { segment: 0, peer_id, start_ms, duration_ms, offset_ms: 0 };
+        let entries = vec![entry(1, 3000, 1000), entry(2, 1000, 1000), entry(1, 500, 400)];
+        let starts = |hits: Vec<ArchiveSegment>| hits.iter().map(|hit| hit.start_ms).collect::<Vec<_>>();
+        assert_eq!(starts(search(entries.clone(), None, 0, u64::MAX)), [500, 1000, 3000]);
+        assert_eq!(starts(search(entries.clone(), Some(1), 0, u64::MAX)), [500, 3000]);
+        // Overlap, not containment; a talk ending at `from` is out
+        assert_eq!(starts(search(entries.clone(), None, 1500, 3500)), [1000, 3000]);
+        assert_eq!(starts(search(entries, None, 2000, 3000)), Vec::<u64>::new());
+    }
+}
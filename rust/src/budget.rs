@@ -0,0 +1,92 @@
+// ===========================================================================
+// BANDWIDTH BUDGET
+// ===========================================================================
+// Data saver (`set_bandwidth_budget_kbps`): one cap on everything we send, so
+// apps can honor Android's Data Saver or a user's limit on a metered link.
+// Every frame, the input callback turns the budget into what the encoder and
+// packetizer may spend:
+//
+// 1. Packet overhead comes off the top (headers, cipher tag, checksum).
+// 2. Redundant frames double the audio bytes. They go first, once the wanted
+//    bitrate no longer fits twice.
+// 3. The bitrate is capped to what is left, though never below a floor where
+//    speech stays intelligible.
+// 4. Opus in-band FEC spends part of the bitrate itself. Below
+//    BUDGET_FEC_MIN_BITRATE_BPS that part is worth more as speech, so FEC is off.
+//
+// The engine sends no keepalives, so there is no other traffic to trim.
+// Emergency transmissions ignore the budget.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::{
+    AudioConfig, BUDGET_FEC_MIN_BITRATE_BPS, BUDGET_METER_WINDOW_MS, BUDGET_MIN_BITRATE_BPS, BUDGET_PACKET_OVERHEAD_BYTES,
+};
+
+/// What the budget leaves the sender.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct BandwidthPlan {
+    pub(crate) bitrate_bps: i32, // 0 = the encoder's own choice still fits
+    pub(crate) redundancy: bool,
+    pub(crate) fec: bool,
+    pub(crate) capped: bool, // Something was cut to fit
+}
+
+/// Fits `config`'s sending into `budget_bps`.
+pub(crate) fn plan(budget_bps: u32, config: &AudioConfig) -> BandwidthPlan {
+    let packets_per_sec = 1000.0 / config.frame_size_ms.max(1) as f32;
+    let available = budget_bps as f32 - packets_per_sec * (BUDGET_PACKET_OVERHEAD_BYTES * 8) as f32;
+    // What Opus picks on its own for mono (see `OPUS_AUTO` in libopus)
+    let frame_samples = (config.sample_rate / 1000 * config.frame_size_ms).max(1);
+    let wanted = if config.bitrate_bps > 0 {
+        config.bitrate_bps
+    } else {
+        60 * config.sample_rate / frame_samples + config.sample_rate
+    };
+
+    let redundancy = config.redundancy && 2.0 * wanted as f32 <= available;
+    let share = if redundancy { available / 2.0 } else { available };
+    let fits = wanted as f32 <= share;
+    let bitrate_bps = if fits {
+        config.bitrate_bps
+    } else {
+        (share as i32).max(BUDGET_MIN_BITRATE_BPS)
+    };
+    let effective = if bitrate_bps > 0 { bitrate_bps } else { wanted };
+    BandwidthPlan {
+        bitrate_bps,
+        redundancy,
+        fec: effective >= BUDGET_FEC_MIN_BITRATE_BPS,
+        capped: !fits || redundancy != config.redundancy || effective < BUDGET_FEC_MIN_BITRATE_BPS,
+    }
+}
+
+/// Send and receive rates over the last BUDGET_METER_WINDOW_MS, from samples of
+/// the engine's byte counters.
+pub(crate) struct RateMeter {
+    samples: VecDeque<(Instant, u64, u64)>, // (taken at, bytes sent, bytes received)
+}
+
+impl RateMeter {
+    pub(crate) fn new() -> Self {
+        Self { samples: VecDeque::new() }
+    }
+
+    /// Adds a sample and returns (send, receive) in kbps since the oldest one
+    /// in the window. Zero until there are two.
+    pub(crate) fn sample(&mut self, sent: u64, received: u64) -> (f32, f32) {
+        let now = Instant::now();
+        let window = Duration::from_millis(BUDGET_METER_WINDOW_MS);
+        // Keep one sample older than the window, so the rate always spans all of it
+        while self.samples.len() > 1 && now.duration_since(self.samples[1].0) >= window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((now, sent, received));
+        let (then, sent_then, received_then) = self.samples[0];
+        let seconds = now.duration_since(then).as_secs_f32();
+        if seconds <= 0.0 { return (0.0, 0.0); }
+        let kbps = |bytes: u64| bytes as f32 * 8.0 / 1000.0 / seconds;
+        (kbps(sent.saturating_sub(sent_then)), kbps(received.saturating_sub(received_then)))
+    }
+}
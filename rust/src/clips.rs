@@ -0,0 +1,99 @@
+// ===========================================================================
+// ALERT CLIP CACHE
+// ===========================================================================
+// Alert sounds are shipped as Ogg/Opus but must start instantly, so they are
+// decoded once up front and kept as PCM. The cache has a hard memory budget and
+// evicts the least recently played clips first.
+
+use std::collections::{HashMap, VecDeque};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use crate::CLIP_CACHE_MAX_BYTES;
+
+/// The parts of an Ogg/Opus file needed to decode it (RFC 7845).
+pub(crate) struct OggOpusStream {
+    pub(crate) channels: u8,
+    pub(crate) pre_skip: u16, // Samples @ 48kHz to discard from the decoder output
+    pub(crate) packets: Vec<Vec<u8>>,
+}
+
+pub(crate) fn demux_ogg_opus(bytes: &[u8]) -> Option<OggOpusStream> {
+    let mut reader = ogg::PacketReader::new(Cursor::new(bytes));
+
+    // 1. Identification header: "OpusHead" + version + channels + pre-skip + ...
+    let head = reader.read_packet().ok()??;
+    if head.data.len() < 19 || &head.data[0..8] != b"OpusHead" {
+        return None;
+    }
+    let channels = head.data[9];
+    let pre_skip = u16::from_le_bytes([head.data[10], head.data[11]]);
+    if channels == 0 || channels > 2 {
+        return None; // Alerts are mono or stereo; no channel mapping families
+    }
+
+    // 2. Comment header ("OpusTags"), ignored
+    let tags = reader.read_packet().ok()??;
+    if tags.data.len() < 8 || &tags.data[0..8] != b"OpusTags" {
+        return None;
+    }
+
+    // 3. Audio packets
+    let mut packets = Vec::new();
+    while let Ok(Some(packet)) = reader.read_packet() {
+        packets.push(packet.data);
+    }
+    Some(OggOpusStream { channels, pre_skip, packets })
+}
+
+pub(crate) struct ClipCache {
+    clips: HashMap<String, Arc<[i16]>>,
+    lru: VecDeque<String>, // Front = least recently used
+    bytes: usize,
+}
+
+impl ClipCache {
+    pub(crate) fn new() -> Self {
+        Self { clips: HashMap::new(), lru: VecDeque::new(), bytes: 0 }
+    }
+
+    /// Stores a decoded clip, evicting older ones to stay within budget.
+    /// Returns `false` if the clip alone is larger than the whole budget.
+    pub(crate) fn insert(&mut self, id: String, pcm: Vec<i16>) -> bool {
+        let size = pcm.len() * std::mem::size_of::<i16>();
+        if size > CLIP_CACHE_MAX_BYTES {
+            return false;
+        }
+        self.remove(&id);
+        while self.bytes + size > CLIP_CACHE_MAX_BYTES {
+            let Some(oldest) = self.lru.pop_front() else { break };
+            if let Some(evicted) = self.clips.remove(&oldest) {
+                self.bytes -= evicted.len() * std::mem::size_of::<i16>();
+                info!("Clip cache: evicted '{}'", oldest);
+            }
+        }
+        self.bytes += size;
+        self.lru.push_back(id.clone());
+        self.clips.insert(id, pcm.into());
+        true
+    }
+
+    /// Looks up a clip and marks it as most recently used.
+    pub(crate) fn get(&mut self, id: &str) -> Option<Arc<[i16]>> {
+        let clip = self.clips.get(id)?.clone();
+        self.lru.retain(|entry| entry != id);
+        self.lru.push_back(id.to_string());
+        Some(clip)
+    }
+
+    pub(crate) fn remove(&mut self, id: &str) {
+        if let Some(clip) = self.clips.remove(id) {
+            self.bytes -= clip.len() * std::mem::size_of::<i16>();
+            self.lru.retain(|entry| entry != id);
+        }
+    }
+
+    pub(crate) fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
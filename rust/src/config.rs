@@ -0,0 +1,399 @@
+// ===========================================================================
+// CONFIG PROFILES
+// ===========================================================================
+// `AudioConfig` stays one flat record over UniFFI. `ConfigBuilder` layers on
+// top of it: a named profile expands into a full parameter set tuned for one
+// kind of link, individual settings can then be overridden, and `build`
+// validates the result. `describe_config` shows what a config resolves to
+// once the engine's own limits and clamps are applied.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::locale::{Arg, message};
+use crate::{
+    AudioConfig, AudioError, AudioRoutingMode, ConfigIssue, ConfigProfile, EffectiveConfig, FloorPolicy, MAX_BUFFER_SIZE, MAX_OUTPUT_VOLUME,
+    MAX_PACKET_BYTES_MIN, Message, MessageId, OPUS_BITRATE_RANGE, PTT_PRE_ROLL_MAX_MS, REPLAY_MAX_SECS, ROGER_BEEP_MAX_MS, RogerBeep,
+    SecondaryOutput,
+};
+
+// Rates Opus encodes at natively
+const OPUS_SAMPLE_RATES: [i32; 5] = [8000, 12000, 16000, 24000, 48000];
+// Frame sizes Opus accepts that fit in whole milliseconds
+const OPUS_FRAME_SIZES_MS: [i32; 4] = [10, 20, 40, 60];
+// `AudioConfig.comfort_noise_dbfs`: quieter is inaudible, louder is distracting
+const COMFORT_NOISE_MIN_DBFS: f32 = -90.0;
+const COMFORT_NOISE_MAX_DBFS: f32 = -20.0;
+
+/// The full parameter set a profile stands for.
+pub(crate) fn profile_config(profile: ConfigProfile) -> AudioConfig {
+    let base = AudioConfig { profile: Some(profile), ..AudioConfig::default() };
+    match profile {
+        // Wi-Fi or wired: little loss or reordering, so keep latency low
+        ConfigProfile::LowLatencyLan => AudioConfig {
+            frame_size_ms: 20,
+            jitter_buffer_ms: 200,
+            bitrate_bps: 32_000,
+            ..base
+        },
+        // Mobile data: jitter, bursts of loss and the odd stalled uplink
+        ConfigProfile::Cellular => AudioConfig {
+            frame_size_ms: 40,
+            jitter_buffer_ms: 600,
+            bitrate_bps: 24_000,
+            reorder_wait_ms: 40,
+            redundancy: true,
+            drop_stale_audio: true,
+            ..base
+        },
+        // Long round trips and expensive bytes: big frames, a low bitrate, patience
+        ConfigProfile::Satellite => AudioConfig {
+            frame_size_ms: 60,
+            jitter_buffer_ms: 2000,
+            bitrate_bps: 12_000,
+            reorder_wait_ms: 120,
+            resync_gap_packets: 20,
+            drop_stale_audio: true,
+            ..base
+        },
+        // Least CPU and radio time: wideband audio, a cheap encoder, no silent frames sent
+        ConfigProfile::Battery => AudioConfig {
+            sample_rate: 16000,
+            frame_size_ms: 60,
+            bitrate_bps: 16_000,
+            opus_complexity: 3,
+            vad_enabled: true,
+            vad_gate_transmit: true,
+            ..base
+        },
+    }
+}
+
+/// Why `config` can't run, if it can't.
+pub(crate) fn validate_config(config: &AudioConfig) -> Result<(), ConfigIssue> {
+    if !OPUS_SAMPLE_RATES.contains(&config.sample_rate) {
+        return Err(ConfigIssue::SampleRateUnsupported { supported_hz: OPUS_SAMPLE_RATES.to_vec() });
+    }
+    if !OPUS_FRAME_SIZES_MS.contains(&config.frame_size_ms)
+        || config.sample_rate / 1000 * config.frame_size_ms > MAX_BUFFER_SIZE as i32
+    {
+        return Err(ConfigIssue::FrameSizeUnsupported { supported_ms: OPUS_FRAME_SIZES_MS.to_vec() });
+    }
+    if config.jitter_buffer_ms < config.frame_size_ms {
+        return Err(ConfigIssue::JitterBufferTooShort { min_ms: config.frame_size_ms });
+    }
+    if config.bitrate_bps != 0 && !OPUS_BITRATE_RANGE.contains(&config.bitrate_bps) {
+        return Err(ConfigIssue::BitrateOutOfRange { min_bps: *OPUS_BITRATE_RANGE.start(), max_bps: *OPUS_BITRATE_RANGE.end() });
+    }
+    if config.reorder_wait_ms < 0 || config.agc_target_level_db < 0 || config.agc_max_gain_db < 0 {
+        return Err(ConfigIssue::NegativeValue);
+    }
+    if config.noise_suppression && config.sample_rate != 48000 {
+        return Err(ConfigIssue::NoiseSuppressionNeedsSampleRate { sample_rate_hz: 48000 });
+    }
+    if config.vad_gate_transmit && !config.vad_enabled {
+        return Err(ConfigIssue::VadGateNeedsVad);
+    }
+    if config.max_packet_bytes < MAX_PACKET_BYTES_MIN {
+        return Err(ConfigIssue::MaxPacketBytesTooSmall { min_bytes: MAX_PACKET_BYTES_MIN });
+    }
+    if config.voice_message_duck_db.is_nan() || config.voice_message_duck_db < 0.0
+        || config.preemption_duck_db.is_nan() || config.preemption_duck_db < 0.0
+    {
+        return Err(ConfigIssue::NegativeDuck);
+    }
+    if let Some(beep) = &config.roger_beep
+        && (beep.duration_ms == 0 || beep.duration_ms > ROGER_BEEP_MAX_MS
+            || !(beep.frequency_hz > 0.0 && beep.frequency_hz < config.sample_rate as f32 / 2.0))
+    {
+        return Err(ConfigIssue::RogerBeepOutOfRange { max_ms: ROGER_BEEP_MAX_MS, max_frequency_hz: config.sample_rate as f32 / 2.0 });
+    }
+    if let Some(level) = config.comfort_noise_dbfs
+        && !(COMFORT_NOISE_MIN_DBFS..=COMFORT_NOISE_MAX_DBFS).contains(&level)
+    {
+        return Err(ConfigIssue::ComfortNoiseOutOfRange { min_dbfs: COMFORT_NOISE_MIN_DBFS, max_dbfs: COMFORT_NOISE_MAX_DBFS });
+    }
+    if config.ptt_pre_roll_ms > PTT_PRE_ROLL_MAX_MS {
+        return Err(ConfigIssue::PttPreRollTooLong { max_ms: PTT_PRE_ROLL_MAX_MS });
+    }
+    if let Some(output) = config.secondary_output
+        && (output.device_id == 0 || !(0.0..=MAX_OUTPUT_VOLUME).contains(&output.gain))
+    {
+        return Err(ConfigIssue::SecondaryOutputInvalid { max_gain: MAX_OUTPUT_VOLUME });
+    }
+    Ok(())
+}
+
+/// `validate_config` for the engine's entry points: `InvalidParameter`, with the
+/// reason logged.
+pub(crate) fn check_config(config: &AudioConfig) -> Result<(), AudioError> {
+    validate_config(config).map_err(|issue| {
+        log::warn!("Invalid config: {:?}", issue);
+        AudioError::InvalidParameter
+    })
+}
+
+/// `issue` for the user, in the locale set with `set_locale`.
+fn issue_message(issue: &ConfigIssue) -> Message {
+    match issue {
+        ConfigIssue::SampleRateUnsupported { supported_hz } => message(MessageId::SampleRateUnsupported, &[Arg::Ints(supported_hz.clone())]),
+        ConfigIssue::FrameSizeUnsupported { supported_ms } => message(MessageId::FrameSizeUnsupported, &[Arg::Ints(supported_ms.clone())]),
+        ConfigIssue::JitterBufferTooShort { min_ms } => message(MessageId::JitterBufferTooShort, &[Arg::Int(*min_ms as i64)]),
+        ConfigIssue::BitrateOutOfRange { min_bps, max_bps } => {
+            message(MessageId::BitrateOutOfRange, &[Arg::Int(*min_bps as i64), Arg::Int(*max_bps as i64)])
+        }
+        ConfigIssue::NegativeValue => message(MessageId::NegativeValue, &[]),
+        ConfigIssue::NoiseSuppressionNeedsSampleRate { sample_rate_hz } => {
+            message(MessageId::NoiseSuppressionNeedsSampleRate, &[Arg::Int(*sample_rate_hz as i64)])
+        }
+        ConfigIssue::VadGateNeedsVad => message(MessageId::VadGateNeedsVad, &[]),
+        ConfigIssue::MaxPacketBytesTooSmall { min_bytes } => message(MessageId::MaxPacketBytesTooSmall, &[Arg::Int(*min_bytes as i64)]),
+        ConfigIssue::NegativeDuck => message(MessageId::NegativeDuck, &[]),
+        ConfigIssue::RogerBeepOutOfRange { max_ms, max_frequency_hz } => {
+            message(MessageId::RogerBeepOutOfRange, &[Arg::Int(*max_ms as i64), Arg::Float(*max_frequency_hz)])
+        }
+        ConfigIssue::ComfortNoiseOutOfRange { min_dbfs, max_dbfs } => {
+            message(MessageId::ComfortNoiseOutOfRange, &[Arg::Float(*min_dbfs), Arg::Float(*max_dbfs)])
+        }
+        ConfigIssue::PttPreRollTooLong { max_ms } => message(MessageId::PttPreRollTooLong, &[Arg::Int(*max_ms as i64)]),
+        ConfigIssue::SecondaryOutputInvalid { max_gain } => message(MessageId::SecondaryOutputInvalid, &[Arg::Float(*max_gain)]),
+    }
+}
+
+/// The issue, if any, then each setting the engine quietly changes.
+fn config_messages(config: &AudioConfig, issue: Option<&ConfigIssue>) -> Vec<Message> {
+    let mut messages: Vec<Message> = issue.map(issue_message).into_iter().collect();
+    let complexity = config.opus_complexity.clamp(0, 10);
+    if complexity != config.opus_complexity {
+        messages.push(message(MessageId::OpusComplexityClamped, &[Arg::Int(config.opus_complexity as i64), Arg::Int(complexity as i64)]));
+    }
+    let resync_gap = config.resync_gap_packets.clamp(2, u16::MAX as i32);
+    if resync_gap != config.resync_gap_packets {
+        messages.push(message(MessageId::ResyncGapClamped, &[Arg::Int(config.resync_gap_packets as i64), Arg::Int(resync_gap as i64)]));
+    }
+    if config.replay_buffer_secs > REPLAY_MAX_SECS {
+        messages.push(message(MessageId::ReplayBufferCapped, &[Arg::Int(REPLAY_MAX_SECS as i64)]));
+    }
+    if config.roger_beep.is_some_and(|beep| beep.level_dbfs > 0.0) {
+        messages.push(message(MessageId::RogerBeepLevelCapped, &[Arg::Float(0.0)]));
+    }
+    messages
+}
+
+/// Every setting of `config` as the engine applies it (clamped where the engine clamps).
+pub(crate) fn describe_config(config: &AudioConfig) -> EffectiveConfig {
+    let noise_suppression = config.noise_suppression
+        && config.sample_rate == 48000
+        && config.frame_size_ms % 10 == 0;
+    let settings: [(&str, String); 42] = [
+        ("sample_rate", config.sample_rate.to_string()),
+        ("frame_size_ms", config.frame_size_ms.to_string()),
+        ("jitter_buffer_ms", config.jitter_buffer_ms.to_string()),
+        ("input_device_id", config.input_device_id.to_string()),
+        ("output_device_id", config.output_device_id.to_string()),
+        ("echo_cancellation", config.echo_cancellation.to_string()),
+        ("noise_suppression", noise_suppression.to_string()),
+        ("agc_enabled", config.agc_enabled.to_string()),
+        ("agc_target_level_db", config.agc_target_level_db.to_string()),
+        ("agc_max_gain_db", config.agc_max_gain_db.to_string()),
+        ("vad_enabled", config.vad_enabled.to_string()),
+        ("vad_gate_transmit", (config.vad_enabled && config.vad_gate_transmit).to_string()),
+        ("reorder_wait_ms", config.reorder_wait_ms.max(0).to_string()),
+        ("resync_gap_packets", config.resync_gap_packets.clamp(2, u16::MAX as i32).to_string()),
+        ("bitrate_bps", if config.bitrate_bps > 0 { config.bitrate_bps.to_string() } else { "auto".to_string() }),
+        ("opus_complexity", config.opus_complexity.clamp(0, 10).to_string()),
+        ("use_vbr", config.use_vbr.to_string()),
+        ("rtp_packetization", config.rtp_packetization.to_string()),
+        ("redundancy", config.redundancy.to_string()),
+        ("pad_packets", config.pad_packets.to_string()),
+        ("dedicated_callback_thread", config.dedicated_callback_thread.to_string()),
+        ("drop_stale_audio", config.drop_stale_audio.to_string()),
+        ("replay_buffer_secs", config.replay_buffer_secs.min(REPLAY_MAX_SECS).to_string()),
+        ("output_watchdog", config.output_watchdog.to_string()),
+        ("max_packet_bytes", config.max_packet_bytes.max(MAX_PACKET_BYTES_MIN).to_string()),
+        ("voice_message_duck_db", config.voice_message_duck_db.to_string()),
+        ("talk_permit_tone", config.talk_permit_tone.to_string()),
+        ("talker_id_chirp", config.talker_id_chirp.to_string()),
+        ("ptt_pre_roll_ms", config.ptt_pre_roll_ms.to_string()),
+        ("ptt_toggle", config.ptt_toggle.to_string()),
+        ("roger_beep", config.roger_beep.map_or("off".to_string(), |beep| {
+            format!("{}Hz {}ms {}dBFS", beep.frequency_hz, beep.duration_ms.min(ROGER_BEEP_MAX_MS), beep.level_dbfs.min(0.0))
+        })),
+        ("floor_control", config.floor_control.map_or("off".to_string(), |policy| format!("{:?}", policy))),
+        ("floor_priority", config.floor_priority.to_string()),
+        ("preemption", config.preemption.map_or("off".to_string(), |policy| format!("{:?}", policy))),
+        ("preemption_duck_db", config.preemption_duck_db.to_string()),
+        ("comfort_noise_dbfs", config.comfort_noise_dbfs.map_or("off".to_string(), |level| level.to_string())),
+        ("max_concurrent_speakers", if config.max_concurrent_speakers == 0 { "unlimited".to_string() } else { config.max_concurrent_speakers.to_string() }),
+        ("secondary_output", config.secondary_output.map_or("off".to_string(), |output| {
+            format!("device {} gain {}", output.device_id, output.gain)
+        })),
+        ("routing_mode", format!("{:?}", config.routing_mode.unwrap_or(AudioRoutingMode::Auto))),
+        ("frame_samples", (config.sample_rate / 1000 * config.frame_size_ms).to_string()),
+        ("jitter_max_packets", (config.jitter_buffer_ms / config.frame_size_ms.max(1)).max(1).to_string()),
+        ("valid", validate_config(config).is_ok().to_string()),
+    ];
+    let issue = validate_config(config).err();
+    EffectiveConfig {
+        profile: config.profile,
+        settings: settings.into_iter().map(|(name, value)| (name.to_string(), value)).collect::<HashMap<_, _>>(),
+        messages: config_messages(config, issue.as_ref()),
+        issue,
+    }
+}
+
+/// Builds an `AudioConfig` from a profile plus overrides:
+/// `ConfigBuilder(ConfigProfile.CELLULAR).echoCancellation(true).build()`.
+#[derive(uniffi::Object)]
+pub struct ConfigBuilder {
+    config: Mutex<AudioConfig>,
+}
+
+#[uniffi::export]
+impl ConfigBuilder {
+    /// Starts from `profile`, or from the engine defaults without one.
+    #[uniffi::constructor]
+    pub fn new(profile: Option<ConfigProfile>) -> Arc<Self> {
+        let config = profile.map_or_else(AudioConfig::default, profile_config);
+        Arc::new(Self { config: Mutex::new(config) })
+    }
+
+    pub fn sample_rate(self: Arc<Self>, hz: i32) -> Arc<Self> {
+        self.config.lock().unwrap().sample_rate = hz;
+        self
+    }
+
+    pub fn frame_size_ms(self: Arc<Self>, ms: i32) -> Arc<Self> {
+        self.config.lock().unwrap().frame_size_ms = ms;
+        self
+    }
+
+    pub fn jitter_buffer_ms(self: Arc<Self>, ms: i32) -> Arc<Self> {
+        self.config.lock().unwrap().jitter_buffer_ms = ms;
+        self
+    }
+
+    /// 0 lets the route preference (or the system) pick.
+    pub fn devices(self: Arc<Self>, input_device_id: i32, output_device_id: i32) -> Arc<Self> {
+        let mut config = self.config.lock().unwrap();
+        config.input_device_id = input_device_id;
+        config.output_device_id = output_device_id;
+        drop(config);
+        self
+    }
+
+    pub fn bitrate_bps(self: Arc<Self>, bps: i32) -> Arc<Self> {
+        self.config.lock().unwrap().bitrate_bps = bps;
+        self
+    }
+
+    pub fn echo_cancellation(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().echo_cancellation = enabled;
+        self
+    }
+
+    pub fn noise_suppression(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().noise_suppression = enabled;
+        self
+    }
+
+    pub fn agc_enabled(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().agc_enabled = enabled;
+        self
+    }
+
+    pub fn vad(self: Arc<Self>, enabled: bool, gate_transmit: bool) -> Arc<Self> {
+        let mut config = self.config.lock().unwrap();
+        config.vad_enabled = enabled;
+        config.vad_gate_transmit = gate_transmit;
+        drop(config);
+        self
+    }
+
+    pub fn redundancy(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().redundancy = enabled;
+        self
+    }
+
+    pub fn pad_packets(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().pad_packets = enabled;
+        self
+    }
+
+    pub fn output_watchdog(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().output_watchdog = enabled;
+        self
+    }
+
+    pub fn talk_permit_tone(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().talk_permit_tone = enabled;
+        self
+    }
+
+    pub fn talker_id_chirp(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().talker_id_chirp = enabled;
+        self
+    }
+
+    pub fn ptt_pre_roll_ms(self: Arc<Self>, ms: u32) -> Arc<Self> {
+        self.config.lock().unwrap().ptt_pre_roll_ms = ms;
+        self
+    }
+
+    pub fn ptt_toggle(self: Arc<Self>, enabled: bool) -> Arc<Self> {
+        self.config.lock().unwrap().ptt_toggle = enabled;
+        self
+    }
+
+    pub fn comfort_noise_dbfs(self: Arc<Self>, level: Option<f32>) -> Arc<Self> {
+        self.config.lock().unwrap().comfort_noise_dbfs = level;
+        self
+    }
+
+    pub fn max_concurrent_speakers(self: Arc<Self>, speakers: u32) -> Arc<Self> {
+        self.config.lock().unwrap().max_concurrent_speakers = speakers;
+        self
+    }
+
+    /// A second device playing the channel, with its own gain. None = off.
+    pub fn secondary_output(self: Arc<Self>, output: Option<SecondaryOutput>) -> Arc<Self> {
+        self.config.lock().unwrap().secondary_output = output;
+        self
+    }
+
+    pub fn roger_beep(self: Arc<Self>, beep: Option<RogerBeep>) -> Arc<Self> {
+        self.config.lock().unwrap().roger_beep = beep;
+        self
+    }
+
+    pub fn floor_control(self: Arc<Self>, policy: FloorPolicy, priority: u8) -> Arc<Self> {
+        let mut config = self.config.lock().unwrap();
+        config.floor_control = Some(policy);
+        config.floor_priority = priority;
+        drop(config);
+        self
+    }
+
+    pub fn routing_mode(self: Arc<Self>, mode: AudioRoutingMode) -> Arc<Self> {
+        self.config.lock().unwrap().routing_mode = Some(mode);
+        self
+    }
+
+    /// The finished config, or `InvalidParameter` (with the reason logged) if the
+    /// engine couldn't run it. `validate` says why.
+    pub fn build(&self) -> Result<AudioConfig, AudioError> {
+        let config = *self.config.lock().unwrap();
+        check_config(&config)?;
+        Ok(config)
+    }
+
+    /// Why `build` would fail, as an ID to show the user in their own language.
+    pub fn validate(&self) -> Option<ConfigIssue> {
+        validate_config(&self.config.lock().unwrap()).err()
+    }
+
+    /// What the config built so far resolves to, see `AudioEngine::describe_effective_config`.
+    pub fn describe_effective_config(&self) -> EffectiveConfig {
+        describe_config(&self.config.lock().unwrap())
+    }
+}
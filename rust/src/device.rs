@@ -0,0 +1,75 @@
+// ===========================================================================
+// AUDIO DEVICES
+// ===========================================================================
+// The engine's audio streams, over whichever audio API the target has: Oboe
+// on Android, cpal on iOS and desktops (feature `desktop`). Every stream is mono i16
+// at the session's rate and runs from the moment it opens. The engine only
+// sees buffers, errors and the little it needs for routing and the output
+// watchdog, so everything above this module is the same on every platform.
+
+use std::sync::Arc;
+
+use crate::AudioRoute;
+use crate::errors::ErrorAggregator;
+
+#[cfg(not(target_os = "android"))]
+mod cpal;
+#[cfg(target_os = "android")]
+mod oboe;
+
+#[cfg(not(target_os = "android"))]
+pub(crate) use self::cpal::{BACKEND, CaptureStream, PlaybackStream, init_logger, list_devices, open_capture, open_playback};
+#[cfg(target_os = "android")]
+pub(crate) use self::oboe::{BACKEND, CaptureStream, PlaybackStream, init_logger, list_devices, open_capture, open_playback};
+
+/// What a stream carries, which decides how the platform processes and routes it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Usage {
+    Voice,
+    Media, // A PA or line-out, not the call itself
+}
+
+/// A stream to open: mono i16 at `sample_rate` on `device_id` (0 = the system's choice).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct StreamSpec {
+    pub(crate) sample_rate: i32,
+    pub(crate) device_id: i32,
+    #[cfg_attr(not(target_os = "android"), allow(dead_code))] // cpal streams have no usage
+    pub(crate) usage: Usage,
+}
+
+/// An input or output device, for route selection.
+pub(crate) struct DeviceInfo {
+    pub(crate) id: i32,
+    pub(crate) route: Option<AudioRoute>,
+}
+
+/// Takes each buffer of captured audio, on the platform's audio thread.
+pub(crate) trait CaptureHandler: Send + 'static {
+    fn on_capture(&mut self, frames: &[i16]);
+}
+
+/// Fills each buffer to play, on the platform's audio thread.
+pub(crate) trait RenderHandler: Send + 'static {
+    fn on_render(&mut self, frames: &mut [i16]);
+}
+
+/// Where a stream's errors go. Codes are AAudio's on every backend, so apps see
+/// the same numbers everywhere.
+pub(crate) trait StreamErrors: Send + 'static {
+    fn on_error(&mut self, code: i32);
+
+    /// The device went away and the stream has stopped for good.
+    fn on_disconnected(&mut self) {}
+}
+
+impl StreamErrors for Arc<ErrorAggregator> {
+    fn on_error(&mut self, code: i32) {
+        self.report(code);
+    }
+}
+
+/// For streams whose errors nobody needs to hear about (test tones).
+impl StreamErrors for () {
+    fn on_error(&mut self, _code: i32) {}
+}
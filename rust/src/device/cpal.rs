@@ -0,0 +1,281 @@
+// ===========================================================================
+// CPAL STREAMS (IOS, DESKTOP)
+// ===========================================================================
+// The host's default audio API through cpal: Core Audio on iOS and macOS,
+// ALSA on Linux, WASAPI on Windows. Devices rarely offer mono i16, so each stream
+// takes the fewest channels the device has at the session's rate, as i16 or
+// f32. Capture keeps the first channel; playback copies the mono mix to every
+// channel. No resampling: a device that can't run at the session's rate fails
+// to open, like it would on Android.
+//
+// cpal has no device IDs that fit in an i32, so ours are a hash of cpal's own
+// and stay the same across runs. The engine logs them at session start.
+//
+// On iOS the AVAudioSession decides which route cpal's default devices are,
+// so the app sets its category (PlayAndRecord) and mode (VoiceChat) before
+// starting a session.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{BufferSize, Device, DeviceType, InterfaceType, SampleFormat, StreamConfig};
+
+use super::{CaptureHandler, DeviceInfo, RenderHandler, StreamErrors, StreamSpec};
+use crate::AudioRoute;
+
+pub(crate) const BACKEND: &str = "cpal";
+
+// AAudio's codes for the errors cpal reports (see `StreamErrors`)
+const ERROR_INTERNAL: i32 = -896;
+const ERROR_DISCONNECTED: i32 = -899;
+
+pub(crate) struct CaptureStream {
+    _stream: cpal::Stream,
+    running: Arc<AtomicBool>,
+}
+
+impl CaptureStream {
+    /// False once the stream's device went away.
+    pub(crate) fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    /// The platform's capture preset: cpal has none, the signal arrives unprocessed.
+    pub(crate) fn preset(&self) -> Option<(String, bool)> {
+        None
+    }
+
+    pub(crate) fn close(self) {}
+}
+
+pub(crate) struct PlaybackStream {
+    _stream: cpal::Stream,
+    running: Arc<AtomicBool>,
+    position: Arc<AtomicU64>, // Frames rendered
+    xruns: Arc<AtomicI32>,
+}
+
+impl PlaybackStream {
+    /// For the output watchdog: the frame position and the xrun count, each if
+    /// known. `None` while the stream isn't running.
+    pub(crate) fn health(&mut self) -> Option<(Option<i64>, Option<i32>)> {
+        if !self.running.load(Ordering::Relaxed) { return None; }
+        Some((Some(self.position.load(Ordering::Relaxed) as i64), Some(self.xruns.load(Ordering::Relaxed))))
+    }
+
+    pub(crate) fn close(self) {}
+}
+
+/// Hash of cpal's device ID, folded into the positive i32s (0 is the system's choice).
+fn device_id(device: &Device) -> Option<i32> {
+    let id = device.id().ok()?.to_string();
+    // FNV-1a
+    let hash = id.bytes().fold(0x811c_9dc5u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x0100_0193));
+    Some((hash & 0x7fff_ffff).max(1) as i32)
+}
+
+fn find_device(device_id_wanted: i32, input: bool) -> Result<Device, String> {
+    let host = cpal::default_host();
+    let device = if device_id_wanted == 0 {
+        if input { host.default_input_device() } else { host.default_output_device() }
+    } else {
+        host.devices()
+            .map_err(|e| e.to_string())?
+            .filter(|device| if input { device.supports_input() } else { device.supports_output() })
+            .find(|device| device_id(device) == Some(device_id_wanted))
+    };
+    device.ok_or_else(|| format!("no {} device {}", if input { "input" } else { "output" }, device_id_wanted))
+}
+
+/// The fewest channels `device` offers at `sample_rate`, preferring i16 over f32.
+fn stream_config(device: &Device, sample_rate: i32, input: bool) -> Result<(StreamConfig, SampleFormat), String> {
+    let configs: Vec<_> = if input {
+        device.supported_input_configs().map_err(|e| e.to_string())?.collect()
+    } else {
+        device.supported_output_configs().map_err(|e| e.to_string())?.collect()
+    };
+    let rate = sample_rate as u32;
+    configs.iter()
+        .filter(|config| (config.min_sample_rate()..=config.max_sample_rate()).contains(&rate))
+        .filter(|config| matches!(config.sample_format(), SampleFormat::I16 | SampleFormat::F32))
+        .min_by_key(|config| (config.channels(), config.sample_format() != SampleFormat::I16))
+        .map(|config| {
+            let stream = StreamConfig { channels: config.channels(), sample_rate: rate, buffer_size: BufferSize::Default };
+            (stream, config.sample_format())
+        })
+        .ok_or_else(|| format!("no i16 or f32 format at {}Hz", sample_rate))
+}
+
+fn f32_to_i16(sample: f32) -> i16 {
+    (sample * 32768.0).clamp(i16::MIN as f32, i16::MAX as f32) as i16
+}
+
+fn i16_to_f32(sample: i16) -> f32 {
+    sample as f32 / 32768.0
+}
+
+/// Hands the first channel of `data` to `handler`, through `mono` (kept between calls).
+fn capture<T: Copy>(data: &[T], channels: usize, mono: &mut Vec<i16>, handler: &mut impl CaptureHandler, convert: fn(T) -> i16) {
+    mono.clear();
+    mono.extend(data.iter().step_by(channels).map(|&sample| convert(sample)));
+    handler.on_capture(mono);
+}
+
+/// Lets `handler` fill `mono`, then copies it to every channel of `data`.
+fn render<T: Copy>(data: &mut [T], channels: usize, mono: &mut Vec<i16>, handler: &mut impl RenderHandler, convert: fn(i16) -> T) {
+    mono.resize(data.len() / channels, 0);
+    handler.on_render(mono);
+    for (frame, &sample) in data.chunks_exact_mut(channels).zip(mono.iter()) {
+        frame.fill(convert(sample));
+    }
+}
+
+/// cpal's stream errors, as `StreamErrors` expects them. Underruns aren't errors
+/// on Android either; they only count towards the output watchdog.
+fn error_handler(
+    mut errors: impl StreamErrors,
+    running: Arc<AtomicBool>,
+    xruns: Option<Arc<AtomicI32>>,
+) -> impl FnMut(cpal::StreamError) + Send + 'static {
+    move |error| match error {
+        cpal::StreamError::BufferUnderrun => {
+            if let Some(xruns) = &xruns {
+                xruns.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        cpal::StreamError::DeviceNotAvailable | cpal::StreamError::StreamInvalidated => {
+            errors.on_error(ERROR_DISCONNECTED);
+            if running.swap(false, Ordering::Relaxed) {
+                errors.on_disconnected();
+            }
+        }
+        cpal::StreamError::BackendSpecific { err } => {
+            log::warn!("Audio stream error: {}", err);
+            errors.on_error(ERROR_INTERNAL);
+        }
+    }
+}
+
+/// Opens and starts a mic stream.
+pub(crate) fn open_capture(
+    spec: StreamSpec,
+    mut handler: impl CaptureHandler,
+    errors: impl StreamErrors,
+) -> Result<CaptureStream, String> {
+    let device = find_device(spec.device_id, true)?;
+    let (config, format) = stream_config(&device, spec.sample_rate, true)?;
+    let channels = config.channels as usize;
+    let running = Arc::new(AtomicBool::new(true));
+    let on_error = error_handler(errors, running.clone(), None);
+    let mut mono = Vec::new();
+    let stream = match format {
+        SampleFormat::I16 => device.build_input_stream(
+            &config,
+            move |data: &[i16], _: &_| capture(data, channels, &mut mono, &mut handler, |sample| sample),
+            on_error,
+            None,
+        ),
+        _ => device.build_input_stream(
+            &config,
+            move |data: &[f32], _: &_| capture(data, channels, &mut mono, &mut handler, f32_to_i16),
+            on_error,
+            None,
+        ),
+    }
+    .map_err(|e| e.to_string())?;
+    stream.play().map_err(|e| e.to_string())?;
+    Ok(CaptureStream { _stream: stream, running })
+}
+
+/// Opens and starts an output stream.
+pub(crate) fn open_playback(
+    spec: StreamSpec,
+    mut handler: impl RenderHandler,
+    errors: impl StreamErrors,
+) -> Result<PlaybackStream, String> {
+    let device = find_device(spec.device_id, false)?;
+    let (config, format) = stream_config(&device, spec.sample_rate, false)?;
+    let channels = config.channels as usize;
+    let running = Arc::new(AtomicBool::new(true));
+    let position = Arc::new(AtomicU64::new(0));
+    let xruns = Arc::new(AtomicI32::new(0));
+    let on_error = error_handler(errors, running.clone(), Some(xruns.clone()));
+    let rendered = position.clone();
+    let mut mono = Vec::new();
+    let stream = match format {
+        SampleFormat::I16 => device.build_output_stream(
+            &config,
+            move |data: &mut [i16], _: &_| {
+                render(data, channels, &mut mono, &mut handler, |sample| sample);
+                rendered.fetch_add((data.len() / channels) as u64, Ordering::Relaxed);
+            },
+            on_error,
+            None,
+        ),
+        _ => device.build_output_stream(
+            &config,
+            move |data: &mut [f32], _: &_| {
+                render(data, channels, &mut mono, &mut handler, i16_to_f32);
+                rendered.fetch_add((data.len() / channels) as u64, Ordering::Relaxed);
+            },
+            on_error,
+            None,
+        ),
+    }
+    .map_err(|e| e.to_string())?;
+    stream.play().map_err(|e| e.to_string())?;
+    Ok(PlaybackStream { _stream: stream, running, position, xruns })
+}
+
+/// Headsets by how they connect; the rest by what they are.
+fn route_of(device: &Device) -> Option<AudioRoute> {
+    let description = device.description().ok()?;
+    match (description.interface_type(), description.device_type()) {
+        (InterfaceType::Bluetooth, DeviceType::Headset | DeviceType::Headphones | DeviceType::HearingAid) => {
+            Some(AudioRoute::BluetoothSco)
+        }
+        (_, DeviceType::Headset | DeviceType::Headphones) => Some(AudioRoute::Wired),
+        (_, DeviceType::Earpiece | DeviceType::Handset) => Some(AudioRoute::Earpiece),
+        (_, DeviceType::Speaker) => Some(AudioRoute::Speaker),
+        _ => None,
+    }
+}
+
+/// The host's output (or input) devices. Each one's ID and name go to the log,
+/// which is the only place a desktop user can look them up.
+pub(crate) fn list_devices(output: bool) -> Result<Vec<DeviceInfo>, String> {
+    let devices = cpal::default_host().devices().map_err(|e| e.to_string())?;
+    Ok(devices
+        .filter(|device| if output { device.supports_output() } else { device.supports_input() })
+        .filter_map(|device| {
+            let id = device_id(&device)?;
+            let route = route_of(&device);
+            debug!("{} device {}: {} ({:?})", if output { "Output" } else { "Input" }, id,
+                device.description().map_or_else(|_| "?".to_string(), |description| description.to_string()), route);
+            Some(DeviceInfo { id, route })
+        })
+        .collect())
+}
+
+/// Engine logs to stderr, unless the app installed a logger of its own.
+pub(crate) fn init_logger() {
+    static LOGGER: StderrLogger = StderrLogger;
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(log::LevelFilter::Debug);
+    }
+}
+
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        eprintln!("{:<5} {}: {}", record.level(), record.target(), record.args());
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,188 @@
+// ===========================================================================
+// OBOE STREAMS (ANDROID)
+// ===========================================================================
+// Shared-mode AAudio/OpenSL streams through Oboe. Capture uses the
+// VoiceCommunication preset, so the platform's own echo canceller and noise
+// suppressor may run before we see the signal.
+
+use oboe::{
+    AudioDeviceDirection, AudioDeviceInfo, AudioDeviceType, AudioInputCallback, AudioInputStreamSafe, AudioOutputCallback,
+    AudioOutputStreamSafe, AudioStream, AudioStreamAsync, AudioStreamBase, AudioStreamBuilder, AudioStreamSafe,
+    DataCallbackResult, Input, InputPreset, Mono, Output, PerformanceMode, SharingMode, StreamState,
+};
+
+use super::{CaptureHandler, DeviceInfo, RenderHandler, StreamErrors, StreamSpec, Usage};
+use crate::AudioRoute;
+
+pub(crate) const BACKEND: &str = "oboe";
+
+const CLOCK_MONOTONIC: i32 = 1; // clockid_t for `get_timestamp`
+
+struct Capture {
+    handler: Box<dyn CaptureHandler>,
+    errors: Box<dyn StreamErrors>,
+}
+
+impl AudioInputCallback for Capture {
+    type FrameType = (i16, Mono);
+
+    fn on_audio_ready(&mut self, _stream: &mut dyn AudioInputStreamSafe, frames: &[i16]) -> DataCallbackResult {
+        self.handler.on_capture(frames);
+        DataCallbackResult::Continue
+    }
+
+    fn on_error_before_close(&mut self, _stream: &mut dyn AudioInputStreamSafe, error: oboe::Error) {
+        self.errors.on_error(error as i32);
+    }
+
+    fn on_error_after_close(&mut self, _stream: &mut dyn AudioInputStreamSafe, error: oboe::Error) {
+        if error == oboe::Error::Disconnected {
+            self.errors.on_disconnected();
+        }
+    }
+}
+
+struct Playback {
+    handler: Box<dyn RenderHandler>,
+    errors: Box<dyn StreamErrors>,
+}
+
+impl AudioOutputCallback for Playback {
+    type FrameType = (i16, Mono);
+
+    fn on_audio_ready(&mut self, _stream: &mut dyn AudioOutputStreamSafe, frames: &mut [i16]) -> DataCallbackResult {
+        self.handler.on_render(frames);
+        DataCallbackResult::Continue
+    }
+
+    fn on_error_before_close(&mut self, _stream: &mut dyn AudioOutputStreamSafe, error: oboe::Error) {
+        self.errors.on_error(error as i32);
+    }
+
+    fn on_error_after_close(&mut self, _stream: &mut dyn AudioOutputStreamSafe, error: oboe::Error) {
+        if error == oboe::Error::Disconnected {
+            self.errors.on_disconnected();
+        }
+    }
+}
+
+pub(crate) struct CaptureStream(AudioStreamAsync<Input, Capture>);
+
+impl CaptureStream {
+    /// False once the stream stopped or its device went away.
+    pub(crate) fn is_running(&self) -> bool {
+        self.0.get_state() == StreamState::Started
+    }
+
+    /// The platform's capture preset, and whether it processes the signal before we get it.
+    pub(crate) fn preset(&self) -> Option<(String, bool)> {
+        let preset = self.0.get_input_preset();
+        Some((format!("{:?}", preset), !matches!(preset, InputPreset::Unprocessed | InputPreset::VoicePerformance)))
+    }
+
+    pub(crate) fn close(mut self) {
+        let _ = self.0.close();
+    }
+}
+
+pub(crate) struct PlaybackStream(AudioStreamAsync<Output, Playback>);
+
+impl PlaybackStream {
+    /// For the output watchdog: the frame position and the xrun count, each if
+    /// known. `None` while the stream isn't running.
+    pub(crate) fn health(&mut self) -> Option<(Option<i64>, Option<i32>)> {
+        if self.0.get_state() != StreamState::Started { return None; }
+        Some((
+            self.0.get_timestamp(CLOCK_MONOTONIC).ok().map(|timestamp| timestamp.position),
+            self.0.get_xrun_count().ok(),
+        ))
+    }
+
+    pub(crate) fn close(mut self) {
+        let _ = self.0.close();
+    }
+}
+
+/// Opens and starts a mic stream.
+pub(crate) fn open_capture(
+    spec: StreamSpec,
+    handler: impl CaptureHandler,
+    errors: impl StreamErrors,
+) -> Result<CaptureStream, String> {
+    // 1. Configure properties on the BASE builder first
+    let mut builder = AudioStreamBuilder::default()
+        .set_direction::<Input>()
+        .set_performance_mode(PerformanceMode::None)
+        .set_sharing_mode(SharingMode::Shared)
+        .set_format::<i16>()
+        .set_channel_count::<Mono>()
+        .set_sample_rate(spec.sample_rate)
+        .set_input_preset(InputPreset::VoiceCommunication);
+
+    // 2. Set Device ID on the BASE builder (before setting callback)
+    if spec.device_id != 0 {
+        builder = builder.set_device_id(spec.device_id);
+    }
+
+    // 3. Set Callback (Converts to Async Builder) and Open
+    let mut stream = builder
+        .set_callback(Capture { handler: Box::new(handler), errors: Box::new(errors) })
+        .open_stream()
+        .map_err(|e| e.to_string())?;
+    stream.start().map_err(|e| e.to_string())?;
+    Ok(CaptureStream(stream))
+}
+
+/// Opens and starts an output stream.
+pub(crate) fn open_playback(
+    spec: StreamSpec,
+    handler: impl RenderHandler,
+    errors: impl StreamErrors,
+) -> Result<PlaybackStream, String> {
+    let mut builder = AudioStreamBuilder::default()
+        .set_direction::<Output>()
+        .set_performance_mode(PerformanceMode::None)
+        .set_sharing_mode(SharingMode::Shared)
+        .set_format::<i16>()
+        .set_channel_count::<Mono>()
+        .set_sample_rate(spec.sample_rate)
+        .set_usage(match spec.usage {
+            Usage::Voice => oboe::Usage::VoiceCommunication,
+            Usage::Media => oboe::Usage::Media,
+        });
+    if spec.device_id != 0 {
+        builder = builder.set_device_id(spec.device_id);
+    }
+    let mut stream = builder
+        .set_callback(Playback { handler: Box::new(handler), errors: Box::new(errors) })
+        .open_stream()
+        .map_err(|e| e.to_string())?;
+    stream.start().map_err(|e| e.to_string())?;
+    Ok(PlaybackStream(stream))
+}
+
+fn route_of(device_type: AudioDeviceType) -> Option<AudioRoute> {
+    match device_type {
+        AudioDeviceType::BluetoothSCO => Some(AudioRoute::BluetoothSco),
+        AudioDeviceType::WiredHeadset | AudioDeviceType::WiredHeadphones
+            | AudioDeviceType::UsbHeadset => Some(AudioRoute::Wired),
+        AudioDeviceType::BuiltinEarpiece => Some(AudioRoute::Earpiece),
+        AudioDeviceType::BuiltinSpeaker => Some(AudioRoute::Speaker),
+        _ => None,
+    }
+}
+
+/// The connected output (or input) devices, by Android's device ID.
+pub(crate) fn list_devices(output: bool) -> Result<Vec<DeviceInfo>, String> {
+    let direction = if output { AudioDeviceDirection::Output } else { AudioDeviceDirection::Input };
+    Ok(AudioDeviceInfo::request(direction)?
+        .into_iter()
+        .map(|device| DeviceInfo { id: device.id, route: route_of(device.device_type) })
+        .collect())
+}
+
+pub(crate) fn init_logger() {
+    android_logger::init_once(
+        android_logger::Config::default().with_max_level(log::LevelFilter::Debug),
+    );
+}
@@ -0,0 +1,101 @@
+// ===========================================================================
+// CALLBACK DISPATCH
+// ===========================================================================
+// Foreign callbacks (Kotlin) can be slow. By default they are invoked on the
+// thread that produced the event. In dedicated mode every callback is queued
+// to one Rust thread instead, so a slow handler can never stall the audio or
+// transport threads. When the queue is full, events are dropped (and counted).
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{bounded, Sender};
+
+use crate::resources;
+use crate::{CALLBACK_QUEUE_CAPACITY, THREAD_JOIN_POLL_MS};
+
+/// A registered foreign callback (None until the app sets one).
+pub(crate) type CallbackSlot<T> = Arc<Mutex<Option<Box<T>>>>;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+pub(crate) struct CallbackDispatcher {
+    dedicated: AtomicBool,
+    tx: Mutex<Option<Sender<Job>>>, // None once shut down
+    thread: Mutex<Option<JoinHandle<()>>>,
+    dropped: AtomicU64,
+}
+
+impl CallbackDispatcher {
+    pub(crate) fn new(dedicated: bool) -> Self {
+        let (tx, rx) = bounded::<Job>(CALLBACK_QUEUE_CAPACITY);
+        let thread = resources::spawn(move || {
+            while let Ok(job) = rx.recv() {
+                job();
+            }
+        });
+        Self {
+            dedicated: AtomicBool::new(dedicated),
+            tx: Mutex::new(Some(tx)),
+            thread: Mutex::new(Some(thread)),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Ends the callback thread once the queued callbacks have run, waiting up to
+    /// `timeout` for it. Callbacks queued after this are dropped.
+    pub(crate) fn shutdown(&self, timeout: Duration) {
+        self.tx.lock().unwrap().take();
+        if let Some(thread) = self.thread.lock().unwrap().take()
+            && !join_timeout(thread, timeout) {
+            log::warn!("Callback thread still busy, left to finish on its own");
+        }
+    }
+
+    pub(crate) fn set_dedicated(&self, dedicated: bool) {
+        self.dedicated.store(dedicated, Ordering::Relaxed);
+    }
+
+    /// Events lost because the callback thread fell behind.
+    pub(crate) fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Calls `f` with the registered callback, if there is one.
+    pub(crate) fn notify<T, F>(&self, slot: &CallbackSlot<T>, f: F)
+    where
+        T: ?Sized + Send + 'static,
+        F: FnOnce(&T) + Send + 'static,
+    {
+        if !self.dedicated.load(Ordering::Relaxed) {
+            if let Some(callback) = &*slot.lock().unwrap() {
+                f(callback);
+            }
+            return;
+        }
+
+        let slot = slot.clone();
+        let job: Job = Box::new(move || {
+            if let Some(callback) = &*slot.lock().unwrap() {
+                f(callback);
+            }
+        });
+        let sent = self.tx.lock().unwrap().as_ref().is_some_and(|tx| tx.try_send(job).is_ok());
+        if !sent {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Joins `thread` if it finishes within `timeout`; false (leaving it detached) if not.
+pub(crate) fn join_timeout<T>(thread: JoinHandle<T>, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while !thread.is_finished() {
+        if Instant::now() >= deadline { return false; }
+        thread::sleep(Duration::from_millis(THREAD_JOIN_POLL_MS));
+    }
+    let _ = thread.join();
+    true
+}
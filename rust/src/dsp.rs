@@ -0,0 +1,19 @@
+// ===========================================================================
+// SIGNAL PROCESSING
+// ===========================================================================
+// Platform-independent DSP stages. These operate on plain PCM slices so the
+// audio callbacks only have to decide *where* in the pipeline they run.
+
+pub(crate) mod aec;
+pub(crate) mod agc;
+pub(crate) mod cng;
+pub(crate) mod duck;
+pub(crate) mod limiter;
+pub(crate) mod meter;
+pub(crate) mod ns;
+pub(crate) mod resample;
+pub(crate) mod sidetone;
+pub(crate) mod simd;
+pub(crate) mod tone;
+pub(crate) mod tsm;
+pub(crate) mod vad;
@@ -0,0 +1,348 @@
+use std::collections::VecDeque;
+
+use crate::{
+    AEC_DELAY_BLOCK_SAMPLES, AEC_DELAY_MIN_CORRELATION, AEC_DELAY_UPDATE_MS, AEC_DELAY_WINDOW_MS, AEC_DOUBLE_TALK_THRESHOLD,
+    AEC_FAR_END_MAX_SAMPLES, AEC_FILTER_TAPS, AEC_MAX_DELAY_MS, AEC_STEP_SIZE,
+};
+
+/// Far-end reference shared between the output and input callbacks.
+///
+/// The output callback pushes every mixed frame it hands to the speaker, the
+/// input callback pops the same number of samples it receives from the mic.
+/// Because both streams run at the same rate, the FIFO keeps the reference
+/// roughly aligned with the echo that arrives at the microphone. What is left
+/// over (the output and input latency) is found by the canceller's delay
+/// estimator, which can only delay the reference: `pop_into` keeps it from
+/// running more than `max_lead` samples ahead, so it never trails the echo.
+pub(crate) struct FarEndBuffer {
+    samples: VecDeque<i16>,
+}
+
+impl FarEndBuffer {
+    pub(crate) fn new() -> Self {
+        Self { samples: VecDeque::with_capacity(AEC_FAR_END_MAX_SAMPLES) }
+    }
+
+    pub(crate) fn push(&mut self, frame: &[i16]) {
+        self.samples.extend(frame.iter().copied());
+        // If the input side stalls (or is not running) we must not grow forever.
+        // Dropping the oldest samples keeps the most recent playout as reference.
+        if self.samples.len() > AEC_FAR_END_MAX_SAMPLES {
+            let excess = self.samples.len() - AEC_FAR_END_MAX_SAMPLES;
+            self.samples.drain(..excess);
+        }
+    }
+
+    /// Fills `out` with the next reference samples. Missing data is zero-filled
+    /// (nothing was played, so there is no echo to cancel). Of what is left, only
+    /// the newest `max_lead` samples are kept.
+    pub(crate) fn pop_into(&mut self, out: &mut [i16], max_lead: usize) {
+        for slot in out.iter_mut() {
+            *slot = self.samples.pop_front().unwrap_or(0);
+        }
+        if self.samples.len() > max_lead {
+            let excess = self.samples.len() - max_lead;
+            self.samples.drain(..excess);
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
+
+/// Largest value among the last `window` pushed, kept as a deque of candidates
+/// that decreases from the front, so each push is O(1) amortized.
+struct RunningPeak {
+    window: usize,
+    candidates: VecDeque<(u64, f32)>, // (Sample index, value)
+    count: u64,
+}
+
+impl RunningPeak {
+    fn new(window: usize) -> Self {
+        Self { window, candidates: VecDeque::with_capacity(window), count: 0 }
+    }
+
+    /// Adds `value` and returns the peak of the window ending with it.
+    fn push(&mut self, value: f32) -> f32 {
+        while self.candidates.back().is_some_and(|&(_, last)| last <= value) {
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back((self.count, value));
+        self.count += 1;
+        while self.candidates.front().is_some_and(|&(index, _)| index + (self.window as u64) < self.count) {
+            self.candidates.pop_front();
+        }
+        self.candidates.front().map_or(0.0, |&(_, peak)| peak)
+    }
+
+    fn clear(&mut self) {
+        self.candidates.clear();
+    }
+}
+
+/// Finds the bulk delay of the echo behind the reference by correlating their
+/// envelopes (mean magnitude per AEC_DELAY_BLOCK_SAMPLES) over the last
+/// AEC_DELAY_WINDOW_MS, at every lag up to AEC_MAX_DELAY_MS.
+struct DelayEstimator {
+    far: VecDeque<f32>, // Envelopes, newest at the back
+    near: VecDeque<f32>,
+    far_sum: f32,
+    near_sum: f32,
+    block_fill: usize,
+    window: usize,  // Blocks correlated
+    max_lag: usize, // Blocks
+    update_every: usize,
+    since_update: usize,
+}
+
+impl DelayEstimator {
+    fn new(sample_rate: usize) -> Self {
+        let blocks = |ms: usize| (sample_rate * ms / 1000 / AEC_DELAY_BLOCK_SAMPLES).max(1);
+        let (window, max_lag) = (blocks(AEC_DELAY_WINDOW_MS), blocks(AEC_MAX_DELAY_MS));
+        Self {
+            far: VecDeque::with_capacity(window + max_lag),
+            near: VecDeque::with_capacity(window),
+            far_sum: 0.0,
+            near_sum: 0.0,
+            block_fill: 0,
+            window,
+            max_lag,
+            update_every: blocks(AEC_DELAY_UPDATE_MS),
+            since_update: 0,
+        }
+    }
+
+    /// Adds one reference and one mic sample. Every AEC_DELAY_UPDATE_MS, returns
+    /// the delay in samples if one lag stands out.
+    fn push(&mut self, far: f32, near: f32) -> Option<usize> {
+        self.far_sum += far.abs();
+        self.near_sum += near.abs();
+        self.block_fill += 1;
+        if self.block_fill < AEC_DELAY_BLOCK_SAMPLES { return None; }
+
+        if self.far.len() == self.window + self.max_lag {
+            self.far.pop_front();
+        }
+        if self.near.len() == self.window {
+            self.near.pop_front();
+        }
+        self.far.push_back(self.far_sum);
+        self.near.push_back(self.near_sum);
+        (self.far_sum, self.near_sum, self.block_fill) = (0.0, 0.0, 0);
+
+        self.since_update += 1;
+        if self.since_update < self.update_every || self.far.len() < self.window + self.max_lag { return None; }
+        self.since_update = 0;
+        self.estimate().map(|lag| lag * AEC_DELAY_BLOCK_SAMPLES)
+    }
+
+    /// The lag (in blocks) whose far envelope correlates best (Pearson) with the
+    /// near one. Runs in the input callback, so it works on the deques in place.
+    fn estimate(&self) -> Option<usize> {
+        let blocks = self.window as f32;
+        let near_mean = self.near.iter().sum::<f32>() / blocks;
+        let near_var: f32 = self.near.iter().map(|near| (near - near_mean).powi(2)).sum();
+        if near_var <= f32::EPSILON { return None; }
+        let start = self.far.len() - self.window; // The window's start at lag 0
+        let (mut best_lag, mut best) = (0, f32::MIN);
+        for lag in 0..=self.max_lag {
+            let far = self.far.range(start - lag..start - lag + self.window);
+            let far_mean = far.clone().sum::<f32>() / blocks;
+            let (mut dot, mut far_var) = (0.0, 0.0);
+            for (far, near) in far.zip(&self.near) {
+                dot += (far - far_mean) * (near - near_mean);
+                far_var += (far - far_mean).powi(2);
+            }
+            let correlation = dot / (far_var * near_var).sqrt().max(f32::EPSILON);
+            if correlation > best {
+                (best_lag, best) = (lag, correlation);
+            }
+        }
+        (best >= AEC_DELAY_MIN_CORRELATION).then_some(best_lag)
+    }
+}
+
+/// Time-domain NLMS echo canceller with a Geigel double-talk detector.
+///
+/// The adaptive filter models the speaker -> room -> mic path. While the
+/// near-end talker is active (mic louder than the recent far-end peak) the
+/// weights are frozen so we do not adapt towards cancelling the user's voice.
+/// The filter only spans AEC_FILTER_TAPS, so the reference first goes through
+/// a delay line set by the delay estimator, which puts the echo near the start
+/// of the filter.
+pub(crate) struct EchoCanceller {
+    weights: Vec<f32>,
+    // Far-end history stored twice so the filter window is always contiguous.
+    history: Vec<f32>,
+    pos: usize,
+    far_energy: f32,
+    far_peak: RunningPeak,
+    // Reference before the delay, newest at `line_pos - 1`
+    line: Vec<f32>,
+    line_pos: usize,
+    delay: usize,
+    max_delay: usize,
+    estimator: DelayEstimator,
+}
+
+impl EchoCanceller {
+    pub(crate) fn new(sample_rate: i32) -> Self {
+        let sample_rate = sample_rate.max(1) as usize;
+        let max_delay = sample_rate * AEC_MAX_DELAY_MS / 1000;
+        Self {
+            weights: vec![0.0; AEC_FILTER_TAPS],
+            history: vec![0.0; AEC_FILTER_TAPS * 2],
+            pos: 0,
+            far_energy: 0.0,
+            far_peak: RunningPeak::new(AEC_FILTER_TAPS),
+            line: vec![0.0; max_delay + AEC_FILTER_TAPS],
+            line_pos: 0,
+            delay: 0,
+            max_delay,
+            estimator: DelayEstimator::new(sample_rate),
+        }
+    }
+
+    /// Removes the estimated echo of `far` from `near` in place.
+    /// Both slices must describe the same time span.
+    pub(crate) fn process(&mut self, near: &mut [i16], far: &[i16]) {
+        let taps = self.weights.len();
+
+        for (sample, &far_sample) in near.iter_mut().zip(far.iter()) {
+            let d = *sample as f32 / i16::MAX as f32;
+            let far_now = far_sample as f32 / i16::MAX as f32;
+            self.line[self.line_pos] = far_now;
+            self.line_pos = (self.line_pos + 1) % self.line.len();
+            if let Some(delay) = self.estimator.push(far_now, d) {
+                // Leave some of the filter for echo that arrives early
+                self.set_delay(delay.saturating_sub(taps / 8).min(self.max_delay));
+            }
+            let x = self.delayed(self.delay);
+
+            // 1. Slide the far-end window (newest sample at the front)
+            self.pos = if self.pos == 0 { taps - 1 } else { self.pos - 1 };
+            let oldest = self.history[self.pos + taps];
+            self.history[self.pos] = x;
+            self.history[self.pos + taps] = x;
+            self.far_energy = (self.far_energy + x * x - oldest * oldest).max(0.0);
+            let far_peak = self.far_peak.push(x.abs());
+
+            let window = &self.history[self.pos..self.pos + taps];
+
+            // 2. Estimate and subtract the echo
+            let estimate: f32 = self.weights.iter().zip(window).map(|(w, x)| w * x).sum();
+            let error = d - estimate;
+
+            // 3. Adapt, unless the near-end talker is active (Geigel detector)
+            let double_talk = d.abs() > AEC_DOUBLE_TALK_THRESHOLD * far_peak;
+            if !double_talk && self.far_energy > f32::EPSILON {
+                let gain = AEC_STEP_SIZE * error / (self.far_energy + 1e-6);
+                for (w, x) in self.weights.iter_mut().zip(window) {
+                    *w += gain * x;
+                }
+            }
+
+            *sample = (error * i16::MAX as f32).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        }
+    }
+
+    /// The reference `age` samples before the newest one in the delay line.
+    fn delayed(&self, age: usize) -> f32 {
+        let len = self.line.len();
+        self.line[(self.line_pos + len - 1 - age) % len]
+    }
+
+    /// Moves the filter onto a new delay: the weights shift with it, so an echo
+    /// path already learned is kept, and the window is refilled from the line.
+    fn set_delay(&mut self, delay: usize) {
+        if delay.abs_diff(self.delay) < AEC_DELAY_BLOCK_SAMPLES { return; }
+        let taps = self.weights.len();
+        if delay > self.delay {
+            let shift = (delay - self.delay).min(taps);
+            self.weights.copy_within(shift.., 0);
+            self.weights[taps - shift..].fill(0.0);
+        } else {
+            let shift = (self.delay - delay).min(taps);
+            self.weights.copy_within(..taps - shift, shift);
+            self.weights[..shift].fill(0.0);
+        }
+        self.delay = delay;
+
+        // Sample `age` ago (after the delay) sits at `pos + age`, as in `process`
+        self.far_peak.clear();
+        self.far_energy = 0.0;
+        for age in (0..taps).rev() {
+            let x = self.delayed(delay + age);
+            let slot = (self.pos + age) % taps;
+            self.history[slot] = x;
+            self.history[slot + taps] = x;
+            self.far_energy += x * x;
+            self.far_peak.push(x.abs());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: i32 = 16000;
+
+    /// Deterministic white noise in [-0.25, 0.25] of full scale.
+    fn noise(len: usize) -> Vec<i16> {
+        let mut state = 0x1234_5678u32;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                ((state >> 16) as i16) / 4
+            })
+            .collect()
+    }
+
+    fn energy(samples: &[i16]) -> f64 {
+        samples.iter().map(|&sample| (sample as f64).powi(2)).sum()
+    }
+
+    #[test]
+    fn running_peak_matches_a_full_scan() {
+        let values: Vec<f32> = noise(2000).iter().map(|&sample| (sample as f32).abs()).collect();
+        let mut peak = RunningPeak::new(64);
+        for (i, &value) in values.iter().enumerate() {
+            let scanned = values[i.saturating_sub(63)..=i].iter().fold(0.0f32, |peak, &value| peak.max(value));
+            assert_eq!(peak.push(value), scanned, "at {i}");
+        }
+    }
+
+    #[test]
+    fn delayed_echo_is_found_and_cancelled() {
+        // An echo 100ms late, with a reflection: far past the filter's 512 taps
+        // without the delay line
+        let delay = RATE as usize / 10;
+        let far = noise(RATE as usize * 4);
+        let mut near: Vec<i16> = (0..far.len())
+            .map(|i| if i < delay + 40 { 0 } else { far[i - delay] / 2 + far[i - delay - 40] / 8 })
+            .collect();
+        let echo = energy(&near[near.len() - RATE as usize..]);
+
+        let mut canceller = EchoCanceller::new(RATE);
+        for (near, far) in near.chunks_mut(320).zip(far.chunks(320)) {
+            canceller.process(near, far);
+        }
+        assert!(canceller.delay.abs_diff(delay - AEC_FILTER_TAPS / 8) < AEC_DELAY_BLOCK_SAMPLES, "delay {}", canceller.delay);
+        let residual = energy(&near[near.len() - RATE as usize..]);
+        assert!(residual < echo / 100.0, "only {:.1} dB", 10.0 * (echo / residual).log10());
+    }
+
+    #[test]
+    fn reference_never_leads_by_more_than_the_bound() {
+        let mut buffer = FarEndBuffer::new();
+        buffer.push(&(0..1000).collect::<Vec<i16>>());
+        let mut out = [0i16; 100];
+        buffer.pop_into(&mut out, 300);
+        assert_eq!(out[0], 0);
+        buffer.pop_into(&mut out, 300);
+        assert_eq!(out[0], 700); // The 600 samples before were dropped as too old
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{AGC_ATTACK, AGC_NOISE_GATE_DBFS, AGC_RELEASE};
+
+/// Frame-based automatic gain control.
+///
+/// Measures the RMS of each frame and steers a smoothed gain towards the one
+/// that would put the frame at the target level. Gain drops quickly (attack)
+/// to avoid clipping loud talkers and rises slowly (release) so pauses do not
+/// pump the background noise up. Frames below the noise gate hold the gain.
+pub(crate) struct AutomaticGainControl {
+    target_level_db: f32,
+    max_gain_db: f32,
+    target_rms: f32,
+    max_gain: f32,
+    gain: f32,
+}
+
+impl AutomaticGainControl {
+    /// `target_level_db` is dB below full scale (e.g. 18 => -18 dBFS).
+    pub(crate) fn new(target_level_db: f32, max_gain_db: f32) -> Self {
+        let mut agc = Self { target_level_db: 0.0, max_gain_db: 0.0, target_rms: 1.0, max_gain: 1.0, gain: 1.0 };
+        agc.set_levels(target_level_db, max_gain_db);
+        agc
+    }
+
+    /// Retunes the controller. Cheap to call every frame; only recomputes on change.
+    pub(crate) fn set_levels(&mut self, target_level_db: f32, max_gain_db: f32) {
+        if target_level_db != self.target_level_db || max_gain_db != self.max_gain_db {
+            self.target_level_db = target_level_db;
+            self.max_gain_db = max_gain_db;
+            self.target_rms = db_to_linear(-target_level_db.max(0.0));
+            self.max_gain = db_to_linear(max_gain_db.max(0.0));
+        }
+    }
+
+    pub(crate) fn process(&mut self, frame: &mut [i16]) {
+        if frame.is_empty() { return; }
+
+        let energy: f32 = frame.iter().map(|&s| {
+            let x = s as f32 / i16::MAX as f32;
+            x * x
+        }).sum();
+        let rms = (energy / frame.len() as f32).sqrt();
+
+        let start_gain = self.gain;
+        if rms > db_to_linear(AGC_NOISE_GATE_DBFS) {
+            let desired = (self.target_rms / rms).clamp(0.0, self.max_gain);
+            let rate = if desired < self.gain { AGC_ATTACK } else { AGC_RELEASE };
+            self.gain += (desired - self.gain) * rate;
+        }
+
+        // Ramp from the previous gain to the new one across the frame to avoid zipper noise
+        let step = (self.gain - start_gain) / frame.len() as f32;
+        for (i, sample) in frame.iter_mut().enumerate() {
+            let gain = start_gain + step * i as f32;
+            *sample = (*sample as f32 * gain).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        }
+    }
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
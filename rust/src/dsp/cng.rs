@@ -0,0 +1,44 @@
+/// Low-level noise for gaps in what we receive (`AudioConfig.comfort_noise_dbfs`).
+///
+/// A dead-silent gap mid-transmission sounds like a dropped link; a faint hiss
+/// keeps the channel sounding open. White noise from a xorshift generator goes
+/// through a one-pole low-pass, which takes the edge off the hiss. The level ramps
+/// over one buffer when the noise starts or stops, so it never clicks.
+pub(crate) struct ComfortNoise {
+    level: f32,   // Target amplitude while on (full scale = i16::MAX)
+    gain: f32,    // 0.0-1.0, where the last buffer ended
+    state: u32,   // xorshift32, never 0
+    lowpass: f32, // Filter memory
+}
+
+// One-pole low-pass coefficient: keeps most of the speech band, drops the top.
+const LOWPASS: f32 = 0.5;
+
+impl ComfortNoise {
+    pub(crate) fn new(level_dbfs: f32) -> Self {
+        let mut noise = Self { level: 0.0, gain: 0.0, state: 0x9E37_79B9, lowpass: 0.0 };
+        noise.set_level(level_dbfs);
+        noise
+    }
+
+    pub(crate) fn set_level(&mut self, level_dbfs: f32) {
+        self.level = i16::MAX as f32 * 10f32.powf(level_dbfs / 20.0);
+    }
+
+    /// Adds noise to `mix` while `on`, ramping in and out over the buffer.
+    pub(crate) fn fill(&mut self, mix: &mut [i32], on: bool) {
+        let target = if on { 1.0 } else { 0.0 };
+        if self.gain == 0.0 && target == 0.0 { return; }
+        let step = (target - self.gain) / mix.len().max(1) as f32;
+        for sample in mix.iter_mut() {
+            self.gain += step;
+            self.state ^= self.state << 13;
+            self.state ^= self.state >> 17;
+            self.state ^= self.state << 5;
+            let white = self.state as i32 as f32 / i32::MAX as f32; // -1.0..1.0
+            self.lowpass += LOWPASS * (white - self.lowpass);
+            *sample += (self.lowpass * self.level * self.gain) as i32;
+        }
+        self.gain = target;
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Messages from the API thread to the ducker living in the output callback.
+pub(crate) enum DuckCommand {
+    Add { id: u32, start_sample: u64, end_sample: Option<u64>, gain: f32 },
+    Remove { id: u32 },
+}
+
+struct AttenuationWindow {
+    id: u32,
+    start_sample: u64,
+    end_sample: Option<u64>, // None = until explicitly removed
+    gain: f32,
+}
+
+/// Sample-accurate ducking of the channel mix.
+///
+/// Windows are expressed in output samples (see `clock`) so the dip lines up
+/// exactly with what the host schedules. When several windows overlap the
+/// deepest one wins. Gain changes are ramped linearly to avoid clicks.
+pub(crate) struct Ducker {
+    windows: Vec<AttenuationWindow>,
+    current_gain: f32,
+    ramp_step: f32,
+    clock: Arc<AtomicU64>,
+}
+
+impl Ducker {
+    pub(crate) fn new(ramp_samples: usize, clock: Arc<AtomicU64>) -> Self {
+        Self {
+            windows: Vec::new(),
+            current_gain: 1.0,
+            ramp_step: 1.0 / ramp_samples.max(1) as f32,
+            clock,
+        }
+    }
+
+    pub(crate) fn apply_command(&mut self, command: DuckCommand) {
+        match command {
+            DuckCommand::Add { id, start_sample, end_sample, gain } => {
+                self.windows.push(AttenuationWindow { id, start_sample, end_sample, gain });
+            }
+            DuckCommand::Remove { id } => self.windows.retain(|w| w.id != id),
+        }
+    }
+
+    /// Applies the attenuation to the (not yet clamped) mix and advances the clock.
+    pub(crate) fn process(&mut self, mix: &mut [i32]) {
+        let mut now = self.clock.load(Ordering::Relaxed);
+
+        // Fast path: nothing scheduled and fully restored
+        if self.windows.is_empty() && self.current_gain >= 1.0 {
+            self.clock.store(now + mix.len() as u64, Ordering::Relaxed);
+            return;
+        }
+
+        for sample in mix.iter_mut() {
+            let target = self.windows.iter()
+                .filter(|w| now >= w.start_sample && w.end_sample.is_none_or(|end| now < end))
+                .map(|w| w.gain)
+                .fold(1.0f32, f32::min);
+
+            if self.current_gain < target {
+                self.current_gain = (self.current_gain + self.ramp_step).min(target);
+            } else if self.current_gain > target {
+                self.current_gain = (self.current_gain - self.ramp_step).max(target);
+            }
+
+            *sample = (*sample as f32 * self.current_gain) as i32;
+            now += 1;
+        }
+
+        self.windows.retain(|w| w.end_sample.is_none_or(|end| now < end));
+        self.clock.store(now, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::LIMITER_RELEASE_MS;
+
+/// Peak limiter for the output mix.
+///
+/// Summing several peers easily exceeds i16 range; instead of hard-clipping
+/// (harsh distortion) the gain is pulled down instantly on a peak and then
+/// released smoothly back to unity.
+pub(crate) struct Limiter {
+    gain: f32,
+    release: f32,
+}
+
+impl Limiter {
+    pub(crate) fn new(sample_rate: i32) -> Self {
+        let release_samples = (sample_rate / 1000 * LIMITER_RELEASE_MS).max(1) as f32;
+        Self {
+            gain: 1.0,
+            release: 1.0 - (-1.0 / release_samples).exp(),
+        }
+    }
+
+    pub(crate) fn process(&mut self, mix: &mut [i32], threshold_dbfs: f32) {
+        let threshold = 10f32.powf(threshold_dbfs / 20.0) * i16::MAX as f32;
+
+        for sample in mix.iter_mut() {
+            let peak = (*sample as f32).abs();
+            if peak * self.gain > threshold {
+                self.gain = threshold / peak;
+            } else {
+                self.gain += (1.0 - self.gain) * self.release;
+            }
+            *sample = (*sample as f32 * self.gain) as i32;
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/// RMS and peak of a signal over a metering period, for level meters.
+pub(crate) struct LevelMeter {
+    energy: f64, // Sum of squared samples (full scale = 1.0)
+    peak: i32,
+    samples: usize,
+}
+
+impl LevelMeter {
+    pub(crate) fn new() -> Self {
+        Self { energy: 0.0, peak: 0, samples: 0 }
+    }
+
+    pub(crate) fn add(&mut self, pcm: impl ExactSizeIterator<Item = i32>) {
+        self.samples += pcm.len();
+        for sample in pcm {
+            let x = sample as f64 / i16::MAX as f64;
+            self.energy += x * x;
+            self.peak = self.peak.max(sample.saturating_abs());
+        }
+    }
+
+    /// Samples metered since the last `take`.
+    pub(crate) fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// (RMS, peak) in dBFS since the last call, -100 for silence; starts a new period.
+    pub(crate) fn take(&mut self) -> (f32, f32) {
+        let rms_dbfs = (10.0 * (self.energy / self.samples.max(1) as f64 + 1e-10).log10()) as f32;
+        let peak_dbfs = 20.0 * (self.peak as f32 / i16::MAX as f32).max(1e-5).log10();
+        *self = Self::new();
+        (rms_dbfs, peak_dbfs)
+    }
+}
@@ -0,0 +1,48 @@
+use nnnoiseless::DenoiseState;
+
+/// RNNoise-based noise suppressor (via the pure-Rust `nnnoiseless` port).
+///
+/// RNNoise only works on 48kHz audio in 10ms blocks, so the engine frame must
+/// be a whole number of blocks. `new` returns `None` for anything else.
+pub(crate) struct NoiseSuppressor {
+    state: Box<DenoiseState<'static>>,
+    input: [f32; DenoiseState::FRAME_SIZE],
+    output: [f32; DenoiseState::FRAME_SIZE],
+    // RNNoise output lags its input by one block; the dry path must match for blending
+    dry: [f32; DenoiseState::FRAME_SIZE],
+}
+
+impl NoiseSuppressor {
+    pub(crate) fn new(sample_rate: i32, samples_per_frame: usize) -> Option<Self> {
+        if sample_rate != 48000 || !samples_per_frame.is_multiple_of(DenoiseState::FRAME_SIZE) {
+            log::warn!(
+                "Noise suppression needs 48kHz and 10ms multiples (got {}Hz, {} samples). Disabled.",
+                sample_rate, samples_per_frame
+            );
+            return None;
+        }
+        Some(Self {
+            state: DenoiseState::new(),
+            input: [0.0; DenoiseState::FRAME_SIZE],
+            output: [0.0; DenoiseState::FRAME_SIZE],
+            dry: [0.0; DenoiseState::FRAME_SIZE],
+        })
+    }
+
+    /// Denoises `frame` in place. `strength` blends between the dry signal (0.0)
+    /// and the fully suppressed one (1.0).
+    pub(crate) fn process(&mut self, frame: &mut [i16], strength: f32) {
+        for block in frame.chunks_exact_mut(DenoiseState::FRAME_SIZE) {
+            // RNNoise expects i16-range floats, not normalized [-1, 1]
+            for (dst, &src) in self.input.iter_mut().zip(block.iter()) {
+                *dst = src as f32;
+            }
+            self.state.process_frame(&mut self.output, &self.input);
+            for ((dst, &wet), &dry) in block.iter_mut().zip(self.output.iter()).zip(self.dry.iter()) {
+                let mixed = wet * strength + dry * (1.0 - strength);
+                *dst = mixed.clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+            }
+            self.dry = self.input;
+        }
+    }
+}
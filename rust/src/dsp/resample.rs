@@ -0,0 +1,100 @@
+use crate::RESAMPLER_HALF_TAPS;
+use crate::dsp::simd;
+
+/// Streaming windowed-sinc resampler between two fixed rates.
+///
+/// Opus rates are all small multiples of 4kHz, so the ratio reduces to a
+/// short fraction and every output sample falls on one of a few fixed phases.
+/// The filter for each phase is computed once up front.
+pub(crate) struct Resampler {
+    up: usize,   // Output phases per input sample (out_rate / gcd)
+    down: usize, // Input samples advanced per output sample, in phases (in_rate / gcd)
+    filters: Vec<[f32; 2 * RESAMPLER_HALF_TAPS]>,
+    history: Vec<f32>,
+    index: usize, // Input sample the next output is centred on (within `history`)
+    phase: usize,
+    kernels: simd::Kernels, // Picked for this CPU
+}
+
+impl Resampler {
+    pub(crate) fn new(in_rate: i32, out_rate: i32) -> Self {
+        let g = gcd(in_rate.max(1) as usize, out_rate.max(1) as usize);
+        let up = out_rate.max(1) as usize / g;
+        let down = in_rate.max(1) as usize / g;
+
+        // Low-pass at the lower of the two Nyquist frequencies
+        let cutoff = (up as f32 / down as f32).min(1.0);
+        let half = RESAMPLER_HALF_TAPS as f32;
+        let filters = (0..up)
+            .map(|phase| {
+                let frac = phase as f32 / up as f32;
+                let mut taps = [0f32; 2 * RESAMPLER_HALF_TAPS];
+                for (j, tap) in taps.iter_mut().enumerate() {
+                    let x = j as f32 - (half - 1.0) - frac;
+                    let window = 0.5 * (1.0 + (std::f32::consts::PI * x / half).cos());
+                    *tap = cutoff * sinc(cutoff * x) * window;
+                }
+                taps
+            })
+            .collect();
+
+        Self {
+            up,
+            down,
+            filters,
+            history: vec![0.0; RESAMPLER_HALF_TAPS - 1],
+            index: RESAMPLER_HALF_TAPS - 1,
+            phase: 0,
+            kernels: simd::cpu().kernels(),
+        }
+    }
+
+    pub(crate) fn is_passthrough(&self) -> bool {
+        self.up == self.down
+    }
+
+    /// Consumes `input` and writes as many output samples as are ready (at most
+    /// `output.len()`; anything further stays buffered). Returns the count written.
+    pub(crate) fn process(&mut self, input: &[i16], output: &mut [i16]) -> usize {
+        if self.is_passthrough() {
+            let n = input.len().min(output.len());
+            output[..n].copy_from_slice(&input[..n]);
+            return n;
+        }
+
+        let start = self.history.len();
+        self.history.resize(start + input.len(), 0.0);
+        (self.kernels.to_f32)(input, &mut self.history[start..]);
+        let mut written = 0;
+        while written < output.len() && self.index + RESAMPLER_HALF_TAPS < self.history.len() {
+            let start = self.index + 1 - RESAMPLER_HALF_TAPS;
+            let window = &self.history[start..start + 2 * RESAMPLER_HALF_TAPS];
+            let sum = (self.kernels.dot)(window, &self.filters[self.phase]);
+            output[written] = sum.clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+            written += 1;
+
+            self.phase += self.down;
+            self.index += self.phase / self.up;
+            self.phase %= self.up;
+        }
+
+        // Drop input that no future output can reach
+        let consumed = self.index + 1 - RESAMPLER_HALF_TAPS;
+        self.history.drain(..consumed);
+        self.index -= consumed;
+        written
+    }
+}
+
+fn sinc(x: f32) -> f32 {
+    if x.abs() < 1e-6 {
+        1.0
+    } else {
+        let px = std::f32::consts::PI * x;
+        px.sin() / px
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
@@ -0,0 +1,50 @@
+use std::collections::VecDeque;
+
+/// The mic signal on its way to the output stream (sidetone).
+///
+/// The input callback pushes each capture buffer as it arrives, before frame
+/// assembly and encoding, and the output callback pops the same amount, so the
+/// user hears themselves after roughly one buffer. The two streams don't tick
+/// together: anything beyond `max_samples` is dropped from the front, which
+/// keeps the delay bounded instead of letting it build up.
+///
+/// The same hand-over carries the mix from the main output to the second one
+/// (`AudioConfig.secondary_output`).
+pub(crate) struct SidetoneBuffer {
+    samples: VecDeque<i16>,
+}
+
+impl SidetoneBuffer {
+    pub(crate) fn new() -> Self {
+        Self { samples: VecDeque::new() }
+    }
+
+    /// Takes the samples straight from the caller's iterator, so the audio
+    /// threads don't need a buffer for them. Room is made before they go in, so
+    /// once the buffer has reached `max_samples` it never grows (or allocates).
+    pub(crate) fn push<I>(&mut self, frames: I, max_samples: usize)
+    where
+        I: IntoIterator<Item = i16>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let frames = frames.into_iter();
+        let incoming = frames.len().min(max_samples);
+        let skip = frames.len() - incoming;
+        let excess = (self.samples.len() + incoming).saturating_sub(max_samples);
+        self.samples.drain(..excess);
+        self.samples.extend(frames.skip(skip));
+    }
+
+    /// Adds the next `mix.len()` samples, scaled by `gain`, to `mix`. Nothing
+    /// is added where the mic hasn't delivered yet.
+    pub(crate) fn mix_into(&mut self, mix: &mut [i32], gain: f32) {
+        let n = mix.len().min(self.samples.len());
+        for (mixed, sample) in mix.iter_mut().zip(self.samples.drain(..n)) {
+            *mixed += (sample as f32 * gain) as i32;
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
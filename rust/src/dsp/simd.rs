@@ -0,0 +1,261 @@
+use std::sync::OnceLock;
+
+use crate::CpuFeatures;
+
+/// What the CPU we run on supports, detected once on first use.
+///
+/// One library serves every Android ABI, so nothing beyond the ABI baseline is
+/// assumed at compile time. NEON is probed at runtime on aarch64; 32-bit ARM and
+/// x86 (emulators) get the scalar kernels, which are always correct.
+pub(crate) struct CpuInfo {
+    neon: bool,
+    cores: u32,
+    kernels: Kernels,
+}
+
+/// The hot loops, picked once for this CPU.
+#[derive(Clone, Copy)]
+pub(crate) struct Kernels {
+    name: &'static str,
+    /// Adds `src` to `dst` sample by sample (the peer mix).
+    pub(crate) mix: fn(&mut [i32], &[i32]),
+    /// Dot product (the resampler's filter taps).
+    pub(crate) dot: fn(&[f32], &[f32]) -> f32,
+    /// i16 -> f32, same scale (the resampler's input).
+    pub(crate) to_f32: fn(&[i16], &mut [f32]),
+    /// i32 -> i16 with saturation (the mix to the output buffer).
+    pub(crate) saturate: fn(&[i32], &mut [i16]),
+    /// Multiplies by a gain moving linearly from the first value to the second
+    /// over the buffer (volume and mute ramps).
+    pub(crate) gain_ramp: fn(&mut [i32], f32, f32),
+}
+
+const SCALAR: Kernels = Kernels {
+    name: "scalar",
+    mix: mix_scalar,
+    dot: dot_scalar,
+    to_f32: to_f32_scalar,
+    saturate: saturate_scalar,
+    gain_ramp: gain_ramp_scalar,
+};
+
+static CPU: OnceLock<CpuInfo> = OnceLock::new();
+
+pub(crate) fn cpu() -> &'static CpuInfo {
+    CPU.get_or_init(|| {
+        let neon = detect_neon();
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get() as u32);
+        let kernels = select_kernels(neon);
+        info!("CPU: {} ({} cores), NEON: {}, {} kernels", std::env::consts::ARCH, cores, neon, kernels.name);
+        CpuInfo { neon, cores, kernels }
+    })
+}
+
+impl CpuInfo {
+    pub(crate) fn kernels(&self) -> Kernels {
+        self.kernels
+    }
+
+    pub(crate) fn features(&self) -> CpuFeatures {
+        CpuFeatures {
+            arch: std::env::consts::ARCH.to_string(),
+            neon: self.neon,
+            cores: self.cores,
+            kernels: self.kernels.name.to_string(),
+        }
+    }
+}
+
+/// Adds `src` to `dst` with the kernel for this CPU.
+pub(crate) fn mix(dst: &mut [i32], src: &[i32]) {
+    (cpu().kernels.mix)(dst, src)
+}
+
+/// Clamps `src` into `dst` with the kernel for this CPU.
+pub(crate) fn saturate(src: &[i32], dst: &mut [i16]) {
+    (cpu().kernels.saturate)(src, dst)
+}
+
+/// Ramps the gain over `buf` with the kernel for this CPU.
+pub(crate) fn gain_ramp(buf: &mut [i32], from: f32, to: f32) {
+    (cpu().kernels.gain_ramp)(buf, from, to)
+}
+
+#[cfg(target_arch = "aarch64")]
+fn detect_neon() -> bool {
+    std::arch::is_aarch64_feature_detected!("neon")
+}
+
+// Runtime detection on 32-bit ARM isn't available on stable Rust
+#[cfg(not(target_arch = "aarch64"))]
+fn detect_neon() -> bool {
+    false
+}
+
+#[cfg(target_arch = "aarch64")]
+fn select_kernels(neon: bool) -> Kernels {
+    if neon {
+        Kernels {
+            name: "neon",
+            mix: neon::mix,
+            dot: neon::dot,
+            to_f32: neon::to_f32,
+            saturate: neon::saturate,
+            gain_ramp: neon::gain_ramp,
+        }
+    } else {
+        SCALAR
+    }
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+fn select_kernels(_neon: bool) -> Kernels {
+    SCALAR
+}
+
+fn mix_scalar(dst: &mut [i32], src: &[i32]) {
+    for (mixed, &sample) in dst.iter_mut().zip(src) {
+        *mixed += sample;
+    }
+}
+
+fn dot_scalar(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn to_f32_scalar(src: &[i16], dst: &mut [f32]) {
+    for (out, &sample) in dst.iter_mut().zip(src) {
+        *out = sample as f32;
+    }
+}
+
+fn saturate_scalar(src: &[i32], dst: &mut [i16]) {
+    for (out, &sample) in dst.iter_mut().zip(src) {
+        *out = sample.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    }
+}
+
+/// Ramp step per sample; the gain at sample `i` is `from + step * (i + 1)`, so
+/// the last sample gets exactly `to`.
+fn ramp_step(len: usize, from: f32, to: f32) -> f32 {
+    (to - from) / len.max(1) as f32
+}
+
+fn gain_ramp_scalar(buf: &mut [i32], from: f32, to: f32) {
+    gain_ramp_from(buf, 0, from, ramp_step(buf.len(), from, to));
+}
+
+/// The scalar ramp from sample `start` on (the NEON kernels' tail).
+fn gain_ramp_from(buf: &mut [i32], start: usize, from: f32, step: f32) {
+    for (i, sample) in buf.iter_mut().enumerate().skip(start) {
+        *sample = (*sample as f32 * (from + step * (i + 1) as f32)) as i32;
+    }
+}
+
+/// Four lanes at a time, then the scalar loop for the tail. Only selected once
+/// `detect_neon` confirmed the CPU has it.
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use std::arch::aarch64::*;
+
+    pub(super) fn mix(dst: &mut [i32], src: &[i32]) {
+        // SAFETY: only reachable through `select_kernels` after NEON was detected
+        unsafe { mix_neon(dst, src) }
+    }
+
+    pub(super) fn dot(a: &[f32], b: &[f32]) -> f32 {
+        // SAFETY: as above
+        unsafe { dot_neon(a, b) }
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn mix_neon(dst: &mut [i32], src: &[i32]) {
+        let n = dst.len().min(src.len());
+        let split = n - n % 4;
+        for i in (0..split).step_by(4) {
+            // SAFETY: i + 4 <= n, within both slices
+            unsafe {
+                let sum = vaddq_s32(vld1q_s32(dst.as_ptr().add(i)), vld1q_s32(src.as_ptr().add(i)));
+                vst1q_s32(dst.as_mut_ptr().add(i), sum);
+            }
+        }
+        super::mix_scalar(&mut dst[split..n], &src[split..n]);
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn dot_neon(a: &[f32], b: &[f32]) -> f32 {
+        let n = a.len().min(b.len());
+        let split = n - n % 4;
+        let mut acc = vdupq_n_f32(0.0);
+        for i in (0..split).step_by(4) {
+            // SAFETY: i + 4 <= n, within both slices
+            unsafe {
+                acc = vfmaq_f32(acc, vld1q_f32(a.as_ptr().add(i)), vld1q_f32(b.as_ptr().add(i)));
+            }
+        }
+        vaddvq_f32(acc) + super::dot_scalar(&a[split..n], &b[split..n])
+    }
+
+    pub(super) fn to_f32(src: &[i16], dst: &mut [f32]) {
+        // SAFETY: as above
+        unsafe { to_f32_neon(src, dst) }
+    }
+
+    pub(super) fn saturate(src: &[i32], dst: &mut [i16]) {
+        // SAFETY: as above
+        unsafe { saturate_neon(src, dst) }
+    }
+
+    pub(super) fn gain_ramp(buf: &mut [i32], from: f32, to: f32) {
+        // SAFETY: as above
+        unsafe { gain_ramp_neon(buf, from, to) }
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn to_f32_neon(src: &[i16], dst: &mut [f32]) {
+        let n = src.len().min(dst.len());
+        let split = n - n % 8;
+        for i in (0..split).step_by(8) {
+            // SAFETY: i + 8 <= n, within both slices
+            unsafe {
+                let samples = vld1q_s16(src.as_ptr().add(i));
+                vst1q_f32(dst.as_mut_ptr().add(i), vcvtq_f32_s32(vmovl_s16(vget_low_s16(samples))));
+                vst1q_f32(dst.as_mut_ptr().add(i + 4), vcvtq_f32_s32(vmovl_high_s16(samples)));
+            }
+        }
+        super::to_f32_scalar(&src[split..n], &mut dst[split..n]);
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn saturate_neon(src: &[i32], dst: &mut [i16]) {
+        let n = src.len().min(dst.len());
+        let split = n - n % 8;
+        for i in (0..split).step_by(8) {
+            // SAFETY: i + 8 <= n, within both slices
+            unsafe {
+                let low = vqmovn_s32(vld1q_s32(src.as_ptr().add(i)));
+                vst1q_s16(dst.as_mut_ptr().add(i), vqmovn_high_s32(low, vld1q_s32(src.as_ptr().add(i + 4))));
+            }
+        }
+        super::saturate_scalar(&src[split..n], &mut dst[split..n]);
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn gain_ramp_neon(buf: &mut [i32], from: f32, to: f32) {
+        let step = super::ramp_step(buf.len(), from, to);
+        let split = buf.len() - buf.len() % 4;
+        let lanes = [1.0f32, 2.0, 3.0, 4.0];
+        // SAFETY: reads four floats from a four-element array
+        let lanes = unsafe { vld1q_f32(lanes.as_ptr()) };
+        for i in (0..split).step_by(4) {
+            // Gains from the sample index, not accumulated, so the ramp doesn't drift
+            let gain = vmlaq_n_f32(vdupq_n_f32(from), vaddq_f32(vdupq_n_f32(i as f32), lanes), step);
+            // SAFETY: i + 4 <= len
+            unsafe {
+                let samples = vcvtq_f32_s32(vld1q_s32(buf.as_ptr().add(i)));
+                vst1q_s32(buf.as_mut_ptr().add(i), vcvtq_s32_f32(vmulq_f32(samples, gain)));
+            }
+        }
+        super::gain_ramp_from(buf, split, from, step);
+    }
+}
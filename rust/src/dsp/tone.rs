@@ -0,0 +1,95 @@
+use std::f32::consts::PI;
+
+use crate::{
+    RogerBeep, ROGER_BEEP_FADE_MS, ROGER_BEEP_MAX_MS, TALKER_CHIRP_FADE_MS, TALKER_CHIRP_GAP_MS, TALKER_CHIRP_LEVEL_DBFS,
+    TALKER_CHIRP_NOTES_HZ, TALKER_CHIRP_NOTE_MS,
+};
+
+/// Goertzel detector for a single frequency.
+///
+/// Cheaper than an FFT when only one bin matters: one multiply-add per sample.
+/// Used to hear our own inaudible test tone come back through the mic.
+pub(crate) struct ToneDetector {
+    coeff: f32,
+}
+
+impl ToneDetector {
+    pub(crate) fn new(freq_hz: f32, sample_rate: i32) -> Self {
+        Self { coeff: 2.0 * (2.0 * PI * freq_hz / sample_rate.max(1) as f32).cos() }
+    }
+
+    /// Share of the block's energy at the target frequency: about 1.0 for a pure
+    /// tone, near 0 for speech or broadband noise.
+    pub(crate) fn tone_ratio(&self, block: &[i16]) -> f32 {
+        let (mut s1, mut s2, mut energy) = (0.0f32, 0.0f32, 0.0f32);
+        for &sample in block {
+            let x = sample as f32 / i16::MAX as f32;
+            let s0 = x + self.coeff * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+            energy += x * x;
+        }
+        let power = s1 * s1 + s2 * s2 - self.coeff * s1 * s2;
+        // A sine of amplitude A gives power (A*N/2)^2 and energy A^2*N/2
+        power / (energy * block.len() as f32 / 2.0 + 1e-10)
+    }
+}
+
+/// A sine burst with raised-cosine fades, so switching it on and off doesn't click.
+pub(crate) fn tone_burst(freq_hz: f32, sample_rate: i32, duration_ms: u32, level_dbfs: f32, fade_ms: u32) -> Vec<i16> {
+    let rate = sample_rate.max(1) as f32;
+    let len = (rate * duration_ms as f32 / 1000.0) as usize;
+    let fade = ((rate * fade_ms as f32 / 1000.0) as usize).clamp(1, len / 2 + 1);
+    let amplitude = 10f32.powf(level_dbfs / 20.0) * i16::MAX as f32;
+    (0..len).map(|i| {
+        let edge = i.min(len - 1 - i);
+        let envelope = if edge < fade { 0.5 - 0.5 * (PI * edge as f32 / fade as f32).cos() } else { 1.0 };
+        (amplitude * envelope * (2.0 * PI * freq_hz * i as f32 / rate).sin()) as i16
+    }).collect()
+}
+
+/// The end-of-transmission tone as PCM, clamped to what the rate can carry.
+pub(crate) fn roger_beep(beep: &RogerBeep, sample_rate: i32) -> Vec<i16> {
+    let nyquist = sample_rate.max(2) as f32 / 2.0;
+    let freq_hz = beep.frequency_hz.clamp(1.0, nyquist - 1.0);
+    tone_burst(freq_hz, sample_rate, beep.duration_ms.min(ROGER_BEEP_MAX_MS), beep.level_dbfs.min(0.0), ROGER_BEEP_FADE_MS)
+}
+
+/// The talker ID chirp for `node_id`: three notes with short gaps. IDs are mixed
+/// first, so neighbouring IDs don't share notes.
+pub(crate) fn talker_chirp(node_id: u32, sample_rate: i32) -> Vec<i16> {
+    let hash = node_id.wrapping_mul(0x9E37_79B9);
+    let gap = (sample_rate.max(1) as u32 / 1000 * TALKER_CHIRP_GAP_MS) as usize;
+    let mut chirp = Vec::new();
+    for note in 0..3 {
+        let freq_hz = TALKER_CHIRP_NOTES_HZ[(hash >> (29 - 3 * note)) as usize & 7];
+        chirp.extend(tone_burst(freq_hz, sample_rate, TALKER_CHIRP_NOTE_MS, TALKER_CHIRP_LEVEL_DBFS, TALKER_CHIRP_FADE_MS));
+        chirp.resize(chirp.len() + gap, 0);
+    }
+    chirp
+}
+
+/// Plays a clip into successive frames of the encode path.
+pub(crate) struct TonePlayer {
+    pcm: Vec<i16>,
+    pos: usize,
+}
+
+impl TonePlayer {
+    pub(crate) fn new(pcm: Vec<i16>) -> Self {
+        Self { pcm, pos: 0 }
+    }
+
+    /// Overwrites `frame` with the next samples, silence past the end. False
+    /// (and `frame` untouched) once the clip is over.
+    pub(crate) fn fill(&mut self, frame: &mut [i16]) -> bool {
+        if self.pos >= self.pcm.len() {
+            return false;
+        }
+        let n = frame.len().min(self.pcm.len() - self.pos);
+        frame[..n].copy_from_slice(&self.pcm[self.pos..self.pos + n]);
+        frame[n..].fill(0);
+        self.pos += n;
+        true
+    }
+}
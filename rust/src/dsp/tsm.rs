@@ -0,0 +1,85 @@
+use crate::{TSM_MAX_PITCH_MS, TSM_MIN_CORRELATION, TSM_MIN_PITCH_MS, TSM_SILENCE_RMS};
+
+/// WSOLA-style time-scale modification of a decoded frame (NetEQ's
+/// "accelerate" and "preemptive expand").
+///
+/// Both operations find the pitch period `L` at the start of the frame and
+/// then remove or insert exactly one period, cross-fading across the seam so
+/// the waveform stays continuous. Voiced speech is barely affected because a
+/// pitch period looks like its neighbours; silence can be cut freely. Frames
+/// that are neither periodic nor silent are left alone.
+pub(crate) struct TimeStretcher {
+    min_lag: usize,
+    max_lag: usize,
+}
+
+impl TimeStretcher {
+    pub(crate) fn new(sample_rate: i32) -> Self {
+        Self {
+            min_lag: (sample_rate * TSM_MIN_PITCH_MS / 1000) as usize,
+            max_lag: (sample_rate * TSM_MAX_PITCH_MS / 1000) as usize,
+        }
+    }
+
+    /// Shortens `buf[..len]` by one pitch period. Returns the new length.
+    pub(crate) fn accelerate(&self, buf: &mut [i16], len: usize) -> usize {
+        let Some(lag) = self.find_period(&buf[..len]) else { return len };
+
+        // Cross-fade period A into period B, then keep the rest after B
+        for i in 0..lag {
+            let w = i as f32 / lag as f32;
+            let mixed = buf[i] as f32 * (1.0 - w) + buf[i + lag] as f32 * w;
+            buf[i] = mixed as i16;
+        }
+        buf.copy_within(2 * lag..len, lag);
+        len - lag
+    }
+
+    /// Lengthens `buf[..len]` by one pitch period, if `buf` has room. Returns the new length.
+    pub(crate) fn expand(&self, buf: &mut [i16], len: usize) -> usize {
+        if len + self.max_lag > buf.len() { return len; }
+        let Some(lag) = self.find_period(&buf[..len]) else { return len };
+
+        // Make room after period A, then insert a cross-fade from B back into A
+        buf.copy_within(lag..len, 2 * lag);
+        for i in 0..lag {
+            let w = i as f32 / lag as f32;
+            let mixed = buf[2 * lag + i] as f32 * (1.0 - w) + buf[i] as f32 * w;
+            buf[lag + i] = mixed as i16;
+        }
+        len + lag
+    }
+
+    /// Returns the lag at which the frame best repeats itself, if it is safe to cut there.
+    fn find_period(&self, frame: &[i16]) -> Option<usize> {
+        if frame.len() < 2 * self.max_lag || self.min_lag == 0 {
+            return None;
+        }
+
+        // Silence: any cut is inaudible, take the largest step
+        let energy: f64 = frame[..2 * self.max_lag].iter().map(|&s| (s as f64) * (s as f64)).sum();
+        let rms = (energy / (2 * self.max_lag) as f64).sqrt() as f32 / i16::MAX as f32;
+        if rms < TSM_SILENCE_RMS {
+            return Some(self.max_lag);
+        }
+
+        let mut best_lag = 0;
+        let mut best_corr = 0.0f32;
+        for lag in self.min_lag..=self.max_lag {
+            let (a, b) = (&frame[..lag], &frame[lag..2 * lag]);
+            let (mut ab, mut aa, mut bb) = (0f64, 0f64, 0f64);
+            for (&x, &y) in a.iter().zip(b) {
+                ab += x as f64 * y as f64;
+                aa += x as f64 * x as f64;
+                bb += y as f64 * y as f64;
+            }
+            let corr = (ab / (aa * bb).sqrt().max(1.0)) as f32;
+            if corr > best_corr {
+                best_corr = corr;
+                best_lag = lag;
+            }
+        }
+
+        (best_corr >= TSM_MIN_CORRELATION).then_some(best_lag)
+    }
+}
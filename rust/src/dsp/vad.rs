@@ -0,0 +1,55 @@
+use crate::{VAD_FLOOR_RISE_DB_PER_SEC, VAD_HANGOVER_MS, VAD_MIN_SPEECH_DBFS, VAD_SPEECH_MARGIN_DB};
+
+/// Energy-based voice activity detector with an adaptive noise floor.
+///
+/// A frame counts as speech when it is clearly above the tracked noise floor
+/// (and above an absolute minimum). The floor follows quiet frames down
+/// immediately and creeps up slowly, so steady background noise is learned
+/// while speech bursts are not. A hangover keeps the detector "on" across
+/// short pauses and trailing consonants.
+pub(crate) struct VoiceActivityDetector {
+    noise_floor_db: f32,
+    floor_rise_per_frame: f32,
+    hangover_frames: u32,
+    hangover_left: u32,
+}
+
+impl VoiceActivityDetector {
+    pub(crate) fn new(frame_size_ms: i32) -> Self {
+        let frame_ms = frame_size_ms.max(1) as f32;
+        Self {
+            noise_floor_db: VAD_MIN_SPEECH_DBFS,
+            floor_rise_per_frame: VAD_FLOOR_RISE_DB_PER_SEC * frame_ms / 1000.0,
+            hangover_frames: (VAD_HANGOVER_MS as f32 / frame_ms).ceil() as u32,
+            hangover_left: 0,
+        }
+    }
+
+    /// Classifies one frame. Returns `true` while speech (or its hangover) is active.
+    pub(crate) fn process(&mut self, frame: &[i16]) -> bool {
+        if frame.is_empty() { return false; }
+
+        let energy: f32 = frame.iter().map(|&s| {
+            let x = s as f32 / i16::MAX as f32;
+            x * x
+        }).sum();
+        let level_db = 10.0 * (energy / frame.len() as f32 + 1e-10).log10();
+
+        if level_db < self.noise_floor_db {
+            self.noise_floor_db = level_db;
+        } else {
+            self.noise_floor_db += self.floor_rise_per_frame;
+        }
+
+        let speech = level_db > VAD_MIN_SPEECH_DBFS && level_db > self.noise_floor_db + VAD_SPEECH_MARGIN_DB;
+        if speech {
+            self.hangover_left = self.hangover_frames;
+            true
+        } else if self.hangover_left > 0 {
+            self.hangover_left -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,105 @@
+// ===========================================================================
+// ERROR AGGREGATION
+// ===========================================================================
+// A flapping device can report hundreds of errors per second. Forwarding each
+// one across the FFI would flood the app's UI thread, so errors are reported
+// from any thread into a channel and a dispatcher thread coalesces them: the
+// first error of a kind goes out immediately, repeats within the window are
+// summarized in one follow-up call.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crossbeam_channel::{unbounded, RecvTimeoutError, Sender};
+
+use crate::resources;
+use crate::dispatch::{CallbackDispatcher, CallbackSlot};
+use crate::{AudioErrorCallback, ERROR_AGGREGATION_WINDOW_MS};
+
+struct OpenWindow {
+    closes_at: Instant,
+    count: u32,
+    first_ms: u64,
+    last_ms: u64,
+}
+
+pub(crate) struct ErrorAggregator {
+    tx: Sender<(i32, u64)>, // (code, timestamp ms)
+}
+
+impl ErrorAggregator {
+    pub(crate) fn new(callback: Box<dyn AudioErrorCallback>, dispatcher: Arc<CallbackDispatcher>) -> Self {
+        let (tx, rx) = unbounded::<(i32, u64)>();
+        let window = Duration::from_millis(ERROR_AGGREGATION_WINDOW_MS);
+        let slot: CallbackSlot<dyn AudioErrorCallback> = Arc::new(Mutex::new(Some(callback)));
+        let forward = move |code: i32, count: u32, first_ms: u64, last_ms: u64| {
+            dispatcher.notify(&slot, move |callback| {
+                callback.on_engine_error(code, count, first_ms, last_ms);
+            });
+        };
+
+        resources::spawn(move || {
+            let mut windows: HashMap<i32, OpenWindow> = HashMap::new();
+            loop {
+                let next_close = windows.values().map(|w| w.closes_at).min();
+                let received = match next_close {
+                    Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+                    None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                };
+
+                match received {
+                    Ok((code, timestamp_ms)) => match windows.get_mut(&code) {
+                        Some(open) => {
+                            open.count += 1;
+                            open.first_ms = open.first_ms.min(timestamp_ms);
+                            open.last_ms = timestamp_ms;
+                        }
+                        None => {
+                            forward(code, 1, timestamp_ms, timestamp_ms);
+                            windows.insert(code, OpenWindow {
+                                closes_at: Instant::now() + window,
+                                count: 0,
+                                first_ms: u64::MAX,
+                                last_ms: 0,
+                            });
+                        }
+                    },
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => {
+                        for (code, open) in windows.drain().filter(|(_, w)| w.count > 0) {
+                            forward(code, open.count, open.first_ms, open.last_ms);
+                        }
+                        break;
+                    }
+                }
+
+                // Flush expired windows. A window that saw repeats is reported and
+                // re-opened (the error is still flapping); a quiet one just closes.
+                let now = Instant::now();
+                windows.retain(|&code, open| {
+                    if open.closes_at > now {
+                        return true;
+                    }
+                    if open.count == 0 {
+                        return false;
+                    }
+                    forward(code, open.count, open.first_ms, open.last_ms);
+                    *open = OpenWindow { closes_at: now + window, count: 0, first_ms: u64::MAX, last_ms: 0 };
+                    true
+                });
+            }
+        });
+
+        Self { tx }
+    }
+
+    /// Non-blocking; safe to call from the audio threads.
+    pub(crate) fn report(&self, code: i32) {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let _ = self.tx.send((code, timestamp_ms));
+    }
+}
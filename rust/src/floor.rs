@@ -0,0 +1,252 @@
+// ===========================================================================
+// FLOOR CONTROL
+// ===========================================================================
+// Half-duplex arbitration (`AudioConfig.floor_control`): only the node holding
+// the floor transmits. There is no server; every node follows who holds it from
+// the messages below.
+//
+// - REQUEST: a node wants the floor. The holder answers with a GRANT. A free
+//   floor is taken once nobody has objected within FLOOR_REQUEST_WAIT_MS;
+//   requests that cross on a free floor go to the higher priority, then the
+//   lower node ID, and both sides work that out alike.
+// - GRANT: names the holder. The holder repeats it every FLOOR_REFRESH_MS and in
+//   answer to requests. Under `FloorPolicy::Priority` the holder hands the floor
+//   to a requester with a higher priority by naming them instead.
+// - RELEASE: the holder is done.
+// A holder not heard from (audio or control) for FLOOR_HOLD_TIMEOUT_MS has gone
+// away, and the floor is free again. Every channel (`set_active_channel`) has its
+// own floor; messages for other channels are ignored.
+//
+// FLOOR_REQUEST: [Priority (1)] + [Channel (2)]
+// FLOOR_GRANT: [Holder ID (4)] + [Priority (1)] + [Channel (2)]
+// FLOOR_RELEASE: [Channel (2)]
+// Older builds leave the channel out, which means channel 0.
+
+use std::time::{Duration, Instant};
+
+use crate::{FloorPolicy, FLOOR_HOLD_TIMEOUT_MS};
+
+pub(crate) const CONTROL_FLOOR_REQUEST: u8 = 0x05;
+pub(crate) const CONTROL_FLOOR_GRANT: u8 = 0x06;
+pub(crate) const CONTROL_FLOOR_RELEASE: u8 = 0x07;
+const FLOOR_GRANT_SIZE: usize = 5;
+
+/// What a floor message or timer means for us.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FloorEvent {
+    Granted,
+    Denied { holder_id: u32 },
+    Revoked { holder_id: u32 },
+}
+
+/// Result of a floor operation.
+#[derive(Default)]
+pub(crate) struct FloorOutcome {
+    /// Control messages to broadcast: (type, payload)
+    pub(crate) replies: Vec<(u8, Vec<u8>)>,
+    pub(crate) event: Option<FloorEvent>,
+}
+
+impl FloorOutcome {
+    fn event(event: FloorEvent) -> Self {
+        Self { replies: Vec::new(), event: Some(event) }
+    }
+}
+
+struct Holder {
+    id: u32,
+    heard: Instant,
+}
+
+pub(crate) struct FloorControl {
+    policy: Option<FloorPolicy>, // None = floor control off
+    priority: u8,                // Ours
+    holder: Option<Holder>,
+    pending: Option<u64>, // Our request waiting for answers, by token
+    next_token: u64,
+    grant: u64, // Bumped every time we take the floor
+    channel: u16,
+}
+
+impl FloorControl {
+    pub(crate) fn new() -> Self {
+        Self { policy: None, priority: 0, holder: None, pending: None, next_token: 1, grant: 0, channel: 0 }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.policy.is_some()
+    }
+
+    /// Turning floor control off forgets the floor (releasing it if we held it).
+    pub(crate) fn set_config(&mut self, own_id: u32, policy: Option<FloorPolicy>, priority: u8) -> FloorOutcome {
+        let outcome = if policy.is_none() { self.release(own_id) } else { FloorOutcome::default() };
+        if policy.is_none() {
+            self.holder = None;
+        }
+        self.policy = policy;
+        self.priority = priority;
+        outcome
+    }
+
+    /// Moves to another channel's floor, releasing the one we held.
+    pub(crate) fn set_channel(&mut self, own_id: u32, channel: u16) -> FloorOutcome {
+        let outcome = self.release(own_id);
+        self.holder = None;
+        self.channel = channel;
+        outcome
+    }
+
+    /// The current holder, unless it has gone quiet for too long.
+    pub(crate) fn holder_id(&self) -> Option<u32> {
+        self.holder.as_ref().filter(|holder| !is_stale(holder)).map(|holder| holder.id)
+    }
+
+    pub(crate) fn is_held(&self, own_id: u32) -> bool {
+        self.holder_id() == Some(own_id)
+    }
+
+    /// Asks for the floor. Returns the token to `resolve` with once
+    /// FLOOR_REQUEST_WAIT_MS has passed (`None` if there is nothing to wait for).
+    pub(crate) fn request(&mut self, own_id: u32) -> (FloorOutcome, Option<u64>) {
+        if self.policy.is_none() || self.is_held(own_id) {
+            return (FloorOutcome::default(), None);
+        }
+        let token = self.next_token;
+        self.next_token += 1;
+        self.pending = Some(token);
+        let mut payload = vec![self.priority];
+        payload.extend_from_slice(&self.channel.to_le_bytes());
+        let outcome = FloorOutcome { replies: vec![(CONTROL_FLOOR_REQUEST, payload)], event: None };
+        (outcome, Some(token))
+    }
+
+    /// Ends the wait for a request: the floor is ours unless someone else holds it.
+    pub(crate) fn resolve(&mut self, own_id: u32, token: u64) -> FloorOutcome {
+        if self.pending != Some(token) { return FloorOutcome::default(); } // Already answered
+        self.pending = None;
+        if let Some(holder_id) = self.holder_id().filter(|&id| id != own_id) {
+            return FloorOutcome::event(FloorEvent::Denied { holder_id });
+        }
+        self.take(own_id)
+    }
+
+    /// Gives up the floor (or drops a pending request).
+    pub(crate) fn release(&mut self, own_id: u32) -> FloorOutcome {
+        self.pending = None;
+        if !self.is_held(own_id) { return FloorOutcome::default(); }
+        self.holder = None;
+        FloorOutcome { replies: vec![(CONTROL_FLOOR_RELEASE, self.channel.to_le_bytes().to_vec())], event: None }
+    }
+
+    /// Which time we took the floor, for `refresh`.
+    pub(crate) fn grant(&self) -> u64 {
+        self.grant
+    }
+
+    /// The GRANT the holder repeats, while we still hold the floor from `grant`.
+    pub(crate) fn refresh(&mut self, own_id: u32, grant: u64) -> Option<Vec<u8>> {
+        if grant != self.grant { return None; }
+        let holder = self.holder.as_mut().filter(|holder| holder.id == own_id)?;
+        holder.heard = Instant::now();
+        Some(self.grant_payload(own_id, self.priority))
+    }
+
+    /// Any packet from `origin_id` shows it is still there.
+    pub(crate) fn heard(&mut self, origin_id: u32) {
+        if let Some(holder) = self.holder.as_mut().filter(|holder| holder.id == origin_id) {
+            holder.heard = Instant::now();
+        }
+    }
+
+    pub(crate) fn handle(&mut self, own_id: u32, origin_id: u32, msg_type: u8, payload: &[u8]) -> FloorOutcome {
+        let Some(policy) = self.policy else { return FloorOutcome::default() };
+        match msg_type {
+            CONTROL_FLOOR_REQUEST => {
+                let Some(&priority) = payload.first() else { return FloorOutcome::default() };
+                if message_channel(payload, 1) != self.channel { return FloorOutcome::default(); }
+                self.handle_request(own_id, origin_id, priority, policy)
+            }
+            CONTROL_FLOOR_GRANT => {
+                if payload.len() < FLOOR_GRANT_SIZE || message_channel(payload, FLOOR_GRANT_SIZE) != self.channel {
+                    return FloorOutcome::default();
+                }
+                let holder_id = u32::from_le_bytes(payload[0..4].try_into().unwrap());
+                self.handle_grant(own_id, holder_id, payload[4])
+            }
+            CONTROL_FLOOR_RELEASE => {
+                if message_channel(payload, 0) != self.channel { return FloorOutcome::default(); }
+                if self.holder.as_ref().is_some_and(|holder| holder.id == origin_id) {
+                    self.holder = None;
+                }
+                FloorOutcome::default()
+            }
+            _ => FloorOutcome::default(),
+        }
+    }
+
+    fn handle_request(&mut self, own_id: u32, origin_id: u32, priority: u8, policy: FloorPolicy) -> FloorOutcome {
+        if self.is_held(own_id) {
+            if policy == FloorPolicy::Priority && priority > self.priority {
+                self.holder = Some(Holder { id: origin_id, heard: Instant::now() });
+                return FloorOutcome {
+                    replies: vec![(CONTROL_FLOOR_GRANT, self.grant_payload(origin_id, priority))],
+                    event: Some(FloorEvent::Revoked { holder_id: origin_id }),
+                };
+            }
+            // Still ours: the answer tells them who has it
+            return FloorOutcome { replies: vec![(CONTROL_FLOOR_GRANT, self.grant_payload(own_id, self.priority))], event: None };
+        }
+        // Crossing requests on a free floor: the loser steps back, the winner takes it after the wait
+        if self.pending.is_some() && self.holder_id().is_none() && (priority, u32::MAX - origin_id) > (self.priority, u32::MAX - own_id) {
+            self.pending = None;
+            return FloorOutcome::event(FloorEvent::Denied { holder_id: origin_id });
+        }
+        FloorOutcome::default()
+    }
+
+    fn handle_grant(&mut self, own_id: u32, holder_id: u32, priority: u8) -> FloorOutcome {
+        if holder_id == own_id {
+            // Handed over to us by the holder
+            return if self.pending.take().is_some() { self.take(own_id) } else { FloorOutcome::default() };
+        }
+        let was_held = self.is_held(own_id);
+        if was_held && (self.priority, u32::MAX - own_id) > (priority, u32::MAX - holder_id) {
+            // Two holders after a partition: the weaker one gives way
+            return FloorOutcome { replies: vec![(CONTROL_FLOOR_GRANT, self.grant_payload(own_id, self.priority))], event: None };
+        }
+        self.holder = Some(Holder { id: holder_id, heard: Instant::now() });
+        if was_held {
+            return FloorOutcome::event(FloorEvent::Revoked { holder_id });
+        }
+        if self.pending.take().is_some() {
+            return FloorOutcome::event(FloorEvent::Denied { holder_id });
+        }
+        FloorOutcome::default()
+    }
+
+    fn take(&mut self, own_id: u32) -> FloorOutcome {
+        self.grant += 1;
+        self.holder = Some(Holder { id: own_id, heard: Instant::now() });
+        FloorOutcome {
+            replies: vec![(CONTROL_FLOOR_GRANT, self.grant_payload(own_id, self.priority))],
+            event: Some(FloorEvent::Granted),
+        }
+    }
+
+    fn grant_payload(&self, holder_id: u32, priority: u8) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(FLOOR_GRANT_SIZE + 2);
+        payload.extend_from_slice(&holder_id.to_le_bytes());
+        payload.push(priority);
+        payload.extend_from_slice(&self.channel.to_le_bytes());
+        payload
+    }
+}
+
+fn is_stale(holder: &Holder) -> bool {
+    holder.heard.elapsed() > Duration::from_millis(FLOOR_HOLD_TIMEOUT_MS)
+}
+
+/// The channel a message is for, from its trailing field at `at` (0 without one).
+fn message_channel(payload: &[u8], at: usize) -> u16 {
+    payload.get(at..at + 2).map_or(0, |bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
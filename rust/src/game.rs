@@ -0,0 +1,195 @@
+// ===========================================================================
+// GAME ENGINE INTEGRATION (C ABI)
+// ===========================================================================
+// A plain C ABI (include/walkie_talkie.h) for game engines that own the audio
+// device and the network: Unity native plugins through P/Invoke, Unreal
+// modules. The engine runs a pull-mode session, so no device is opened. The
+// game hands its mic buffers to `wt_capture`, fills its voice output with
+// `wt_render`, sends what `send_packet` gives it over its own netcode and
+// passes what arrives to `wt_receive_packet`. Jitter buffers, codec and mixer
+// are the same code as on the phones.
+//
+// Every function may be called from any thread. Status codes are 0 on
+// success, or an `AudioError` as a negative WT_ERROR_* code.
+
+use std::ffi::c_void;
+
+use crate::{AudioConfig, AudioEngine, AudioError, AudioErrorCallback, ConfigProfile, PacketTransport};
+
+/// Called with every packet to send; `data` is only valid during the call.
+pub type WtSendPacket = extern "C" fn(user_data: *mut c_void, data: *const u8, len: usize);
+
+/// The engine behind a C handle.
+pub struct WtEngine {
+    engine: AudioEngine,
+}
+
+struct CTransport {
+    send_packet: WtSendPacket,
+    user_data: *mut c_void,
+}
+
+// The header asks the game for a `send_packet` that can be called from any thread
+unsafe impl Send for CTransport {}
+unsafe impl Sync for CTransport {}
+
+impl PacketTransport for CTransport {
+    fn send_packet(&self, data: Vec<u8>) {
+        (self.send_packet)(self.user_data, data.as_ptr(), data.len());
+    }
+}
+
+/// Games have no error callback; engine errors go to the log.
+struct LogErrors;
+
+impl AudioErrorCallback for LogErrors {
+    fn on_engine_error(&self, code: i32, count: u32, _first_timestamp_ms: u64, _last_timestamp_ms: u64) {
+        log::warn!("Engine error {} ({}x)", code, count);
+    }
+}
+
+fn error_code(error: AudioError) -> i32 {
+    match error {
+        AudioError::DeviceError => -1,
+        AudioError::EncoderError => -2,
+        AudioError::DecoderError => -3,
+        AudioError::InvalidParameter => -4,
+        AudioError::TransportError => -5,
+        AudioError::RecordingError => -6,
+        AudioError::QuotaExceeded => -7,
+        AudioError::ShutDown => -8,
+    }
+}
+
+fn status(result: Result<(), AudioError>) -> i32 {
+    result.map_or_else(error_code, |()| 0)
+}
+
+/// Creates an engine. `profile` is a `ConfigProfile` (0 = LowLatencyLan,
+/// 1 = Cellular, 2 = Satellite, 3 = Battery) or -1 for the defaults;
+/// `sample_rate` overrides the profile's rate unless it is 0. Returns null for
+/// an unknown profile or a config the engine can't run.
+///
+/// # Safety
+/// `send_packet` must be callable from any thread with `user_data` until
+/// `wt_engine_destroy` returns.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_engine_create(
+    profile: i32,
+    sample_rate: i32,
+    node_id: u32,
+    send_packet: WtSendPacket,
+    user_data: *mut c_void,
+) -> *mut WtEngine {
+    let mut config = match profile {
+        -1 => AudioConfig::default(),
+        0 => crate::config::profile_config(ConfigProfile::LowLatencyLan),
+        1 => crate::config::profile_config(ConfigProfile::Cellular),
+        2 => crate::config::profile_config(ConfigProfile::Satellite),
+        3 => crate::config::profile_config(ConfigProfile::Battery),
+        _ => return std::ptr::null_mut(),
+    };
+    if sample_rate != 0 {
+        config.sample_rate = sample_rate;
+    }
+    let transport = Box::new(CTransport { send_packet, user_data });
+    match AudioEngine::new(config, transport, Box::new(LogErrors), node_id) {
+        Ok(engine) => Box::into_raw(Box::new(WtEngine { engine })),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Stops and frees an engine. Null is ignored.
+///
+/// # Safety
+/// `engine` comes from `wt_engine_create` and no other call on it is running or follows.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_engine_destroy(engine: *mut WtEngine) {
+    if !engine.is_null() {
+        drop(unsafe { Box::from_raw(engine) });
+    }
+}
+
+/// # Safety
+/// `engine` is null or a live handle from `wt_engine_create`.
+unsafe fn engine<'a>(engine: *const WtEngine) -> Option<&'a AudioEngine> {
+    unsafe { engine.as_ref() }.map(|engine| &engine.engine)
+}
+
+/// Starts the pull-mode session: from here on `wt_capture` and `wt_render` drive it.
+///
+/// # Safety
+/// `engine` is null or a live handle from `wt_engine_create`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_start(engine: *const WtEngine) -> i32 {
+    let Some(engine) = (unsafe { self::engine(engine) }) else { return error_code(AudioError::InvalidParameter) };
+    status(engine.start_pull_session())
+}
+
+/// Ends the session; `wt_render` plays silence until the next `wt_start`.
+///
+/// # Safety
+/// `engine` is null or a live handle from `wt_engine_create`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_stop(engine: *const WtEngine) -> i32 {
+    let Some(engine) = (unsafe { self::engine(engine) }) else { return error_code(AudioError::InvalidParameter) };
+    status(engine.stop_session())
+}
+
+/// Feeds `len` samples of mono mic PCM at the engine's rate into the send path.
+///
+/// # Safety
+/// `engine` is null or a live handle, and `frames` points to `len` samples.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_capture(engine: *const WtEngine, frames: *const i16, len: usize) {
+    let Some(engine) = (unsafe { self::engine(engine) }) else { return };
+    if frames.is_null() { return; }
+    engine.capture_frames(unsafe { std::slice::from_raw_parts(frames, len) });
+}
+
+/// Fills `len` samples of mono output at the engine's rate with the voice mix
+/// (silence while no session runs).
+///
+/// # Safety
+/// `engine` is null or a live handle, and `frames` points to room for `len` samples.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_render(engine: *const WtEngine, frames: *mut i16, len: usize) {
+    if frames.is_null() { return; }
+    let frames = unsafe { std::slice::from_raw_parts_mut(frames, len) };
+    match unsafe { self::engine(engine) } {
+        Some(engine) => engine.render_frames(frames),
+        None => frames.fill(0),
+    }
+}
+
+/// Hands the engine a packet that arrived from another player.
+///
+/// # Safety
+/// `engine` is null or a live handle, and `data` points to `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_receive_packet(engine: *const WtEngine, data: *const u8, len: usize) {
+    let Some(engine) = (unsafe { self::engine(engine) }) else { return };
+    if data.is_null() { return; }
+    engine.push_incoming_packet(unsafe { std::slice::from_raw_parts(data, len) }.to_vec());
+}
+
+/// Push-to-talk: `set_mic_enabled`.
+///
+/// # Safety
+/// `engine` is null or a live handle from `wt_engine_create`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_set_mic_enabled(engine: *const WtEngine, enabled: bool) -> i32 {
+    let Some(engine) = (unsafe { self::engine(engine) }) else { return error_code(AudioError::InvalidParameter) };
+    status(engine.set_mic_enabled(enabled))
+}
+
+/// Moves the player to talkgroup `channel` (e.g. their team): `set_active_channel`.
+///
+/// # Safety
+/// `engine` is null or a live handle from `wt_engine_create`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_set_active_channel(engine: *const WtEngine, channel: u16) {
+    if let Some(engine) = unsafe { self::engine(engine) } {
+        engine.set_active_channel(channel);
+    }
+}
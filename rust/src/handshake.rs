@@ -0,0 +1,408 @@
+// ===========================================================================
+// KEY AGREEMENT
+// ===========================================================================
+// Per-peer keys without a shared secret. Every node has a long-term X25519
+// identity key (stored by the app) and a fresh ephemeral key per identity load.
+//
+// 1. HELLO: a node broadcasts its identity + ephemeral public keys. Each pair of
+//    nodes derives a pairwise key from both DH results (eph-eph for forward
+//    secrecy, identity-identity so only the real key holders can derive it).
+// 2. SENDER_KEY: each node picks a random sender key and hands it to every peer
+//    sealed under their pairwise key. Audio is broadcast, so it's encrypted once
+//    under the sender key and every peer opens it with the key it received.
+//
+// A peer counts as established once its SENDER_KEY opens, which proves it holds
+// the identity key it announced. HELLOs aren't authenticated, so new keys for an
+// established peer wait in a pending slot until a SENDER_KEY opens under them;
+// a forged HELLO can't drop the real peer's session. This module is pure state;
+// the engine does I/O.
+
+use std::collections::HashMap;
+
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+use hkdf::Hkdf;
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::crypto::GroupCipher;
+
+pub(crate) const KEY_SIZE: usize = 32;
+// Control message types (first byte after the packet header)
+pub(crate) const CONTROL_HELLO: u8 = 0x01;
+pub(crate) const CONTROL_SENDER_KEY: u8 = 0x02;
+// HELLO: [Identity Key (32)] + [Ephemeral Key (32)] + [Reply Wanted (1)]
+const HELLO_SIZE: usize = 2 * KEY_SIZE + 1;
+// SENDER_KEY: [Target ID (4)] + [Key ID (4)] + sealed [Sender Key (32)] + [Tag (16)]
+const SENDER_KEY_SIZE: usize = 8 + KEY_SIZE + 16;
+const PAIRWISE_LABEL: &[u8] = b"walkie-talkie pairwise v1";
+
+/// A fresh random identity key for the app to store.
+pub(crate) fn generate_identity_key() -> Vec<u8> {
+    Zeroizing::new(StaticSecret::random_from_rng(OsRng).to_bytes()).to_vec()
+}
+
+/// Result of handling one control message.
+#[derive(Default)]
+pub(crate) struct ControlOutcome {
+    /// Control messages to broadcast: (type, payload)
+    pub(crate) replies: Vec<(u8, Vec<u8>)>,
+    /// Identity key of a peer whose sender key was just accepted
+    pub(crate) established: Option<[u8; KEY_SIZE]>,
+    /// The peer switched to a new sender key (its packet indices start over)
+    pub(crate) rekeyed: bool,
+}
+
+// Our secrets wipe themselves when dropped with x25519-dalek's `zeroize` feature (on by
+// default); this stops compiling without it
+const _: fn() = zeroizes::<StaticSecret>;
+fn zeroizes<T: Zeroize>() {}
+
+struct LocalKeys {
+    identity: StaticSecret,
+    identity_public: PublicKey,
+    ephemeral: StaticSecret,
+    ephemeral_public: PublicKey,
+    sender_key: Zeroizing<[u8; KEY_SIZE]>,
+    sender_cipher: GroupCipher,
+    key_id: u32,
+}
+
+struct PeerSession {
+    identity_public: [u8; KEY_SIZE],
+    ephemeral_public: [u8; KEY_SIZE],
+    pairwise: GroupCipher,
+    sender: Option<GroupCipher>,
+    sender_key_id: Option<u32>, // Kept across restarts of the session, see `handle_sender_key`
+    pending: Option<PendingKeys>, // From a newer HELLO, not yet proven
+}
+
+/// Keys from a HELLO that differs from the peer's session: a restart, or a forgery.
+struct PendingKeys {
+    identity_public: [u8; KEY_SIZE],
+    ephemeral_public: [u8; KEY_SIZE],
+    pairwise: GroupCipher,
+}
+
+impl PeerSession {
+    /// The pairwise cipher for these HELLO keys, if they are the session's or the pending ones.
+    fn pairwise_for(&self, identity_public: &[u8; KEY_SIZE], ephemeral_public: &[u8; KEY_SIZE]) -> Option<&GroupCipher> {
+        if self.identity_public == *identity_public && self.ephemeral_public == *ephemeral_public {
+            return Some(&self.pairwise);
+        }
+        self.pending.as_ref()
+            .filter(|pending| pending.identity_public == *identity_public && pending.ephemeral_public == *ephemeral_public)
+            .map(|pending| &pending.pairwise)
+    }
+}
+
+pub(crate) struct KeyAgreement {
+    local: Option<LocalKeys>,
+    peers: HashMap<u32, PeerSession>,
+}
+
+impl KeyAgreement {
+    pub(crate) fn new() -> Self {
+        Self { local: None, peers: HashMap::new() }
+    }
+
+    /// Loads our identity (32 bytes) and starts a new key session, dropping all
+    /// peer keys. Returns our identity public key, or `None` if the key is invalid.
+    pub(crate) fn set_identity(&mut self, secret: &[u8]) -> Option<[u8; KEY_SIZE]> {
+        let secret = Zeroizing::new(<[u8; KEY_SIZE]>::try_from(secret).ok()?);
+        let identity = StaticSecret::from(*secret);
+        let ephemeral = StaticSecret::random_from_rng(OsRng);
+        let mut sender_key = Zeroizing::new([0u8; KEY_SIZE]);
+        OsRng.fill_bytes(&mut *sender_key);
+
+        let identity_public = PublicKey::from(&identity);
+        self.local = Some(LocalKeys {
+            identity_public,
+            ephemeral_public: PublicKey::from(&ephemeral),
+            identity,
+            ephemeral,
+            sender_cipher: GroupCipher::new(&*sender_key)?,
+            sender_key,
+            key_id: OsRng.next_u32(),
+        });
+        self.peers.clear();
+        Some(identity_public.to_bytes())
+    }
+
+    /// Drops our keys and every peer's, which wipes them.
+    pub(crate) fn clear(&mut self) {
+        self.local = None;
+        self.peers.clear();
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.local.is_some()
+    }
+
+    pub(crate) fn identity_public(&self) -> Option<[u8; KEY_SIZE]> {
+        self.local.as_ref().map(|local| local.identity_public.to_bytes())
+    }
+
+    /// Identity of a peer whose sender key has arrived (so it proved holding it).
+    pub(crate) fn peer_identity(&self, origin_id: u32) -> Option<[u8; KEY_SIZE]> {
+        self.peers.get(&origin_id).filter(|peer| peer.sender.is_some()).map(|peer| peer.identity_public)
+    }
+
+    pub(crate) fn established_peers(&self) -> Vec<u32> {
+        self.peers.iter().filter(|(_, peer)| peer.sender.is_some()).map(|(&id, _)| id).collect()
+    }
+
+    /// Seals a message for one peer under our pairwise key. Each message type uses
+    /// its own `seq` so their nonces never collide (sender keys use 0).
+    pub(crate) fn seal_for_peer(&self, own_id: u32, target: u32, epoch: u32, seq: u32, header: &[u8], plaintext: &[u8]) -> Option<Vec<u8>> {
+        self.peers.get(&target)?.pairwise.seal(own_id, epoch, seq, header, plaintext)
+    }
+
+    /// Opens a message sealed by `origin_id` with `seal_for_peer`.
+    pub(crate) fn open_from_peer(&self, origin_id: u32, epoch: u32, seq: u32, header: &[u8], ciphertext: &[u8]) -> Option<Vec<u8>> {
+        self.peers.get(&origin_id)?.pairwise.open(origin_id, epoch, seq, header, ciphertext)
+    }
+
+    /// Cipher for our own outgoing audio.
+    pub(crate) fn sender_cipher(&self) -> Option<&GroupCipher> {
+        self.local.as_ref().map(|local| &local.sender_cipher)
+    }
+
+    /// Cipher for audio from `origin_id`, once its sender key has arrived.
+    pub(crate) fn peer_cipher(&self, origin_id: u32) -> Option<&GroupCipher> {
+        self.peers.get(&origin_id)?.sender.as_ref()
+    }
+
+    pub(crate) fn hello(&self, reply_wanted: bool) -> Option<Vec<u8>> {
+        let local = self.local.as_ref()?;
+        let mut payload = Vec::with_capacity(HELLO_SIZE);
+        payload.extend_from_slice(local.identity_public.as_bytes());
+        payload.extend_from_slice(local.ephemeral_public.as_bytes());
+        payload.push(reply_wanted as u8);
+        Some(payload)
+    }
+
+    pub(crate) fn handle(&mut self, own_id: u32, origin_id: u32, msg_type: u8, payload: &[u8]) -> ControlOutcome {
+        let mut outcome = ControlOutcome::default();
+        if self.local.is_none() || origin_id == own_id { return outcome; }
+        match msg_type {
+            CONTROL_HELLO => self.handle_hello(own_id, origin_id, payload, &mut outcome),
+            CONTROL_SENDER_KEY => self.handle_sender_key(own_id, origin_id, payload, &mut outcome),
+            _ => {}
+        }
+        outcome
+    }
+
+    fn handle_hello(&mut self, own_id: u32, origin_id: u32, payload: &[u8], outcome: &mut ControlOutcome) {
+        let Some(local) = &self.local else { return };
+        if payload.len() != HELLO_SIZE { return; }
+        let identity_public: [u8; KEY_SIZE] = payload[..KEY_SIZE].try_into().unwrap();
+        let ephemeral_public: [u8; KEY_SIZE] = payload[KEY_SIZE..2 * KEY_SIZE].try_into().unwrap();
+        let reply_wanted = payload[2 * KEY_SIZE] != 0;
+
+        let known = self.peers.get(&origin_id)
+            .is_some_and(|peer| peer.pairwise_for(&identity_public, &ephemeral_public).is_some());
+        if known && !reply_wanted { return; }
+
+        if !known {
+            let Some(pairwise) = derive_pairwise(local, &identity_public, &ephemeral_public) else {
+                log::warn!("Rejected weak handshake key from peer {}", origin_id);
+                return;
+            };
+            match self.peers.get_mut(&origin_id) {
+                // Anyone can send a HELLO under any origin ID: the session stays until a
+                // SENDER_KEY opens under the new keys (see `handle_sender_key`)
+                Some(peer) => peer.pending = Some(PendingKeys { identity_public, ephemeral_public, pairwise }),
+                None => {
+                    let peer = PeerSession { identity_public, ephemeral_public, pairwise, sender: None, sender_key_id: None, pending: None };
+                    self.peers.insert(origin_id, peer);
+                }
+            }
+        }
+
+        if reply_wanted {
+            outcome.replies.extend(self.hello(false).map(|hello| (CONTROL_HELLO, hello)));
+        }
+        let sealed = self.sender_key_for(own_id, origin_id, &identity_public, &ephemeral_public);
+        outcome.replies.extend(sealed.map(|sealed| (CONTROL_SENDER_KEY, sealed)));
+    }
+
+    fn handle_sender_key(&mut self, own_id: u32, origin_id: u32, payload: &[u8], outcome: &mut ControlOutcome) {
+        if payload.len() != SENDER_KEY_SIZE { return; }
+        let target = u32::from_le_bytes(payload[0..4].try_into().unwrap());
+        if target != own_id { return; } // Addressed to someone else
+        let key_id = u32::from_le_bytes(payload[4..8].try_into().unwrap());
+        let Some(peer) = self.peers.get_mut(&origin_id) else { return };
+        if peer.sender.is_some() && peer.sender_key_id == Some(key_id) { return; } // A resend (or a replay)
+
+        let (header, sealed) = payload.split_at(8);
+        let sender_key = match peer.pairwise.open(origin_id, key_id, 0, header, sealed) {
+            Some(sender_key) => sender_key,
+            None => {
+                let pending = peer.pending.as_ref();
+                let Some(sender_key) = pending.and_then(|pending| pending.pairwise.open(origin_id, key_id, 0, header, sealed)) else {
+                    log::warn!("Sender key from peer {} failed to verify", origin_id);
+                    return;
+                };
+                // The pending keys are genuine: the peer restarted, and its old sender key is gone too
+                let pending = peer.pending.take().unwrap();
+                peer.identity_public = pending.identity_public;
+                peer.ephemeral_public = pending.ephemeral_public;
+                peer.pairwise = pending.pairwise;
+                peer.sender = None;
+                sender_key
+            }
+        };
+        let sender_key = Zeroizing::new(sender_key);
+        let first = peer.sender.is_none();
+        peer.sender = GroupCipher::new(&sender_key);
+        // Only a genuinely new key restarts the replay window; the same key coming back
+        // (e.g. after a replayed HELLO) must not let old packets through again
+        outcome.rekeyed = peer.sender_key_id != Some(key_id);
+        peer.sender_key_id = Some(key_id);
+        if first && peer.sender.is_some() {
+            outcome.established = Some(peer.identity_public);
+        }
+    }
+
+    /// Our sender key for `target`, sealed under the pairwise key of the HELLO that asked for it.
+    fn sender_key_for(&self, own_id: u32, target: u32, identity_public: &[u8; KEY_SIZE], ephemeral_public: &[u8; KEY_SIZE]) -> Option<Vec<u8>> {
+        let local = self.local.as_ref()?;
+        let pairwise = self.peers.get(&target)?.pairwise_for(identity_public, ephemeral_public)?;
+        let mut payload = Vec::with_capacity(SENDER_KEY_SIZE);
+        payload.extend_from_slice(&target.to_le_bytes());
+        payload.extend_from_slice(&local.key_id.to_le_bytes());
+        let sealed = pairwise.seal(own_id, local.key_id, 0, &payload, &*local.sender_key)?;
+        payload.extend_from_slice(&sealed);
+        Some(payload)
+    }
+}
+
+/// Both sides sort the two (identity, ephemeral) pairs, so they derive the same key.
+fn derive_pairwise(local: &LocalKeys, identity_public: &[u8; KEY_SIZE], ephemeral_public: &[u8; KEY_SIZE]) -> Option<GroupCipher> {
+    let ephemeral_dh = local.ephemeral.diffie_hellman(&PublicKey::from(*ephemeral_public));
+    let identity_dh = local.identity.diffie_hellman(&PublicKey::from(*identity_public));
+    if !ephemeral_dh.was_contributory() || !identity_dh.was_contributory() { return None; }
+
+    let mut ikm = Zeroizing::new([0u8; 2 * KEY_SIZE]);
+    ikm[..KEY_SIZE].copy_from_slice(ephemeral_dh.as_bytes());
+    ikm[KEY_SIZE..].copy_from_slice(identity_dh.as_bytes());
+
+    let ours = [*local.identity_public.as_bytes(), *local.ephemeral_public.as_bytes()].concat();
+    let theirs = [*identity_public, *ephemeral_public].concat();
+    let (first, second) = if ours <= theirs { (ours, theirs) } else { (theirs, ours) };
+    let info = [PAIRWISE_LABEL, &first, &second].concat();
+
+    let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+    Hkdf::<Sha256>::new(None, &*ikm).expand(&info, &mut *key).ok()?;
+    GroupCipher::new(&*key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    const A: u32 = 1;
+    const B: u32 = 2;
+
+    fn node(identity: &[u8]) -> KeyAgreement {
+        let mut node = KeyAgreement::new();
+        node.set_identity(identity).unwrap();
+        node
+    }
+
+    /// Delivers `a`'s HELLO to `b`, then every reply back and forth until both go quiet.
+    /// Returns the outcomes at `b`.
+    fn handshake(a: &mut KeyAgreement, b: &mut KeyAgreement) -> Vec<ControlOutcome> {
+        let mut queue = VecDeque::from([(A, CONTROL_HELLO, a.hello(true).unwrap())]);
+        let mut outcomes = Vec::new();
+        while let Some((from, msg_type, payload)) = queue.pop_front() {
+            let (to, own_id, node) = if from == A { (B, B, &mut *b) } else { (A, A, &mut *a) };
+            let outcome = node.handle(own_id, from, msg_type, &payload);
+            queue.extend(outcome.replies.iter().map(|(msg_type, payload)| (to, *msg_type, payload.clone())));
+            if to == B {
+                outcomes.push(outcome);
+            }
+        }
+        outcomes
+    }
+
+    /// Whether `receiver` opens audio sealed by `sender` (as origin `origin_id`).
+    fn opens(sender: &KeyAgreement, receiver: &KeyAgreement, origin_id: u32) -> bool {
+        let sealed = sender.sender_cipher().unwrap().seal(origin_id, 9, 1, b"header", b"audio").unwrap();
+        receiver.peer_cipher(origin_id)
+            .and_then(|cipher| cipher.open(origin_id, 9, 1, b"header", &sealed))
+            .is_some_and(|audio| audio == b"audio")
+    }
+
+    #[test]
+    fn handshake_establishes_both_ways() {
+        let (mut a, mut b) = (node(&generate_identity_key()), node(&generate_identity_key()));
+        assert!(b.peer_cipher(A).is_none());
+        let outcomes = handshake(&mut a, &mut b);
+        assert!(outcomes.iter().any(|outcome| outcome.established == a.identity_public()));
+
+        assert!(opens(&a, &b, A));
+        assert!(opens(&b, &a, B));
+        assert_eq!(b.peer_identity(A), a.identity_public());
+        assert_eq!(a.peer_identity(B), b.identity_public());
+        assert_eq!(a.established_peers(), [B]);
+        assert_eq!(b.established_peers(), [A]);
+    }
+
+    #[test]
+    fn a_resent_sender_key_does_not_rekey() {
+        let (mut a, mut b) = (node(&generate_identity_key()), node(&generate_identity_key()));
+        handshake(&mut a, &mut b);
+
+        // B asks again (e.g. a replayed HELLO): A resends the same sender key
+        let outcome = a.handle(A, B, CONTROL_HELLO, &b.hello(true).unwrap());
+        let (_, sender_key) = outcome.replies.iter().find(|(msg_type, _)| *msg_type == CONTROL_SENDER_KEY).unwrap();
+        let outcome = b.handle(B, A, CONTROL_SENDER_KEY, sender_key);
+        assert!(!outcome.rekeyed);
+        assert!(outcome.established.is_none());
+        assert!(opens(&a, &b, A));
+
+        // Addressed to someone else
+        assert!(!a.handle(A, 3, CONTROL_SENDER_KEY, sender_key).rekeyed);
+    }
+
+    #[test]
+    fn forged_hello_keeps_the_session() {
+        let identity = generate_identity_key();
+        let (mut a, mut b) = (node(&identity), node(&generate_identity_key()));
+        handshake(&mut a, &mut b);
+        assert!(opens(&a, &b, A));
+
+        // Someone else's keys under A's origin ID: B answers, but keeps A's session
+        let mut forger = node(&generate_identity_key());
+        let outcome = b.handle(B, A, CONTROL_HELLO, &forger.hello(true).unwrap());
+        assert!(!outcome.replies.is_empty());
+        assert!(outcome.established.is_none());
+        assert_eq!(b.peer_identity(A), a.identity_public());
+        assert!(opens(&a, &b, A));
+
+        // A forger that completes the handshake only gets in under its own identity,
+        // which the app gets to judge
+        let outcomes = handshake(&mut forger, &mut b);
+        assert!(outcomes.iter().any(|outcome| outcome.established == forger.identity_public()));
+        assert_eq!(b.peer_identity(A), forger.identity_public());
+    }
+
+    #[test]
+    fn restart_switches_once_its_sender_key_opens() {
+        let identity = generate_identity_key();
+        let (mut a, mut b) = (node(&identity), node(&generate_identity_key()));
+        handshake(&mut a, &mut b);
+
+        // Same identity, new ephemeral and sender key
+        let mut restarted = node(&identity);
+        let outcomes = handshake(&mut restarted, &mut b);
+        assert!(outcomes.iter().any(|outcome| outcome.rekeyed && outcome.established == a.identity_public()));
+        assert!(opens(&restarted, &b, A));
+        assert!(!opens(&a, &b, A));
+        assert!(opens(&b, &restarted, B));
+    }
+}
@@ -0,0 +1,668 @@
+// ===========================================================================
+// INBOUND PACKETS
+// ===========================================================================
+// Everything a received datagram goes through before the output callback sees
+// it: telling v0, RTP and versioned packets apart, header parsing, checksums,
+// decryption, replay and loop checks, blocking, channel and scan filtering.
+// Control packets (handshakes, roster, floor, capabilities) are answered here
+// and never reach the audio path.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::Sender as StdSender;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use byteorder::{ByteOrder, LittleEndian};
+use crossbeam_channel::Sender;
+
+use crate::crypto::GroupCipher;
+use crate::dispatch::{CallbackDispatcher, CallbackSlot};
+use crate::floor::{FloorControl, FloorEvent, FloorOutcome, CONTROL_FLOOR_GRANT, CONTROL_FLOOR_RELEASE, CONTROL_FLOOR_REQUEST};
+use crate::handshake::{KeyAgreement, CONTROL_HELLO};
+use crate::legacy::LegacyPeers;
+use crate::loops::LoopDetector;
+use crate::protocol::{
+    is_rtp, parse_opus_toc, parses_as_versioned, read_header, unwrap_legacy, unwrap_packet, unwrap_rtp, verify_checksum, wrap_control,
+    IncomingPacket,
+};
+use crate::replay::ReplayGuard;
+use crate::resources;
+use crate::roster::{RosterSync, CONTROL_ROSTER};
+use crate::{
+    AudioConfig, AudioLoopCallback, EmergencyCallback, FloorCallback, PeerCapabilities, PeerKeyCallback, PeerRoster, RosterCallback,
+    ScanCallback, CAPABILITIES_SIZE, CAPABILITIES_TIMEOUT_MS, CONTROL_CAPABILITIES, EMERGENCY_TIMEOUT_MS, FLOOR_REFRESH_MS,
+    FLOOR_REQUEST_WAIT_MS, HANDSHAKE_RETRY_MS, LOCAL_CAPABILITIES, PACKET_FLAG_CONTROL, PROTOCOL_MIN_VERSION, PROTOCOL_VERSION,
+    SCAN_ACTIVITY_GAP_MS,
+};
+
+/// Where incoming packets are handed to the running output callback (None when stopped).
+pub(crate) type PacketSlot = Mutex<Option<Sender<IncomingPacket>>>;
+pub(crate) type CipherSlot = Mutex<Option<GroupCipher>>;
+
+/// Engine-wide traffic totals (since the engine was created).
+#[derive(Default)]
+pub(crate) struct TrafficCounters {
+    pub(crate) packets_sent: AtomicU64,
+    pub(crate) bytes_sent: AtomicU64,
+    pub(crate) packets_received: AtomicU64,
+    pub(crate) bytes_received: AtomicU64,
+    pub(crate) packets_replayed: AtomicU64,
+    pub(crate) packets_corrupted: AtomicU64,
+    pub(crate) packets_looped: AtomicU64,
+    pub(crate) packets_blocked: AtomicU64,
+    pub(crate) packets_oversized: AtomicU64,
+    pub(crate) packets_malformed: AtomicU64,
+    pub(crate) packets_legacy: AtomicU64,
+    pub(crate) packets_dropped_stale: AtomicU64,
+    pub(crate) frames_gated: AtomicU64,
+    pub(crate) frames_dtx: AtomicU64,
+    // Usage accounting, reset at session start
+    pub(crate) session_transmit_ms: AtomicU64,
+    pub(crate) session_transmit_bytes: AtomicU64,
+}
+
+impl TrafficCounters {
+    pub(crate) fn begin_session(&self) {
+        self.session_transmit_ms.store(0, Ordering::Relaxed);
+        self.session_transmit_bytes.store(0, Ordering::Relaxed);
+    }
+
+    /// Transmit time left under a quota (0 = no quota), in ms.
+    pub(crate) fn quota_remaining_ms(&self, quota_ms: u64) -> Option<u64> {
+        (quota_ms != 0).then(|| quota_ms.saturating_sub(self.session_transmit_ms.load(Ordering::Relaxed)))
+    }
+}
+
+/// A packet on its way to the transport thread.
+pub(crate) struct Outbound {
+    pub(crate) data: Vec<u8>,
+    pub(crate) queued_at: Instant,
+    pub(crate) audio: bool, // Only audio may be dropped as stale
+}
+
+impl Outbound {
+    pub(crate) fn audio(data: Vec<u8>) -> Self {
+        Self { data, queued_at: Instant::now(), audio: true }
+    }
+
+    pub(crate) fn control(data: Vec<u8>) -> Self {
+        Self { data, queued_at: Instant::now(), audio: false }
+    }
+
+    /// Ends the transport thread once everything queued before it is sent. Nothing
+    /// else is ever empty.
+    pub(crate) fn stop() -> Self {
+        Self::control(Vec::new())
+    }
+}
+
+pub(crate) struct Inbound {
+    pub(crate) packet_tx: Arc<PacketSlot>,
+    pub(crate) group_cipher: Arc<CipherSlot>,
+    pub(crate) key_agreement: Arc<Mutex<KeyAgreement>>,
+    pub(crate) own_node_id: Arc<AtomicU32>,
+    pub(crate) tx_transport: StdSender<Outbound>, // Handshake replies
+    pub(crate) key_callback: CallbackSlot<dyn PeerKeyCallback>,
+    pub(crate) roster: Mutex<RosterSync>, // Locked before the key agreement when both are needed
+    pub(crate) roster_callback: CallbackSlot<dyn RosterCallback>,
+    pub(crate) dispatcher: Arc<CallbackDispatcher>,
+    pub(crate) last_handshake_request: Mutex<Option<Instant>>,
+    pub(crate) replay_guard: Mutex<ReplayGuard>, // Only ever updated with authenticated packets
+    pub(crate) traffic: Arc<TrafficCounters>,
+    pub(crate) peer_capabilities: Mutex<BTreeMap<u32, (PeerCapabilities, Instant)>>, // With when we last heard from the peer
+    pub(crate) common_capabilities: Arc<AtomicU32>, // Bits every known peer announced (0 while there are none)
+    pub(crate) last_capabilities_request: Mutex<Option<Instant>>,
+    pub(crate) max_packet_bytes: AtomicU32,
+    pub(crate) floor: Mutex<FloorControl>,
+    pub(crate) floor_callback: CallbackSlot<dyn FloorCallback>,
+    pub(crate) floor_open: Arc<AtomicBool>, // We may transmit: floor control is off or we hold the floor
+    pub(crate) is_mic_enabled: Arc<AtomicBool>, // Turned off when we miss or lose the floor
+    pub(crate) emergencies: Mutex<HashMap<u32, Instant>>, // Last emergency packet per sender
+    pub(crate) emergency_callback: CallbackSlot<dyn EmergencyCallback>,
+    pub(crate) channel: Arc<AtomicU16>, // Audio for other channels is dropped
+    pub(crate) loops: Arc<Mutex<LoopDetector>>, // The input callback records what we send
+    pub(crate) loop_callback: CallbackSlot<dyn AudioLoopCallback>,
+    pub(crate) scan_channels: Mutex<Vec<u16>>, // Besides the active channel, in priority order
+    pub(crate) scan_activity: Mutex<HashMap<u16, Instant>>, // Last packet per scanned channel
+    pub(crate) scan_callback: CallbackSlot<dyn ScanCallback>,
+    pub(crate) priority_channel: Mutex<Option<u16>>, // Outranks the rest, which then rank equally
+    pub(crate) blocked: Mutex<BTreeSet<u32>>, // Audio from these is dropped on arrival
+    pub(crate) legacy: Mutex<LegacyPeers>,
+}
+
+impl Inbound {
+    /// Parses a received packet and hands it to the output callback (if a session is running).
+    pub(crate) fn deliver(self: &Arc<Self>, data: &[u8]) {
+        if data.len() > self.max_packet_bytes.load(Ordering::Relaxed) as usize {
+            self.traffic.packets_oversized.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        if data.len() >= 4 && !self.keys_in_use() {
+            let origin_id = LittleEndian::read_u32(&data[0..4]);
+            if self.legacy.lock().unwrap().is_confirmed(origin_id) {
+                self.deliver_legacy(data);
+                return;
+            }
+        }
+        if is_rtp(data) {
+            match unwrap_rtp(data).filter(|packet| parse_opus_toc(&packet.opus_data).is_some()) {
+                Some(packet) => self.deliver_rtp(packet),
+                None => self.deliver_legacy(data),
+            }
+            return;
+        }
+        let Some(header) = read_header(data) else {
+            self.deliver_legacy(data);
+            return;
+        };
+        let origin_id = header.origin_id;
+        if header.flags & PACKET_FLAG_CONTROL != 0 {
+            if header.version == 1 {
+                self.handle_control(origin_id, &data[header.size..]);
+            }
+            return;
+        }
+        if !parses_as_versioned(data, &header) {
+            self.deliver_legacy(data);
+            return;
+        }
+        if self.is_blocked(origin_id) { return; }
+        let Some(scan_rank) = self.scan_rank(header.channel) else { return };
+
+        let Some(checked) = verify_checksum(data, &header) else {
+            self.traffic.packets_corrupted.fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+
+        let (packet, missing_key) = {
+            let key_agreement = self.key_agreement.lock().unwrap();
+            let group_cipher = self.group_cipher.lock().unwrap();
+            match key_agreement.peer_cipher(origin_id).or(group_cipher.as_ref()) {
+                None if key_agreement.is_active() => (None, true),
+                cipher => (unwrap_packet(checked, cipher), false),
+            }
+        };
+        if missing_key {
+            self.request_handshake();
+        }
+        if let Some(mut packet) = packet {
+            if packet.epoch.is_some() {
+                // Decrypted, so it really is from `origin_id`
+                self.legacy.lock().unwrap().forget(origin_id);
+            }
+            packet.wire_len = data.len(); // Including the checksum
+            packet.scan_rank = scan_rank;
+            self.heard_from(origin_id);
+            self.accept(packet);
+        }
+    }
+
+    /// RTP is cleartext, so it is only accepted while no key is set. It has no
+    /// channel, so it counts as channel 0.
+    fn deliver_rtp(&self, mut packet: IncomingPacket) {
+        let Some(scan_rank) = self.scan_rank(0) else { return };
+        if self.keys_in_use() || self.is_blocked(packet.origin_id) { return; }
+        packet.scan_rank = scan_rank;
+        self.accept(packet);
+    }
+
+    /// v0 packets (see legacy.rs) are cleartext and have no channel, like RTP,
+    /// so they are ignored while a key is set. Nothing else is counted until
+    /// their sender is confirmed.
+    fn deliver_legacy(&self, data: &[u8]) {
+        if self.keys_in_use() { return; }
+        let Some(mut packet) = unwrap_legacy(data) else { return };
+        if !self.legacy.lock().unwrap().note(packet.origin_id, packet.seq as u16) { return; }
+        self.traffic.packets_legacy.fetch_add(1, Ordering::Relaxed);
+        let Some(scan_rank) = self.scan_rank(0) else { return };
+        if self.is_blocked(packet.origin_id) { return; }
+        packet.scan_rank = scan_rank;
+        self.accept(packet);
+    }
+
+    fn keys_in_use(&self) -> bool {
+        self.key_agreement.lock().unwrap().is_active() || self.group_cipher.lock().unwrap().is_some()
+    }
+
+    /// Where `channel` ranks in what we listen to: 0 for the active channel, then
+    /// the scan list in order. With a priority channel, it is 0 and the rest are 1.
+    /// `None` if we don't listen to it.
+    fn scan_rank(&self, channel: u16) -> Option<u32> {
+        let active = channel == self.channel.load(Ordering::Relaxed);
+        let position = self.scan_channels.lock().unwrap().iter().position(|&scanned| scanned == channel);
+        if !active && position.is_none() { return None; }
+        match *self.priority_channel.lock().unwrap() {
+            Some(priority) => Some(if channel == priority { 0 } else { 1 }),
+            None => Some(position.filter(|_| !active).map_or(0, |position| position as u32 + 1)),
+        }
+    }
+
+    /// Whether cleartext control messages from `origin_id` are trusted: always
+    /// while no key is set, otherwise only once key agreement with it is
+    /// complete. A group key alone names no one, so then no one is.
+    fn is_authenticated(&self, origin_id: u32) -> bool {
+        if !self.keys_in_use() { return true; }
+        self.key_agreement.lock().unwrap().peer_identity(origin_id).is_some()
+    }
+
+    fn is_blocked(&self, origin_id: u32) -> bool {
+        let blocked = self.blocked.lock().unwrap().contains(&origin_id);
+        if blocked {
+            self.traffic.packets_blocked.fetch_add(1, Ordering::Relaxed);
+        }
+        blocked
+    }
+
+    /// Reports a scanned channel that just became active.
+    fn note_scan_activity(&self, channel: u16, origin_id: u32) {
+        let last = self.scan_activity.lock().unwrap().insert(channel, Instant::now());
+        if last.is_none_or(|last| last.elapsed() > Duration::from_millis(SCAN_ACTIVITY_GAP_MS)) {
+            info!("Activity on scanned channel {} (peer {})", channel, origin_id);
+            self.dispatcher.notify(&self.scan_callback, move |callback| callback.on_scan_activity(channel, origin_id));
+        }
+    }
+
+    /// Final checks, then hands the packet to the output callback.
+    fn accept(&self, mut packet: IncomingPacket) {
+        // Before the replay window: a malformed payload mustn't advance it
+        if parse_opus_toc(&packet.opus_data).is_none() {
+            self.traffic.packets_malformed.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        if packet.redundant.as_deref().is_some_and(|frame| parse_opus_toc(frame).is_none()) {
+            packet.redundant = None;
+        }
+        if !self.replay_guard.lock().unwrap().accept(packet.origin_id, packet.epoch, packet.seq, packet.seq_bits) {
+            self.traffic.packets_replayed.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        if let Some(looped) = self.loops.lock().unwrap().check(packet.origin_id, &packet.opus_data) {
+            self.traffic.packets_looped.fetch_add(1, Ordering::Relaxed);
+            if looped.report {
+                let (looped_id, original_id) = (packet.origin_id, looped.original_id);
+                log::warn!("Audio from peer {} loops back from peer {}", original_id, looped_id);
+                self.dispatcher.notify(&self.loop_callback, move |callback| {
+                    callback.on_audio_loop_detected(looped_id, original_id)
+                });
+            }
+            return;
+        }
+        self.floor.lock().unwrap().heard(packet.origin_id);
+        if packet.channel != self.channel.load(Ordering::Relaxed) {
+            self.note_scan_activity(packet.channel, packet.origin_id);
+        }
+        if packet.emergency {
+            let origin_id = packet.origin_id;
+            let started = self.emergencies.lock().unwrap().insert(origin_id, Instant::now())
+                .is_none_or(|last| last.elapsed() > Duration::from_millis(EMERGENCY_TIMEOUT_MS));
+            if started {
+                log::warn!("Emergency transmission from peer {}", origin_id);
+                self.dispatcher.notify(&self.emergency_callback, move |callback| {
+                    callback.on_emergency_received(origin_id)
+                });
+            }
+        }
+        // LOCK-FREE SEND: We lock mutex only to get the sender, then send non-blockingly
+        if let Ok(guard) = self.packet_tx.lock()
+            && let Some(tx) = &*guard {
+            let _ = tx.send(packet);
+        }
+    }
+
+    fn handle_control(self: &Arc<Self>, origin_id: u32, body: &[u8]) {
+        let Some((&msg_type, payload)) = body.split_first() else { return };
+        let own_id = self.own_node_id.load(Ordering::Relaxed);
+        if origin_id == own_id { return; }
+        if (CONTROL_FLOOR_REQUEST..=CONTROL_FLOOR_RELEASE).contains(&msg_type) {
+            if !self.is_authenticated(origin_id) { return; }
+            let outcome = {
+                let mut floor = self.floor.lock().unwrap();
+                floor.heard(origin_id);
+                floor.handle(own_id, origin_id, msg_type, payload)
+            };
+            self.apply_floor(outcome);
+            return;
+        }
+        if msg_type == CONTROL_CAPABILITIES {
+            self.handle_capabilities(origin_id, payload);
+            return;
+        }
+        if msg_type == CONTROL_ROSTER {
+            let updated = {
+                let mut roster = self.roster.lock().unwrap();
+                roster.handle(&self.key_agreement.lock().unwrap(), own_id, origin_id, payload)
+            };
+            if updated {
+                info!("Roster updated by peer {}", origin_id);
+                self.notify_roster();
+            }
+            return;
+        }
+        let outcome = self.key_agreement.lock().unwrap().handle(own_id, origin_id, msg_type, payload);
+        if outcome.rekeyed {
+            self.replay_guard.lock().unwrap().reset(origin_id);
+        }
+        for (msg_type, payload) in outcome.replies {
+            let _ = self.tx_transport.send(Outbound::control(wrap_control(own_id, msg_type, &payload)));
+        }
+        if let Some(identity_key) = outcome.established {
+            info!("Key agreement with peer {} complete", origin_id);
+            // As the roster admin, bring the new peer up to date
+            let roster = {
+                let roster = self.roster.lock().unwrap();
+                roster.message_for(&self.key_agreement.lock().unwrap(), own_id, origin_id)
+            };
+            if let Some(roster) = roster {
+                let _ = self.tx_transport.send(Outbound::control(wrap_control(own_id, CONTROL_ROSTER, &roster)));
+            }
+            self.dispatcher.notify(&self.key_callback, move |callback| {
+                callback.on_peer_key_established(origin_id, identity_key.to_vec())
+            });
+        }
+    }
+
+    pub(crate) fn roster_snapshot(&self) -> PeerRoster {
+        let roster = self.roster.lock().unwrap();
+        PeerRoster { version: roster.version(), keys: roster.keys().iter().map(|key| key.to_vec()).collect() }
+    }
+
+    pub(crate) fn notify_roster(&self) {
+        let roster = self.roster_snapshot();
+        self.dispatcher.notify(&self.roster_callback, move |callback| callback.on_roster_updated(roster));
+    }
+
+    pub(crate) fn configure_floor(self: &Arc<Self>, config: &AudioConfig) {
+        let own_id = self.own_node_id.load(Ordering::Relaxed);
+        let outcome = self.floor.lock().unwrap().set_config(own_id, config.floor_control, config.floor_priority);
+        self.apply_floor(outcome);
+    }
+
+    /// Asks for the floor; a free one is ours after FLOOR_REQUEST_WAIT_MS unless someone objects.
+    pub(crate) fn request_floor(self: &Arc<Self>) {
+        let (outcome, token) = self.floor.lock().unwrap().request(self.own_node_id.load(Ordering::Relaxed));
+        self.apply_floor(outcome);
+        let Some(token) = token else { return };
+        let inbound = Arc::downgrade(self);
+        resources::spawn(move || {
+            thread::sleep(Duration::from_millis(FLOOR_REQUEST_WAIT_MS));
+            let Some(inbound) = inbound.upgrade() else { return };
+            let outcome = inbound.floor.lock().unwrap().resolve(inbound.own_node_id.load(Ordering::Relaxed), token);
+            inbound.apply_floor(outcome);
+        });
+    }
+
+    /// Moves to another channel's floor (releasing the one we held).
+    pub(crate) fn set_floor_channel(self: &Arc<Self>, channel: u16) {
+        let outcome = self.floor.lock().unwrap().set_channel(self.own_node_id.load(Ordering::Relaxed), channel);
+        self.apply_floor(outcome);
+    }
+
+    pub(crate) fn release_floor(self: &Arc<Self>) {
+        let outcome = self.floor.lock().unwrap().release(self.own_node_id.load(Ordering::Relaxed));
+        self.apply_floor(outcome);
+    }
+
+    /// Updates the mic gate, sends the outcome's messages and acts on its event.
+    fn apply_floor(self: &Arc<Self>, outcome: FloorOutcome) {
+        let own_id = self.own_node_id.load(Ordering::Relaxed);
+        let open = {
+            let floor = self.floor.lock().unwrap();
+            !floor.is_enabled() || floor.is_held(own_id)
+        };
+        self.floor_open.store(open, Ordering::Relaxed);
+        for (msg_type, payload) in outcome.replies {
+            let _ = self.tx_transport.send(Outbound::control(wrap_control(own_id, msg_type, &payload)));
+        }
+        match outcome.event {
+            Some(FloorEvent::Granted) => {
+                info!("Floor granted");
+                self.keep_floor();
+                self.dispatcher.notify(&self.floor_callback, |callback| callback.on_floor_granted());
+            }
+            Some(FloorEvent::Denied { holder_id }) => {
+                info!("Floor denied, held by peer {}", holder_id);
+                self.is_mic_enabled.store(false, Ordering::Relaxed);
+                self.dispatcher.notify(&self.floor_callback, move |callback| callback.on_floor_denied(holder_id));
+            }
+            Some(FloorEvent::Revoked { holder_id }) => {
+                info!("Floor taken over by peer {}", holder_id);
+                self.is_mic_enabled.store(false, Ordering::Relaxed);
+                self.dispatcher.notify(&self.floor_callback, move |callback| callback.on_floor_revoked(holder_id));
+            }
+            None => {}
+        }
+    }
+
+    /// Repeats our GRANT while we hold the floor from the latest grant.
+    fn keep_floor(self: &Arc<Self>) {
+        let grant = self.floor.lock().unwrap().grant();
+        let inbound = Arc::downgrade(self);
+        resources::spawn(move || loop {
+            thread::sleep(Duration::from_millis(FLOOR_REFRESH_MS));
+            let Some(inbound) = inbound.upgrade() else { return };
+            let own_id = inbound.own_node_id.load(Ordering::Relaxed);
+            let Some(payload) = inbound.floor.lock().unwrap().refresh(own_id, grant) else { return };
+            let _ = inbound.tx_transport.send(Outbound::control(wrap_control(own_id, CONTROL_FLOOR_GRANT, &payload)));
+        });
+    }
+
+    fn handle_capabilities(&self, origin_id: u32, payload: &[u8]) {
+        // Newer versions may append fields, so only the known prefix is read
+        if payload.len() < CAPABILITIES_SIZE { return; }
+        // Announced before key agreement finished: asked for again on its first audio
+        if !self.is_authenticated(origin_id) { return; }
+        let capabilities = PeerCapabilities {
+            peer_id: origin_id,
+            min_version: payload[0],
+            max_version: payload[1],
+            capabilities: LittleEndian::read_u32(&payload[2..6]),
+        };
+        let reply_wanted = payload[6] != 0;
+        if capabilities.max_version < PROTOCOL_MIN_VERSION || capabilities.min_version > PROTOCOL_VERSION {
+            log::warn!("Peer {} speaks protocol v{}-{}, we speak v{}-{}",
+                origin_id, capabilities.min_version, capabilities.max_version, PROTOCOL_MIN_VERSION, PROTOCOL_VERSION);
+        }
+        let mut peers = self.peer_capabilities.lock().unwrap();
+        peers.insert(origin_id, (capabilities, Instant::now()));
+        self.expire_capabilities(&mut peers);
+        drop(peers);
+        if reply_wanted {
+            self.announce_capabilities(false);
+        }
+    }
+
+    /// Notes audio from `origin_id`, keeping its capabilities fresh. A peer we
+    /// know none for (it announced before we could trust it, or was forgotten
+    /// while silent) is asked, at most once per HANDSHAKE_RETRY_MS.
+    fn heard_from(&self, origin_id: u32) {
+        let known = {
+            let mut peers = self.peer_capabilities.lock().unwrap();
+            let known = peers.get_mut(&origin_id).map(|(_, heard)| *heard = Instant::now()).is_some();
+            self.expire_capabilities(&mut peers);
+            known
+        };
+        if known { return; }
+        {
+            let mut last = self.last_capabilities_request.lock().unwrap();
+            if last.is_some_and(|at| at.elapsed() < Duration::from_millis(HANDSHAKE_RETRY_MS)) { return; }
+            *last = Some(Instant::now());
+        }
+        self.announce_capabilities(true);
+    }
+
+    /// Forgets peers silent for CAPABILITIES_TIMEOUT_MS and updates the bits the
+    /// rest have in common.
+    pub(crate) fn expire_capabilities(&self, peers: &mut BTreeMap<u32, (PeerCapabilities, Instant)>) {
+        peers.retain(|_, (_, heard)| heard.elapsed() < Duration::from_millis(CAPABILITIES_TIMEOUT_MS));
+        let common = if peers.is_empty() {
+            0
+        } else {
+            peers.values().fold(u32::MAX, |common, (peer, _)| common & peer.capabilities)
+        };
+        self.common_capabilities.store(common, Ordering::Relaxed);
+    }
+
+    /// Drops every peer's capabilities; the next session learns them afresh.
+    pub(crate) fn forget_capabilities(&self) {
+        self.peer_capabilities.lock().unwrap().clear();
+        self.common_capabilities.store(0, Ordering::Relaxed);
+        *self.last_capabilities_request.lock().unwrap() = None;
+    }
+
+    /// Tells everyone which versions and features we support.
+    pub(crate) fn announce_capabilities(&self, reply_wanted: bool) {
+        let mut payload = [0u8; CAPABILITIES_SIZE];
+        payload[0] = PROTOCOL_MIN_VERSION;
+        payload[1] = PROTOCOL_VERSION;
+        LittleEndian::write_u32(&mut payload[2..6], LOCAL_CAPABILITIES);
+        payload[6] = reply_wanted as u8;
+        let _ = self.tx_transport.send(Outbound::control(wrap_control(self.own_node_id.load(Ordering::Relaxed), CONTROL_CAPABILITIES, &payload)));
+    }
+
+    /// Audio arrived from a peer we have no key for (we joined late, or missed
+    /// its handshake): announce ourselves again and ask everyone to reply.
+    fn request_handshake(&self) {
+        {
+            let mut last = self.last_handshake_request.lock().unwrap();
+            if last.is_some_and(|at| at.elapsed() < Duration::from_millis(HANDSHAKE_RETRY_MS)) { return; }
+            *last = Some(Instant::now());
+        }
+        let hello = self.key_agreement.lock().unwrap().hello(true);
+        if let Some(hello) = hello {
+            let _ = self.tx_transport.send(Outbound::control(wrap_control(self.own_node_id.load(Ordering::Relaxed), CONTROL_HELLO, &hello)));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{wrap_packet, PacketOptions};
+    use crossbeam_channel::{unbounded, Receiver};
+
+    // A 20ms mono CELT frame (RFC 6716 §3.1). The filler, read as the flags of a
+    // versioned header, claims padding that isn't there, as Opus data easily does.
+    const TOC: u8 = 31 << 3;
+    const FILLER: u8 = 0x18;
+
+    fn inbound() -> (Arc<Inbound>, Receiver<IncomingPacket>) {
+        let (packet_tx, packets) = unbounded();
+        let (tx_transport, _) = std::sync::mpsc::channel();
+        let inbound = Arc::new(Inbound {
+            packet_tx: Arc::new(Mutex::new(Some(packet_tx))),
+            group_cipher: Arc::new(Mutex::new(None)),
+            key_agreement: Arc::new(Mutex::new(KeyAgreement::new())),
+            own_node_id: Arc::new(AtomicU32::new(7)),
+            tx_transport,
+            key_callback: Arc::new(Mutex::new(None)),
+            roster: Mutex::new(RosterSync::new()),
+            roster_callback: Arc::new(Mutex::new(None)),
+            dispatcher: Arc::new(CallbackDispatcher::new(false)),
+            last_handshake_request: Mutex::new(None),
+            replay_guard: Mutex::new(ReplayGuard::new()),
+            traffic: Arc::new(TrafficCounters::default()),
+            peer_capabilities: Mutex::new(BTreeMap::new()),
+            common_capabilities: Arc::new(AtomicU32::new(0)),
+            last_capabilities_request: Mutex::new(None),
+            max_packet_bytes: AtomicU32::new(1500),
+            floor: Mutex::new(FloorControl::new()),
+            floor_callback: Arc::new(Mutex::new(None)),
+            floor_open: Arc::new(AtomicBool::new(true)),
+            is_mic_enabled: Arc::new(AtomicBool::new(false)),
+            emergencies: Mutex::new(HashMap::new()),
+            emergency_callback: Arc::new(Mutex::new(None)),
+            channel: Arc::new(AtomicU16::new(0)),
+            loops: Arc::new(Mutex::new(LoopDetector::new())),
+            loop_callback: Arc::new(Mutex::new(None)),
+            scan_channels: Mutex::new(Vec::new()),
+            scan_activity: Mutex::new(HashMap::new()),
+            scan_callback: Arc::new(Mutex::new(None)),
+            priority_channel: Mutex::new(None),
+            blocked: Mutex::new(BTreeSet::new()),
+            legacy: Mutex::new(LegacyPeers::default()),
+        });
+        (inbound, packets)
+    }
+
+    /// A packet as the baseline build sent it: [OriginID LE][Seq LE][Opus].
+    fn baseline(origin_id: u32, seq: u16) -> Vec<u8> {
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&origin_id.to_le_bytes());
+        packet.extend_from_slice(&seq.to_le_bytes());
+        packet.push(TOC);
+        packet.extend_from_slice(&[FILLER; 20]);
+        packet
+    }
+
+    fn received(packets: &Receiver<IncomingPacket>) -> Vec<(u32, u32)> {
+        packets.try_iter().map(|packet| (packet.origin_id, packet.seq)).collect()
+    }
+
+    #[test]
+    fn baseline_packets_play_once_their_sender_is_confirmed() {
+        // Low bytes that read as no version, as v1 to v3, and as non-Opus RTP
+        for origin_id in [0x0102_0342, 0x0102_0301, 0x0102_0302, 0x0102_0303, 0x0102_42A5] {
+            let (inbound, packets) = inbound();
+            inbound.deliver(&baseline(origin_id, 100));
+            assert!(received(&packets).is_empty(), "{origin_id:#x}");
+            inbound.deliver(&baseline(origin_id, 101));
+            inbound.deliver(&baseline(origin_id, 102));
+            assert_eq!(received(&packets), [(origin_id, 101), (origin_id, 102)], "{origin_id:#x}");
+            assert_eq!(inbound.traffic.packets_legacy.load(Ordering::Relaxed), 2);
+        }
+    }
+
+    #[test]
+    fn packets_out_of_sequence_confirm_no_one() {
+        let (inbound, packets) = inbound();
+        for seq in [100, 300, 500, 100] {
+            inbound.deliver(&baseline(0x0102_0342, seq));
+        }
+        assert!(received(&packets).is_empty());
+        assert_eq!(inbound.traffic.packets_legacy.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn baseline_packets_are_dropped_while_a_key_is_set() {
+        let (inbound, packets) = inbound();
+        *inbound.group_cipher.lock().unwrap() = GroupCipher::new(&[7; 32]);
+        inbound.deliver(&baseline(0x0102_0342, 100));
+        inbound.deliver(&baseline(0x0102_0342, 101));
+        assert!(received(&packets).is_empty());
+        assert_eq!(inbound.traffic.packets_legacy.load(Ordering::Relaxed), 0);
+        assert!(!inbound.legacy.lock().unwrap().is_confirmed(0x0102_0342));
+    }
+
+    #[test]
+    fn a_decrypted_packet_clears_a_forged_confirmation() {
+        // Two packets that read as v0 under the ID of a peer that speaks v3
+        let (inbound, packets) = inbound();
+        let origin_id = 0x0102_0342;
+        inbound.deliver(&baseline(origin_id, 100));
+        inbound.deliver(&baseline(origin_id, 101));
+        assert!(inbound.legacy.lock().unwrap().is_confirmed(origin_id));
+        received(&packets);
+
+        *inbound.group_cipher.lock().unwrap() = GroupCipher::new(&[7; 32]);
+        let cipher = GroupCipher::new(&[7; 32]).unwrap();
+        let packet = wrap_packet(origin_id, 5, 0, &[TOC, FILLER, FILLER], Some((&cipher, 1)), PacketOptions::default()).unwrap();
+        inbound.deliver(&packet);
+        assert_eq!(received(&packets), [(origin_id, 5)]);
+        assert!(!inbound.legacy.lock().unwrap().is_confirmed(origin_id));
+    }
+
+    #[test]
+    fn versioned_packets_are_not_taken_for_baseline() {
+        let (inbound, packets) = inbound();
+        for (seq, channel) in [(100, 0), (101, 5), (102, 0)] {
+            let options = PacketOptions { channel, ..Default::default() };
+            let packet = wrap_packet(0x0102_0342, seq, 0, &[TOC, FILLER, FILLER], None, options).unwrap();
+            inbound.deliver(&packet);
+        }
+        assert_eq!(received(&packets), [(0x0102_0342, 100), (0x0102_0342, 102)]);
+        assert_eq!(inbound.traffic.packets_legacy.load(Ordering::Relaxed), 0);
+    }
+}
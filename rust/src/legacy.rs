@@ -0,0 +1,83 @@
+// ===========================================================================
+// UNVERSIONED SENDERS
+// ===========================================================================
+// Builds from before protocol versions (v0) send [OriginID][Sequence][Opus]
+// with no version byte, so their packets can only be told apart by layout,
+// and a v0 packet may well look like RTP or like one of ours. Any single
+// packet can be misread, but misread RTP or corrupted versioned packets don't
+// keep an origin and a running sequence, so a sender only counts as v0 once
+// LEGACY_CONFIRM_PACKETS of its packets arrived in sequence. From then on its
+// packets are taken as v0 before anything else is tried.
+//
+// v0 packets are cleartext and unauthenticated, so a confirmation is easy to
+// forge for any origin. It lapses after LEGACY_EXPIRE_MS without a packet that
+// continues the sequence, a versioned packet that decrypts clears it, and no
+// one is noted at all while keys are set (the engine checks that).
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::{LEGACY_CONFIRM_MS, LEGACY_CONFIRM_PACKETS, LEGACY_EXPIRE_MS, LEGACY_SEQ_GAP};
+
+/// A v0 sender: packets in sequence so far, and the last one's sequence and arrival.
+struct Candidate {
+    packets: u32,
+    seq: u16,
+    at: Instant,
+}
+
+impl Candidate {
+    /// Whether `seq` continues the sequence.
+    fn follows(&self, seq: u16) -> bool {
+        (1..=LEGACY_SEQ_GAP).contains(&seq.wrapping_sub(self.seq))
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct LegacyPeers {
+    confirmed: HashMap<u32, Candidate>, // `at` is the last packet in sequence
+    candidates: HashMap<u32, Candidate>,
+}
+
+impl LegacyPeers {
+    /// Whether packets from `origin_id` are taken as v0 without a second look.
+    pub(crate) fn is_confirmed(&self, origin_id: u32) -> bool {
+        self.confirmed.get(&origin_id)
+            .is_some_and(|confirmed| confirmed.at.elapsed() < Duration::from_millis(LEGACY_EXPIRE_MS))
+    }
+
+    /// Notes a packet that parses as v0. `true` once its sender is confirmed.
+    pub(crate) fn note(&mut self, origin_id: u32, seq: u16) -> bool {
+        let now = Instant::now();
+        let expiry = Duration::from_millis(LEGACY_EXPIRE_MS);
+        self.confirmed.retain(|_, confirmed| now.duration_since(confirmed.at) < expiry);
+        if let Some(confirmed) = self.confirmed.get_mut(&origin_id) {
+            // Out-of-order packets still play, but only the sequence keeps the confirmation
+            if confirmed.follows(seq) {
+                confirmed.seq = seq;
+                confirmed.at = now;
+            }
+            return true;
+        }
+        let window = Duration::from_millis(LEGACY_CONFIRM_MS);
+        self.candidates.retain(|_, candidate| now.duration_since(candidate.at) <= window);
+
+        let candidate = self.candidates.entry(origin_id).or_insert(Candidate { packets: 0, seq, at: now });
+        candidate.packets = if candidate.packets > 0 && candidate.follows(seq) { candidate.packets + 1 } else { 1 };
+        candidate.seq = seq;
+        candidate.at = now;
+        if candidate.packets < LEGACY_CONFIRM_PACKETS { return false; }
+
+        let candidate = self.candidates.remove(&origin_id).unwrap();
+        self.confirmed.insert(origin_id, candidate);
+        log::warn!("Peer {} runs a build without protocol versions and can't hear us", origin_id);
+        true
+    }
+
+    /// Drops what we know about `origin_id`: it sent a versioned packet that
+    /// decrypted, so whatever looked like v0 under its ID was forged.
+    pub(crate) fn forget(&mut self, origin_id: u32) {
+        self.confirmed.remove(&origin_id);
+        self.candidates.remove(&origin_id);
+    }
+}
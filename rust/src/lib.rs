@@ -1,40 +1,77 @@
-use std::sync::{Arc, Mutex};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::Sender as StdSender;
-use std::collections::{BTreeMap, HashMap};
-use crossbeam_channel::{unbounded, Receiver, Sender};
+use std::collections::HashMap;
 
-uniffi::setup_scaffolding!("walkie_talkie_engine");
-
-// ===========================================================================
-// CENTRALIZED CONFIGURATION
-// ===========================================================================
-
-// --- Buffer & Channel Limits ---
-// Buffer size for 120ms of audio at 48kHz (48000Hz * 0.120s = 5760 samples).
-// We need enough room to hold data while processing.
-const MAX_BUFFER_SIZE: usize = 5760;
-
-// Maximum size of a raw Opus encoded packet. 512 bytes is plenty for voice.
-const OPUS_OUT_BUFFER_SIZE: usize = 512;
-
-// --- Protocol Layout ---
-// Header: [OriginID (4 bytes)] + [Sequence (2 bytes)]
-const PACKET_HEADER_SIZE: usize = 6;
+// The engine's info and debug lines, silent in privacy mode (see logging.rs). Warnings
+// and errors go straight to `log`.
+macro_rules! info {
+    ($($arg:tt)+) => { if !$crate::logging::is_quiet() { log::info!($($arg)+) } };
+}
+macro_rules! debug {
+    ($($arg:tt)+) => { if !$crate::logging::is_quiet() { log::debug!($($arg)+) } };
+}
 
-// --- Tuning Parameters ---
-// How many frames of silence (missing packets) before we delete a peer?
-// 50 frames * 60ms = ~3 seconds.
-const PEER_TIMEOUT_FRAMES: usize = 50;
+// The modules below are only driven by the real engine (`audio_backend`, see
+// build.rs). Without it the stub keeps them compiled (and type-checked, and their
+// tests running) but never calls them, so there they may hold code it doesn't reach.
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod archive;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod budget;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod clips;
+mod config;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod crypto;
+#[cfg(audio_backend)]
+mod device;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod dispatch;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod dsp;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod errors;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod floor;
+#[cfg(all(feature = "game-abi", audio_backend))]
+mod game;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod handshake;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod inbound;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod legacy;
+mod locale;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod logging;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod loops;
+#[cfg(feature = "net-sim")]
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod netsim;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod params;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod pipeline;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod protocol;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod ptt;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod recording;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod replay;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod resources;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod roster;
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod settings;
+#[cfg(feature = "udp-transport")]
+#[cfg_attr(not(audio_backend), allow(dead_code))]
+mod udp;
 
-// Jitter Buffer: How many packets to buffer before STARTING playback?
-// 6 packets * 60ms = 360ms latency.
-// Higher = smoother audio, Lower = faster conversation.
-const JITTER_BUFFER_START_THRESHOLD: usize = 6;
+use settings::*;
 
-// Jitter Buffer: How far ahead to check for a "future" packet if the expected one is missing?
-// If we expect Seq 10, but have Seq 15, we treat 11-14 as lost and skip to 15.
-const JITTER_LOOKAHEAD_WINDOW: u16 = 10;
+uniffi::setup_scaffolding!("walkie_talkie_engine");
 
 // ===========================================================================
 // SHARED DEFINITIONS
@@ -49,6 +86,16 @@ pub enum AudioError {
     EncoderError,
     #[error("Failed to decode audio")]
     DecoderError,
+    #[error("Invalid parameter")]
+    InvalidParameter,
+    #[error("Failed to open transport")]
+    TransportError,
+    #[error("Failed to create recording")]
+    RecordingError,
+    #[error("Transmit quota used up")]
+    QuotaExceeded,
+    #[error("Engine has been shut down")]
+    ShutDown,
 }
 
 #[derive(Clone, Copy, uniffi::Record)]
@@ -56,8 +103,152 @@ pub struct AudioConfig {
     pub sample_rate: i32,
     pub frame_size_ms: i32,
     pub jitter_buffer_ms: i32,
+    /// Android's device ID (`AudioDeviceInfo.getId()`), or on iOS and desktops the
+    /// ID the engine logs for each device. 0 = the system default.
     pub input_device_id: i32,
     pub output_device_id: i32,
+    /// Cancel our own speaker output from the mic signal (needed on loudspeaker).
+    #[uniffi(default = false)]
+    pub echo_cancellation: bool,
+    /// RNNoise suppression of background noise (wind, traffic) before encoding.
+    /// Requires 48kHz and a frame size that is a multiple of 10ms.
+    #[uniffi(default = false)]
+    pub noise_suppression: bool,
+    /// Normalize the mic level before encoding so quiet and loud talkers sound alike.
+    #[uniffi(default = false)]
+    pub agc_enabled: bool,
+    /// AGC target level in dB below full scale (18 => -18 dBFS).
+    #[uniffi(default = 18)]
+    pub agc_target_level_db: i32,
+    /// Upper bound for the AGC boost, in dB.
+    #[uniffi(default = 24)]
+    pub agc_max_gain_db: i32,
+    /// Run voice activity detection on the mic while PTT is held (drives `VoiceActivityCallback`).
+    #[uniffi(default = false)]
+    pub vad_enabled: bool,
+    /// With VAD enabled: do not encode/send frames classified as silence, even with PTT held.
+    #[uniffi(default = false)]
+    pub vad_gate_transmit: bool,
+    /// How long playout of a peer pauses for a missing packet (while later ones are
+    /// already buffered) before it is declared lost. 0 = conceal immediately.
+    /// Raise on links with heavy reordering (e.g. bonded cellular); costs latency.
+    #[uniffi(default = 0)]
+    pub reorder_wait_ms: i32,
+    /// A hole smaller than this many packets is concealed packet-by-packet (PLC);
+    /// a larger one makes the peer resync to the next buffered packet. Counted in
+    /// packets of our own `frame_size_ms`, like the jitter limits.
+    #[uniffi(default = 10)]
+    pub resync_gap_packets: i32,
+    /// Opus target bitrate in bits per second. 0 lets the encoder choose.
+    #[uniffi(default = 0)]
+    pub bitrate_bps: i32,
+    /// Opus encoder complexity, 0-10 (higher values are clamped). Lower values save
+    /// CPU on low-end devices at some cost in quality.
+    #[uniffi(default = 10)]
+    pub opus_complexity: i32,
+    /// Variable bitrate. Turn off for constant-bitrate packets under a strict
+    /// bandwidth budget.
+    #[uniffi(default = true)]
+    pub use_vbr: bool,
+    /// Send standard RTP instead of the engine's own format, for SIP/RTP tooling and
+    /// Wireshark. Drops position metadata; ignored while encryption is on.
+    #[uniffi(default = false)]
+    pub rtp_packetization: bool,
+    /// Repeat the previous frame in every packet, so a single lost packet is filled
+    /// from the next one. Roughly doubles the bitrate; meant for lossy mesh links.
+    #[uniffi(default = false)]
+    pub redundancy: bool,
+    /// Pad encrypted packets to a fixed size bucket, so their sizes don't reveal when
+    /// someone is speaking. Costs bandwidth; best combined with `use_vbr = false`.
+    /// No effect without encryption.
+    #[uniffi(default = false)]
+    pub pad_packets: bool,
+    /// Deliver all callbacks from one dedicated engine thread (bounded queue) instead of
+    /// the audio/transport threads, so a slow handler can never cause dropouts.
+    #[uniffi(default = false)]
+    pub dedicated_callback_thread: bool,
+    /// Drop outgoing audio that waited longer than `jitter_buffer_ms` for the transport
+    /// (e.g. while `send_packet` was blocked) instead of sending it late, so a stall
+    /// doesn't leave peers lagging behind afterwards. Control messages always go out.
+    #[uniffi(default = false)]
+    pub drop_stale_audio: bool,
+    /// Keep the last this many seconds of the received mix in memory (at most 60),
+    /// so `get_replay` / `play_replay` can bring back a missed transmission. 0 = off.
+    /// Nothing is kept in privacy mode.
+    #[uniffi(default = 0)]
+    pub replay_buffer_secs: u32,
+    /// Watch for an output stream that runs but plays nothing (device routing bugs)
+    /// and report it through `OutputHealthCallback`. Device sessions only (not pull mode).
+    #[uniffi(default = false)]
+    pub output_watchdog: bool,
+    /// Incoming packets larger than this are dropped unparsed and counted in
+    /// `EngineStats.packets_oversized`, whichever way they arrive. At least 576.
+    #[uniffi(default = 1500)]
+    pub max_packet_bytes: u32,
+    /// How far the channel is ducked while `play_voice_message` plays, in dB.
+    /// 0 = not at all.
+    #[uniffi(default = 12.0)]
+    pub voice_message_duck_db: f32,
+    /// `set_mic_enabled(true)` first plays a short confirm tone locally and opens the
+    /// mic once it has finished, like a radio's talk permit tone. `TransmitCallback`
+    /// reports when sending actually starts.
+    #[uniffi(default = false)]
+    pub talk_permit_tone: bool,
+    /// Keeps this much of the mic signal while PTT is up and sends it at the start
+    /// of the next transmission, so the first syllables spoken while pressing the
+    /// button aren't lost. Opt-in: the mic is then processed all the time, though
+    /// nothing leaves the device until PTT is pressed. Up to 1000ms; 0 = off.
+    #[uniffi(default = 0)]
+    pub ptt_pre_roll_ms: u32,
+    /// `on_accessory_ptt` toggles the mic on a tap instead of keeping it on while
+    /// the key is held. A long press still works as momentary.
+    #[uniffi(default = false)]
+    pub ptt_toggle: bool,
+    /// Before a peer's transmission plays, a short chirp derived from its node ID, so
+    /// listeners can tell who is talking without looking at the screen.
+    #[uniffi(default = false)]
+    pub talker_id_chirp: bool,
+    /// Tone sent after our transmission when PTT is released. None = off.
+    #[uniffi(default = None)]
+    pub roger_beep: Option<RogerBeep>,
+    /// Half-duplex floor control: only the node holding the floor transmits, and
+    /// `set_mic_enabled(true)` asks for it first (see `FloorCallback`). Every node
+    /// should use the same policy. None = off.
+    #[uniffi(default = None)]
+    pub floor_control: Option<FloorPolicy>,
+    /// Our priority under `FloorPolicy::Priority` (higher wins).
+    #[uniffi(default = 0)]
+    pub floor_priority: u8,
+    /// What happens to peers with a lower priority (`set_local_priority`) than the
+    /// highest one currently talking, e.g. everyone else while a dispatcher speaks.
+    /// None = all peers mix equally.
+    #[uniffi(default = None)]
+    pub preemption: Option<PreemptionPolicy>,
+    /// How far `PreemptionPolicy::Duck` lowers them, in dB.
+    #[uniffi(default = 12.0)]
+    pub preemption_duck_db: f32,
+    /// Faint noise at this level (dBFS, -90 to -20) while a peer's transmission
+    /// underruns or stalls, so the gap doesn't sound like a dropped link. None = off.
+    #[uniffi(default = None)]
+    pub comfort_noise_dbfs: Option<f32>,
+    /// Most peers mixed at once. Past it, the highest-priority speakers keep their
+    /// turn (those already talking first) and the rest are held in their jitter
+    /// buffers, losing the oldest audio, until one stops. Muted peers don't count.
+    /// 0 = no limit.
+    #[uniffi(default = 0)]
+    pub max_concurrent_speakers: u32,
+    /// Also play the channel on a second device, e.g. a wired line-out feeding a
+    /// vehicle PA while the user listens on a Bluetooth headset. Device sessions
+    /// only (not pull mode). None = off.
+    #[uniffi(default = None)]
+    pub secondary_output: Option<SecondaryOutput>,
+    /// Where audio goes when no device ID is set. None is `Auto`.
+    #[uniffi(default = None)]
+    pub routing_mode: Option<AudioRoutingMode>,
+    /// The profile this config was built from (see `ConfigBuilder`). Informational:
+    /// the engine only looks at the fields above.
+    #[uniffi(default = None)]
+    pub profile: Option<ConfigProfile>,
 }
 
 impl Default for AudioConfig {
@@ -68,10 +259,545 @@ impl Default for AudioConfig {
             jitter_buffer_ms: 1000,
             input_device_id: 0,
             output_device_id: 0,
+            echo_cancellation: false,
+            noise_suppression: false,
+            agc_enabled: false,
+            agc_target_level_db: 18,
+            agc_max_gain_db: 24,
+            vad_enabled: false,
+            vad_gate_transmit: false,
+            reorder_wait_ms: 0,
+            resync_gap_packets: JITTER_LOOKAHEAD_WINDOW as i32,
+            bitrate_bps: 0,
+            opus_complexity: 10,
+            use_vbr: true,
+            rtp_packetization: false,
+            redundancy: false,
+            pad_packets: false,
+            dedicated_callback_thread: false,
+            drop_stale_audio: false,
+            replay_buffer_secs: 0,
+            output_watchdog: false,
+            max_packet_bytes: MAX_PACKET_BYTES_DEFAULT,
+            voice_message_duck_db: 12.0,
+            talk_permit_tone: false,
+            ptt_pre_roll_ms: 0,
+            ptt_toggle: false,
+            talker_id_chirp: false,
+            roger_beep: None,
+            floor_control: None,
+            floor_priority: 0,
+            preemption: None,
+            preemption_duck_db: 12.0,
+            comfort_noise_dbfs: None,
+            max_concurrent_speakers: 0,
+            secondary_output: None,
+            routing_mode: None,
+            profile: None,
         }
     }
 }
 
+/// A second output device fed from the same mix (`AudioConfig.secondary_output`).
+///
+/// It gets the peers, ducking and alerts, but not `set_output_volume`, the sidetone
+/// or the echo canceller's reference: those belong to the main output. If the
+/// device can't be opened, the session carries on without it.
+#[derive(Clone, Copy, Debug, PartialEq, uniffi::Record)]
+pub struct SecondaryOutput {
+    pub device_id: i32,
+    /// Its own volume, 0.0-2.0 like `set_output_volume`. Changes apply live.
+    #[uniffi(default = 1.0)]
+    pub gain: f32,
+}
+
+/// The end-of-transmission tone (`AudioConfig.roger_beep`), so listeners know
+/// the channel is clear.
+#[derive(Clone, Copy, Debug, PartialEq, uniffi::Record)]
+pub struct RogerBeep {
+    #[uniffi(default = 1000.0)]
+    pub frequency_hz: f32,
+    /// Up to 1000ms.
+    #[uniffi(default = 150)]
+    pub duration_ms: u32,
+    #[uniffi(default = -12.0)]
+    pub level_dbfs: f32,
+    /// Also play it on our own output, as confirmation that PTT was released.
+    #[uniffi(default = false)]
+    pub play_locally: bool,
+}
+
+/// Named starting points for `ConfigBuilder`, each a full parameter set for one kind of link.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum ConfigProfile {
+    /// Wi-Fi or wired LAN: 20ms frames, a short jitter buffer.
+    LowLatencyLan,
+    /// Mobile data: redundancy, some reorder tolerance, stale audio dropped after stalls.
+    Cellular,
+    /// High-latency, metered links: 60ms frames, 12 kbps, a deep jitter buffer.
+    Satellite,
+    /// Least CPU and radio time: 16kHz, a cheap encoder, silence not transmitted.
+    Battery,
+}
+
+/// Why the engine can't run a config (`ConfigBuilder::validate`). A stable ID with
+/// the limits as plain numbers, so apps word and format it in the user's language
+/// instead of parsing English from the engine (see also `EffectiveConfig.messages`).
+#[derive(Clone, Debug, PartialEq, uniffi::Enum)]
+pub enum ConfigIssue {
+    SampleRateUnsupported { supported_hz: Vec<i32> },
+    FrameSizeUnsupported { supported_ms: Vec<i32> },
+    /// The jitter buffer must hold at least one frame.
+    JitterBufferTooShort { min_ms: i32 },
+    /// 0 (auto) is allowed too.
+    BitrateOutOfRange { min_bps: i32, max_bps: i32 },
+    /// `reorder_wait_ms`, `agc_target_level_db` or `agc_max_gain_db`.
+    NegativeValue,
+    NoiseSuppressionNeedsSampleRate { sample_rate_hz: i32 },
+    VadGateNeedsVad,
+    MaxPacketBytesTooSmall { min_bytes: u32 },
+    NegativeDuck,
+    RogerBeepOutOfRange { max_ms: u32, max_frequency_hz: f32 },
+    ComfortNoiseOutOfRange { min_dbfs: f32, max_dbfs: f32 },
+    PttPreRollTooLong { max_ms: u32 },
+    /// Needs a device ID and a gain within range.
+    SecondaryOutputInvalid { max_gain: f32 },
+}
+
+/// A config as the engine applies it, see `describe_effective_config`.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct EffectiveConfig {
+    pub profile: Option<ConfigProfile>,
+    /// Every setting by field name, after the engine's clamps and requirements
+    /// (e.g. noise suppression shows as off below 48kHz). "valid" says whether
+    /// `ConfigBuilder::build` would accept it. Values are stable tokens for
+    /// tooling; `messages` is what to show users.
+    pub settings: HashMap<String, String>,
+    /// Why it isn't valid, if it isn't.
+    #[uniffi(default = None)]
+    pub issue: Option<ConfigIssue>,
+    /// The issue, then any settings the engine changes, for the user.
+    pub messages: Vec<Message>,
+}
+
+/// Text the engine has for users, by ID. The arguments are listed with each ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum MessageId {
+    /// The supported rates (Hz).
+    SampleRateUnsupported,
+    /// The supported frame sizes (ms).
+    FrameSizeUnsupported,
+    /// The shortest jitter buffer (ms).
+    JitterBufferTooShort,
+    /// The lowest and highest bitrate (bps).
+    BitrateOutOfRange,
+    NegativeValue,
+    /// The sample rate needed (Hz).
+    NoiseSuppressionNeedsSampleRate,
+    VadGateNeedsVad,
+    /// The smallest limit (bytes).
+    MaxPacketBytesTooSmall,
+    NegativeDuck,
+    /// The longest beep (ms) and the highest frequency (Hz).
+    RogerBeepOutOfRange,
+    /// The quietest and loudest level (dBFS).
+    ComfortNoiseOutOfRange,
+    /// The longest pre-roll (ms).
+    PttPreRollTooLong,
+    /// The highest gain.
+    SecondaryOutputInvalid,
+    /// The complexity asked for, and the one used.
+    OpusComplexityClamped,
+    /// The gap asked for, and the one used (packets).
+    ResyncGapClamped,
+    /// The longest replay buffer (s).
+    ReplayBufferCapped,
+    /// The loudest beep (dBFS).
+    RogerBeepLevelCapped,
+}
+
+/// User-facing text: an ID with its arguments, and the engine's own wording in the
+/// language set with `set_locale`. Apps with their own translations use `id` and
+/// `args`; the arguments are already formatted for the locale.
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+pub struct Message {
+    pub id: MessageId,
+    pub args: Vec<String>,
+    pub text: String,
+}
+
+/// Kinds of output device the engine can pick by itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum AudioRoute {
+    BluetoothSco,
+    /// Wired or USB headset/headphones.
+    Wired,
+    Earpiece,
+    Speaker,
+}
+
+/// How the engine picks its devices (`AudioConfig.routing_mode`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum AudioRoutingMode {
+    /// Walk the route preference list (see `set_route_preference`).
+    Auto,
+    Earpiece,
+    Speaker,
+    /// A Bluetooth headset's voice link, mic included. The engine asks the app to
+    /// start SCO through `BluetoothScoCallback`, and falls back to the preference
+    /// list while no headset is connected.
+    BluetoothSco,
+}
+
+/// How a peer's lost frames are filled in (`set_peer_concealment`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum ConcealmentMode {
+    /// Opus PLC, except that peers other than the current speaker repeat their last
+    /// frame once the mixer callback is short on CPU time.
+    Auto,
+    /// Always Opus PLC: the best sound, at the cost of a decode per lost frame.
+    Full,
+    /// The last frame again, quieter each time. No decoder work.
+    Repeat,
+    /// Silence. No decoder work.
+    Silence,
+}
+
+/// What happens to lower-priority speakers (`AudioConfig.preemption`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum PreemptionPolicy {
+    /// Turned down by `AudioConfig.preemption_duck_db`.
+    Duck,
+    /// Muted (still decoded, so they come back without a gap in their stream).
+    Suppress,
+}
+
+/// The network the device just moved to (`notify_network_change`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum NetworkKind {
+    Wifi,
+    Cellular,
+    Ethernet,
+    Other,
+}
+
+/// Who gets the floor when it is taken (`AudioConfig.floor_control`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum FloorPolicy {
+    /// The holder keeps the floor until it lets go.
+    FirstCome,
+    /// A request with a higher `floor_priority` than the holder's takes the floor over.
+    Priority,
+}
+
+/// Why the output watchdog suspects the device plays nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum OutputSilentReason {
+    /// We keep rendering, but the frame position the device presents doesn't move.
+    PositionStalled,
+    /// The stream under-ran on every check: the device isn't pulling audio in time.
+    ContinuousXruns,
+}
+
+#[cfg(feature = "udp-transport")]
+#[derive(Clone, uniffi::Record)]
+pub struct UdpMulticastConfig {
+    /// IPv4 multicast group, e.g. "239.255.42.99".
+    pub group_address: String,
+    pub port: u16,
+    /// Multicast TTL: 1 keeps traffic on the local subnet.
+    #[uniffi(default = 1)]
+    pub ttl: u32,
+    /// Local interface to join on; "0.0.0.0" lets the OS choose.
+    #[uniffi(default = "0.0.0.0")]
+    pub interface_address: String,
+}
+
+/// What `set_network_impairment` does to outgoing packets. Percentages are per
+/// packet; the same seed gives the same decisions for the same packet stream.
+#[cfg(feature = "net-sim")]
+#[derive(Clone, Copy, Debug, uniffi::Record)]
+pub struct NetworkImpairment {
+    pub loss_percent: f32,
+    pub duplicate_percent: f32,
+    /// Packets held back long enough for the next one to overtake them.
+    pub reorder_percent: f32,
+    pub delay_ms: u32,
+    /// Up to this much extra delay per packet (reorders packets by itself too).
+    pub jitter_ms: u32,
+    #[uniffi(default = 1)]
+    pub seed: u64,
+}
+
+/// Where a peer is, as reported by the peer itself. Opaque to the engine:
+/// it is only carried alongside the audio and handed back on playout.
+#[derive(Clone, Copy, Debug, PartialEq, uniffi::Record)]
+pub struct PeerPosition {
+    pub latitude_deg: f64,
+    pub longitude_deg: f64,
+    pub altitude_m: f32,
+    /// Direction the speaker is facing (0 = north, clockwise).
+    pub heading_deg: f32,
+}
+
+#[derive(Clone, Copy, Debug, uniffi::Record)]
+pub struct PeerStats {
+    pub peer_id: u32,
+    pub packets_received: u64,
+    /// Sequence numbers that never arrived in time (concealed or skipped on resync).
+    pub packets_lost: u64,
+    pub packet_loss_percent: f32,
+    /// Packets currently waiting in this peer's jitter buffer.
+    pub jitter_buffer_depth: u32,
+    /// Frames synthesized by Opus packet loss concealment.
+    pub packets_concealed: u64,
+    /// Lost frames rebuilt from the packet after them (in-band FEC or redundancy).
+    pub packets_recovered: u64,
+    pub decode_errors: u64,
+    pub bytes_received: u64,
+    pub talking: bool,
+    /// Capture-to-playout delay (smoothed), from the sender's capture timestamps.
+    /// Includes any offset between the two devices' clocks. None for v1 senders.
+    pub end_to_end_delay_ms: Option<i32>,
+    /// Mixer callbacks this peer sat out because decoding ran over budget, or
+    /// because `AudioConfig.max_concurrent_speakers` were already talking.
+    pub callbacks_deferred: u64,
+    /// Of `packets_concealed`, those filled in without Opus PLC (see `ConcealmentMode`).
+    pub packets_concealed_cheaply: u64,
+    /// What the peer announced (see `set_local_priority`), or our override of it.
+    pub priority: u8,
+    /// Duration of the peer's packets, from their Opus TOC. Senders pick their own
+    /// `frame_size_ms`, so this may differ from ours.
+    pub frame_size_ms: f32,
+}
+
+/// A peer's participation over the current session (kept after the peer times out,
+/// reset when a new session starts). Measured on the audio actually played.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct PeerTalkStats {
+    pub peer_id: u32,
+    pub talk_seconds: f32,
+    /// Average level of the peer's speech in dBFS.
+    pub average_level_dbfs: f32,
+    /// Talk seconds per hour of day, 24 entries (UTC: index 0 is 00:00-01:00).
+    pub talk_seconds_by_hour: Vec<f32>,
+}
+
+/// One transmission in the channel archive (see `start_archive`), as found by
+/// `search_archive`. Pass it to `export_archive_clip` to get the audio.
+#[derive(Clone, Copy, Debug, PartialEq, uniffi::Record)]
+pub struct ArchiveSegment {
+    /// The segment file it is in.
+    pub segment: u32,
+    pub peer_id: u32,
+    /// Unix time in ms.
+    pub start_ms: u64,
+    pub duration_ms: u32,
+    /// Where it starts in the segment file.
+    pub offset_ms: u32,
+}
+
+/// A peer's loudness over the last `AudioLevelCallback` period, as decoded (before
+/// volume, ducking, preemption and mute), for speaking indicators.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct PeerLevel {
+    pub peer_id: u32,
+    /// -100 when the peer played nothing.
+    pub rms_dbfs: f32,
+    pub peak_dbfs: f32,
+}
+
+/// Our own mic level over the last `AudioLevelCallback` period, as it goes to the
+/// encoder (after AEC, NS, AGC and input gain) while transmitting. Otherwise as
+/// captured (unless the pre-roll or a voice message has it processed anyway), so it
+/// also helps position the mic before pressing PTT.
+#[derive(Clone, Copy, Debug, uniffi::Record)]
+pub struct MicLevel {
+    pub rms_dbfs: f32,
+    pub peak_dbfs: f32,
+    pub transmitting: bool,
+}
+
+/// One processing stage and its current settings (values formatted for display).
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct PipelineNode {
+    pub name: String,
+    /// Inactive stages are listed too, so the graph shape is the same on every device.
+    pub active: bool,
+    pub params: HashMap<String, String>,
+}
+
+/// The processing chain in signal order, as currently configured.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct PipelineGraph {
+    /// The audio backend ("oboe" or "cpal"), "pull" or "stopped".
+    pub mode: String,
+    pub sample_rate: i32,
+    pub capture: Vec<PipelineNode>,
+    pub playback: Vec<PipelineNode>,
+    pub cpu: CpuFeatures,
+}
+
+/// The CPU as detected at startup, and which optimized code paths that selected.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct CpuFeatures {
+    /// Architecture of this build of the library: "aarch64", "arm", "x86_64", ...
+    pub arch: String,
+    pub neon: bool,
+    pub cores: u32,
+    /// Mixing and resampling kernels in use: "neon" or "scalar".
+    pub kernels: String,
+}
+
+/// Time spent in one stage of `run_benchmark`.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct BenchmarkStage {
+    pub name: String,
+    pub total_ms: f64,
+    /// Average per audio frame (all peers together for the receive stages).
+    pub per_frame_us: f64,
+}
+
+/// What the engines in this process hold right now (`get_resource_report`). After
+/// `stop_session`, `pipelines`, `peer_streams` and `encoders` are back to 0; threads
+/// are the engine's own (transport, callbacks, UDP receive) plus short-lived timers.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct ResourceReport {
+    pub engines: u32,
+    /// Input and output callbacks, i.e. open audio streams or pull-mode pipelines.
+    pub pipelines: u32,
+    /// Peers being played, each with its decoder and jitter buffer.
+    pub peer_streams: u32,
+    pub encoders: u32,
+    pub threads: u32,
+}
+
+/// Result of `run_benchmark`.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct BenchmarkReport {
+    pub peers: u32,
+    pub sample_rate: i32,
+    pub frame_size_ms: i32,
+    /// Audio processed, in seconds.
+    pub audio_seconds: f64,
+    /// Time spent processing (all stages together), in seconds.
+    pub processing_seconds: f64,
+    /// How many times faster than realtime the pipeline ran. The audio thread shares
+    /// the CPU with everything else, so leave generous headroom above 1.
+    pub realtime_factor: f64,
+    pub stages: Vec<BenchmarkStage>,
+}
+
+/// What a peer announced it supports. Bits: 1 = position, 2 = encryption,
+/// 4 = key agreement, 8 = checksum, 16 = redundancy, 32 = padding, 64 = priority; unknown bits belong
+/// to newer versions.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct PeerCapabilities {
+    pub peer_id: u32,
+    pub min_version: u8,
+    pub max_version: u8,
+    pub capabilities: u32,
+}
+
+/// The trusted-peer roster published by the admin node (see `publish_roster`).
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct PeerRoster {
+    /// 0 until a roster has been published or received.
+    pub version: u32,
+    /// Identity public keys (32 bytes each) the app should trust.
+    pub keys: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct EngineStats {
+    /// Peers currently in the mixer (as of the last publish, at most 250ms old).
+    pub peers: Vec<PeerStats>,
+    /// Talk time per peer heard this session, including peers that have left.
+    pub talk: Vec<PeerTalkStats>,
+    pub packets_sent: u64,
+    pub bytes_sent: u64,
+    pub packets_received: u64,
+    pub bytes_received: u64,
+    /// Mid-transmission playout underruns since the engine was created.
+    pub playout_stalls: u64,
+    /// Times the mic disappeared mid-session and the engine switched to the default one.
+    pub input_failovers: u64,
+    /// Callbacks dropped because the dedicated callback thread fell behind.
+    pub callbacks_dropped: u64,
+    /// Packets rejected as replays or duplicates.
+    pub packets_replayed: u64,
+    /// Packets dropped because their checksum did not match (damaged in transit).
+    pub packets_corrupted: u64,
+    /// Looped copies of audio we already sent or heard, dropped (see `AudioLoopCallback`).
+    pub packets_looped: u64,
+    /// Audio from peers we blocked (`block_peer`), dropped on arrival.
+    pub packets_blocked: u64,
+    /// Packets dropped for exceeding `AudioConfig.max_packet_bytes`.
+    pub packets_oversized: u64,
+    /// Audio dropped because its Opus payload was malformed (RFC 6716 §3.4).
+    pub packets_malformed: u64,
+    /// Audio from builds before protocol versions (v0), played only while no key is set.
+    pub packets_legacy: u64,
+    /// Outgoing audio dropped as stale after a transport stall (`drop_stale_audio`).
+    pub packets_dropped_stale: u64,
+    /// Encoded frames the app's `TransmitGate` held back.
+    pub frames_gated: u64,
+    /// Silent frames not sent because the encoder's DTX had nothing new to say.
+    pub frames_dtx: u64,
+    /// Audio we transmitted this session (frames actually sent), for usage accounting.
+    pub session_transmit_seconds: f64,
+    /// Bytes of audio packets sent this session.
+    pub session_transmit_bytes: u64,
+    /// Transmit time left under `set_transmit_quota`; `None` without a quota.
+    pub transmit_quota_remaining_seconds: Option<f64>,
+    pub privacy_mode: bool,
+}
+
+/// Data saver status (`get_bandwidth_usage`).
+#[derive(Clone, Copy, Debug, uniffi::Record)]
+pub struct BandwidthUsage {
+    /// `None` without a budget.
+    pub budget_kbps: Option<u32>,
+    /// Audio and control traffic, averaged over the last few seconds.
+    pub send_kbps: f32,
+    pub receive_kbps: f32,
+    /// What the encoder is set to while transmitting; 0 = Opus picks.
+    pub bitrate_bps: i32,
+    /// Whether redundant frames and in-band FEC still fit.
+    pub redundancy: bool,
+    pub fec: bool,
+    /// The budget is holding transmissions below `AudioConfig`.
+    pub capped: bool,
+}
+
+/// An encoded frame about to be sent, as shown to `TransmitGate`.
+#[derive(Clone, Copy, Debug, uniffi::Record)]
+pub struct TransmitFrameMeta {
+    /// Sequence number the frame goes out with.
+    pub seq: u32,
+    pub frame_size_ms: i32,
+    /// First frame of a transmission (PTT pressed, or speech after VAD silence).
+    pub burst_start: bool,
+    /// Level of the frame as encoded, in dBFS.
+    pub level_dbfs: f32,
+    pub encoded_bytes: u32,
+}
+
+/// What shapes the transmit level, see `get_input_gain_report`.
+#[derive(Clone, Debug, uniffi::Record)]
+pub struct InputGainReport {
+    /// Gain we apply in software right before encoding (`set_input_gain`).
+    pub software_gain_db: f32,
+    /// Our own AGC is levelling the signal (`AudioConfig.agc_enabled`).
+    pub software_agc: bool,
+    /// Input preset the platform opened the mic with; `None` outside a session.
+    pub platform_preset: Option<String>,
+    /// The platform may apply its own mic gain and preprocessing (AGC, NS, AEC) before
+    /// we see the audio. Which effects run depends on the device; only the
+    /// unprocessed presets rule them out.
+    pub platform_processing: bool,
+}
+
 // --- Callback Interfaces ---
 
 #[uniffi::export(callback_interface)]
@@ -81,26 +807,212 @@ pub trait PacketTransport: Send + Sync {
 
 #[uniffi::export(callback_interface)]
 pub trait AudioErrorCallback: Send + Sync {
-    fn on_engine_error(&self, code: i32);
+    /// `count` occurrences of error `code` between the two timestamps (ms since the Unix epoch).
+    /// The first occurrence is reported on its own right away; repeats within the following
+    /// second are summarized in a single call.
+    fn on_engine_error(&self, code: i32, count: u32, first_timestamp_ms: u64, last_timestamp_ms: u64);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait VoiceActivityCallback: Send + Sync {
+    /// Fired on transitions only: `true` when the local user starts speaking while transmitting.
+    fn on_voice_activity_changed(&self, speaking: bool);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait TransmitCallback: Send + Sync {
+    /// Our first frame after PTT was pressed is going out (after the talk permit
+    /// tone, if there is one).
+    fn on_transmit_started(&self);
+}
+
+/// App policy on what we transmit (e.g. nothing while a driving UI is shown, or
+/// external moderation), decided per frame without routing the PCM out and back.
+#[uniffi::export(callback_interface)]
+pub trait TransmitGate: Send + Sync {
+    /// Called on the audio thread for every encoded frame before it is sent; `false`
+    /// drops the frame. Runs inside the capture callback, so it must return quickly
+    /// and never block.
+    fn allow_frame(&self, meta: TransmitFrameMeta) -> bool;
+}
+
+#[uniffi::export(callback_interface)]
+pub trait DspParamCallback: Send + Sync {
+    fn on_dsp_param_changed(&self, name: String, value: f32);
+    /// A snapshot applied via `apply_param_snapshot` was reverted because playout became unstable.
+    fn on_param_snapshot_rolled_back(&self, stalls: u32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait PeerEventCallback: Send + Sync {
+    /// A peer's audio starts playing (new peer, or a new talk burst from a known one).
+    fn on_peer_started_talking(&self, peer_id: u32);
+    /// A peer's talk burst has finished playing.
+    fn on_peer_stopped_talking(&self, peer_id: u32);
+    /// A peer was silent long enough to be dropped from the mixer.
+    fn on_peer_timed_out(&self, peer_id: u32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait PeerPositionCallback: Send + Sync {
+    /// Fired from the audio thread when audio carrying this position starts playing,
+    /// so overlays line up with what the user hears.
+    fn on_peer_position(&self, peer_id: u32, position: PeerPosition);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait PeerKeyCallback: Send + Sync {
+    /// A peer proved it holds `identity_key` and its audio can now be decrypted.
+    /// The app decides whether it trusts that key (e.g. against a contact list).
+    fn on_peer_key_established(&self, peer_id: u32, identity_key: Vec<u8>);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait RosterCallback: Send + Sync {
+    /// The admin node's newer roster arrived (or we published one as the admin).
+    fn on_roster_updated(&self, roster: PeerRoster);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait FloorCallback: Send + Sync {
+    /// We hold the floor and the mic gate is open.
+    fn on_floor_granted(&self);
+    /// Our request lost to `holder_id`. The mic is turned off.
+    fn on_floor_denied(&self, holder_id: u32);
+    /// `holder_id` took the floor over from us (`FloorPolicy::Priority`). The mic is turned off.
+    fn on_floor_revoked(&self, holder_id: u32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait EmergencyCallback: Send + Sync {
+    /// `node_id` started an emergency transmission. Fired once per emergency, on the
+    /// first packet that carries the flag; its audio plays above every priority.
+    fn on_emergency_received(&self, node_id: u32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait AudioLevelCallback: Send + Sync {
+    /// Every peer in the mix, about every 50ms while there are any.
+    fn on_peer_levels(&self, levels: Vec<PeerLevel>);
+
+    /// Our mic, about every 50ms while a session runs.
+    fn on_mic_level(&self, level: MicLevel);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait ScanCallback: Send + Sync {
+    /// `peer_id` started talking on scanned `channel` (`set_scan_channels`) after it
+    /// had been quiet. Whether it is heard depends on the channels ranked above it.
+    fn on_scan_activity(&self, channel: u16, peer_id: u32);
+
+    /// The channel being played changed: the mixer locked onto `channel`, or went
+    /// back to scanning (`None`) once every channel it listens to is quiet.
+    fn on_scan_channel_changed(&self, channel: Option<u16>);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait AudioLoopCallback: Send + Sync {
+    /// Audio from `looped_id` is a copy of `original_id`'s (our own node ID when we
+    /// hear ourselves), so two bridges forward the same channel in a loop. The copies
+    /// are dropped. Fired when the loop is found, and every 30s while it lasts.
+    fn on_audio_loop_detected(&self, looped_id: u32, original_id: u32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait AudioRouteCallback: Send + Sync {
+    /// The engine switched to `route` (device `device_id`), at session start or
+    /// after `notify_route_changed`. Not fired when an explicit device ID is configured.
+    fn on_audio_route_changed(&self, route: AudioRoute, device_id: i32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait BluetoothScoCallback: Send + Sync {
+    /// The output stream moved onto (true) or off (false) a Bluetooth SCO device:
+    /// the app should call `startBluetoothSco` / `setCommunicationDevice`, or
+    /// release it. Also fired with false when the session stops.
+    fn on_bluetooth_sco_needed(&self, needed: bool);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait TransportHealthCallback: Send + Sync {
+    /// The app's `send_packet` blocked for `stall_ms` before returning; packets queued
+    /// up behind it in the meantime. Fired from the transport thread after each slow call.
+    fn on_transport_stalled(&self, stall_ms: u32);
+}
+
+#[uniffi::export(callback_interface)]
+pub trait OutputHealthCallback: Send + Sync {
+    /// OutputSilentSuspected: the output stream claims to be running but probably makes
+    /// no sound, so the app can suggest switching routes. `tone_checked` is true if an
+    /// inaudible test tone was played and not heard on the mic. Fired once per episode.
+    fn on_output_silent_suspected(&self, reason: OutputSilentReason, tone_checked: bool);
+}
+
+/// Creates a random X25519 identity key for `AudioEngine::set_identity_key`.
+/// The app stores it; the engine derives everything else per session.
+#[uniffi::export]
+pub fn generate_identity_key() -> Vec<u8> {
+    handshake::generate_identity_key()
 }
 
 // ===========================================================================
-// ANDROID IMPLEMENTATION
+// DEVICE IMPLEMENTATION (ANDROID, IOS, DESKTOP)
 // ===========================================================================
 
-#[cfg(target_os = "android")]
+#[cfg(audio_backend)]
 mod real_impl {
     use super::*;
+    use std::sync::{Arc, Mutex, Weak};
+    use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU16, AtomicU32, AtomicU64, Ordering};
+    use std::sync::mpsc::Sender as StdSender;
+    use crossbeam_channel::{bounded, unbounded, Receiver, Sender};
     use std::thread;
+    use std::time::{Duration, Instant};
+    use std::collections::{BTreeMap, BTreeSet, VecDeque};
     use std::sync::mpsc::{channel, Receiver as StdReceiver};
+    use std::fs::File;
+    use std::io::{BufWriter, Write};
+    use std::path::PathBuf;
     use byteorder::{ByteOrder, LittleEndian};
 
-    use oboe::{
-        AudioInputCallback, AudioOutputCallback, AudioStreamBuilder, AudioStreamAsync,
-        PerformanceMode, SharingMode, Mono, DataCallbackResult, InputPreset, Usage,
-        Input, Output, AudioInputStreamSafe, AudioOutputStreamSafe, AudioStream
+    use crate::device::{
+        self, CaptureHandler, CaptureStream, PlaybackStream, RenderHandler, StreamErrors, StreamSpec, Usage,
     };
-    use opus_codec::{Encoder, Decoder, Application, Channels, SampleRate};
+    use opus_codec::{Encoder, Decoder, Application, Bitrate, Channels, Complexity, SampleRate};
+    use crate::dsp::aec::{EchoCanceller, FarEndBuffer};
+    use crate::dsp::agc::AutomaticGainControl;
+    use crate::dsp::cng::ComfortNoise;
+    use crate::dsp::duck::{DuckCommand, Ducker};
+    use crate::dsp::limiter::Limiter;
+    use crate::dsp::meter::LevelMeter;
+    use crate::params::{DspParam, DspParams};
+    use crate::budget::{self, RateMeter};
+    use crate::clips::{demux_ogg_opus, ClipCache};
+    use crate::recording::{MixHistory, OggOpusWriter, WavWriter};
+    use crate::archive::{self, Segmenter};
+    use crate::dsp::ns::NoiseSuppressor;
+    use crate::errors::ErrorAggregator;
+    use crate::crypto::{next_epoch, random_sequence, GroupCipher};
+    use crate::handshake::{KeyAgreement, CONTROL_HELLO, KEY_SIZE};
+    use crate::roster::{RosterSync, CONTROL_ROSTER};
+    use crate::floor::FloorControl;
+    use crate::logging::Quiet;
+    use crate::loops::LoopDetector;
+    use crate::resources::{self, Resource, Tracked};
+    use crate::ptt::PttButton;
+    use crate::replay::ReplayGuard;
+    use crate::config::check_config;
+    use crate::inbound::{CipherSlot, Inbound, Outbound, PacketSlot, TrafficCounters};
+    use crate::legacy::LegacyPeers;
+    use crate::pipeline::{FrameDecoder, FrameEncoder, PeerEvent, PeerSetting, ReceivePipeline, SendPipeline, TalkTally};
+    use crate::protocol::{native_decode_rate, unwrap_own_packet, wrap_control, IncomingPacket};
+    use zeroize::Zeroize;
+    use crate::dispatch::{join_timeout, CallbackDispatcher, CallbackSlot};
+    use crate::dsp::resample::Resampler;
+    use crate::dsp::sidetone::SidetoneBuffer;
+    use crate::dsp::simd;
+    use crate::dsp::tone::{roger_beep, tone_burst, ToneDetector, TonePlayer};
+    use crate::dsp::vad::VoiceActivityDetector;
 
     // --- Helpers ---
 
@@ -118,505 +1030,3688 @@ mod real_impl {
         }
     }
 
-    fn wrap_packet(origin_id: u32, seq: u16, opus_data: &[u8]) -> Vec<u8> {
-        let mut packet = Vec::with_capacity(PACKET_HEADER_SIZE + opus_data.len());
-        let mut id_buf = [0u8; 4];
-        let mut seq_buf = [0u8; 2];
-        LittleEndian::write_u32(&mut id_buf, origin_id);
-        LittleEndian::write_u16(&mut seq_buf, seq);
-        packet.extend_from_slice(&id_buf);
-        packet.extend_from_slice(&seq_buf);
-        packet.extend_from_slice(opus_data);
-        packet
-    }
+    impl FrameEncoder for Encoder {
+        type Error = opus_codec::Error;
 
-    fn unwrap_packet(data: &[u8]) -> Option<(u32, u16, &[u8])> {
-        if data.len() < PACKET_HEADER_SIZE { return None; }
-        let origin_id = LittleEndian::read_u32(&data[0..4]);
-        let seq = LittleEndian::read_u16(&data[4..6]);
-        Some((origin_id, seq, &data[PACKET_HEADER_SIZE..]))
+        fn encode_frame(&mut self, pcm: &[i16], out: &mut [u8]) -> Result<usize, Self::Error> {
+            self.encode(pcm, out)
+        }
     }
 
-    // --- Core Logic ---
+    impl FrameDecoder for Decoder {
+        fn with_rate(sample_rate: i32) -> Option<Self> {
+            Decoder::new(map_sample_rate(sample_rate), Channels::Mono).ok()
+        }
 
-    struct PeerStream {
-        decoder: Decoder,
-        jitter_buffer: BTreeMap<u16, Vec<u8>>,
-        next_expected_seq: Option<u16>,
-        buffering: bool,
-        buffer: [i16; MAX_BUFFER_SIZE], // Internal scratch buffer for decoding
-        buffer_len: usize,              // How much valid data is in buffer
-        silence_counter: usize,         // For garbage collection
-    }
+        fn decode_frame(&mut self, data: &[u8], out: &mut [i16], fec: bool) -> Option<usize> {
+            self.decode(data, out, fec).ok()
+        }
 
-    impl PeerStream {
-        fn new(sample_rate_hz: i32) -> Self {
-            let rate = map_sample_rate(sample_rate_hz);
-            let decoder = Decoder::new(rate, Channels::Mono).unwrap();
-            Self {
-                decoder,
-                jitter_buffer: BTreeMap::new(),
-                next_expected_seq: None,
-                buffering: true,
-                buffer: [0i16; MAX_BUFFER_SIZE],
-                buffer_len: 0,
-                silence_counter: 0,
-            }
+        fn packet_samples(&self, data: &[u8]) -> Option<usize> {
+            Decoder::packet_samples(self, data).ok()
+        }
+
+        fn reset(&mut self) {
+            let _ = Decoder::reset(self);
         }
     }
 
-    #[derive(uniffi::Object)]
-    pub struct AudioEngine {
-        input_stream: Mutex<Option<AudioStreamAsync<Input, InputCallback>>>,
-        output_stream: Mutex<Option<AudioStreamAsync<Output, OutputCallback>>>,
-        tx_transport: StdSender<Vec<u8>>,
-        packet_tx: Mutex<Option<Sender<(u32, u16, Vec<u8>)>>>,
-        sequence_number: Arc<Mutex<u16>>,
-        config: AudioConfig,
-        is_mic_enabled: Arc<AtomicBool>,
-        own_node_id: u32,
-        error_callback: Arc<Box<dyn AudioErrorCallback>>,
+    fn stale_audio_ms(config: &AudioConfig) -> u32 {
+        if config.drop_stale_audio { config.jitter_buffer_ms.max(0) as u32 } else { 0 }
     }
 
-    // --- RESOURCE CLEANUP ---
-    impl Drop for AudioEngine {
-        fn drop(&mut self) {
-            // Automatically cleanup when the object is destroyed
-            self.release_resources();
+    /// The transport thread: hands packets to the app's `send_packet` in order and
+    /// reports calls that block. Audio that waited longer than `stale_audio_ms` is
+    /// dropped instead of sent, which bounds the latency a stall leaves behind.
+    fn run_transport(
+        rx: StdReceiver<Outbound>,
+        transport: Box<dyn PacketTransport>,
+        dispatcher: &CallbackDispatcher,
+        callback: &CallbackSlot<dyn TransportHealthCallback>,
+        traffic: &TrafficCounters,
+        stale_audio_ms: &AtomicU32,
+    ) {
+        while let Ok(packet) = rx.recv() {
+            if packet.data.is_empty() { break; } // `Outbound::stop`
+            let stale_ms = stale_audio_ms.load(Ordering::Relaxed) as u64;
+            if packet.audio && stale_ms > 0 && packet.queued_at.elapsed() > Duration::from_millis(stale_ms) {
+                traffic.packets_dropped_stale.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+            let started = Instant::now();
+            transport.send_packet(packet.data);
+            let stall = started.elapsed();
+            if stall >= Duration::from_millis(TRANSPORT_STALL_MS) {
+                let stall_ms = stall.as_millis().min(u32::MAX as u128) as u32;
+                log::warn!("Transport send blocked for {}ms", stall_ms);
+                dispatcher.notify(callback, move |callback| callback.on_transport_stalled(stall_ms));
+            }
         }
     }
 
-    #[uniffi::export]
-    impl AudioEngine {
-        #[uniffi::constructor]
-        pub fn new(
-            config: AudioConfig,
-            transport: Box<dyn PacketTransport>,
-            callback: Box<dyn AudioErrorCallback>,
-            own_node_id: u32
-        ) -> Self {
-            let (tx, rx): (StdSender<Vec<u8>>, StdReceiver<Vec<u8>>) = channel();
+    /// An Opus encoder for recordings, and its lookahead in 48kHz samples (the pre-skip).
+    fn recording_encoder(rate: SampleRate) -> Result<(Encoder, u16), AudioError> {
+        let mut encoder = Encoder::new(rate, Channels::Mono, Application::Voip)
+            .map_err(|_| AudioError::EncoderError)?;
+        let _ = encoder.set_bitrate(Bitrate::Custom(RECORDING_BITRATE_BPS));
+        // Lookahead is reported at the encoder rate; the header wants 48kHz samples
+        let pre_skip = encoder.lookahead().unwrap_or(0) * (48000 / rate.as_i32());
+        Ok((encoder, pre_skip as u16))
+    }
 
-            thread::spawn(move || {
-                while let Ok(packet) = rx.recv() {
-                    transport.send_packet(packet);
-                }
-            });
+    /// Encodes PCM into an Ogg/Opus stream in RECORDING_FRAME_MS packets.
+    struct OggOpusEncoder<W: Write> {
+        encoder: Encoder,
+        writer: OggOpusWriter<W>,
+        rate: i32,
+        frame: usize,
+        pcm: Vec<i16>, // Less than a frame, waiting for more
+    }
 
-            Self {
-                input_stream: Mutex::new(None),
-                output_stream: Mutex::new(None),
-                tx_transport: tx,
-                packet_tx: Mutex::new(None),
-                sequence_number: Arc::new(Mutex::new(0)),
-                config,
-                is_mic_enabled: Arc::new(AtomicBool::new(false)),
-                own_node_id,
-                error_callback: Arc::new(callback),
+    impl<W: Write> OggOpusEncoder<W> {
+        /// Writes the stream headers to `inner`.
+        fn new(inner: W, rate: SampleRate) -> Result<Self, AudioError> {
+            let (encoder, pre_skip) = recording_encoder(rate)?;
+            let rate = rate.as_i32();
+            let writer = OggOpusWriter::new(inner, random_sequence(), rate as u32, pre_skip)
+                .map_err(|_| AudioError::RecordingError)?;
+            let frame = (rate / 1000 * RECORDING_FRAME_MS) as usize;
+            Ok(Self { encoder, writer, rate, frame, pcm: Vec::with_capacity(frame * 2) })
+        }
+
+        fn push(&mut self, samples: &[i16]) -> std::io::Result<()> {
+            self.pcm.extend_from_slice(samples);
+            let mut start = 0;
+            let mut result = Ok(());
+            while result.is_ok() && self.pcm.len() - start >= self.frame {
+                result = Self::encode(&mut self.encoder, &mut self.writer, &self.pcm[start..start + self.frame], self.rate);
+                start += self.frame;
             }
+            self.pcm.drain(..start);
+            result
         }
 
-        /// Starts BOTH Input and Output streams.
-        /// Call this when joining a group.
-        pub fn start_session(&self) -> Result<(), AudioError> {
-            log::info!("Starting Audio Session (Rate: {}Hz)...", self.config.sample_rate);
-            self.start_output_stream()?;
-            self.start_input_stream()?;
-            Ok(())
+        /// Pads the tail to a whole frame (the final granule position trims it again)
+        /// and ends the stream.
+        fn finish(mut self) -> std::io::Result<W> {
+            let padding = if self.pcm.is_empty() { 0 } else { self.frame - self.pcm.len() };
+            if padding > 0 {
+                self.pcm.resize(self.frame, 0);
+                Self::encode(&mut self.encoder, &mut self.writer, &self.pcm, self.rate)?;
+            }
+            self.writer.finish(padding, self.rate as u32)
         }
 
-        /// Stops BOTH streams.
-        /// Call this when leaving a group.
-        pub fn stop_session(&self) -> Result<(), AudioError> {
-            log::info!("Stopping Audio Session...");
-            // Now explicitly releases hardware immediately!
-            self.release_resources();
-            self.is_mic_enabled.store(false, Ordering::Relaxed);
-            Ok(())
+        fn encode(encoder: &mut Encoder, writer: &mut OggOpusWriter<W>, chunk: &[i16], rate: i32) -> std::io::Result<()> {
+            let mut packet = [0u8; OPUS_OUT_BUFFER_SIZE];
+            let len = encoder.encode(chunk, &mut packet).map_err(std::io::Error::other)?;
+            writer.write(packet[..len].to_vec(), chunk.len(), rate as u32)
         }
+    }
 
-        pub fn is_session_active(&self) -> bool {
-            let input_active = self.input_stream.lock().unwrap().is_some();
-            let output_active = self.output_stream.lock().unwrap().is_some();
-            input_active && output_active
+    /// An Ogg/Opus writer thread: encodes the PCM handed over by an audio callback
+    /// until the sender is dropped or `max_samples` are in. Returns the finished
+    /// inner writer, or `None` if writing failed.
+    fn run_recorder<W: Write>(rx: Receiver<PcmBlock>, mut output: OggOpusEncoder<W>, max_samples: usize) -> Option<W> {
+        let mut written = 0;
+        let mut result = Ok(());
+        for block in rx.iter() {
+            let mix = block.samples();
+            let take = mix.len().min(max_samples - written);
+            written += take;
+            result = output.push(&mix[..take]);
+            if result.is_err() || written == max_samples { break; }
         }
+        result.and_then(|_| output.finish())
+            .inspect_err(|e| log::error!("Recording write failed: {}", e))
+            .ok()
+    }
 
-        pub fn set_mic_enabled(&self, enabled: bool) {
-            self.is_mic_enabled.store(enabled, Ordering::Relaxed);
-            if enabled {
-                log::info!("Microphone UNMUTED");
-            } else {
-                log::info!("Microphone MUTED");
+    /// The archive writer thread: writes the mix to a new segment file in `dir` for
+    /// every stretch of voice activity and indexes its transmissions when it ends.
+    fn run_archiver(rx: Receiver<ArchiveInput>, dir: PathBuf, rate: SampleRate, next_segment: u32) {
+        let mut segmenter = Segmenter::new(rate.as_i32() as u32, next_segment);
+        let mut output: Option<OggOpusEncoder<BufWriter<File>>> = None;
+        let close = |output: Option<OggOpusEncoder<BufWriter<File>>>, talks: Vec<ArchiveSegment>| {
+            // A segment that failed to write is left out of the index
+            let Some(output) = output else { return };
+            let written = output.finish().and_then(|mut file| file.flush());
+            let indexed = written.and_then(|_| {
+                let mut index = std::fs::OpenOptions::new().create(true).append(true).open(dir.join(archive::INDEX_FILE))?;
+                index.write_all(talks.iter().map(archive::format_entry).collect::<String>().as_bytes())
+            });
+            if let Err(e) = indexed {
+                log::error!("Archive write failed: {}", e);
             }
-        }
+        };
 
-        pub fn push_incoming_packet(&self, data: Vec<u8>) {
-            if let Some((origin_id, seq, opus_data)) = unwrap_packet(&data) {
-                // LOCK-FREE SEND: We lock mutex only to get the sender, then send non-blockingly
-                if let Ok(guard) = self.packet_tx.lock() {
-                    if let Some(tx) = &*guard {
-                        let _ = tx.send((origin_id, seq, opus_data.to_vec()));
+        for input in rx.iter() {
+            match input {
+                ArchiveInput::Started(id) => {
+                    let now_ms = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)
+                        .map_or(0, |since| since.as_millis() as u64);
+                    if let Some(segment) = segmenter.started(id, now_ms) {
+                        let path = dir.join(archive::segment_file(segment));
+                        output = File::create(&path)
+                            .inspect_err(|e| log::error!("Archive: cannot create {}: {}", path.display(), e))
+                            .ok()
+                            .and_then(|file| OggOpusEncoder::new(BufWriter::new(file), rate).ok());
                     }
                 }
-            }
-        }
-
-        fn release_resources(&self) {
-            // Clear the sender so incoming packets stop piling up
-            if let Ok(mut guard) = self.packet_tx.lock() {
-                *guard = None;
-            }
-
-            if let Ok(mut stream_opt) = self.input_stream.lock() {
-                if let Some(mut stream) = stream_opt.take() {
-                    let _ = stream.close();
+                ArchiveInput::Stopped(id) => {
+                    if let Some(talks) = segmenter.stopped(id) {
+                        close(output.take(), talks);
+                    }
                 }
-            }
-            if let Ok(mut stream_opt) = self.output_stream.lock() {
-                if let Some(mut stream) = stream_opt.take() {
-                    let _ = stream.close();
+                ArchiveInput::Pcm(block) => {
+                    if !segmenter.is_open() { continue; }
+                    segmenter.advance(block.len);
+                    if let Some(writing) = &mut output
+                        && let Err(e) = writing.push(block.samples()) {
+                        log::error!("Archive write failed: {}", e);
+                        output = None;
+                    }
                 }
             }
         }
+        if let Some(talks) = segmenter.close() {
+            close(output, talks);
+        }
+    }
 
-        fn start_input_stream(&self) -> Result<(), AudioError> {
-            let samples_per_frame = (self.config.sample_rate / 1000 * self.config.frame_size_ms) as usize;
-            let encoder_rate = map_sample_rate(self.config.sample_rate);
-
-            let mut encoder = Encoder::new(encoder_rate, Channels::Mono, Application::Voip)
-                .map_err(|_| AudioError::EncoderError)?;
-            let _ = encoder.set_dtx(true);
-            let _ = encoder.set_inband_fec(true);
+    /// The mic recording writer thread: appends what the input callback hands over.
+    fn run_mic_recorder(rx: Receiver<PcmBlock>, mut writer: WavWriter<BufWriter<File>>) {
+        let mut result = Ok(());
+        for block in rx.iter() {
+            result = writer.write(block.samples());
+            if result.is_err() { break; }
+        }
+        if let Err(e) = result.and_then(|_| writer.finish()) {
+            log::error!("Mic recording write failed: {}", e);
+        }
+    }
 
-            let callback = InputCallback {
-                encoder,
-                sequence_number: self.sequence_number.clone(),
-                tx_transport: self.tx_transport.clone(),
-                buffer: [0i16; MAX_BUFFER_SIZE],
-                buffer_pos: 0,
-                samples_per_frame,
-                is_mic_enabled: self.is_mic_enabled.clone(),
-                own_node_id: self.own_node_id,
-                error_callback: self.error_callback.clone(),
-            };
+    /// Decodes a whole Ogg/Opus file to mono PCM at `sample_rate`.
+    fn decode_ogg_opus(ogg_bytes: &[u8], sample_rate: i32) -> Result<Vec<i16>, AudioError> {
+        let stream = demux_ogg_opus(ogg_bytes).ok_or(AudioError::DecoderError)?;
+        let channels = if stream.channels == 2 { Channels::Stereo } else { Channels::Mono };
+        let mut decoder = Decoder::new(map_sample_rate(sample_rate), channels)
+            .map_err(|_| AudioError::DecoderError)?;
 
-            // 1. Configure properties on the BASE builder first
-            let mut builder = AudioStreamBuilder::default()
-                .set_direction::<Input>()
-                .set_performance_mode(PerformanceMode::None)
-                .set_sharing_mode(SharingMode::Shared)
-                .set_format::<i16>()
-                .set_channel_count::<Mono>()
-                .set_sample_rate(self.config.sample_rate)
-                .set_input_preset(InputPreset::VoiceCommunication);
-
-            // 2. Set Device ID on the BASE builder (before setting callback)
-            if self.config.input_device_id != 0 {
-                log::info!("Input: Explicit Device ID {}", self.config.input_device_id);
-                builder = builder.set_device_id(self.config.input_device_id);
-            }
-
-            // 3. Set Callback (Converts to Async Builder) and Open
-            let mut stream = builder
-                .set_callback(callback)
-                .open_stream()
-                .map_err(|e| {
-                    log::error!("Open Input Stream Error: {}", e);
-                    AudioError::DeviceError
-                })?;
-
-            stream.start().map_err(|_| AudioError::DeviceError)?;
-            *self.input_stream.lock().unwrap() = Some(stream);
-            Ok(())
+        let channel_count = stream.channels as usize;
+        let mut decoded = vec![0i16; MAX_BUFFER_SIZE * channel_count];
+        let mut pcm = Vec::new();
+        for packet in &stream.packets {
+            let len = decoder.decode(packet, &mut decoded, false).map_err(|_| AudioError::DecoderError)?;
+            // Downmix to mono (the engine output is mono)
+            pcm.extend(decoded[..len * channel_count].chunks_exact(channel_count).map(|frame| {
+                (frame.iter().map(|&s| s as i32).sum::<i32>() / channel_count as i32) as i16
+            }));
         }
 
-        fn start_output_stream(&self) -> Result<(), AudioError> {
-            // Create lock-free channel
-            let (tx, rx) = unbounded();
+        // Pre-skip is specified at 48kHz regardless of the decode rate
+        let skip = (stream.pre_skip as usize * sample_rate as usize / 48000).min(pcm.len());
+        pcm.drain(..skip);
+        Ok(pcm)
+    }
 
-            // Update the sender for incoming packets
-            *self.packet_tx.lock().unwrap() = Some(tx);
+    /// Offline run of the audio pipeline, see `AudioEngine::run_benchmark`. Each synthetic
+    /// talker is a voice-like harmonic tone with a syllable-rate envelope and some noise,
+    /// encoded once and looped, so only the per-frame work is timed.
+    fn run_benchmark(config: &AudioConfig, params: &DspParams, peers: u32, seconds: u32) -> Result<BenchmarkReport, AudioError> {
+        let rate = config.sample_rate;
+        let samples_per_frame = (rate / 1000 * config.frame_size_ms) as usize;
+        let frames = (seconds * 1000 / config.frame_size_ms.max(1) as u32) as usize;
+        let clip_frames = (1000 / config.frame_size_ms.max(1)).max(1) as usize; // About a second
 
-            // Give receiver to the callback (it owns the map now)
-            let callback = OutputCallback {
-                peers: HashMap::new(),
-                packet_rx: rx,
-                sample_rate: self.config.sample_rate,
-                max_jitter_packets: (self.config.jitter_buffer_ms / self.config.frame_size_ms) as usize,
-                error_callback: self.error_callback.clone(),
-            };
+        let mut encoder = Encoder::new(map_sample_rate(rate), Channels::Mono, Application::Voip)
+            .map_err(|_| AudioError::EncoderError)?;
+        let _ = encoder.set_bitrate(map_bitrate(config.bitrate_bps));
+        let _ = encoder.set_complexity(map_complexity(config.opus_complexity));
+        let _ = encoder.set_vbr(config.use_vbr);
 
-            let mut builder = AudioStreamBuilder::default()
-                .set_direction::<Output>()
-                .set_performance_mode(PerformanceMode::None)
-                .set_sharing_mode(SharingMode::Shared)
-                .set_format::<i16>()
-                .set_channel_count::<Mono>()
-                .set_sample_rate(self.config.sample_rate)
-                .set_usage(Usage::VoiceCommunication);
-
-            if self.config.output_device_id != 0 {
-                log::info!("Output: Explicit Device ID {}", self.config.output_device_id);
-                builder = builder.set_device_id(self.config.output_device_id);
-            }
-
-            let mut stream = builder
-                .set_callback(callback)
-                .open_stream()
-                .map_err(|e| {
-                    log::error!("Open Output Stream Error: {}", e);
-                    AudioError::DeviceError
-                })?;
-
-            stream.start().map_err(|_| AudioError::DeviceError)?;
-            *self.output_stream.lock().unwrap() = Some(stream);
-            Ok(())
+        let mut noise = 0x2545_f491u32;
+        let mut synthetic = |peer: u32, i: usize| {
+            let t = i as f32 / rate as f32;
+            let f0 = 110.0 + 20.0 * peer as f32;
+            let envelope = 0.5 - 0.5 * (2.0 * std::f32::consts::PI * 4.0 * t).cos();
+            let voiced: f32 = (1..=5).map(|k| (2.0 * std::f32::consts::PI * f0 * k as f32 * t).sin() / k as f32).sum();
+            noise = noise.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let hiss = (noise >> 16) as f32 / 32768.0 - 1.0;
+            ((0.25 * envelope * voiced + 0.02 * hiss) * i16::MAX as f32) as i16
+        };
+
+        let mut talkers = Vec::with_capacity(peers as usize);
+        for peer in 0..peers {
+            let mut packets = Vec::with_capacity(clip_frames);
+            for frame in 0..clip_frames {
+                let pcm: Vec<i16> = (0..samples_per_frame).map(|i| synthetic(peer, frame * samples_per_frame + i)).collect();
+                let mut packet = [0u8; OPUS_OUT_BUFFER_SIZE];
+                let len = encoder.encode(&pcm, &mut packet).map_err(|_| AudioError::EncoderError)?;
+                packets.push(packet[..len].to_vec());
+            }
+            let decode_rate = native_decode_rate(&packets[0], rate);
+            let decoder = Decoder::new(map_sample_rate(decode_rate), Channels::Mono)
+                .map_err(|_| AudioError::DecoderError)?;
+            talkers.push((packets, decoder, Resampler::new(decode_rate, rate)));
         }
-    }
+        let mic: Vec<i16> = (0..samples_per_frame * clip_frames).map(|i| synthetic(peers, i)).collect();
 
-    // --- Callbacks ---
+        let mut echo_canceller = config.echo_cancellation.then(|| EchoCanceller::new(rate));
+        let mut noise_suppressor = if config.noise_suppression { NoiseSuppressor::new(rate, samples_per_frame) } else { None };
+        let mut agc = config.agc_enabled.then(|| {
+            AutomaticGainControl::new(params.get(DspParam::AgcTargetLevelDb), params.get(DspParam::AgcMaxGainDb))
+        });
+        let mut limiter = Limiter::new(rate);
 
-    struct InputCallback {
-        encoder: Encoder,
-        sequence_number: Arc<Mutex<u16>>,
-        tx_transport: StdSender<Vec<u8>>,
-        buffer: [i16; MAX_BUFFER_SIZE],
-        buffer_pos: usize,
-        samples_per_frame: usize,
-        is_mic_enabled: Arc<AtomicBool>,
-        own_node_id: u32,
-        error_callback: Arc<Box<dyn AudioErrorCallback>>,
-    }
+        const STAGES: [&str; 8] = ["aec", "ns", "agc", "encode", "decode", "resample", "mix", "limiter"];
+        let mut spent = [Duration::ZERO; STAGES.len()];
+        let mut timed = |stage: usize, started: Instant| spent[stage] += started.elapsed();
 
-    impl AudioInputCallback for InputCallback {
-        type FrameType = (i16, Mono);
+        let mut frame_buffer = vec![0i16; samples_per_frame];
+        let mut far = vec![0i16; samples_per_frame];
+        let mut native = [0i16; MAX_BUFFER_SIZE];
+        let mut resampled = [0i16; MAX_BUFFER_SIZE];
+        let mut mix = vec![0i32; samples_per_frame];
+        let mut packet = [0u8; OPUS_OUT_BUFFER_SIZE];
+        for frame in 0..frames {
+            let clip_frame = frame % clip_frames;
 
-        fn on_audio_ready(&mut self, _stream: &mut dyn AudioInputStreamSafe, frames: &[i16]) -> DataCallbackResult {
-            // 1. Copy data into our local buffer
-            for &sample in frames {
-                if self.buffer_pos < MAX_BUFFER_SIZE {
-                    self.buffer[self.buffer_pos] = sample;
-                    self.buffer_pos += 1;
+            // Capture: our own voice, with the previous mix as the echo reference
+            frame_buffer.copy_from_slice(&mic[clip_frame * samples_per_frame..][..samples_per_frame]);
+            let started = Instant::now();
+            if let Some(aec) = &mut echo_canceller {
+                aec.process(&mut frame_buffer, &far);
+            }
+            timed(0, started);
+            let started = Instant::now();
+            if let Some(ns) = &mut noise_suppressor {
+                ns.process(&mut frame_buffer, params.get(DspParam::NsStrength));
+            }
+            timed(1, started);
+            let started = Instant::now();
+            if let Some(agc) = &mut agc {
+                agc.process(&mut frame_buffer);
+            }
+            timed(2, started);
+            let started = Instant::now();
+            let _ = encoder.encode(&frame_buffer, &mut packet);
+            timed(3, started);
+
+            // Playback: every peer decoded, resampled and summed
+            mix.fill(0);
+            for (packets, decoder, resampler) in &mut talkers {
+                let started = Instant::now();
+                let native_len = decoder.decode(&packets[clip_frame], &mut native, false).unwrap_or(0);
+                timed(4, started);
+                let started = Instant::now();
+                let len = resampler.process(&native[..native_len], &mut resampled);
+                timed(5, started);
+                let started = Instant::now();
+                for (mixed, &sample) in mix.iter_mut().zip(&resampled[..len]) {
+                    *mixed += sample as i32;
                 }
+                timed(6, started);
             }
+            let started = Instant::now();
+            limiter.process(&mut mix, params.get(DspParam::LimiterThresholdDbfs));
+            timed(7, started);
+            for (reference, &sample) in far.iter_mut().zip(&mix) {
+                *reference = sample.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+            }
+        }
 
-            // 2. Process full frames
-            while self.buffer_pos >= self.samples_per_frame {
-                // Check the Gate!
-                // If false, we process the buffer (to clear it) but DO NOT encode/send.
-                let should_send = self.is_mic_enabled.load(Ordering::Relaxed);
+        let audio_seconds = (frames * samples_per_frame) as f64 / rate as f64;
+        let processing_seconds: f64 = spent.iter().map(Duration::as_secs_f64).sum();
+        Ok(BenchmarkReport {
+            peers,
+            sample_rate: rate,
+            frame_size_ms: config.frame_size_ms,
+            audio_seconds,
+            processing_seconds,
+            realtime_factor: audio_seconds / processing_seconds.max(1e-9),
+            stages: STAGES.iter().zip(spent).map(|(name, spent)| BenchmarkStage {
+                name: name.to_string(),
+                total_ms: spent.as_secs_f64() * 1000.0,
+                per_frame_us: spent.as_secs_f64() * 1e6 / frames.max(1) as f64,
+            }).collect(),
+        })
+    }
+
+    fn map_bitrate(bps: i32) -> Bitrate {
+        if bps > 0 { Bitrate::Custom(bps) } else { Bitrate::Auto }
+    }
+
+    fn map_complexity(complexity: i32) -> Complexity {
+        Complexity::new(complexity.clamp(0, 10) as u32)
+    }
+
+    /// The devices backing the chosen route (0 = let the system pick).
+    #[derive(Clone, Copy, PartialEq)]
+    struct RouteSelection {
+        route: AudioRoute,
+        output_device_id: i32,
+        input_device_id: i32,
+    }
+
+    /// First route in `preference` with a connected output device. Headsets bring
+    /// their own mic; the built-in routes keep the default one.
+    fn select_route(preference: &[AudioRoute]) -> Option<RouteSelection> {
+        let outputs = device::list_devices(true)
+            .map_err(|e| log::warn!("Listing output devices failed: {}", e))
+            .ok()?;
+        let inputs = device::list_devices(false).unwrap_or_default();
+        let device_for = |devices: &[device::DeviceInfo], route| {
+            devices.iter().find(|device| device.route == Some(route)).map(|device| device.id)
+        };
+
+        preference.iter().find_map(|&route| {
+            let output_device_id = device_for(&outputs, route)?;
+            let input_device_id = match route {
+                AudioRoute::BluetoothSco | AudioRoute::Wired => device_for(&inputs, route).unwrap_or(0),
+                AudioRoute::Earpiece | AudioRoute::Speaker => 0,
+            };
+            Some(RouteSelection { route, output_device_id, input_device_id })
+        })
+    }
+
+    fn max_packet_bytes(config: &AudioConfig) -> u32 {
+        config.max_packet_bytes.max(MAX_PACKET_BYTES_MIN)
+    }
+
+    fn replay_capacity(config: &AudioConfig) -> usize {
+        config.sample_rate.max(0) as usize * config.replay_buffer_secs.min(REPLAY_MAX_SECS) as usize
+    }
+
+    /// Scales `buf` by a gain that moves linearly from `*current` to `target`
+    /// over the buffer, so volume and mute changes don't click.
+    fn apply_gain_ramp(buf: &mut [i32], current: &mut f32, target: f32) {
+        if *current == target && target == 1.0 {
+            return;
+        }
+        simd::gain_ramp(buf, *current, target);
+        *current = target;
+    }
+
+    /// Same as `apply_gain_ramp` for the mic signal, saturating instead of clipping later.
+    fn apply_input_gain(buf: &mut [i16], current: &mut f32, target: f32) {
+        if *current == target && target == 1.0 {
+            return;
+        }
+        let step = (target - *current) / buf.len().max(1) as f32;
+        for sample in buf.iter_mut() {
+            *current += step;
+            *sample = (*sample as f32 * *current).clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        }
+        *current = target;
+    }
+
+    fn pipeline_node(name: &str, active: bool, params: &[(&str, String)]) -> PipelineNode {
+        PipelineNode {
+            name: name.to_string(),
+            active,
+            params: params.iter().map(|(key, value)| (key.to_string(), value.clone())).collect(),
+        }
+    }
+
+    // --- Core Logic ---
+
+    /// Where alert PCM is handed to the running output callback.
+    type AlertSlot = Mutex<Option<Sender<Arc<[i16]>>>>;
+    /// Where an audio callback hands PCM to a recording writer.
+    type RecordingSlot<M = PcmBlock> = Mutex<Option<Sender<M>>>;
+
+    /// PCM on its way to a recording writer. Fixed-size, so the audio callbacks
+    /// hand it over without allocating: the bounded queue's slots are allocated
+    /// when the recording starts.
+    struct PcmBlock {
+        samples: [i16; MAX_BUFFER_SIZE],
+        len: usize,
+    }
+
+    impl PcmBlock {
+        /// Queues `pcm` in as many blocks as it takes. What doesn't fit in the queue
+        /// is dropped (a gap in the file).
+        fn send<M: From<PcmBlock>>(tx: &Sender<M>, pcm: impl IntoIterator<Item = i16>) {
+            let mut pcm = pcm.into_iter().peekable();
+            while pcm.peek().is_some() {
+                let mut block = PcmBlock { samples: [0; MAX_BUFFER_SIZE], len: 0 };
+                for (slot, sample) in block.samples.iter_mut().zip(pcm.by_ref()) {
+                    *slot = sample;
+                    block.len += 1;
+                }
+                if tx.try_send(block.into()).is_err() { return; }
+            }
+        }
+
+        fn samples(&self) -> &[i16] {
+            &self.samples[..self.len]
+        }
+    }
+
+    /// What the output callback hands the archive writer (`run_archiver`).
+    #[allow(clippy::large_enum_variant)] // Boxing the PCM would allocate on the audio thread
+    enum ArchiveInput {
+        Started(u32),
+        Stopped(u32),
+        Pcm(PcmBlock),
+    }
+
+    impl From<PcmBlock> for ArchiveInput {
+        fn from(block: PcmBlock) -> Self {
+            ArchiveInput::Pcm(block)
+        }
+    }
+
+    /// A file writer thread fed through a bounded queue (see `start_recording`).
+    struct Recorder<T = (), M = PcmBlock> {
+        tx: Arc<RecordingSlot<M>>,
+        thread: Mutex<Option<thread::JoinHandle<T>>>,
+    }
+
+    impl<T, M> Default for Recorder<T, M> {
+        fn default() -> Self {
+            Self { tx: Arc::default(), thread: Mutex::default() }
+        }
+    }
+
+    impl<T, M> Recorder<T, M> {
+        fn start(&self, tx: Sender<M>, thread: thread::JoinHandle<T>) {
+            *self.thread.lock().unwrap() = Some(thread);
+            *self.tx.lock().unwrap() = Some(tx);
+        }
+
+        /// Drops the sender, which ends the writer's loop, then waits for the output to
+        /// be finished. Returns what the writer returned; `None` if nothing was recording.
+        fn stop(&self) -> Option<T> {
+            self.tx.lock().unwrap().take();
+            let thread = self.thread.lock().unwrap().take();
+            thread.and_then(|thread| thread.join().ok())
+        }
+    }
+    /// The thread that hands outgoing packets to the app's transport (`run_transport`).
+    /// Every part of the engine holds a sender, so the queue never closes by itself.
+    struct TransportThread {
+        thread: Mutex<Option<thread::JoinHandle<()>>>,
+    }
+
+    impl TransportThread {
+        /// Lets the thread send what is queued, then stops it, waiting up to
+        /// THREAD_JOIN_TIMEOUT_MS.
+        fn stop(&self, tx: &StdSender<Outbound>) {
+            let _ = tx.send(Outbound::stop());
+            if let Some(thread) = self.thread.lock().unwrap().take()
+                && !join_timeout(thread, Duration::from_millis(THREAD_JOIN_TIMEOUT_MS)) {
+                log::warn!("Transport thread still in send_packet, left to finish on its own");
+            }
+        }
+    }
+
+    #[derive(uniffi::Object)]
+    pub struct AudioEngine {
+        input_stream: Arc<Mutex<Option<CaptureStream>>>, // Shared with mic failover
+        output_stream: Arc<Mutex<Option<PlaybackStream>>>, // Shared with the watchdog
+        tx_transport: StdSender<Outbound>,
+        transport_thread: TransportThread,
+        shut_down: AtomicBool,
+        _tracked: Tracked,
+        packet_tx: Arc<PacketSlot>,
+        sequence_number: Arc<Mutex<u32>>,
+        config: Mutex<AudioConfig>,
+        // Live reconfiguration: `update_config` hands new configs to the running callbacks
+        input_config_tx: Mutex<Option<Sender<AudioConfig>>>,
+        output_config_tx: Mutex<Option<Sender<AudioConfig>>>,
+        is_mic_enabled: Arc<AtomicBool>,
+        ptt_button: Mutex<PttButton>, // Debounce and toggle state for `on_accessory_ptt`
+        own_node_id: Arc<AtomicU32>, // Replaced by `resume_session`
+        error_callback: Arc<ErrorAggregator>,
+        dispatcher: Arc<CallbackDispatcher>,
+        far_end: Arc<Mutex<FarEndBuffer>>,
+        // Pull mode: the host drives these pipelines instead of the device streams
+        pull_input: Mutex<Option<InputCallback>>,
+        pull_output: Mutex<Option<OutputCallback>>,
+        // Ducking: commands go to the output callback, which owns the windows
+        duck_tx: Mutex<Option<Sender<DuckCommand>>>,
+        flush_tx: Mutex<Option<Sender<u32>>>, // Peers to flush, see `flush_peer`
+        // Per-peer settings, kept for new sessions; changes also go to the running one
+        peer_concealment: Mutex<BTreeMap<u32, ConcealmentMode>>, // Peers not on `Auto`
+        peer_priorities: Mutex<BTreeMap<u32, u8>>,
+        muted_peers: Mutex<BTreeSet<u32>>,
+        peer_settings_tx: Mutex<Option<Sender<(u32, PeerSetting)>>>,
+        local_priority: Arc<AtomicU8>,
+        output_clock: Arc<AtomicU64>, // Samples rendered in the current session
+        next_window_id: AtomicU32,
+        params: Arc<DspParams>,
+        param_callback: CallbackSlot<dyn DspParamCallback>,
+        param_generation: Arc<AtomicU64>, // Bumped on every change; supersedes pending probations
+        stall_count: Arc<AtomicU64>,
+        input_failovers: Arc<AtomicU64>,
+        // Network handover grace period (`notify_network_change`)
+        handover: Arc<AtomicBool>,
+        handover_generation: Arc<AtomicU64>, // A newer change extends the grace period
+        handover_jitter_base: Arc<Mutex<Option<f32>>>, // Jitter target from before the grace period
+        vad_callback: CallbackSlot<dyn VoiceActivityCallback>,
+        transmit_callback: CallbackSlot<dyn TransmitCallback>,
+        transmit_granted_at: Arc<AtomicU64>, // Output clock sample the mic opens at (talk permit tone)
+        emergency: Arc<AtomicBool>, // Transmitting an emergency (`start_emergency_transmission`)
+        transmit_gate: CallbackSlot<dyn TransmitGate>,
+        self_monitor: Arc<AtomicBool>, // `set_loopback_enabled`
+        transmit_quota_ms: Arc<AtomicU64>, // 0 = no quota
+        bandwidth_budget_bps: Arc<AtomicU32>, // 0 = no budget
+        bandwidth_meter: Mutex<RateMeter>,
+        // Alerts: decoded once, handed to the output callback by reference
+        clip_cache: Mutex<ClipCache>,
+        alert_tx: Arc<AlertSlot>,
+        recording: Recorder,     // Received mix, Ogg/Opus
+        archive: Recorder<(), ArchiveInput>, // Received mix while someone talks, segmented
+        archive_dir: Mutex<Option<PathBuf>>, // Of the last start_archive, for searches
+        mic_recording: Recorder, // Outgoing audio, WAV
+        voice_message: Recorder<Option<Vec<u8>>>, // In-memory Ogg/Opus
+        replay_history: Arc<Mutex<MixHistory>>,
+        local_position: Arc<Mutex<Option<PeerPosition>>>,
+        position_callback: CallbackSlot<dyn PeerPositionCallback>,
+        output_volume: Arc<AtomicU32>, // f32 bits
+        sidetone: Arc<Mutex<SidetoneBuffer>>,
+        sidetone_gain: Arc<AtomicU32>, // f32 bits, 0 = off
+        secondary_output_stream: Mutex<Option<PlaybackStream>>,
+        secondary_mix: Arc<Mutex<SidetoneBuffer>>, // The mix on its way to the second output
+        secondary_gain: Arc<AtomicU32>,            // f32 bits
+        input_gain_db: Arc<AtomicU32>, // f32 bits
+        receive_muted: Arc<AtomicBool>,
+        privacy_mode: Arc<AtomicBool>,
+        privacy_quiet: Mutex<Option<Quiet>>, // Silences our info/debug logging in privacy mode
+        peer_event_callback: CallbackSlot<dyn PeerEventCallback>,
+        traffic: Arc<TrafficCounters>,
+        peer_stats: Arc<Mutex<Vec<PeerStats>>>, // Published by the output callback
+        talk_stats: Arc<Mutex<BTreeMap<u32, TalkTally>>>, // Same, for the whole session
+        peer_levels: Arc<Mutex<Vec<PeerLevel>>>, // Same, every LEVEL_PUBLISH_MS
+        mic_level: Arc<Mutex<Option<MicLevel>>>, // Published by the input callback
+        level_callback: CallbackSlot<dyn AudioLevelCallback>,
+        peer_delays: Arc<Mutex<HashMap<u32, u32>>>, // Debug: artificial delay (ms) per peer
+        route_preference: Mutex<Vec<AudioRoute>>,
+        route: Mutex<Option<RouteSelection>>, // None = system default devices
+        route_callback: CallbackSlot<dyn AudioRouteCallback>,
+        sco_callback: CallbackSlot<dyn BluetoothScoCallback>,
+        sco_needed: AtomicBool, // Last state reported through `sco_callback`
+        watchdog_generation: Arc<AtomicU64>, // Bumped to stop the running watchdog
+        loopback: Arc<LoopbackProbe>,
+        health_callback: CallbackSlot<dyn OutputHealthCallback>,
+        transport_callback: CallbackSlot<dyn TransportHealthCallback>,
+        stale_audio_ms: Arc<AtomicU32>, // 0 = never drop queued audio
+        group_cipher: Arc<CipherSlot>,
+        key_epoch: Arc<AtomicU32>, // Nonce epoch for our packets; bumped when the sequence wraps
+        resumed: AtomicBool, // The next session keeps the sequence number from `resume_session`
+        key_agreement: Arc<Mutex<KeyAgreement>>,
+        inbound: Arc<Inbound>,
+        #[cfg(feature = "udp-transport")]
+        udp_receiver: Mutex<Option<crate::udp::UdpReceiver>>,
+        #[cfg(feature = "net-sim")]
+        network_impairment: crate::netsim::ImpairmentSlot,
+    }
+
+    // --- RESOURCE CLEANUP ---
+    impl Drop for AudioEngine {
+        fn drop(&mut self) {
+            // Automatically cleanup when the object is destroyed
+            self.shutdown();
+        }
+    }
+
+    #[uniffi::export]
+    impl AudioEngine {
+        /// Fails with `InvalidParameter` (reason logged) for a config the engine
+        /// can't run, see `ConfigBuilder::validate`.
+        #[uniffi::constructor]
+        pub fn new(
+            config: AudioConfig,
+            transport: Box<dyn PacketTransport>,
+            callback: Box<dyn AudioErrorCallback>,
+            own_node_id: u32
+        ) -> Result<Self, AudioError> {
+            check_config(&config)?;
+            // Detect the CPU (and pick kernels) here rather than on the first audio callback
+            simd::cpu();
+            let (tx, rx): (StdSender<Outbound>, StdReceiver<Outbound>) = channel();
+            let dispatcher = Arc::new(CallbackDispatcher::new(config.dedicated_callback_thread));
+            let traffic = Arc::new(TrafficCounters::default());
+            let transport_callback: CallbackSlot<dyn TransportHealthCallback> = Arc::new(Mutex::new(None));
+            let stale_audio_ms = Arc::new(AtomicU32::new(stale_audio_ms(&config)));
+            #[cfg(feature = "net-sim")]
+            let (transport, network_impairment): (Box<dyn PacketTransport>, _) = {
+                let simulator = crate::netsim::NetworkSimulator::new(transport);
+                let link = simulator.link();
+                (Box::new(simulator), link)
+            };
+
+            let transport_thread = {
+                let dispatcher = dispatcher.clone();
+                let traffic = traffic.clone();
+                let transport_callback = transport_callback.clone();
+                let stale_audio_ms = stale_audio_ms.clone();
+                let thread = resources::spawn(move || {
+                    run_transport(rx, transport, &dispatcher, &transport_callback, &traffic, &stale_audio_ms)
+                });
+                TransportThread { thread: Mutex::new(Some(thread)) }
+            };
+
+            let packet_tx = Arc::new(Mutex::new(None));
+            let own_node_id = Arc::new(AtomicU32::new(own_node_id));
+            let group_cipher = Arc::new(Mutex::new(None));
+            let key_agreement = Arc::new(Mutex::new(KeyAgreement::new()));
+            let is_mic_enabled = Arc::new(AtomicBool::new(false));
+            let inbound = Arc::new(Inbound {
+                packet_tx: packet_tx.clone(),
+                group_cipher: group_cipher.clone(),
+                key_agreement: key_agreement.clone(),
+                own_node_id: own_node_id.clone(),
+                tx_transport: tx.clone(),
+                key_callback: Arc::new(Mutex::new(None)),
+                roster: Mutex::new(RosterSync::new()),
+                roster_callback: Arc::new(Mutex::new(None)),
+                dispatcher: dispatcher.clone(),
+                last_handshake_request: Mutex::new(None),
+                replay_guard: Mutex::new(ReplayGuard::new()),
+                traffic: traffic.clone(),
+                peer_capabilities: Mutex::new(BTreeMap::new()),
+                common_capabilities: Arc::new(AtomicU32::new(0)),
+                last_capabilities_request: Mutex::new(None),
+                max_packet_bytes: AtomicU32::new(max_packet_bytes(&config)),
+                floor: Mutex::new(FloorControl::new()),
+                floor_callback: Arc::new(Mutex::new(None)),
+                floor_open: Arc::new(AtomicBool::new(true)),
+                is_mic_enabled: is_mic_enabled.clone(),
+                emergencies: Mutex::new(HashMap::new()),
+                emergency_callback: Arc::new(Mutex::new(None)),
+                channel: Arc::new(AtomicU16::new(0)),
+                loops: Arc::new(Mutex::new(LoopDetector::new())),
+                loop_callback: Arc::new(Mutex::new(None)),
+                scan_channels: Mutex::new(Vec::new()),
+                scan_activity: Mutex::new(HashMap::new()),
+                scan_callback: Arc::new(Mutex::new(None)),
+                priority_channel: Mutex::new(None),
+                blocked: Mutex::new(BTreeSet::new()),
+                legacy: Mutex::new(LegacyPeers::default()),
+            });
+            inbound.configure_floor(&config);
+
+            Ok(Self {
+                input_stream: Arc::new(Mutex::new(None)),
+                output_stream: Arc::new(Mutex::new(None)),
+                tx_transport: tx,
+                transport_thread,
+                shut_down: AtomicBool::new(false),
+                _tracked: Tracked::new(Resource::Engine),
+                packet_tx,
+                sequence_number: Arc::new(Mutex::new(0)),
+                config: Mutex::new(config),
+                input_config_tx: Mutex::new(None),
+                output_config_tx: Mutex::new(None),
+                is_mic_enabled,
+                ptt_button: Mutex::new(PttButton::new()),
+                own_node_id,
+                error_callback: Arc::new(ErrorAggregator::new(callback, dispatcher.clone())),
+                dispatcher,
+                far_end: Arc::new(Mutex::new(FarEndBuffer::new())),
+                pull_input: Mutex::new(None),
+                pull_output: Mutex::new(None),
+                duck_tx: Mutex::new(None),
+                flush_tx: Mutex::new(None),
+                peer_concealment: Mutex::new(BTreeMap::new()),
+                peer_priorities: Mutex::new(BTreeMap::new()),
+                muted_peers: Mutex::new(BTreeSet::new()),
+                peer_settings_tx: Mutex::new(None),
+                local_priority: Arc::new(AtomicU8::new(0)),
+                output_clock: Arc::new(AtomicU64::new(0)),
+                next_window_id: AtomicU32::new(1),
+                params: Arc::new(DspParams::new(&config)),
+                param_callback: Arc::new(Mutex::new(None)),
+                param_generation: Arc::new(AtomicU64::new(0)),
+                stall_count: Arc::new(AtomicU64::new(0)),
+                input_failovers: Arc::new(AtomicU64::new(0)),
+                handover: Arc::new(AtomicBool::new(false)),
+                handover_generation: Arc::new(AtomicU64::new(0)),
+                handover_jitter_base: Arc::new(Mutex::new(None)),
+                vad_callback: Arc::new(Mutex::new(None)),
+                transmit_callback: Arc::new(Mutex::new(None)),
+                transmit_granted_at: Arc::new(AtomicU64::new(0)),
+                emergency: Arc::new(AtomicBool::new(false)),
+                transmit_gate: Arc::new(Mutex::new(None)),
+                self_monitor: Arc::new(AtomicBool::new(false)),
+                transmit_quota_ms: Arc::new(AtomicU64::new(0)),
+                bandwidth_budget_bps: Arc::new(AtomicU32::new(0)),
+                bandwidth_meter: Mutex::new(RateMeter::new()),
+                clip_cache: Mutex::new(ClipCache::new()),
+                alert_tx: Arc::new(Mutex::new(None)),
+                recording: Recorder::default(),
+                archive: Recorder::default(),
+                archive_dir: Mutex::new(None),
+                mic_recording: Recorder::default(),
+                voice_message: Recorder::default(),
+                replay_history: Arc::new(Mutex::new(MixHistory::new(replay_capacity(&config)))),
+                local_position: Arc::new(Mutex::new(None)),
+                position_callback: Arc::new(Mutex::new(None)),
+                output_volume: Arc::new(AtomicU32::new(1f32.to_bits())),
+                sidetone: Arc::new(Mutex::new(SidetoneBuffer::new())),
+                sidetone_gain: Arc::new(AtomicU32::new(0f32.to_bits())),
+                secondary_output_stream: Mutex::new(None),
+                secondary_mix: Arc::new(Mutex::new(SidetoneBuffer::new())),
+                secondary_gain: Arc::new(AtomicU32::new(config.secondary_output.map_or(1.0, |output| output.gain).to_bits())),
+                input_gain_db: Arc::new(AtomicU32::new(0f32.to_bits())),
+                receive_muted: Arc::new(AtomicBool::new(false)),
+                privacy_mode: Arc::new(AtomicBool::new(false)),
+                privacy_quiet: Mutex::new(None),
+                peer_event_callback: Arc::new(Mutex::new(None)),
+                traffic,
+                peer_stats: Arc::new(Mutex::new(Vec::new())),
+                talk_stats: Arc::new(Mutex::new(BTreeMap::new())),
+                peer_levels: Arc::new(Mutex::new(Vec::new())),
+                mic_level: Arc::new(Mutex::new(None)),
+                level_callback: Arc::new(Mutex::new(None)),
+                peer_delays: Arc::new(Mutex::new(HashMap::new())),
+                route_preference: Mutex::new(DEFAULT_ROUTE_PREFERENCE.to_vec()),
+                route: Mutex::new(None),
+                route_callback: Arc::new(Mutex::new(None)),
+                sco_callback: Arc::new(Mutex::new(None)),
+                sco_needed: AtomicBool::new(false),
+                watchdog_generation: Arc::new(AtomicU64::new(0)),
+                loopback: Arc::new(LoopbackProbe::default()),
+                health_callback: Arc::new(Mutex::new(None)),
+                transport_callback,
+                stale_audio_ms,
+                group_cipher,
+                key_epoch: Arc::new(AtomicU32::new(next_epoch(0))),
+                resumed: AtomicBool::new(false),
+                key_agreement,
+                inbound,
+                #[cfg(feature = "udp-transport")]
+                udp_receiver: Mutex::new(None),
+                #[cfg(feature = "net-sim")]
+                network_impairment,
+            })
+        }
+
+        /// Starts BOTH Input and Output streams.
+        /// Call this when joining a group.
+        pub fn start_session(&self) -> Result<(), AudioError> {
+            if self.shut_down.load(Ordering::Relaxed) { return Err(AudioError::ShutDown); }
+            info!("Starting Audio Session (Rate: {}Hz)...", self.config().sample_rate);
+            self.begin_sequence();
+            self.talk_stats.lock().unwrap().clear();
+            self.traffic.begin_session();
+            self.inbound.announce_capabilities(true);
+            self.refresh_route();
+            self.start_output_stream()?;
+            self.start_secondary_output_stream();
+            self.start_input_stream()?;
+            if self.config().output_watchdog {
+                self.spawn_output_watchdog();
+            }
+            Ok(())
+        }
+
+        /// Starts the session WITHOUT opening any audio device.
+        /// The host owns the audio graph and drives I/O through
+        /// `submit_capture_frames` and `read_render_frames`.
+        pub fn start_pull_session(&self) -> Result<(), AudioError> {
+            if self.shut_down.load(Ordering::Relaxed) { return Err(AudioError::ShutDown); }
+            info!("Starting Pull-Mode Session (Rate: {}Hz)...", self.config().sample_rate);
+            self.begin_sequence();
+            self.talk_stats.lock().unwrap().clear();
+            self.traffic.begin_session();
+            self.inbound.announce_capabilities(true);
+            let output = self.build_output_callback();
+            let input = self.build_input_callback()?;
+            *self.pull_output.lock().unwrap() = Some(output);
+            *self.pull_input.lock().unwrap() = Some(input);
+            Ok(())
+        }
+
+        /// Pull mode: feeds captured mono PCM (at `config.sample_rate`) into the send path.
+        pub fn submit_capture_frames(&self, frames: Vec<i16>) {
+            self.capture_frames(&frames);
+        }
+
+        /// Pull mode: renders the next `num_frames` samples of the receive mix.
+        /// Returns silence if no pull session is active.
+        pub fn read_render_frames(&self, num_frames: u32) -> Vec<i16> {
+            let mut frames = vec![0i16; num_frames as usize];
+            self.render_frames(&mut frames);
+            frames
+        }
+
+        /// Dips the channel audio by `depth_db` for `duration_ms`, starting `start_in_ms` from now
+        /// (e.g. while the app plays a navigation prompt). A `duration_ms` of 0 keeps the window
+        /// open until `remove_attenuation_window`. Returns a handle for the window.
+        pub fn add_attenuation_window(&self, depth_db: f32, start_in_ms: u32, duration_ms: u32) -> u32 {
+            let id = self.next_window_id.fetch_add(1, Ordering::Relaxed);
+            let rate = self.config().sample_rate.max(0) as u64;
+            let now = self.output_clock.load(Ordering::Relaxed);
+            let start_sample = now + start_in_ms as u64 * rate / 1000;
+            let end_sample = (duration_ms > 0).then(|| start_sample + duration_ms as u64 * rate / 1000);
+            let gain = 10f32.powf(-depth_db.abs() / 20.0);
+
+            if let Some(tx) = &*self.duck_tx.lock().unwrap() {
+                let _ = tx.send(DuckCommand::Add { id, start_sample, end_sample, gain });
+            }
+            id
+        }
+
+        /// Ends an attenuation window early (or closes an open-ended one). The gain ramps back up.
+        pub fn remove_attenuation_window(&self, window_id: u32) {
+            if let Some(tx) = &*self.duck_tx.lock().unwrap() {
+                let _ = tx.send(DuckCommand::Remove { id: window_id });
+            }
+        }
+
+        /// Changes a DSP tunable on the fly (e.g. "agc.target_level_db", "jitter.max_packets").
+        /// Fails with `InvalidParameter` for unknown names or out-of-range values.
+        pub fn set_dsp_param(&self, name: String, value: f32) -> Result<(), AudioError> {
+            let param = DspParams::lookup(&name).ok_or(AudioError::InvalidParameter)?;
+            if !self.params.set(param, value) {
+                log::warn!("Rejected DSP param {} = {}", name, value);
+                return Err(AudioError::InvalidParameter);
+            }
+            info!("DSP param {} = {}", name, value);
+            self.param_generation.fetch_add(1, Ordering::Relaxed);
+            self.dispatcher.notify(&self.param_callback, move |callback| callback.on_dsp_param_changed(name, value));
+            Ok(())
+        }
+
+        /// Returns the current value of a DSP tunable, or `None` if the name is unknown.
+        pub fn get_dsp_param(&self, name: String) -> Option<f32> {
+            DspParams::lookup(&name).map(|param| self.params.get(param))
+        }
+
+        /// Applies a whole set of DSP tunables atomically: either every value is valid and
+        /// all are applied, or nothing changes. The snapshot then runs on probation and is
+        /// rolled back automatically if playout stalls spike.
+        pub fn apply_param_snapshot(&self, snapshot: HashMap<String, f32>) -> Result<(), AudioError> {
+            let values = snapshot.iter()
+                .map(|(name, &value)| DspParams::lookup(name).map(|param| (param, value)))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(AudioError::InvalidParameter)?;
+            let previous = self.params.apply_snapshot(&values).ok_or_else(|| {
+                log::warn!("Rejected DSP param snapshot ({} entries)", values.len());
+                AudioError::InvalidParameter
+            })?;
+            info!("Applied DSP param snapshot ({} entries), probation started", values.len());
+
+            let changed = values.clone();
+            self.dispatcher.notify(&self.param_callback, move |callback| {
+                for (param, value) in changed {
+                    callback.on_dsp_param_changed(DspParams::name(param).to_string(), value);
+                }
+            });
+
+            // Probation: a watcher thread compares the stall counter against the baseline
+            let generation = self.param_generation.fetch_add(1, Ordering::Relaxed) + 1;
+            let current_generation = self.param_generation.clone();
+            let stall_count = self.stall_count.clone();
+            let baseline = stall_count.load(Ordering::Relaxed);
+            let params = self.params.clone();
+            let param_callback = self.param_callback.clone();
+            let dispatcher = self.dispatcher.clone();
+
+            resources::spawn(move || {
+                let deadline = Instant::now() + Duration::from_millis(PARAM_PROBATION_MS);
+                while Instant::now() < deadline {
+                    thread::sleep(Duration::from_millis(PARAM_PROBATION_POLL_MS));
+                    if current_generation.load(Ordering::Relaxed) != generation {
+                        return; // A newer change superseded this snapshot
+                    }
+                    let stalls = stall_count.load(Ordering::Relaxed) - baseline;
+                    if stalls > PARAM_PROBATION_MAX_STALLS {
+                        log::warn!("DSP param snapshot caused {} stalls. Rolling back.", stalls);
+                        params.apply_snapshot(&previous);
+                        current_generation.fetch_add(1, Ordering::Relaxed);
+                        dispatcher.notify(&param_callback, move |callback| {
+                            for (param, value) in previous {
+                                callback.on_dsp_param_changed(DspParams::name(param).to_string(), value);
+                            }
+                            callback.on_param_snapshot_rolled_back(stalls as u32);
+                        });
+                        return;
+                    }
+                }
+                info!("DSP param snapshot passed probation");
+            });
+            Ok(())
+        }
+
+        /// Registers the listener notified after every successful `set_dsp_param`.
+        pub fn set_dsp_param_callback(&self, callback: Box<dyn DspParamCallback>) {
+            *self.param_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener for local speaking/silent transitions (requires `vad_enabled`).
+        pub fn set_voice_activity_callback(&self, callback: Box<dyn VoiceActivityCallback>) {
+            *self.vad_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener told when a transmission actually starts.
+        pub fn set_transmit_callback(&self, callback: Box<dyn TransmitCallback>) {
+            *self.transmit_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Installs the app's per-frame transmit policy, replacing any previous one.
+        /// Frames it rejects are dropped before they get a sequence number.
+        pub fn set_transmit_gate(&self, gate: Box<dyn TransmitGate>) {
+            *self.transmit_gate.lock().unwrap() = Some(gate);
+        }
+
+        /// Removes the transmit policy: every encoded frame is sent again.
+        pub fn clear_transmit_gate(&self) {
+            self.transmit_gate.lock().unwrap().take();
+        }
+
+        /// Decodes an Ogg/Opus alert clip and caches its PCM so `play_alert` starts instantly.
+        /// Re-using an `id` replaces the previous clip. Older clips are evicted if the cache is full.
+        pub fn preload_clip(&self, id: String, ogg_bytes: Vec<u8>) -> Result<(), AudioError> {
+            let pcm = decode_ogg_opus(&ogg_bytes, self.config().sample_rate)?;
+            let samples = pcm.len();
+            if !self.clip_cache.lock().unwrap().insert(id.clone(), pcm) {
+                log::warn!("Clip '{}' is larger than the whole clip cache", id);
+                return Err(AudioError::InvalidParameter);
+            }
+            info!("Preloaded clip '{}' ({} samples)", id, samples);
+            Ok(())
+        }
+
+        /// Up to the last `seconds` of the received mix (see `AudioConfig.replay_buffer_secs`),
+        /// as mono PCM at the session sample rate. Taken before receive mute and ducking.
+        pub fn get_replay(&self, seconds: u32) -> Vec<i16> {
+            let len = self.config().sample_rate.max(0) as usize * seconds as usize;
+            self.replay_history.lock().unwrap().latest(len)
+        }
+
+        /// Plays back up to the last `seconds` of the received mix, like an alert.
+        pub fn play_replay(&self, seconds: u32) -> Result<(), AudioError> {
+            let pcm = self.get_replay(seconds);
+            if pcm.is_empty() {
+                return Err(AudioError::InvalidParameter);
+            }
+            if let Some(tx) = &*self.alert_tx.lock().unwrap() {
+                let _ = tx.send(pcm.into());
+            }
+            Ok(())
+        }
+
+        /// Plays a preloaded clip on top of the channel mix (not affected by ducking).
+        pub fn play_alert(&self, id: String) -> Result<(), AudioError> {
+            let clip = self.clip_cache.lock().unwrap().get(&id).ok_or(AudioError::InvalidParameter)?;
+            if let Some(tx) = &*self.alert_tx.lock().unwrap() {
+                let _ = tx.send(clip);
+            }
+            Ok(())
+        }
+
+        pub fn unload_clip(&self, id: String) {
+            self.clip_cache.lock().unwrap().remove(&id);
+        }
+
+        /// Memory currently held by pre-decoded clips, in bytes.
+        pub fn clip_cache_bytes(&self) -> u64 {
+            self.clip_cache.lock().unwrap().bytes() as u64
+        }
+
+        /// Applies a new config to the running session without stopping it.
+        /// Encoder settings (bitrate, frame size), DSP toggles and jitter targets are
+        /// swapped in by the audio callbacks themselves. Only a sample rate or device
+        /// change reopens the affected stream(s), which is briefly audible.
+        /// A config `new` would refuse is refused here too, leaving the old one in place.
+        pub fn update_config(&self, config: AudioConfig) -> Result<(), AudioError> {
+            check_config(&config)?;
+            let old = std::mem::replace(&mut *self.config.lock().unwrap(), config);
+            self.params.apply_config(&old, &config);
+            self.dispatcher.set_dedicated(config.dedicated_callback_thread);
+            self.stale_audio_ms.store(stale_audio_ms(&config), Ordering::Relaxed);
+            self.inbound.max_packet_bytes.store(max_packet_bytes(&config), Ordering::Relaxed);
+            self.inbound.configure_floor(&config);
+
+            let rate_changed = config.sample_rate != old.sample_rate;
+            if rate_changed {
+                self.far_end.lock().unwrap().clear();
+                // Recordings are fixed to the old rate
+                self.stop_recording();
+                self.stop_archive();
+                self.stop_mic_recording();
+                self.voice_message.stop();
+                self.replay_history.lock().unwrap().clear();
+            }
+            if rate_changed || config.replay_buffer_secs != old.replay_buffer_secs {
+                self.replay_history.lock().unwrap().set_capacity(replay_capacity(&config));
+            }
+            if config.output_watchdog != old.output_watchdog && self.output_stream.lock().unwrap().is_some() {
+                if config.output_watchdog {
+                    self.spawn_output_watchdog();
+                } else {
+                    self.watchdog_generation.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            if let Some(output) = config.secondary_output {
+                self.secondary_gain.store(output.gain.to_bits(), Ordering::Relaxed);
+            }
+            let restart_input = rate_changed || config.input_device_id != old.input_device_id;
+            let restart_output = rate_changed || config.output_device_id != old.output_device_id;
+
+            // Pull mode has no devices; only the rate forces new pipelines
+            if rate_changed && self.pull_output.lock().unwrap().is_some() {
+                info!("Sample rate changed: rebuilding pull pipelines");
+                let output = self.build_output_callback();
+                let input = self.build_input_callback()?;
+                *self.pull_output.lock().unwrap() = Some(output);
+                *self.pull_input.lock().unwrap() = Some(input);
+                return Ok(());
+            }
+
+            if !(restart_output && self.reopen_output_stream("new config")?)
+                && let Some(tx) = &*self.output_config_tx.lock().unwrap() {
+                let _ = tx.send(config);
+            }
+            if !(restart_input && self.reopen_input_stream("new config")?)
+                && let Some(tx) = &*self.input_config_tx.lock().unwrap() {
+                let _ = tx.send(config);
+            }
+            let secondary_device = |config: &AudioConfig| config.secondary_output.map(|output| output.device_id);
+            if (rate_changed || secondary_device(&config) != secondary_device(&old)) && self.output_stream.lock().unwrap().is_some() {
+                self.stop_secondary_output_stream();
+                self.start_secondary_output_stream();
+            }
+            if config.routing_mode != old.routing_mode && self.output_stream.lock().unwrap().is_some() {
+                self.notify_route_changed()?;
+            }
+            Ok(())
+        }
+
+        /// Changes the Opus bitrate of the running encoder, e.g. 8-12 kbps on a poor link
+        /// or 32 kbps on Wi-Fi, without restarting the session. Takes effect from the next
+        /// frame and is kept in the config. 0 lets the encoder choose.
+        pub fn set_encoder_bitrate(&self, bits_per_sec: i32) -> Result<(), AudioError> {
+            if bits_per_sec != 0 && !OPUS_BITRATE_RANGE.contains(&bits_per_sec) {
+                return Err(AudioError::InvalidParameter);
+            }
+            let mut config = self.config.lock().unwrap();
+            config.bitrate_bps = bits_per_sec;
+            if let Some(tx) = &*self.input_config_tx.lock().unwrap() {
+                let _ = tx.send(*config);
+            }
+            Ok(())
+        }
+
+        /// Order in which the engine tries routes when picking devices (first connected
+        /// one wins, the system default if none is). A nonzero `input_device_id` /
+        /// `output_device_id` in the config still overrides it. Applies immediately.
+        pub fn set_route_preference(&self, order: Vec<AudioRoute>) -> Result<(), AudioError> {
+            info!("Route preference: {:?}", order);
+            *self.route_preference.lock().unwrap() = order;
+            if self.output_stream.lock().unwrap().is_some() {
+                self.notify_route_changed()?;
+            }
+            Ok(())
+        }
+
+        /// Call when Android reports audio devices added or removed. Re-walks the route
+        /// preference and reopens the streams if a different device is now preferred.
+        pub fn notify_route_changed(&self) -> Result<(), AudioError> {
+            let previous = *self.route.lock().unwrap();
+            let current = self.refresh_route();
+            let config = self.config();
+            let restart_output = config.output_device_id == 0
+                && previous.map(|r| r.output_device_id) != current.map(|r| r.output_device_id);
+            let restart_input = config.input_device_id == 0
+                && previous.map(|r| r.input_device_id) != current.map(|r| r.input_device_id);
+            if restart_output {
+                self.reopen_output_stream("new route")?;
+            }
+            if restart_input {
+                self.reopen_input_stream("new route")?;
+            }
+            Ok(())
+        }
+
+        /// Call when the device switches networks (e.g. Wi-Fi to cellular). For a grace
+        /// period the jitter buffer aims deeper and the encoder adds more in-band FEC,
+        /// so the burst of late and lost packets around the switch doesn't break up the
+        /// audio; afterwards both go back to normal and the buffer drains back to its
+        /// usual depth. A jitter target changed by the app meanwhile is left alone.
+        pub fn notify_network_change(&self, kind: NetworkKind) {
+            info!("Network changed to {:?}, easing the handover for {}ms", kind, HANDOVER_GRACE_MS);
+            let generation = self.handover_generation.fetch_add(1, Ordering::Relaxed) + 1;
+            let frame_ms = self.config().frame_size_ms.max(1);
+            let extra_packets = (HANDOVER_JITTER_EXTRA_MS + frame_ms - 1) / frame_ms;
+            let boosted = {
+                let mut base = self.handover_jitter_base.lock().unwrap();
+                let base = *base.get_or_insert(self.params.get(DspParam::JitterMinPackets));
+                let boosted = (base + extra_packets as f32).min(self.params.get(DspParam::JitterMaxPackets));
+                self.params.set(DspParam::JitterMinPackets, boosted);
+                boosted
+            };
+            self.handover.store(true, Ordering::Relaxed);
+
+            let current_generation = self.handover_generation.clone();
+            let handover = self.handover.clone();
+            let jitter_base = self.handover_jitter_base.clone();
+            let params = self.params.clone();
+            resources::spawn(move || {
+                thread::sleep(Duration::from_millis(HANDOVER_GRACE_MS));
+                if current_generation.load(Ordering::Relaxed) != generation {
+                    return; // A later change restarted the grace period
+                }
+                if let Some(base) = jitter_base.lock().unwrap().take()
+                    && params.get(DspParam::JitterMinPackets) == boosted {
+                    params.set(DspParam::JitterMinPackets, base);
+                }
+                handover.store(false, Ordering::Relaxed);
+                info!("Handover grace period over");
+            });
+        }
+
+        /// Moves playback to device `device_id` (0 = back to the route
+        /// preference / system default) without stopping the session: only the
+        /// output stream is reopened, so the mic keeps sending. If the new device
+        /// fails to open, the previous one is restored and `DeviceError` returned.
+        pub fn set_output_device(&self, device_id: i32) -> Result<(), AudioError> {
+            if device_id < 0 {
+                return Err(AudioError::InvalidParameter);
+            }
+            let previous = std::mem::replace(&mut self.config.lock().unwrap().output_device_id, device_id);
+            if previous == device_id {
+                return Ok(());
+            }
+            info!("Output device {} -> {}", previous, device_id);
+            self.reopen_output_stream("new output device").map(|_| ()).or_else(|e| {
+                self.config.lock().unwrap().output_device_id = previous;
+                self.reopen_output_stream("failed device switch")?;
+                Err(e)
+            })
+        }
+
+        /// Same as `set_output_device` for the mic: only the input stream is
+        /// reopened, so peers keep playing.
+        pub fn set_input_device(&self, device_id: i32) -> Result<(), AudioError> {
+            if device_id < 0 {
+                return Err(AudioError::InvalidParameter);
+            }
+            let previous = std::mem::replace(&mut self.config.lock().unwrap().input_device_id, device_id);
+            if previous == device_id {
+                return Ok(());
+            }
+            info!("Input device {} -> {}", previous, device_id);
+            self.reopen_input_stream("new input device").map(|_| ()).or_else(|e| {
+                self.config.lock().unwrap().input_device_id = previous;
+                self.reopen_input_stream("failed device switch")?;
+                Err(e)
+            })
+        }
+
+        /// Registers the listener told when the app's `send_packet` blocks.
+        pub fn set_transport_health_callback(&self, callback: Box<dyn TransportHealthCallback>) {
+            *self.transport_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener for the output watchdog (see `AudioConfig.output_watchdog`).
+        pub fn set_output_health_callback(&self, callback: Box<dyn OutputHealthCallback>) {
+            *self.health_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener told which route the engine picked.
+        pub fn set_audio_route_callback(&self, callback: Box<dyn AudioRouteCallback>) {
+            *self.route_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener told when to start and stop Bluetooth SCO.
+        pub fn set_bluetooth_sco_callback(&self, callback: Box<dyn BluetoothScoCallback>) {
+            *self.sco_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Plays a `freq_hz` tone for `duration_ms` (up to TEST_TONE_MAX_MS), so a settings
+        /// screen can let the user confirm which speaker or headset the engine uses. With
+        /// no `route` it goes where the session plays: through the running output stream,
+        /// or outside a session to the device the route preference picks. A given `route`
+        /// must be connected. Returns the route the tone plays on, if known.
+        pub fn play_test_tone(&self, route: Option<AudioRoute>, freq_hz: f32, duration_ms: u32) -> Result<Option<AudioRoute>, AudioError> {
+            let config = self.config();
+            if !(freq_hz > 0.0 && freq_hz < config.sample_rate as f32 / 2.0) || duration_ms == 0 || duration_ms > TEST_TONE_MAX_MS {
+                return Err(AudioError::InvalidParameter);
+            }
+            let tone = tone_burst(freq_hz, config.sample_rate, duration_ms, TEST_TONE_LEVEL_DBFS, TEST_TONE_FADE_MS);
+
+            if route.is_none() && let Some(tx) = &*self.alert_tx.lock().unwrap() {
+                let _ = tx.send(tone.into());
+                let current = self.route.lock().unwrap().filter(|_| config.output_device_id == 0);
+                return Ok(current.map(|selection| selection.route));
+            }
+
+            // Not what the session plays through: a temporary stream just for the tone
+            let (device_id, played_on) = match route {
+                Some(route) => (select_route(&[route]).ok_or(AudioError::DeviceError)?.output_device_id, Some(route)),
+                None if config.output_device_id != 0 => (config.output_device_id, None),
+                None => select_route(&self.route_preference.lock().unwrap())
+                    .map_or((0, None), |selection| (selection.output_device_id, Some(selection.route))),
+            };
+            info!("Test tone: {}Hz for {}ms on {:?} (device {})", freq_hz, duration_ms, played_on, device_id);
+
+            let spec = StreamSpec { sample_rate: config.sample_rate, device_id, usage: Usage::Voice };
+            let stream = device::open_playback(spec, ToneCallback { tone, pos: 0 }, ()).map_err(|e| {
+                log::error!("Open Test Tone Stream Error: {}", e);
+                AudioError::DeviceError
+            })?;
+            resources::spawn(move || {
+                thread::sleep(Duration::from_millis(duration_ms as u64 + TEST_TONE_DRAIN_MS));
+                stream.close();
+            });
+            Ok(played_on)
+        }
+
+        /// Master volume for everything we play (peers and alerts). 1.0 = unity.
+        pub fn set_output_volume(&self, volume: f32) {
+            if volume.is_nan() {
+                return;
+            }
+            let volume = volume.clamp(0.0, MAX_OUTPUT_VOLUME);
+            self.output_volume.store(volume.to_bits(), Ordering::Relaxed);
+        }
+
+        /// Lets the user hear their own mic while transmitting (headset users
+        /// otherwise hear nothing of themselves). `gain` is relative to the captured
+        /// level, 0.0-1.0; 0 turns the sidetone off. It bypasses the send pipeline's
+        /// frame assembly and DSP, so it lags by about one audio buffer.
+        pub fn set_sidetone_gain(&self, gain: f32) {
+            if gain.is_nan() {
+                return;
+            }
+            let gain = gain.clamp(0.0, MAX_SIDETONE_GAIN);
+            self.sidetone_gain.store(gain.to_bits(), Ordering::Relaxed);
+            if gain == 0.0 {
+                self.sidetone.lock().unwrap().clear();
+            }
+        }
+
+        /// Gain applied to our outgoing audio right before encoding (after AGC), in dB,
+        /// clamped to MIN_INPUT_GAIN_DB..=MAX_INPUT_GAIN_DB. 0 = unchanged. Lets
+        /// operators even out transmit loudness across different devices.
+        pub fn set_input_gain(&self, db: f32) {
+            if db.is_nan() {
+                return;
+            }
+            let db = db.clamp(MIN_INPUT_GAIN_DB, MAX_INPUT_GAIN_DB);
+            self.input_gain_db.store(db.to_bits(), Ordering::Relaxed);
+            info!("Input gain: {}dB", db);
+        }
+
+        /// Reports our software gain next to what the platform does to the mic signal
+        /// before we get it, which `set_input_gain` can't see or undo.
+        pub fn get_input_gain_report(&self) -> InputGainReport {
+            let preset = self.input_stream.lock().unwrap().as_ref().and_then(|stream| stream.preset());
+            InputGainReport {
+                software_gain_db: f32::from_bits(self.input_gain_db.load(Ordering::Relaxed)),
+                software_agc: self.config().agc_enabled,
+                platform_processing: preset.as_ref().is_some_and(|(_, processing)| *processing),
+                platform_preset: preset.map(|(name, _)| name),
+            }
+        }
+
+        /// Silences incoming peer audio without stopping the output stream.
+        /// Alerts still play; peers keep being decoded so unmuting is instant.
+        pub fn set_receive_muted(&self, muted: bool) {
+            self.receive_muted.store(muted, Ordering::Relaxed);
+            info!("Receive {}", if muted { "MUTED" } else { "UNMUTED" });
+        }
+
+        /// Engine-wide data minimization. While enabled the engine attaches no
+        /// position metadata to outgoing audio, keeps no talk history, wipes keys when
+        /// the session stops, and only logs warnings and errors (its own lines only; the
+        /// process-wide log level is left to the app).
+        /// Anything that would persist or record activity must check `is_privacy_mode`.
+        pub fn set_privacy_mode(&self, enabled: bool) {
+            if enabled {
+                log::warn!("Privacy mode ENABLED");
+                *self.privacy_quiet.lock().unwrap() = Some(Quiet::new());
+                self.stop_recording();
+                self.stop_archive();
+                self.stop_mic_recording();
+                self.voice_message.stop();
+            } else {
+                self.privacy_quiet.lock().unwrap().take();
+                log::warn!("Privacy mode DISABLED");
+            }
+            self.privacy_mode.store(enabled, Ordering::Relaxed);
+            if enabled {
+                // After the flag, so the output callback can't add to them again
+                self.replay_history.lock().unwrap().clear();
+                self.talk_stats.lock().unwrap().clear();
+            }
+        }
+
+        /// Starts archiving the received mix (all peers, before receive mute, ducking
+        /// and alerts) to an Ogg/Opus file at `path`, replacing any running recording.
+        /// Encoding happens on a writer thread. Refused in privacy mode; enabling it,
+        /// stopping the session or changing the sample rate ends the recording.
+        pub fn start_recording(&self, path: String) -> Result<(), AudioError> {
+            if self.privacy_mode.load(Ordering::Relaxed) {
+                return Err(AudioError::InvalidParameter);
+            }
+            self.stop_recording();
+
+            let rate = map_sample_rate(self.config().sample_rate);
+            let file = File::create(&path).map_err(|e| {
+                log::error!("Recording: cannot create {}: {}", path, e);
+                AudioError::RecordingError
+            })?;
+            let output = OggOpusEncoder::new(BufWriter::new(file), rate)?;
+
+            let (tx, rx) = bounded(RECORDING_QUEUE_BUFFERS);
+            self.recording.start(tx, resources::spawn(move || {
+                run_recorder(rx, output, usize::MAX);
+            }));
+            info!("Recording STARTED");
+            Ok(())
+        }
+
+        /// Finishes the file of a running recording (returns once it is complete).
+        pub fn stop_recording(&self) {
+            if self.recording.stop().is_some() {
+                info!("Recording STOPPED");
+            }
+        }
+
+        /// Starts archiving the channel to the directory `dir`, which is created if
+        /// needed. Only voice activity is kept: the received mix, taken as for
+        /// `start_recording`, goes to a new Ogg/Opus segment file whenever a peer
+        /// starts talking while nobody else does, and the segment ends when the last
+        /// talker stops. Each transmission is indexed for `search_archive`; an
+        /// archive from an earlier session is continued. The same privacy rules as
+        /// `start_recording` apply.
+        pub fn start_archive(&self, dir: String) -> Result<(), AudioError> {
+            if self.privacy_mode.load(Ordering::Relaxed) {
+                return Err(AudioError::InvalidParameter);
+            }
+            self.stop_archive();
+
+            let dir = PathBuf::from(dir);
+            std::fs::create_dir_all(&dir).map_err(|e| {
+                log::error!("Archive: cannot create {}: {}", dir.display(), e);
+                AudioError::RecordingError
+            })?;
+            let next_segment = archive::read_index(&dir).iter().map(|entry| entry.segment + 1).max().unwrap_or(0);
+            *self.archive_dir.lock().unwrap() = Some(dir.clone());
+
+            let rate = map_sample_rate(self.config().sample_rate);
+            let (tx, rx) = bounded(RECORDING_QUEUE_BUFFERS);
+            self.archive.start(tx, resources::spawn(move || run_archiver(rx, dir, rate, next_segment)));
+            info!("Archive STARTED");
+            Ok(())
+        }
+
+        /// Closes the segment being written and indexes it (returns once that is done).
+        pub fn stop_archive(&self) {
+            if self.archive.stop().is_some() {
+                info!("Archive STOPPED");
+            }
+        }
+
+        /// The transmissions in the archive of the last `start_archive` that overlap
+        /// `from_ms..to_ms` (Unix time), from `peer_id` or anyone, oldest first.
+        pub fn search_archive(&self, peer_id: Option<u32>, from_ms: u64, to_ms: u64) -> Vec<ArchiveSegment> {
+            let Some(dir) = self.archive_dir.lock().unwrap().clone() else { return Vec::new() };
+            archive::search(archive::read_index(&dir), peer_id, from_ms, to_ms)
+        }
+
+        /// Cuts a transmission found by `search_archive` out of its segment, as an
+        /// Ogg/Opus file like a voice message (`play_voice_message` plays it).
+        pub fn export_archive_clip(&self, segment: ArchiveSegment) -> Result<Vec<u8>, AudioError> {
+            let dir = self.archive_dir.lock().unwrap().clone().ok_or(AudioError::InvalidParameter)?;
+            let path = dir.join(archive::segment_file(segment.segment));
+            let ogg_bytes = std::fs::read(&path).map_err(|e| {
+                log::error!("Archive: cannot read {}: {}", path.display(), e);
+                AudioError::RecordingError
+            })?;
+            let rate = map_sample_rate(self.config().sample_rate);
+            let pcm = decode_ogg_opus(&ogg_bytes, rate.as_i32())?;
+            let to_samples = |ms: u32| (ms as u64 * rate.as_i32() as u64 / 1000).min(pcm.len() as u64) as usize;
+            let start = to_samples(segment.offset_ms);
+            let end = to_samples(segment.offset_ms.saturating_add(segment.duration_ms));
+
+            let mut output = OggOpusEncoder::new(Vec::new(), rate)?;
+            output.push(&pcm[start..end])
+                .and_then(|_| output.finish())
+                .map_err(|_| AudioError::EncoderError)
+        }
+
+        /// Starts capturing our own outgoing audio to a 16-bit WAV file at `path`, as
+        /// it goes into the encoder (after echo cancellation, noise suppression and AGC;
+        /// only while transmitting). Replaces any running mic recording. Written on a
+        /// writer thread. The same privacy rules as `start_recording` apply.
+        pub fn start_mic_recording(&self, path: String) -> Result<(), AudioError> {
+            if self.privacy_mode.load(Ordering::Relaxed) {
+                return Err(AudioError::InvalidParameter);
+            }
+            self.stop_mic_recording();
+
+            let file = File::create(&path).map_err(|e| {
+                log::error!("Mic recording: cannot create {}: {}", path, e);
+                AudioError::RecordingError
+            })?;
+            let writer = WavWriter::new(BufWriter::new(file), self.config().sample_rate as u32)
+                .map_err(|_| AudioError::RecordingError)?;
+
+            let (tx, rx) = bounded(RECORDING_QUEUE_BUFFERS);
+            self.mic_recording.start(tx, resources::spawn(move || run_mic_recorder(rx, writer)));
+            info!("Mic recording STARTED");
+            Ok(())
+        }
+
+        /// Finishes the file of a running mic recording (returns once it is complete).
+        pub fn stop_mic_recording(&self) {
+            if self.mic_recording.stop().is_some() {
+                info!("Mic recording STOPPED");
+            }
+        }
+
+        /// Starts capturing a voice message, for when the recipient is offline. The mic
+        /// is processed as for transmission (AEC, NS, AGC) whether or not it is enabled,
+        /// but nothing is sent. A writer thread encodes it into an in-memory Ogg/Opus
+        /// blob of at most VOICE_MESSAGE_MAX_MS. Discards a message already being
+        /// captured. The same privacy rules as `start_recording` apply.
+        pub fn start_voice_message(&self) -> Result<(), AudioError> {
+            if self.privacy_mode.load(Ordering::Relaxed) {
+                return Err(AudioError::InvalidParameter);
+            }
+            self.voice_message.stop();
+
+            let rate = map_sample_rate(self.config().sample_rate);
+            let output = OggOpusEncoder::new(Vec::new(), rate)?;
+            let max_samples = (rate.as_i32() as u64 * VOICE_MESSAGE_MAX_MS as u64 / 1000) as usize;
+
+            let (tx, rx) = bounded(RECORDING_QUEUE_BUFFERS);
+            self.voice_message.start(tx, resources::spawn(move || run_recorder(rx, output, max_samples)));
+            info!("Voice message STARTED");
+            Ok(())
+        }
+
+        /// Ends the voice message and returns it as an Ogg/Opus file, ready for
+        /// `play_voice_message` on the recipient's side.
+        pub fn finish_voice_message(&self) -> Result<Vec<u8>, AudioError> {
+            let blob = self.voice_message.stop()
+                .ok_or(AudioError::InvalidParameter)? // Nothing being captured
+                .ok_or(AudioError::RecordingError)?;
+            info!("Voice message FINISHED ({} bytes)", blob.len());
+            Ok(blob)
+        }
+
+        /// Decodes a voice message and plays it on top of the channel mix, like an alert.
+        /// The channel is ducked by `AudioConfig.voice_message_duck_db` for exactly as
+        /// long as the message plays.
+        pub fn play_voice_message(&self, ogg_bytes: Vec<u8>) -> Result<(), AudioError> {
+            let config = self.config();
+            let pcm = decode_ogg_opus(&ogg_bytes, config.sample_rate)?;
+            let Some(tx) = &*self.alert_tx.lock().unwrap() else { return Ok(()) };
+            // Both start with the next output callback, so the window matches the message
+            if config.voice_message_duck_db > 0.0
+                && let Some(duck_tx) = &*self.duck_tx.lock().unwrap() {
+                let start_sample = self.output_clock.load(Ordering::Relaxed);
+                let _ = duck_tx.send(DuckCommand::Add {
+                    id: self.next_window_id.fetch_add(1, Ordering::Relaxed),
+                    start_sample,
+                    end_sample: Some(start_sample + pcm.len() as u64),
+                    gain: 10f32.powf(-config.voice_message_duck_db / 20.0),
+                });
+            }
+            let _ = tx.send(pcm.into());
+            Ok(())
+        }
+
+        pub fn is_privacy_mode(&self) -> bool {
+            self.privacy_mode.load(Ordering::Relaxed)
+        }
+
+        /// Sets the position attached to our transmissions (None stops sending it).
+        pub fn set_local_position(&self, position: Option<PeerPosition>) {
+            *self.local_position.lock().unwrap() = position;
+        }
+
+        /// Sets the 32-byte group key: audio is encrypted end-to-end (ChaCha20-Poly1305)
+        /// and unencrypted packets are rejected. An empty key turns encryption off.
+        pub fn set_group_key(&self, mut key: Vec<u8>) -> Result<(), AudioError> {
+            let cipher = if key.is_empty() {
+                None
+            } else {
+                let cipher = GroupCipher::new(&key);
+                key.zeroize();
+                Some(cipher.ok_or(AudioError::InvalidParameter)?)
+            };
+            info!("Payload encryption {}", if cipher.is_some() { "ENABLED" } else { "DISABLED" });
+            *self.group_cipher.lock().unwrap() = cipher;
+            self.inbound.replay_guard.lock().unwrap().clear(); // Index spaces change with the key
+            Ok(())
+        }
+
+        /// Loads our long-term X25519 identity key (from `generate_identity_key`) and
+        /// announces it. Peers then agree on per-peer keys and our audio is encrypted
+        /// under a fresh session key only they receive; audio from peers we have no
+        /// key for yet is dropped. Returns our public identity key. An empty key
+        /// turns key agreement off (the group key, if any, applies again).
+        pub fn set_identity_key(&self, mut key: Vec<u8>) -> Result<Vec<u8>, AudioError> {
+            self.inbound.replay_guard.lock().unwrap().clear(); // Index spaces change with the key
+            let mut key_agreement = self.key_agreement.lock().unwrap();
+            if key.is_empty() {
+                key_agreement.clear();
+                info!("Key agreement DISABLED");
+                return Ok(Vec::new());
+            }
+            let public_key = key_agreement.set_identity(&key);
+            key.zeroize();
+            let public_key = public_key.ok_or(AudioError::InvalidParameter)?;
+            let hello = key_agreement.hello(true);
+            drop(key_agreement);
+
+            info!("Key agreement ENABLED");
+            if let Some(hello) = hello {
+                let _ = self.tx_transport.send(Outbound::control(wrap_control(self.own_node_id.load(Ordering::Relaxed), CONTROL_HELLO, &hello)));
+            }
+            Ok(public_key.to_vec())
+        }
+
+        /// Registers the listener told when a peer's identity key has been verified.
+        pub fn set_peer_key_callback(&self, callback: Box<dyn PeerKeyCallback>) {
+            *self.inbound.key_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Wipes all key material: the group key, our identity key with every key agreed
+        /// with peers, and the roster with its admin. Audio goes out unencrypted until
+        /// keys are set again. `stop_session` does this by itself in privacy mode.
+        pub fn clear_keys(&self) {
+            *self.group_cipher.lock().unwrap() = None;
+            self.key_agreement.lock().unwrap().clear();
+            self.inbound.roster.lock().unwrap().clear();
+            self.inbound.replay_guard.lock().unwrap().clear(); // Index spaces change with the key
+            info!("Keys cleared");
+        }
+
+        /// Sets the identity key of the admin node, the only one allowed to publish the
+        /// trusted-peer roster. An empty key clears it. Changing the admin drops the
+        /// current roster.
+        pub fn set_roster_admin(&self, admin_identity_key: Vec<u8>) -> Result<(), AudioError> {
+            let admin = if admin_identity_key.is_empty() {
+                None
+            } else {
+                Some(<[u8; KEY_SIZE]>::try_from(admin_identity_key.as_slice()).map_err(|_| AudioError::InvalidParameter)?)
+            };
+            self.inbound.roster.lock().unwrap().set_admin(admin);
+            Ok(())
+        }
+
+        /// Admin only (our identity key must be the roster admin's): publishes a new
+        /// roster of trusted identity keys, at most 40 of 32 bytes each. Every peer we
+        /// have agreed keys with gets it now, later ones once key agreement completes.
+        /// `version` must be higher than the current one.
+        pub fn publish_roster(&self, version: u32, keys: Vec<Vec<u8>>) -> Result<(), AudioError> {
+            let keys = keys.iter()
+                .map(|key| <[u8; KEY_SIZE]>::try_from(key.as_slice()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| AudioError::InvalidParameter)?;
+            let own_id = self.own_node_id.load(Ordering::Relaxed);
+            let messages = {
+                let mut roster = self.inbound.roster.lock().unwrap();
+                let key_agreement = self.key_agreement.lock().unwrap();
+                if !roster.publish(&key_agreement, version, keys) {
+                    return Err(AudioError::InvalidParameter);
+                }
+                key_agreement.established_peers().into_iter()
+                    .filter_map(|peer| roster.message_for(&key_agreement, own_id, peer))
+                    .collect::<Vec<_>>()
+            };
+            info!("Published roster v{} to {} peer(s)", version, messages.len());
+            for message in messages {
+                let _ = self.tx_transport.send(Outbound::control(wrap_control(own_id, CONTROL_ROSTER, &message)));
+            }
+            self.inbound.notify_roster();
+            Ok(())
+        }
+
+        /// The current roster (version 0 and no keys until one is published or received).
+        pub fn get_roster(&self) -> PeerRoster {
+            self.inbound.roster_snapshot()
+        }
+
+        /// Registers the listener told when a newer roster arrives.
+        pub fn set_roster_callback(&self, callback: Box<dyn RosterCallback>) {
+            *self.inbound.roster_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// The node holding the floor (us included), if any (`AudioConfig.floor_control`).
+        pub fn get_floor_holder(&self) -> Option<u32> {
+            self.inbound.floor.lock().unwrap().holder_id()
+        }
+
+        /// Registers the listener told when we get, miss or lose the floor.
+        pub fn set_floor_callback(&self, callback: Box<dyn FloorCallback>) {
+            *self.inbound.floor_callback.lock().unwrap() = Some(callback);
+        }
+
+        pub fn set_emergency_callback(&self, callback: Box<dyn EmergencyCallback>) {
+            *self.inbound.emergency_callback.lock().unwrap() = Some(callback);
+        }
+
+        pub fn set_audio_loop_callback(&self, callback: Box<dyn AudioLoopCallback>) {
+            *self.inbound.loop_callback.lock().unwrap() = Some(callback);
+        }
+
+        pub fn set_scan_callback(&self, callback: Box<dyn ScanCallback>) {
+            *self.inbound.scan_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Snapshot of the state a restarted process needs to carry on as the same node:
+        /// node ID, sequence counter, encryption epoch (no key material), and the active,
+        /// scanned and priority channels. Refused in privacy mode, since the app would
+        /// have to persist it.
+        pub fn export_session_token(&self) -> Result<Vec<u8>, AudioError> {
+            if self.privacy_mode.load(Ordering::Relaxed) {
+                return Err(AudioError::InvalidParameter);
+            }
+            let scan_channels = self.inbound.scan_channels.lock().unwrap().clone();
+            let scan_channels = &scan_channels[..scan_channels.len().min(u16::MAX as usize)];
+            let priority = *self.inbound.priority_channel.lock().unwrap();
+            let mut token = vec![0u8; SESSION_TOKEN_SIZE + 2 * scan_channels.len()];
+            token[0] = SESSION_TOKEN_VERSION;
+            LittleEndian::write_u32(&mut token[1..5], self.own_node_id.load(Ordering::Relaxed));
+            LittleEndian::write_u32(&mut token[5..9], *self.sequence_number.lock().unwrap());
+            LittleEndian::write_u32(&mut token[9..13], self.key_epoch.load(Ordering::Relaxed));
+            LittleEndian::write_u16(&mut token[13..15], self.inbound.channel.load(Ordering::Relaxed));
+            token[15] = priority.is_some() as u8;
+            LittleEndian::write_u16(&mut token[16..18], priority.unwrap_or(0));
+            LittleEndian::write_u16(&mut token[18..20], scan_channels.len() as u16);
+            LittleEndian::write_u16_into(scan_channels, &mut token[SESSION_TOKEN_SIZE..]);
+            Ok(token)
+        }
+
+        /// Restores a token from `export_session_token` (call before starting a session).
+        /// The sequence number skips ahead and the epoch is bumped, so packets sent after
+        /// the export are never repeated and no nonce is reused. The channels are
+        /// restored as `set_active_channel`, `set_scan_channels` and
+        /// `set_priority_channel` would.
+        pub fn resume_session(&self, token: Vec<u8>) -> Result<(), AudioError> {
+            if self.is_session_active() {
+                return Err(AudioError::InvalidParameter);
+            }
+            let mut channels = None;
+            let (seq, epoch) = match (token.first(), token.len()) {
+                (Some(&SESSION_TOKEN_VERSION), len) if len >= SESSION_TOKEN_SIZE => {
+                    let scan_count = LittleEndian::read_u16(&token[18..20]) as usize;
+                    if len != SESSION_TOKEN_SIZE + 2 * scan_count {
+                        return Err(AudioError::InvalidParameter);
+                    }
+                    let mut scan_channels = vec![0u16; scan_count];
+                    LittleEndian::read_u16_into(&token[SESSION_TOKEN_SIZE..], &mut scan_channels);
+                    let priority = (token[15] != 0).then(|| LittleEndian::read_u16(&token[16..18]));
+                    channels = Some((LittleEndian::read_u16(&token[13..15]), scan_channels, priority));
+                    (LittleEndian::read_u32(&token[5..9]), LittleEndian::read_u32(&token[9..13]))
+                }
+                (Some(2), SESSION_TOKEN_SIZE_V2) => (LittleEndian::read_u32(&token[5..9]), LittleEndian::read_u32(&token[9..13])),
+                (Some(1), SESSION_TOKEN_SIZE_V1) => (LittleEndian::read_u16(&token[5..7]) as u32, LittleEndian::read_u32(&token[7..11])),
+                _ => return Err(AudioError::InvalidParameter),
+            };
+            let node_id = LittleEndian::read_u32(&token[1..5]);
+
+            if let Some((active, scan_channels, priority)) = channels {
+                self.set_active_channel(active);
+                self.set_scan_channels(scan_channels);
+                self.set_priority_channel(priority);
+            }
+            self.own_node_id.store(node_id, Ordering::Relaxed);
+            *self.sequence_number.lock().unwrap() = seq.wrapping_add(SESSION_RESUME_SEQ_ADVANCE);
+            self.key_epoch.store(epoch.wrapping_add(1), Ordering::Relaxed);
+            self.resumed.store(true, Ordering::Relaxed);
+            info!("Resumed session as node {}", node_id);
+            Ok(())
+        }
+
+        /// Connection quality: per-peer loss/jitter/PLC plus global traffic totals.
+        pub fn get_stats(&self) -> EngineStats {
+            EngineStats {
+                peers: self.peer_stats.lock().unwrap().clone(),
+                talk: if self.privacy_mode.load(Ordering::Relaxed) {
+                    Vec::new()
+                } else {
+                    self.talk_stats.lock().unwrap().iter().map(|(&id, tally)| tally.stats(id)).collect()
+                },
+                packets_sent: self.traffic.packets_sent.load(Ordering::Relaxed),
+                bytes_sent: self.traffic.bytes_sent.load(Ordering::Relaxed),
+                packets_received: self.traffic.packets_received.load(Ordering::Relaxed),
+                bytes_received: self.traffic.bytes_received.load(Ordering::Relaxed),
+                playout_stalls: self.stall_count.load(Ordering::Relaxed),
+                input_failovers: self.input_failovers.load(Ordering::Relaxed),
+                callbacks_dropped: self.dispatcher.dropped(),
+                packets_replayed: self.traffic.packets_replayed.load(Ordering::Relaxed),
+                packets_corrupted: self.traffic.packets_corrupted.load(Ordering::Relaxed),
+                packets_looped: self.traffic.packets_looped.load(Ordering::Relaxed),
+                packets_blocked: self.traffic.packets_blocked.load(Ordering::Relaxed),
+                packets_oversized: self.traffic.packets_oversized.load(Ordering::Relaxed),
+                packets_malformed: self.traffic.packets_malformed.load(Ordering::Relaxed),
+                packets_legacy: self.traffic.packets_legacy.load(Ordering::Relaxed),
+                packets_dropped_stale: self.traffic.packets_dropped_stale.load(Ordering::Relaxed),
+                frames_gated: self.traffic.frames_gated.load(Ordering::Relaxed),
+                frames_dtx: self.traffic.frames_dtx.load(Ordering::Relaxed),
+                session_transmit_seconds: self.traffic.session_transmit_ms.load(Ordering::Relaxed) as f64 / 1000.0,
+                session_transmit_bytes: self.traffic.session_transmit_bytes.load(Ordering::Relaxed),
+                transmit_quota_remaining_seconds: self.traffic
+                    .quota_remaining_ms(self.transmit_quota_ms.load(Ordering::Relaxed))
+                    .map(|ms| ms as f64 / 1000.0),
+                privacy_mode: self.privacy_mode.load(Ordering::Relaxed),
+            }
+        }
+
+        /// Runs the pipeline offline as fast as the CPU allows: our capture chain with the
+        /// current DSP settings plus decoding, resampling and mixing of `peers` (1-64)
+        /// synthetic talkers, for `seconds` (1-60) of audio. Blocks the caller until done,
+        /// so call it off the UI thread; a running session is not touched. Apps can run
+        /// it once per device to pick safe peer caps and DSP settings.
+        pub fn run_benchmark(&self, peers: u32, seconds: u32) -> Result<BenchmarkReport, AudioError> {
+            if !(1..=BENCHMARK_MAX_PEERS).contains(&peers) || !(1..=BENCHMARK_MAX_SECS).contains(&seconds) {
+                return Err(AudioError::InvalidParameter);
+            }
+            let report = run_benchmark(&self.config(), &self.params, peers, seconds)?;
+            info!("Benchmark: {} peers, {:.1}x realtime", peers, report.realtime_factor);
+            Ok(report)
+        }
+
+        /// The current config as the engine applies it, including which profile it came from.
+        pub fn describe_effective_config(&self) -> EffectiveConfig {
+            crate::config::describe_config(&self.config())
+        }
+
+        /// Every processing stage in signal order with its live settings, for tooling
+        /// and support to see exactly what runs on this device.
+        pub fn describe_pipeline(&self) -> PipelineGraph {
+            let config = self.config();
+            let mode = if self.output_stream.lock().unwrap().is_some() {
+                device::BACKEND
+            } else if self.pull_output.lock().unwrap().is_some() {
+                "pull"
+            } else {
+                "stopped"
+            };
+            let param = |param: DspParam| self.params.get(param).to_string();
+            let encryption = if self.key_agreement.lock().unwrap().is_active() {
+                "sender_key"
+            } else if self.group_cipher.lock().unwrap().is_some() {
+                "group_key"
+            } else {
+                "off"
+            };
+            let volume = f32::from_bits(self.output_volume.load(Ordering::Relaxed));
+            let input_gain_db = f32::from_bits(self.input_gain_db.load(Ordering::Relaxed));
+
+            let capture = vec![
+                pipeline_node("capture", true, &[
+                    ("device_id", config.input_device_id.to_string()),
+                    ("frame_size_ms", config.frame_size_ms.to_string()),
+                    ("mic_enabled", self.is_mic_enabled.load(Ordering::Relaxed).to_string()),
+                ]),
+                pipeline_node("aec", config.echo_cancellation, &[]),
+                pipeline_node("ns", config.noise_suppression && config.sample_rate == 48000, &[
+                    ("strength", param(DspParam::NsStrength)),
+                ]),
+                pipeline_node("vad", config.vad_enabled, &[("gate_transmit", config.vad_gate_transmit.to_string())]),
+                pipeline_node("agc", config.agc_enabled, &[
+                    ("target_level_db", param(DspParam::AgcTargetLevelDb)),
+                    ("max_gain_db", param(DspParam::AgcMaxGainDb)),
+                ]),
+                pipeline_node("input_gain", input_gain_db != 0.0, &[("gain_db", input_gain_db.to_string())]),
+                pipeline_node("encoder", true, &[
+                    ("codec", "opus".to_string()),
+                    ("bitrate_bps", if config.bitrate_bps > 0 { config.bitrate_bps.to_string() } else { "auto".to_string() }),
+                    ("complexity", config.opus_complexity.clamp(0, 10).to_string()),
+                    ("mode", if config.use_vbr { "vbr" } else { "cbr" }.to_string()),
+                ]),
+                pipeline_node("encryption", encryption != "off", &[("key", encryption.to_string())]),
+                pipeline_node("transport", true, &[
+                    ("protocol_version", PROTOCOL_VERSION.to_string()),
+                    ("packetization", if config.rtp_packetization && encryption == "off" { "rtp" } else { "native" }.to_string()),
+                    ("redundancy", config.redundancy.to_string()),
+                    ("padding", (config.pad_packets && encryption != "off").to_string()),
+                    ("position", (!self.privacy_mode.load(Ordering::Relaxed) && self.local_position.lock().unwrap().is_some()).to_string()),
+                    ("priority", self.local_priority.load(Ordering::Relaxed).to_string()),
+                    ("channel", self.inbound.channel.load(Ordering::Relaxed).to_string()),
+                ]),
+            ];
+            let playback = vec![
+                pipeline_node("receive", true, &[
+                    ("replay_window_packets", REPLAY_WINDOW_PACKETS.to_string()),
+                    ("debug_delayed_peers", self.peer_delays.lock().unwrap().len().to_string()),
+                ]),
+                pipeline_node("jitter", true, &[
+                    ("min_packets", param(DspParam::JitterMinPackets)),
+                    ("max_packets", param(DspParam::JitterMaxPackets)),
+                    ("reorder_wait_ms", config.reorder_wait_ms.to_string()),
+                    ("resync_gap_packets", config.resync_gap_packets.to_string()),
+                ]),
+                pipeline_node("decoder", true, &[("codec", "opus".to_string()), ("resample_to_hz", config.sample_rate.to_string())]),
+                pipeline_node("time_stretch", true, &[]),
+                pipeline_node("gain", true, &[("receive_muted", self.receive_muted.load(Ordering::Relaxed).to_string())]),
+                pipeline_node("comfort_noise", config.comfort_noise_dbfs.is_some(), &[
+                    ("level_dbfs", config.comfort_noise_dbfs.map_or("off".to_string(), |level| level.to_string())),
+                ]),
+                pipeline_node("ducking", true, &[]),
+                pipeline_node("preemption", config.preemption.is_some(), &[
+                    ("policy", config.preemption.map_or("off".to_string(), |policy| format!("{:?}", policy))),
+                    ("duck_db", config.preemption_duck_db.to_string()),
+                ]),
+                pipeline_node("mixer", true, &[]),
+                pipeline_node("alerts", true, &[]),
+                pipeline_node("volume", volume != 1.0, &[("volume", volume.to_string())]),
+                pipeline_node("limiter", true, &[("threshold_dbfs", param(DspParam::LimiterThresholdDbfs))]),
+                pipeline_node("device", true, &[
+                    ("device_id", config.output_device_id.to_string()),
+                    ("watchdog", config.output_watchdog.to_string()),
+                    ("route", self.route.lock().unwrap().map_or("default".to_string(), |r| format!("{:?}", r.route))),
+                ]),
+                pipeline_node("secondary_output", self.secondary_output_stream.lock().unwrap().is_some(), &[
+                    ("device_id", config.secondary_output.map_or("off".to_string(), |output| output.device_id.to_string())),
+                    ("gain", f32::from_bits(self.secondary_gain.load(Ordering::Relaxed)).to_string()),
+                ]),
+            ];
+            PipelineGraph { mode: mode.to_string(), sample_rate: config.sample_rate, capture, playback, cpu: simd::cpu().features() }
+        }
+
+        /// Registers the listener for peer talk/timeout events (who is speaking).
+        pub fn set_peer_event_callback(&self, callback: Box<dyn PeerEventCallback>) {
+            *self.peer_event_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener for peer positions, delivered in sync with their audio.
+        pub fn set_peer_position_callback(&self, callback: Box<dyn PeerPositionCallback>) {
+            *self.position_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// Registers the listener for per-peer levels, for speaking indicators.
+        pub fn set_audio_level_callback(&self, callback: Box<dyn AudioLevelCallback>) {
+            *self.level_callback.lock().unwrap() = Some(callback);
+        }
+
+        /// The latest per-peer levels, for polling instead of `AudioLevelCallback`.
+        pub fn get_peer_levels(&self) -> Vec<PeerLevel> {
+            self.peer_levels.lock().unwrap().clone()
+        }
+
+        /// The latest mic level, for polling instead of `AudioLevelCallback`. None
+        /// until a session has captured some audio.
+        pub fn get_mic_level(&self) -> Option<MicLevel> {
+            *self.mic_level.lock().unwrap()
+        }
+
+        /// Discards the packets buffered for `node_id` and restarts its playout, e.g.
+        /// when signaling says the peer restarted or seeked, instead of playing out
+        /// stale audio. Its next packet starts a new talk burst. Stats are kept.
+        pub fn flush_peer(&self, node_id: u32) {
+            if let Some(tx) = &*self.flush_tx.lock().unwrap() {
+                info!("Flushing peer {}", node_id);
+                let _ = tx.send(node_id);
+            }
+        }
+
+        /// How `node_id`'s lost frames are filled in. `Repeat` or `Silence` keep
+        /// background channels from spending decoder time the current speaker needs.
+        /// Kept across sessions; `Auto` (the default) clears it.
+        pub fn set_peer_concealment(&self, node_id: u32, mode: ConcealmentMode) {
+            let mut peer_concealment = self.peer_concealment.lock().unwrap();
+            if mode == ConcealmentMode::Auto {
+                peer_concealment.remove(&node_id);
+            } else {
+                peer_concealment.insert(node_id, mode);
+            }
+            self.send_peer_setting(node_id, PeerSetting::Concealment(mode));
+        }
+
+        /// Our priority, sent with our audio (0, the default, is left out). Receivers
+        /// with `AudioConfig.preemption` duck or mute lower-priority speakers while we
+        /// talk, so e.g. a dispatcher always gets through.
+        pub fn set_local_priority(&self, priority: u8) {
+            self.local_priority.store(priority, Ordering::Relaxed);
+            info!("Local priority: {}", priority);
+        }
+
+        /// Overrides the priority `node_id` announces, for our own mix only. `None`
+        /// goes back to the announced one. Kept across sessions.
+        pub fn set_peer_priority(&self, node_id: u32, priority: Option<u8>) {
+            let mut peer_priorities = self.peer_priorities.lock().unwrap();
+            match priority {
+                Some(priority) => peer_priorities.insert(node_id, priority),
+                None => peer_priorities.remove(&node_id),
+            };
+            self.send_peer_setting(node_id, PeerSetting::Priority(priority));
+        }
+
+        /// Silences `node_id` in our mix. Its audio is still received and decoded
+        /// (talk events and stats keep coming), so unmuting is instant. Kept across
+        /// sessions; see `block_peer` to drop it instead.
+        pub fn mute_peer(&self, node_id: u32) {
+            info!("Muted peer {}", node_id);
+            self.muted_peers.lock().unwrap().insert(node_id);
+            self.send_peer_setting(node_id, PeerSetting::Muted(true));
+        }
+
+        pub fn unmute_peer(&self, node_id: u32) {
+            self.muted_peers.lock().unwrap().remove(&node_id);
+            self.send_peer_setting(node_id, PeerSetting::Muted(false));
+        }
+
+        /// Drops audio from `node_id` as it arrives, before it is decrypted or takes
+        /// jitter buffer memory, and flushes what is already buffered. Its control
+        /// messages (floor, keys, roster) still get through. Counted in
+        /// `EngineStats.packets_blocked`. Kept across sessions.
+        pub fn block_peer(&self, node_id: u32) {
+            info!("Blocked peer {}", node_id);
+            self.inbound.blocked.lock().unwrap().insert(node_id);
+            self.flush_peer(node_id);
+        }
+
+        pub fn unblock_peer(&self, node_id: u32) {
+            self.inbound.blocked.lock().unwrap().remove(&node_id);
+        }
+
+        /// What each peer announced it supports (announced at session start, so
+        /// features can be turned on only once everyone understands them). Peers
+        /// silent for 3s are left out until they are heard again.
+        pub fn get_peer_capabilities(&self) -> Vec<PeerCapabilities> {
+            let mut peers = self.inbound.peer_capabilities.lock().unwrap();
+            self.inbound.expire_capabilities(&mut peers);
+            peers.values().map(|(capabilities, _)| capabilities.clone()).collect()
+        }
+
+        /// DEBUG: holds back `node_id`'s packets for `ms` before they reach its jitter
+        /// buffer, to demo one participant on a terrible link. 0 removes the delay.
+        pub fn debug_set_peer_delay(&self, node_id: u32, ms: u32) {
+            let ms = ms.min(DEBUG_MAX_PEER_DELAY_MS);
+            log::warn!("Debug delay for peer {}: {}ms", node_id, ms);
+            let mut delays = self.peer_delays.lock().unwrap();
+            if ms == 0 {
+                delays.remove(&node_id);
+            } else {
+                delays.insert(node_id, ms);
+            }
+        }
+
+        /// Stops BOTH streams.
+        /// Call this when leaving a group. In privacy mode all keys are wiped as well
+        /// (see `clear_keys`).
+        pub fn stop_session(&self) -> Result<(), AudioError> {
+            info!("Stopping Audio Session...");
+            // Now explicitly releases hardware immediately!
+            self.release_resources();
+            self.is_mic_enabled.store(false, Ordering::Relaxed);
+            self.emergency.store(false, Ordering::Relaxed);
+            if self.privacy_mode.load(Ordering::Relaxed) {
+                self.clear_keys();
+            }
+            self.inbound.forget_capabilities();
+            resources::debug_assert_session_released();
+            Ok(())
+        }
+
+        /// Counts of what the engines in this process hold (see `ResourceReport`), so
+        /// long-running deployments can check for slow leaks across sessions.
+        pub fn get_resource_report(&self) -> ResourceReport {
+            resources::report()
+        }
+
+        /// Ends the session and stops the engine's own threads: the transport thread,
+        /// the UDP receive thread and the callback thread. Each gets up to 1s; a
+        /// `send_packet` or callback still running after that is left to finish on its
+        /// own. Nothing is sent afterwards, and starting a session fails with `ShutDown`.
+        /// Dropping the engine does the same; this releases the threads at a known point
+        /// while the app may still hold a reference.
+        pub fn shutdown(&self) {
+            if self.shut_down.swap(true, Ordering::Relaxed) { return; }
+            info!("Shutting down the engine...");
+            self.release_resources();
+            self.is_mic_enabled.store(false, Ordering::Relaxed);
+            self.emergency.store(false, Ordering::Relaxed);
+            #[cfg(feature = "udp-transport")]
+            drop(self.udp_receiver.lock().unwrap().take());
+            self.transport_thread.stop(&self.tx_transport);
+            self.dispatcher.shutdown(Duration::from_millis(THREAD_JOIN_TIMEOUT_MS));
+        }
+
+        pub fn is_session_active(&self) -> bool {
+            let input_active = self.input_stream.lock().unwrap().is_some()
+                || self.pull_input.lock().unwrap().is_some();
+            let output_active = self.output_stream.lock().unwrap().is_some()
+                || self.pull_output.lock().unwrap().is_some();
+            input_active && output_active
+        }
+
+        /// Fails with `QuotaExceeded` (leaving the mic off) once the transmit quota is used up.
+        pub fn set_mic_enabled(&self, enabled: bool) -> Result<(), AudioError> {
+            if enabled && self.traffic.quota_remaining_ms(self.transmit_quota_ms.load(Ordering::Relaxed)) == Some(0) {
+                log::warn!("Transmit quota used up, PTT refused");
+                return Err(AudioError::QuotaExceeded);
+            }
+            let config = self.config();
+            if enabled && !self.is_mic_enabled.load(Ordering::Relaxed) {
+                // The mic opens once the permit tone has played out (at once without one)
+                let mut granted_at = 0;
+                if config.talk_permit_tone
+                    && let Some(tx) = &*self.alert_tx.lock().unwrap() {
+                    let tone = tone_burst(
+                        TALK_PERMIT_TONE_HZ, config.sample_rate, TALK_PERMIT_TONE_MS,
+                        TALK_PERMIT_TONE_LEVEL_DBFS, TALK_PERMIT_TONE_FADE_MS,
+                    );
+                    granted_at = self.output_clock.load(Ordering::Relaxed) + tone.len() as u64;
+                    let _ = tx.send(tone.into());
+                }
+                self.transmit_granted_at.store(granted_at, Ordering::Relaxed);
+                self.inbound.request_floor();
+            } else if !enabled {
+                self.inbound.release_floor();
+            }
+            let was_enabled = self.is_mic_enabled.swap(enabled, Ordering::Relaxed);
+            if enabled {
+                info!("Microphone UNMUTED");
+            } else {
+                info!("Microphone MUTED");
+            }
+            if was_enabled && !enabled
+                && let Some(beep) = config.roger_beep.filter(|beep| beep.play_locally)
+                && let Some(tx) = &*self.alert_tx.lock().unwrap() {
+                let _ = tx.send(roger_beep(&beep, config.sample_rate).into());
+            }
+            Ok(())
+        }
+
+        /// A PTT key edge from an accessory (wired headset button, Bluetooth remote)
+        /// or the app's own button, so every entry point behaves alike. Bounces and
+        /// key repeats are filtered out, and `AudioConfig.ptt_toggle` turns taps into
+        /// on/off toggles. Drives `set_mic_enabled`, whose errors it returns (an edge
+        /// that arrives during the debounce window is applied later, and its errors
+        /// are only logged).
+        pub fn on_accessory_ptt(self: Arc<Self>, pressed: bool) -> Result<(), AudioError> {
+            let mic_on = self.is_mic_enabled.load(Ordering::Relaxed);
+            let toggle = self.config().ptt_toggle;
+            let (enabled, settle_in) = {
+                let mut ptt_button = self.ptt_button.lock().unwrap();
+                (ptt_button.event(pressed, toggle, mic_on), ptt_button.settle_in())
+            };
+            if let Some(wait) = settle_in {
+                let engine = Arc::downgrade(&self);
+                resources::spawn(move || {
+                    thread::sleep(wait);
+                    if let Some(engine) = Weak::upgrade(&engine) {
+                        engine.settle_ptt();
+                    }
+                });
+            }
+            match enabled {
+                Some(enabled) => self.set_mic_enabled(enabled),
+                None => Ok(()),
+            }
+        }
+
+        /// Starts transmitting an emergency at once: the mic opens without a floor
+        /// request or talk permit tone and stays open regardless of floor control and
+        /// the transmit quota. The encoder switches to its highest bitrate and most FEC,
+        /// and every packet carries the emergency flag, so receivers fire
+        /// `EmergencyCallback.on_emergency_received` and play it above every priority.
+        /// Lasts until `stop_emergency_transmission`.
+        pub fn start_emergency_transmission(&self) {
+            log::warn!("Emergency transmission started");
+            self.inbound.release_floor(); // Not needed, and can't be lost either
+            self.transmit_granted_at.store(0, Ordering::Relaxed);
+            self.emergency.store(true, Ordering::Relaxed);
+            self.is_mic_enabled.store(true, Ordering::Relaxed);
+        }
+
+        /// Ends an emergency transmission and turns the mic off, like releasing PTT.
+        pub fn stop_emergency_transmission(&self) -> Result<(), AudioError> {
+            if self.emergency.swap(false, Ordering::Relaxed) {
+                info!("Emergency transmission stopped");
+            }
+            self.set_mic_enabled(false)
+        }
+
+        /// Caps how much audio this session may transmit, counted like
+        /// `EngineStats.session_transmit_seconds` (frames actually sent). Once it is used up
+        /// the engine stops transmitting and `set_mic_enabled(true)` fails with
+        /// `QuotaExceeded`. Usage starts over with every session, so apps on a metered
+        /// plan set what is left of it. 0 removes the quota.
+        pub fn set_transmit_quota(&self, seconds: u32) {
+            self.transmit_quota_ms.store(seconds as u64 * 1000, Ordering::Relaxed);
+            info!("Transmit quota: {}s", seconds);
+        }
+
+        /// Data saver: caps what we send to `total_kbps`, packet overhead included.
+        /// Redundant frames go first, then the bitrate comes down (to at least 6kbps),
+        /// and in-band FEC goes once the bitrate is too low to spare it. Emergency
+        /// transmissions ignore the budget. What we receive can't be capped from
+        /// here, but is reported alongside (`get_bandwidth_usage`). 0 removes the budget.
+        pub fn set_bandwidth_budget_kbps(&self, total_kbps: u32) {
+            self.bandwidth_budget_bps.store(total_kbps.saturating_mul(1000), Ordering::Relaxed);
+            info!("Bandwidth budget: {}kbps", total_kbps);
+        }
+
+        /// Current consumption and what the budget leaves the sender. Rates are
+        /// measured between calls, so poll this (every second or so) for a live figure.
+        pub fn get_bandwidth_usage(&self) -> BandwidthUsage {
+            let config = self.config();
+            let budget_bps = self.bandwidth_budget_bps.load(Ordering::Relaxed);
+            let (send_kbps, receive_kbps) = self.bandwidth_meter.lock().unwrap().sample(
+                self.traffic.bytes_sent.load(Ordering::Relaxed),
+                self.traffic.bytes_received.load(Ordering::Relaxed),
+            );
+            let plan = (budget_bps > 0).then(|| budget::plan(budget_bps, &config));
+            BandwidthUsage {
+                budget_kbps: (budget_bps > 0).then_some(budget_bps / 1000),
+                send_kbps,
+                receive_kbps,
+                bitrate_bps: plan.map_or(config.bitrate_bps, |p| p.bitrate_bps),
+                redundancy: plan.map_or(config.redundancy, |p| p.redundancy),
+                fec: plan.is_none_or(|p| p.fec),
+                capped: plan.is_some_and(|p| p.capped),
+            }
+        }
+
+        /// Self-monitor: our own encoded packets are also fed straight into the receive
+        /// pipeline, where they play out as a peer with our own node ID. Checks mic,
+        /// codec, encryption and playout end-to-end without a second device (use a
+        /// headset, or the speaker feeds back into the mic). Turning it off flushes
+        /// what is still buffered.
+        pub fn set_loopback_enabled(&self, enabled: bool) {
+            let was_enabled = self.self_monitor.swap(enabled, Ordering::Relaxed);
+            info!("Self-monitor loopback {}", if enabled { "ENABLED" } else { "DISABLED" });
+            if was_enabled && !enabled {
+                self.flush_peer(self.own_node_id.load(Ordering::Relaxed));
+            }
+        }
+
+        /// Moves us to talkgroup `channel`, so several groups can share one transport.
+        /// Our audio is tagged with it, and audio tagged with another channel is dropped
+        /// on arrival (unless scanned, see `set_scan_channels`). Channel 0 is the
+        /// default and goes out with the v2 header, so builds without channels still
+        /// hear it. Control messages are shared by all channels, except that each
+        /// channel has its own floor: one we hold is released, and one is requested on
+        /// the new channel while PTT is held.
+        pub fn set_active_channel(&self, channel: u16) {
+            if self.inbound.channel.swap(channel, Ordering::Relaxed) == channel { return; }
+            info!("Active channel: {}", channel);
+            self.inbound.set_floor_channel(channel);
+            if self.is_mic_enabled.load(Ordering::Relaxed) {
+                self.inbound.request_floor();
+            }
+        }
+
+        /// Listens to `channels` besides the active one, in priority order (after the
+        /// active channel, the first is highest). Audio from the highest-ranked channel
+        /// where someone is talking plays; the others are monitored but muted, and keep
+        /// decoding so a channel that takes over comes in mid-sentence.
+        /// `ScanCallback.on_scan_activity` reports when a scanned channel becomes active.
+        /// We still transmit on the active channel only. An empty list stops scanning.
+        pub fn set_scan_channels(&self, channels: Vec<u16>) {
+            info!("Scanning channels: {:?}", channels);
+            self.inbound.scan_activity.lock().unwrap().retain(|channel, _| channels.contains(channel));
+            *self.inbound.scan_channels.lock().unwrap() = channels;
+        }
+
+        /// Scans like a radio with a priority channel: the other channels we listen to
+        /// (the active one and the scan list) rank equally, so the mixer locks onto
+        /// whichever one someone starts talking on and stays there until it goes quiet,
+        /// but `channel` takes over whenever someone talks on it. It must be the active
+        /// channel or a scanned one to be heard. `None` goes back to the scan list's order.
+        /// `ScanCallback.on_scan_channel_changed` reports what is being played.
+        pub fn set_priority_channel(&self, channel: Option<u16>) {
+            info!("Priority channel: {:?}", channel);
+            *self.inbound.priority_channel.lock().unwrap() = channel;
+        }
+
+        pub fn push_incoming_packet(&self, data: Vec<u8>) {
+            self.inbound.deliver(&data);
+        }
+
+        fn release_resources(&self) {
+            self.watchdog_generation.fetch_add(1, Ordering::Relaxed);
+            // Clear the sender so incoming packets stop piling up
+            if let Ok(mut guard) = self.packet_tx.lock() {
+                *guard = None;
+            }
+            if let Ok(mut guard) = self.duck_tx.lock() {
+                *guard = None;
+            }
+            if let Ok(mut guard) = self.flush_tx.lock() {
+                *guard = None;
+            }
+            if let Ok(mut guard) = self.peer_settings_tx.lock() {
+                *guard = None;
+            }
+            if let Ok(mut guard) = self.alert_tx.lock() {
+                *guard = None;
+            }
+            self.stop_recording();
+            self.stop_archive();
+            self.stop_mic_recording();
+            self.voice_message.stop();
+            self.inbound.release_floor();
+            if let Ok(mut guard) = self.input_config_tx.lock() {
+                *guard = None;
+            }
+            if let Ok(mut guard) = self.output_config_tx.lock() {
+                *guard = None;
+            }
+
+            if let Ok(mut stream_opt) = self.input_stream.lock()
+                && let Some(stream) = stream_opt.take() {
+                stream.close();
+            }
+            if let Ok(mut stream_opt) = self.output_stream.lock()
+                && let Some(stream) = stream_opt.take() {
+                stream.close();
+            }
+            self.stop_secondary_output_stream();
+            if let Ok(mut input) = self.pull_input.lock() {
+                *input = None;
+            }
+            if let Ok(mut output) = self.pull_output.lock() {
+                *output = None;
+            }
+            self.request_sco(false);
+
+            // Stale reference audio would misalign the echo canceller next session
+            if let Ok(mut sidetone) = self.sidetone.lock() {
+                sidetone.clear();
+            }
+            if let Ok(mut far_end) = self.far_end.lock() {
+                far_end.clear();
+            }
+        }
+
+        fn start_input_stream(&self) -> Result<(), AudioError> {
+            let config = self.config();
+            let callback = InputStream {
+                input: Arc::new(Mutex::new(self.build_input_callback()?)),
+                stream: self.input_stream.clone(),
+                sample_rate: config.sample_rate,
+                error_callback: self.error_callback.clone(),
+                failovers: self.input_failovers.clone(),
+            };
+
+            let device_id = if config.input_device_id != 0 {
+                info!("Input: Explicit Device ID {}", config.input_device_id);
+                config.input_device_id
+            } else if let Some(route) = self.route.lock().unwrap().filter(|r| r.input_device_id != 0) {
+                info!("Input: {:?} Device ID {}", route.route, route.input_device_id);
+                route.input_device_id
+            } else {
+                0
+            };
+            let stream = open_input_stream(callback, config.sample_rate, device_id)?;
+            *self.input_stream.lock().unwrap() = Some(stream);
+            Ok(())
+        }
+
+        fn start_output_stream(&self) -> Result<(), AudioError> {
+            let config = self.config();
+            let callback = self.build_output_callback();
+
+            let route = (config.output_device_id == 0).then(|| *self.route.lock().unwrap()).flatten();
+            let device_id = if config.output_device_id != 0 {
+                info!("Output: Explicit Device ID {}", config.output_device_id);
+                config.output_device_id
+            } else if let Some(route) = route {
+                info!("Output: {:?} Device ID {}", route.route, route.output_device_id);
+                route.output_device_id
+            } else {
+                0
+            };
+            // Before opening, so the link is coming up while the stream starts
+            self.request_sco(route.is_some_and(|route| route.route == AudioRoute::BluetoothSco));
+
+            let spec = StreamSpec { sample_rate: config.sample_rate, device_id, usage: Usage::Voice };
+            let stream = device::open_playback(spec, callback, self.error_callback.clone()).map_err(|e| {
+                log::error!("Open Output Stream Error: {}", e);
+                AudioError::DeviceError
+            })?;
+            *self.output_stream.lock().unwrap() = Some(stream);
+            Ok(())
+        }
+
+        /// Closes and reopens the output stream, if one is running (false if not).
+        /// Everything else in the session carries on.
+        fn reopen_output_stream(&self, reason: &str) -> Result<bool, AudioError> {
+            // Take the stream out first: start_output_stream locks the slot again
+            let Some(stream) = self.output_stream.lock().unwrap().take() else { return Ok(false) };
+            info!("Reopening output stream for {}", reason);
+            stream.close();
+            self.start_output_stream()?;
+            Ok(true)
+        }
+
+        /// Opens the second output (`AudioConfig.secondary_output`), if configured.
+        /// A device that fails to open is logged and left out: the main output and
+        /// the rest of the session don't depend on it.
+        fn start_secondary_output_stream(&self) {
+            let config = self.config();
+            let Some(output) = config.secondary_output else { return };
+            self.secondary_gain.store(output.gain.to_bits(), Ordering::Relaxed);
+            self.secondary_mix.lock().unwrap().clear();
+            let callback = SecondaryOutputCallback {
+                mix: self.secondary_mix.clone(),
+                gain: self.secondary_gain.clone(),
+                current_gain: output.gain,
+                limiter: Limiter::new(config.sample_rate),
+                params: self.params.clone(),
+                mix_buffer: Vec::with_capacity(MAX_BUFFER_SIZE),
+                _tracked: Tracked::new(Resource::Pipeline),
+            };
+
+            info!("Secondary Output: Device ID {}", output.device_id);
+            let spec = StreamSpec { sample_rate: config.sample_rate, device_id: output.device_id, usage: Usage::Media };
+            match device::open_playback(spec, callback, ()) {
+                Ok(stream) => *self.secondary_output_stream.lock().unwrap() = Some(stream),
+                Err(e) => log::warn!("Open Secondary Output Stream Error: {}", e),
+            }
+        }
+
+        fn stop_secondary_output_stream(&self) {
+            if let Some(stream) = self.secondary_output_stream.lock().unwrap().take() {
+                stream.close();
+            }
+            self.secondary_mix.lock().unwrap().clear();
+        }
+
+        /// Same as `reopen_output_stream` for the input stream.
+        fn reopen_input_stream(&self, reason: &str) -> Result<bool, AudioError> {
+            let Some(stream) = self.input_stream.lock().unwrap().take() else { return Ok(false) };
+            info!("Reopening input stream for {}", reason);
+            stream.close();
+            self.start_input_stream()?;
+            Ok(true)
+        }
+    }
+
+    #[cfg(feature = "udp-transport")]
+    #[uniffi::export]
+    impl AudioEngine {
+        /// Creates an engine that owns a UDP multicast socket as its transport.
+        /// Outgoing packets go to the group; a receive thread feeds incoming ones
+        /// into the engine, so the app needs neither `PacketTransport` nor
+        /// `push_incoming_packet`.
+        #[uniffi::constructor]
+        pub fn new_with_udp_multicast(
+            config: AudioConfig,
+            udp: UdpMulticastConfig,
+            callback: Box<dyn AudioErrorCallback>,
+            own_node_id: u32
+        ) -> Result<Self, AudioError> {
+            let (transport, socket) = crate::udp::UdpMulticastTransport::open(&udp)?;
+            let engine = Self::new(config, Box::new(transport), callback, own_node_id)?;
+            let inbound = engine.inbound.clone();
+            *engine.udp_receiver.lock().unwrap() = Some(crate::udp::UdpReceiver::spawn(socket, move |data| inbound.deliver(data)));
+            Ok(engine)
+        }
+    }
+
+    #[cfg(feature = "net-sim")]
+    #[uniffi::export]
+    impl AudioEngine {
+        /// QA only: makes the link worse on purpose (loss, duplication,
+        /// reordering, delay jitter) for everything we send. `None` restores the
+        /// real link. Setting an impairment restarts its random sequence from
+        /// `seed`, so a run can be repeated exactly.
+        pub fn set_network_impairment(&self, impairment: Option<NetworkImpairment>) {
+            match &impairment {
+                Some(i) => log::warn!("Network impairment on: {:?}", i),
+                None => info!("Network impairment off"),
+            }
+            *self.network_impairment.lock().unwrap() = impairment.map(crate::netsim::ImpairedLink::new);
+        }
+    }
+
+    // --- Pipeline Construction (shared by device and pull mode) ---
+    impl AudioEngine {
+        fn config(&self) -> AudioConfig {
+            *self.config.lock().unwrap()
+        }
+
+        /// `submit_capture_frames` on a borrowed buffer (the C ABI's `wt_capture`).
+        pub(crate) fn capture_frames(&self, frames: &[i16]) {
+            if let Some(input) = self.pull_input.lock().unwrap().as_mut() {
+                input.process_capture(frames);
+            }
+        }
+
+        /// `read_render_frames` into a borrowed buffer (the C ABI's `wt_render`).
+        pub(crate) fn render_frames(&self, frames: &mut [i16]) {
+            match self.pull_output.lock().unwrap().as_mut() {
+                Some(output) => output.render(frames),
+                None => frames.fill(0),
+            }
+        }
+
+        /// Applies a PTT edge that `on_accessory_ptt` had to hold back for debouncing.
+        fn settle_ptt(&self) {
+            let mic_on = self.is_mic_enabled.load(Ordering::Relaxed);
+            let toggle = self.config().ptt_toggle;
+            let enabled = self.ptt_button.lock().unwrap().settle(toggle, mic_on);
+            if let Some(enabled) = enabled
+                && let Err(error) = self.set_mic_enabled(enabled) {
+                log::warn!("Debounced PTT edge failed: {:?}", error);
+            }
+        }
+
+        /// Hands a per-peer setting to the running output callback, if any.
+        fn send_peer_setting(&self, node_id: u32, setting: PeerSetting) {
+            if let Some(tx) = &*self.peer_settings_tx.lock().unwrap() {
+                let _ = tx.send((node_id, setting));
+            }
+        }
+
+        /// Every session starts at a random sequence number in a new epoch
+        /// (a resumed session keeps its sequence number).
+        fn begin_sequence(&self) {
+            if !self.resumed.swap(false, Ordering::Relaxed) {
+                *self.sequence_number.lock().unwrap() = random_sequence();
+            }
+            let epoch = self.key_epoch.load(Ordering::Relaxed);
+            self.key_epoch.store(next_epoch(epoch), Ordering::Relaxed);
+        }
+
+        /// Picks the preferred route among the connected devices and reports it if it changed.
+        fn refresh_route(&self) -> Option<RouteSelection> {
+            let preference = self.route_preference.lock().unwrap().clone();
+            let selection = select_route(&match self.config().routing_mode.unwrap_or(AudioRoutingMode::Auto) {
+                AudioRoutingMode::Auto => preference,
+                AudioRoutingMode::Earpiece => vec![AudioRoute::Earpiece],
+                AudioRoutingMode::Speaker => vec![AudioRoute::Speaker],
+                AudioRoutingMode::BluetoothSco => std::iter::once(AudioRoute::BluetoothSco).chain(preference).collect(),
+            });
+            let previous = std::mem::replace(&mut *self.route.lock().unwrap(), selection);
+            let explicit = self.config().output_device_id != 0;
+            if let Some(route) = selection.filter(|_| selection != previous && !explicit) {
+                info!("Audio route: {:?} (device {})", route.route, route.output_device_id);
+                self.dispatcher.notify(&self.route_callback, move |callback| {
+                    callback.on_audio_route_changed(route.route, route.output_device_id)
+                });
+            }
+            selection
+        }
+
+        /// Tells the app to start or stop Bluetooth SCO, if that changed.
+        fn request_sco(&self, needed: bool) {
+            if self.sco_needed.swap(needed, Ordering::Relaxed) != needed {
+                info!("Bluetooth SCO {}", if needed { "needed" } else { "released" });
+                self.dispatcher.notify(&self.sco_callback, move |callback| callback.on_bluetooth_sco_needed(needed));
+            }
+        }
+
+        /// Polls the output stream until the session stops (or the watchdog is turned
+        /// off). A suspicion lasting OUTPUT_WATCHDOG_STALL_MS is double-checked with the
+        /// loopback tone where possible, then reported once until the stream recovers.
+        fn spawn_output_watchdog(&self) {
+            let generation = self.watchdog_generation.fetch_add(1, Ordering::Relaxed) + 1;
+            let current_generation = self.watchdog_generation.clone();
+            let output_stream = self.output_stream.clone();
+            let alert_tx = self.alert_tx.clone();
+            let loopback = self.loopback.clone();
+            let health_callback = self.health_callback.clone();
+            let dispatcher = self.dispatcher.clone();
+            let sample_rate = self.config().sample_rate;
+            let tone: Arc<[i16]> = tone_burst(
+                LOOPBACK_TONE_HZ, sample_rate, LOOPBACK_TONE_MS, LOOPBACK_TONE_LEVEL_DBFS, LOOPBACK_TONE_FADE_MS,
+            ).into();
+
+            resources::spawn(move || {
+                let mut previous: Option<(Option<i64>, Option<i32>)> = None; // (position, xruns)
+                let mut suspect_since: Option<Instant> = None;
+                let mut reported = false;
+                while current_generation.load(Ordering::Relaxed) == generation {
+                    thread::sleep(Duration::from_millis(OUTPUT_WATCHDOG_POLL_MS));
+                    // None: stopped, or being reopened
+                    let sample = output_stream.lock().unwrap().as_mut().and_then(|stream| stream.health());
+                    let reason = match (previous, sample) {
+                        (Some((Some(before), _)), Some((Some(now), _))) if now == before => Some(OutputSilentReason::PositionStalled),
+                        (Some((_, Some(before))), Some((_, Some(now)))) if now > before => Some(OutputSilentReason::ContinuousXruns),
+                        _ => None,
+                    };
+                    previous = sample;
+
+                    let Some(reason) = reason else {
+                        suspect_since = None;
+                        reported = false;
+                        continue;
+                    };
+                    let since = *suspect_since.get_or_insert_with(Instant::now);
+                    if reported || since.elapsed() < Duration::from_millis(OUTPUT_WATCHDOG_STALL_MS) { continue; }
+                    reported = true;
+
+                    let tone_checked = sample_rate >= LOOPBACK_MIN_SAMPLE_RATE
+                        && alert_tx.lock().unwrap().as_ref().is_some_and(|tx| {
+                            loopback.heard.store(false, Ordering::Relaxed);
+                            loopback.listening.store(true, Ordering::Relaxed);
+                            tx.send(tone.clone()).is_ok()
+                        });
+                    if tone_checked {
+                        thread::sleep(Duration::from_millis(LOOPBACK_LISTEN_MS));
+                        loopback.listening.store(false, Ordering::Relaxed);
+                        if loopback.heard.load(Ordering::Relaxed) {
+                            info!("Output watchdog: {:?}, but the test tone came back. Ignoring.", reason);
+                            continue;
+                        }
+                    }
+                    log::warn!("Output watchdog: {:?} (tone checked: {}). Output may be silent.", reason, tone_checked);
+                    dispatcher.notify(&health_callback, move |callback| {
+                        callback.on_output_silent_suspected(reason, tone_checked)
+                    });
+                }
+            });
+        }
+
+        fn build_input_callback(&self) -> Result<InputCallback, AudioError> {
+            let config = self.config();
+            let samples_per_frame = (config.sample_rate / 1000 * config.frame_size_ms) as usize;
+            let encoder_rate = map_sample_rate(config.sample_rate);
+
+            let mut encoder = Encoder::new(encoder_rate, Channels::Mono, Application::Voip)
+                .map_err(|_| AudioError::EncoderError)?;
+            let _ = encoder.set_dtx(true);
+            let _ = encoder.set_inband_fec(true);
+            let _ = encoder.set_packet_loss_perc(OPUS_EXPECTED_LOSS_PERCENT);
+            let _ = encoder.set_bitrate(map_bitrate(config.bitrate_bps));
+            let _ = encoder.set_complexity(map_complexity(config.opus_complexity));
+            let _ = encoder.set_vbr(config.use_vbr);
+
+            let (config_tx, config_rx) = unbounded();
+            *self.input_config_tx.lock().unwrap() = Some(config_tx);
+            *self.mic_level.lock().unwrap() = None;
+
+            Ok(InputCallback {
+                send: SendPipeline::new(encoder, config, self.own_node_id.load(Ordering::Relaxed)),
+                config,
+                config_rx,
+                sequence_number: self.sequence_number.clone(),
+                tx_transport: self.tx_transport.clone(),
+                is_mic_enabled: self.is_mic_enabled.clone(),
+                mic_recording_tx: self.mic_recording.tx.clone(),
+                voice_message_tx: self.voice_message.tx.clone(),
+                input_gain_db: self.input_gain_db.clone(),
+                input_gain: 1.0,
+                failover_gain: 1.0,
+                echo_canceller: config.echo_cancellation.then(|| EchoCanceller::new(config.sample_rate)),
+                far_end: self.far_end.clone(),
+                far_buffer: [0i16; MAX_BUFFER_SIZE],
+                noise_suppressor: if config.noise_suppression {
+                    NoiseSuppressor::new(config.sample_rate, samples_per_frame)
+                } else {
+                    None
+                },
+                agc: config.agc_enabled.then(|| {
+                    AutomaticGainControl::new(
+                        self.params.get(DspParam::AgcTargetLevelDb),
+                        self.params.get(DspParam::AgcMaxGainDb),
+                    )
+                }),
+                params: self.params.clone(),
+                vad: config.vad_enabled.then(|| VoiceActivityDetector::new(config.frame_size_ms)),
+                vad_gate_transmit: config.vad_gate_transmit,
+                vad_callback: self.vad_callback.clone(),
+                transmit_callback: self.transmit_callback.clone(),
+                transmit_granted_at: self.transmit_granted_at.clone(),
+                handover: self.handover.clone(),
+                emergency: self.emergency.clone(),
+                bandwidth_budget_bps: self.bandwidth_budget_bps.clone(),
+                bitrate: config.bitrate_bps,
+                fec: true,
+                expected_loss: OPUS_EXPECTED_LOSS_PERCENT,
+                floor_open: self.inbound.floor_open.clone(),
+                output_clock: self.output_clock.clone(),
+                transmit_gate: self.transmit_gate.clone(),
+                self_monitor: self.self_monitor.clone(),
+                transmit_quota_ms: self.transmit_quota_ms.clone(),
+                packet_tx: self.packet_tx.clone(),
+                dispatcher: self.dispatcher.clone(),
+                is_speaking: false,
+                local_position: self.local_position.clone(),
+                local_priority: self.local_priority.clone(),
+                channel: self.inbound.channel.clone(),
+                loops: self.inbound.loops.clone(),
+                position_countdown: 0,
+                privacy_mode: self.privacy_mode.clone(),
+                traffic: self.traffic.clone(),
+                group_cipher: self.group_cipher.clone(),
+                key_agreement: self.key_agreement.clone(),
+                key_epoch: self.key_epoch.clone(),
+                common_capabilities: self.inbound.common_capabilities.clone(),
+                loopback: self.loopback.clone(),
+                tone_detector: ToneDetector::new(LOOPBACK_TONE_HZ, config.sample_rate),
+                sidetone: self.sidetone.clone(),
+                sidetone_gain: self.sidetone_gain.clone(),
+                was_sending: false,
+                roger_beep: None,
+                pre_roll: VecDeque::new(),
+                mic_meter: LevelMeter::new(),
+                mic_level: self.mic_level.clone(),
+                level_callback: self.level_callback.clone(),
+                _tracked: Tracked::new(Resource::Pipeline),
+            })
+        }
+
+        fn build_output_callback(&self) -> OutputCallback {
+            let config = self.config();
+
+            // Create lock-free channel
+            let (tx, rx) = unbounded();
+
+            // Update the sender for incoming packets
+            *self.packet_tx.lock().unwrap() = Some(tx);
+
+            // Attenuation windows are scheduled against a fresh sample clock
+            let (duck_tx, duck_rx) = unbounded();
+            *self.duck_tx.lock().unwrap() = Some(duck_tx);
+            self.output_clock.store(0, Ordering::Relaxed);
+            self.transmit_granted_at.store(0, Ordering::Relaxed); // A permit tone pending on the old clock is gone
+            let ramp_samples = (config.sample_rate / 1000 * ATTENUATION_RAMP_MS) as usize;
+
+            let (alert_tx, alert_rx) = unbounded();
+            *self.alert_tx.lock().unwrap() = Some(alert_tx);
+
+            let (flush_tx, flush_rx) = unbounded();
+            *self.flush_tx.lock().unwrap() = Some(flush_tx);
+
+            let (peer_settings_tx, peer_settings_rx) = unbounded();
+            *self.peer_settings_tx.lock().unwrap() = Some(peer_settings_tx);
+
+            let (config_tx, config_rx) = unbounded();
+            *self.output_config_tx.lock().unwrap() = Some(config_tx);
+
+            // The new callback starts without peers
+            self.peer_stats.lock().unwrap().clear();
+            self.peer_levels.lock().unwrap().clear();
+
+            let mut receive = ReceivePipeline::new(&config, self.params.clone());
+            receive.talk = self.talk_stats.lock().unwrap().clone(); // Carried over when a stream is reopened
+            receive.concealment = self.peer_concealment.lock().unwrap().clone();
+            receive.priorities = self.peer_priorities.lock().unwrap().clone();
+            receive.muted = self.muted_peers.lock().unwrap().clone();
+
+            // Give receiver to the callback (it owns the map now)
+            OutputCallback {
+                receive,
+                packet_rx: rx,
+                sample_rate: config.sample_rate,
+                far_end: config.echo_cancellation.then(|| self.far_end.clone()),
+                shared_far_end: self.far_end.clone(),
+                secondary_mix: config.secondary_output.is_some().then(|| self.secondary_mix.clone()),
+                shared_secondary_mix: self.secondary_mix.clone(),
+                duck_rx,
+                flush_rx,
+                peer_settings_rx,
+                ducker: Ducker::new(ramp_samples, self.output_clock.clone()),
+                limiter: Limiter::new(config.sample_rate),
+                params: self.params.clone(),
+                output_clock: self.output_clock.clone(),
+                stall_count: self.stall_count.clone(),
+                alert_rx,
+                alerts: Vec::new(),
+                recording_tx: self.recording.tx.clone(),
+                archive_tx: self.archive.tx.clone(),
+                replay_history: self.replay_history.clone(),
+                privacy_mode: self.privacy_mode.clone(),
+                config_rx,
+                position_callback: self.position_callback.clone(),
+                level_callback: self.level_callback.clone(),
+                peer_levels: self.peer_levels.clone(),
+                level_interval: (config.sample_rate / 1000 * LEVEL_PUBLISH_MS) as u64,
+                levels_published_at: 0,
+                _tracked: Tracked::new(Resource::Pipeline),
+                scan_callback: self.inbound.scan_callback.clone(),
+                output_volume: self.output_volume.clone(),
+                sidetone: self.sidetone.clone(),
+                sidetone_gain: self.sidetone_gain.clone(),
+                receive_muted: self.receive_muted.clone(),
+                volume_gain: f32::from_bits(self.output_volume.load(Ordering::Relaxed)),
+                receive_gain: if self.receive_muted.load(Ordering::Relaxed) { 0.0 } else { 1.0 },
+                comfort_noise: config.comfort_noise_dbfs.map(ComfortNoise::new),
+                peer_event_callback: self.peer_event_callback.clone(),
+                dispatcher: self.dispatcher.clone(),
+                traffic: self.traffic.clone(),
+                peer_stats: self.peer_stats.clone(),
+                talk_stats: self.talk_stats.clone(),
+                stats_interval: (config.sample_rate / 1000 * STATS_PUBLISH_MS) as u64,
+                stats_published_at: 0,
+                peer_delays: self.peer_delays.clone(),
+                delayed: Vec::new(),
+            }
+        }
+    }
+
+    // --- Callbacks ---
+
+    /// Shared by the output watchdog and the input callback while the test tone plays.
+    #[derive(Default)]
+    struct LoopbackProbe {
+        listening: AtomicBool,
+        heard: AtomicBool,
+    }
+
+    struct InputCallback {
+        send: SendPipeline<Encoder>,
+        config: AudioConfig,
+        config_rx: Receiver<AudioConfig>,
+        sequence_number: Arc<Mutex<u32>>,
+        tx_transport: StdSender<Outbound>,
+        is_mic_enabled: Arc<AtomicBool>,
+        mic_recording_tx: Arc<RecordingSlot>,
+        voice_message_tx: Arc<RecordingSlot>,
+        input_gain_db: Arc<AtomicU32>,
+        input_gain: f32, // Linear gain at the end of the last frame (ramps towards input_gain_db)
+        failover_gain: f32, // Below 1.0 while the mic that took over fades in
+        echo_canceller: Option<EchoCanceller>,
+        far_end: Arc<Mutex<FarEndBuffer>>,
+        far_buffer: [i16; MAX_BUFFER_SIZE], // Far-end reference aligned with the current frame
+        noise_suppressor: Option<NoiseSuppressor>,
+        agc: Option<AutomaticGainControl>,
+        params: Arc<DspParams>,
+        vad: Option<VoiceActivityDetector>,
+        vad_gate_transmit: bool,
+        vad_callback: CallbackSlot<dyn VoiceActivityCallback>,
+        transmit_callback: CallbackSlot<dyn TransmitCallback>,
+        transmit_granted_at: Arc<AtomicU64>,
+        floor_open: Arc<AtomicBool>, // Floor control: only the holder transmits
+        handover: Arc<AtomicBool>,
+        emergency: Arc<AtomicBool>,
+        bandwidth_budget_bps: Arc<AtomicU32>,
+        bitrate: i32, // What the encoder is set to (config, budget or emergency)
+        fec: bool,
+        expected_loss: i32, // What the encoder is set up for (in-band FEC)
+        output_clock: Arc<AtomicU64>,
+        transmit_gate: CallbackSlot<dyn TransmitGate>,
+        self_monitor: Arc<AtomicBool>,
+        transmit_quota_ms: Arc<AtomicU64>,
+        packet_tx: Arc<PacketSlot>, // The output callback's packet queue, for the self-monitor
+        dispatcher: Arc<CallbackDispatcher>,
+        is_speaking: bool, // Last state reported to the VAD callback
+        local_position: Arc<Mutex<Option<PeerPosition>>>,
+        local_priority: Arc<AtomicU8>,
+        channel: Arc<AtomicU16>, // Our talkgroup (`set_active_channel`)
+        loops: Arc<Mutex<LoopDetector>>, // Fingerprints of what we send
+        position_countdown: u32, // Packets until the position is attached again (0 = next one)
+        privacy_mode: Arc<AtomicBool>,
+        traffic: Arc<TrafficCounters>,
+        group_cipher: Arc<CipherSlot>,
+        key_agreement: Arc<Mutex<KeyAgreement>>, // Our sender key takes precedence over the group key
+        key_epoch: Arc<AtomicU32>,
+        common_capabilities: Arc<AtomicU32>,
+        loopback: Arc<LoopbackProbe>,
+        tone_detector: ToneDetector,
+        sidetone: Arc<Mutex<SidetoneBuffer>>,
+        sidetone_gain: Arc<AtomicU32>,
+        was_sending: bool,             // PTT state of the previous frame
+        roger_beep: Option<TonePlayer>, // Playing into the frames after PTT release
+        pre_roll: VecDeque<Vec<i16>>, // Processed frames from while PTT was up (`ptt_pre_roll_ms`)
+        mic_meter: LevelMeter,
+        mic_level: Arc<Mutex<Option<MicLevel>>>,
+        level_callback: CallbackSlot<dyn AudioLevelCallback>,
+        _tracked: Tracked,
+    }
+
+    impl InputCallback {
+        /// The mic went away (see `InputStream::fail_over`): what it left in the frame
+        /// buffer fades out and the next mic's audio fades in, so the gap doesn't click.
+        fn begin_failover(&mut self) {
+            self.send.fade_out_queued((self.config.sample_rate / 1000 * INPUT_FAILOVER_FADE_MS) as usize);
+            self.failover_gain = 0.0;
+        }
 
+        /// Swaps in a new config, recreating only the stages it affects.
+        fn apply_config(&mut self, config: AudioConfig) {
+            let old = std::mem::replace(&mut self.config, config);
+
+            // The bitrate is picked up by `process_capture`, which weighs in the budget
+            let encoder = &mut self.send.encoder;
+            if config.opus_complexity != old.opus_complexity {
+                let _ = encoder.set_complexity(map_complexity(config.opus_complexity));
+            }
+            if config.use_vbr != old.use_vbr {
+                let _ = encoder.set_vbr(config.use_vbr);
+            }
+            // Opus takes any valid frame size per call, so the encoder itself is kept
+            self.send.apply_config(config);
+            let frame_changed = config.frame_size_ms != old.frame_size_ms;
+
+            if config.echo_cancellation != old.echo_cancellation {
+                self.echo_canceller = config.echo_cancellation.then(|| EchoCanceller::new(config.sample_rate));
+            }
+            if config.noise_suppression != old.noise_suppression || frame_changed {
+                self.noise_suppressor = if config.noise_suppression {
+                    NoiseSuppressor::new(config.sample_rate, self.send.frame_samples())
+                } else {
+                    None
+                };
+            }
+            if config.agc_enabled != old.agc_enabled {
+                self.agc = config.agc_enabled.then(|| {
+                    AutomaticGainControl::new(
+                        self.params.get(DspParam::AgcTargetLevelDb),
+                        self.params.get(DspParam::AgcMaxGainDb),
+                    )
+                });
+            }
+            if config.vad_enabled != old.vad_enabled || frame_changed {
+                self.vad = config.vad_enabled.then(|| VoiceActivityDetector::new(config.frame_size_ms));
+                if self.vad.is_none() && self.is_speaking {
+                    self.is_speaking = false;
+                    self.dispatcher.notify(&self.vad_callback, |callback| callback.on_voice_activity_changed(false));
+                }
+            }
+            self.vad_gate_transmit = config.vad_gate_transmit;
+            if config.ptt_pre_roll_ms == 0 || frame_changed {
+                self.pre_roll.clear();
+            }
+        }
+
+        /// The next transmission starts with a fresh position and no redundant frame.
+        fn end_burst(&mut self) {
+            self.position_countdown = 0;
+            self.send.end_burst();
+        }
+
+        /// Asks the app's `TransmitGate`, if there is one, whether a frame may go out.
+        fn frame_allowed(&self, pcm: &[i16], encoded_bytes: usize) -> bool {
+            let gate = self.transmit_gate.lock().unwrap();
+            let Some(gate) = gate.as_ref() else { return true };
+            let energy: f32 = pcm.iter().map(|&s| (s as f32 / i16::MAX as f32).powi(2)).sum();
+            gate.allow_frame(TransmitFrameMeta {
+                seq: *self.sequence_number.lock().unwrap(),
+                frame_size_ms: self.config.frame_size_ms,
+                burst_start: self.send.is_burst_start(),
+                level_dbfs: 10.0 * (energy / pcm.len().max(1) as f32 + 1e-10).log10(),
+                encoded_bytes: encoded_bytes as u32,
+            })
+        }
+
+        /// Packetizes the frame just encoded and sends it.
+        fn send_encoded(&mut self) {
+            let position = if self.privacy_mode.load(Ordering::Relaxed) {
+                None
+            } else if self.position_countdown == 0 {
+                self.position_countdown = POSITION_REFRESH_PACKETS;
+                *self.local_position.lock().unwrap()
+            } else {
+                None
+            };
+            self.position_countdown = self.position_countdown.saturating_sub(1);
+
+            let mut seq = self.sequence_number.lock().unwrap();
+            let key_agreement = self.key_agreement.lock().unwrap();
+            let group_cipher = self.group_cipher.lock().unwrap();
+            let cipher = key_agreement.sender_cipher().or(group_cipher.as_ref());
+            let epoch = self.key_epoch.load(Ordering::Relaxed);
+            let common = self.common_capabilities.load(Ordering::Relaxed);
+            let priority = self.local_priority.load(Ordering::Relaxed);
+            let channel = self.channel.load(Ordering::Relaxed);
+            let packet = self.send.packetize(*seq, cipher.map(|cipher| (cipher, epoch)), position, priority, channel, common);
+            *seq = seq.wrapping_add(1);
+            if *seq == 0 {
+                // Sequence wrapped: move to a fresh nonce space
+                self.key_epoch.fetch_add(1, Ordering::Relaxed);
+            }
+
+            if self.self_monitor.load(Ordering::Relaxed)
+                && let Some(own) = packet.as_deref().and_then(|packet| unwrap_own_packet(packet, cipher))
+                && let Ok(slot) = self.packet_tx.try_lock()
+                && let Some(tx) = &*slot {
+                let _ = tx.send(own);
+            }
+            if let Some(packet) = packet {
+                self.traffic.packets_sent.fetch_add(1, Ordering::Relaxed);
+                self.traffic.bytes_sent.fetch_add(packet.len() as u64, Ordering::Relaxed);
+                self.traffic.session_transmit_ms.fetch_add(self.config.frame_size_ms.max(0) as u64, Ordering::Relaxed);
+                self.traffic.session_transmit_bytes.fetch_add(packet.len() as u64, Ordering::Relaxed);
+                let _ = self.tx_transport.send(Outbound::audio(packet));
+                if let Ok(mut loops) = self.loops.try_lock() {
+                    loops.record(self.send.own_node_id(), self.send.encoded());
+                }
+            } else {
+                log::error!("Packet encryption failed");
+            }
+        }
+
+        /// Keeps a processed frame for `AudioConfig.ptt_pre_roll_ms`, reusing the
+        /// oldest one's buffer once `max_frames` are kept.
+        fn keep_pre_roll(pre_roll: &mut VecDeque<Vec<i16>>, frame: &[i16], max_frames: usize) {
+            let mut kept = Vec::new();
+            while pre_roll.len() >= max_frames {
+                kept = pre_roll.pop_front().unwrap_or_default();
+            }
+            kept.clear();
+            kept.extend_from_slice(frame);
+            pre_roll.push_back(kept);
+        }
+
+        /// Sends the frames kept while PTT was up, ahead of the first live one.
+        fn send_pre_roll(&mut self) {
+            let mut frames = std::mem::take(&mut self.pre_roll);
+            for pcm in &frames {
+                match self.send.encode_pcm(pcm) {
+                    Ok(len) if !self.frame_allowed(pcm, len) => {
+                        self.traffic.frames_gated.fetch_add(1, Ordering::Relaxed);
+                        self.end_burst();
+                    }
+                    Ok(len) if self.send.skip_dtx_frame(len) => {
+                        self.traffic.frames_dtx.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Ok(_) => self.send_encoded(),
+                    Err(e) => { log::error!("Opus Encode Failed: {}", e); }
+                }
+            }
+            frames.clear();
+            self.pre_roll = frames;
+        }
+
+        fn process_capture(&mut self, frames: &[i16]) {
+            while let Ok(config) = self.config_rx.try_recv() {
+                self.apply_config(config);
+            }
+
+            // An emergency goes out at full bitrate; it and a network handover get more in-band FEC.
+            // Otherwise the data saver budget may cut redundancy, bitrate and FEC.
+            let emergency = self.emergency.load(Ordering::Relaxed);
+            self.send.emergency = emergency;
+            let budget_bps = self.bandwidth_budget_bps.load(Ordering::Relaxed);
+            let plan = (budget_bps > 0 && !emergency).then(|| budget::plan(budget_bps, &self.config));
+            let bitrate = if emergency {
+                EMERGENCY_BITRATE_BPS
+            } else {
+                plan.map_or(self.config.bitrate_bps, |p| p.bitrate_bps)
+            };
+            if bitrate != self.bitrate {
+                self.bitrate = bitrate;
+                let _ = self.send.encoder.set_bitrate(map_bitrate(bitrate));
+            }
+            let fec = plan.is_none_or(|p| p.fec);
+            if fec != self.fec {
+                self.fec = fec;
+                let _ = self.send.encoder.set_inband_fec(fec);
+            }
+            self.send.redundancy_allowed = plan.is_none_or(|p| p.redundancy);
+            let expected_loss = if emergency {
+                EMERGENCY_EXPECTED_LOSS_PERCENT
+            } else if self.handover.load(Ordering::Relaxed) {
+                HANDOVER_EXPECTED_LOSS_PERCENT
+            } else {
+                OPUS_EXPECTED_LOSS_PERCENT
+            };
+            if expected_loss != self.expected_loss {
+                self.expected_loss = expected_loss;
+                let _ = self.send.encoder.set_packet_loss_perc(expected_loss);
+            }
+
+            // The output watchdog is listening for its test tone
+            if self.loopback.listening.load(Ordering::Relaxed)
+                && self.tone_detector.tone_ratio(frames) > LOOPBACK_DETECT_RATIO
+            {
+                self.loopback.heard.store(true, Ordering::Relaxed);
+            }
+
+            // Sidetone straight from the capture buffer, ahead of frame assembly
+            if f32::from_bits(self.sidetone_gain.load(Ordering::Relaxed)) > 0.0
+                && self.is_mic_enabled.load(Ordering::Relaxed)
+                && let Ok(mut sidetone) = self.sidetone.try_lock() {
+                sidetone.push(frames.iter().copied(), (self.config.sample_rate / 1000 * SIDETONE_MAX_LATENCY_MS) as usize);
+            }
+
+            // 1. Queue the data in the send pipeline (fading in a mic that just took over)
+            if self.failover_gain < 1.0 {
+                let mut faded = frames.to_vec();
+                let ramp = faded.len().min((self.config.sample_rate / 1000 * INPUT_FAILOVER_FADE_MS) as usize);
+                apply_input_gain(&mut faded[..ramp], &mut self.failover_gain, 1.0);
+                self.send.push(&faded);
+            } else {
+                self.send.push(frames);
+            }
+            let pre_roll_frames = (self.config.ptt_pre_roll_ms.min(PTT_PRE_ROLL_MAX_MS) as i32 / self.config.frame_size_ms.max(1)) as usize;
+
+            // 2. Process full frames
+            while self.send.frame_ready() {
+                // Check the Gate!
+                // If false, we process the buffer (to clear it) but DO NOT encode/send.
+                // With a talk permit tone, PTT only opens the mic once the tone has played;
+                // with floor control, once we hold the floor (an emergency goes out regardless)
+                let mut should_send = self.is_mic_enabled.load(Ordering::Relaxed)
+                    && self.output_clock.load(Ordering::Relaxed) >= self.transmit_granted_at.load(Ordering::Relaxed)
+                    && (self.floor_open.load(Ordering::Relaxed) || emergency);
+                if should_send && !emergency && self.traffic.quota_remaining_ms(self.transmit_quota_ms.load(Ordering::Relaxed)) == Some(0) {
+                    log::warn!("Transmit quota used up, microphone MUTED");
+                    self.is_mic_enabled.store(false, Ordering::Relaxed);
+                    should_send = false;
+                } else if self.was_sending && !should_send && let Some(beep) = &self.config.roger_beep {
+                    // PTT released: the beep goes out in the frames after it
+                    self.roger_beep = Some(TonePlayer::new(roger_beep(beep, self.config.sample_rate)));
+                }
                 if should_send {
-                    let chunk = &self.buffer[0..self.samples_per_frame];
-                    let mut output_buffer = [0u8; OPUS_OUT_BUFFER_SIZE];
-
-                    match self.encoder.encode(chunk, &mut output_buffer) {
-                        Ok(len) => {
-                            let mut seq = self.sequence_number.lock().unwrap();
-                            let packet = wrap_packet(self.own_node_id, *seq, &output_buffer[..len]);
-                            *seq = seq.wrapping_add(1);
-                            let _ = self.tx_transport.send(packet);
-                        },
+                    self.roger_beep = None;
+                    if !self.was_sending {
+                        self.dispatcher.notify(&self.transmit_callback, |callback| callback.on_transmit_started());
+                        self.send_pre_roll();
+                    }
+                }
+                self.was_sending = should_send;
+                // A voice message being captured gets the same processing, but isn't sent;
+                // so does the pre-roll, kept until the next transmission
+                let message_tx = self.voice_message_tx.try_lock().ok().and_then(|slot| slot.clone());
+                let should_process = should_send || message_tx.is_some() || pre_roll_frames > 0;
+                let frame = self.send.frame_mut();
+
+                // Always consume the far-end reference, even while muted, so it
+                // stays aligned with the mic samples for the next transmission.
+                if let Some(aec) = &mut self.echo_canceller {
+                    let far = &mut self.far_buffer[0..frame.len()];
+                    let max_lead = self.config.sample_rate as usize / 1000 * AEC_FAR_END_MAX_LEAD_MS;
+                    self.far_end.lock().unwrap().pop_into(far, max_lead);
+                    if should_process {
+                        aec.process(frame, far);
+                    }
+                }
+
+                // Noise suppression runs after AEC so it also cleans up residual echo
+                if should_process && let Some(ns) = &mut self.noise_suppressor {
+                    ns.process(frame, self.params.get(DspParam::NsStrength));
+                }
+
+                // Voice activity (on the denoised signal, before AGC changes the level)
+                let mut is_speech = true;
+                if let Some(vad) = &mut self.vad {
+                    let speaking = should_send && vad.process(frame);
+                    if speaking != self.is_speaking {
+                        self.is_speaking = speaking;
+                        self.dispatcher.notify(&self.vad_callback, move |callback| callback.on_voice_activity_changed(speaking));
+                    }
+                    is_speech = speaking || !self.vad_gate_transmit;
+                }
+
+                // AGC last, so it levels the cleaned-up voice rather than the noise
+                if should_process && let Some(agc) = &mut self.agc {
+                    agc.set_levels(self.params.get(DspParam::AgcTargetLevelDb), self.params.get(DspParam::AgcMaxGainDb));
+                    agc.process(frame);
+                }
+
+                if should_process {
+                    let gain_db = f32::from_bits(self.input_gain_db.load(Ordering::Relaxed));
+                    apply_input_gain(frame, &mut self.input_gain, 10f32.powf(gain_db / 20.0));
+                }
+
+                if let Some(tx) = &message_tx {
+                    PcmBlock::send(tx, frame.iter().copied());
+                }
+                if !should_send && pre_roll_frames > 0 {
+                    Self::keep_pre_roll(&mut self.pre_roll, frame, pre_roll_frames);
+                }
+                self.mic_meter.add(frame.iter().map(|&s| s as i32));
+
+                // The roger beep replaces the (unprocessed) mic signal
+                let beeping = !should_send && self.roger_beep.as_mut().is_some_and(|beep| beep.fill(frame));
+                if !beeping {
+                    self.roger_beep = None;
+                }
+
+                if (should_send && is_speech) || beeping {
+                    if should_send
+                        && let Ok(slot) = self.mic_recording_tx.try_lock()
+                        && let Some(tx) = &*slot {
+                        PcmBlock::send(tx, frame.iter().copied());
+                    }
+
+                    match self.send.encode() {
+                        Ok(len) if !self.frame_allowed(self.send.frame(), len) => {
+                            // Held back like silence: the next frame sent starts a new burst
+                            self.traffic.frames_gated.fetch_add(1, Ordering::Relaxed);
+                            self.end_burst();
+                        }
+                        Ok(len) if self.send.skip_dtx_frame(len) => {
+                            self.traffic.frames_dtx.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Ok(_) => self.send_encoded(),
                         Err(e) => { log::error!("Opus Encode Failed: {}", e); }
                     }
                 } else {
                     // Optional: Reset encoder state or send silence if using DTX heavily,
                     // but for PTT, simply skipping encoding is most efficient.
+                    self.end_burst();
                 }
+                self.send.next_frame();
+                self.publish_mic_level(should_send);
+            }
+        }
+
+        /// Hands on the mic level every LEVEL_PUBLISH_MS of captured audio.
+        fn publish_mic_level(&mut self, transmitting: bool) {
+            if self.mic_meter.samples() < (self.config.sample_rate / 1000 * LEVEL_PUBLISH_MS) as usize { return; }
+            let (rms_dbfs, peak_dbfs) = self.mic_meter.take();
+            let level = MicLevel { rms_dbfs, peak_dbfs, transmitting };
+            if let Ok(mut published) = self.mic_level.try_lock() {
+                *published = Some(level);
+            }
+            self.dispatcher.notify(&self.level_callback, move |callback| callback.on_mic_level(level));
+        }
+    }
 
-                // We want to keep everything from 'samples_per_frame' up to 'buffer_pos'
-                // and move it to index 0.
-                let remaining = self.buffer_pos - self.samples_per_frame;
-                self.buffer.copy_within(self.samples_per_frame..self.buffer_pos, 0);
-                self.buffer_pos = remaining;
+    /// Opens and starts a mic stream. Device 0 lets the system pick.
+    fn open_input_stream(callback: InputStream, sample_rate: i32, device_id: i32) -> Result<CaptureStream, AudioError> {
+        let spec = StreamSpec { sample_rate, device_id, usage: Usage::Voice };
+        device::open_capture(spec, callback.clone(), callback).map_err(|e| {
+            log::error!("Open Input Stream Error: {}", e);
+            AudioError::DeviceError
+        })
+    }
+
+    /// The device side of the mic. The pipeline behind it is shared, so if the mic
+    /// disappears mid-session (a Bluetooth headset walking out of range), a stream
+    /// on the default mic carries on with the same encoder, frame buffer and
+    /// transmission instead of the session erroring out (see `fail_over`).
+    #[derive(Clone)]
+    struct InputStream {
+        input: Arc<Mutex<InputCallback>>,
+        stream: Arc<Mutex<Option<CaptureStream>>>,
+        sample_rate: i32,
+        error_callback: Arc<ErrorAggregator>,
+        failovers: Arc<AtomicU64>,
+    }
+
+    impl CaptureHandler for InputStream {
+        fn on_capture(&mut self, frames: &[i16]) {
+            // Only contended while a failover hands the pipeline over
+            if let Ok(mut input) = self.input.try_lock() {
+                input.process_capture(frames);
             }
-            DataCallbackResult::Continue
+        }
+    }
+
+    impl StreamErrors for InputStream {
+        fn on_error(&mut self, code: i32) {
+            self.error_callback.report(code);
         }
 
-        fn on_error_before_close(&mut self, _stream: &mut dyn AudioInputStreamSafe, error: oboe::Error) {
-            self.error_callback.on_engine_error(error as i32);
+        fn on_disconnected(&mut self) {
+            self.fail_over();
+        }
+    }
+
+    impl InputStream {
+        /// Reopens the mic on the default device, which is the built-in mic once a
+        /// headset is gone. Runs on its own thread, so the dead stream is dropped
+        /// there rather than from inside its own callback.
+        fn fail_over(&self) {
+            let replacement = self.clone();
+            let stream = self.stream.clone();
+            resources::spawn(move || {
+                let mut slot = stream.lock().unwrap();
+                // Stopped, or already reopened (new route or config) in the meantime
+                if slot.as_ref().is_none_or(|stream| stream.is_running()) {
+                    return;
+                }
+                log::warn!("Mic disconnected: switching to the default mic");
+                drop(slot.take());
+                replacement.input.lock().unwrap().begin_failover();
+                let (sample_rate, failovers) = (replacement.sample_rate, replacement.failovers.clone());
+                match open_input_stream(replacement, sample_rate, 0) {
+                    Ok(stream) => {
+                        *slot = Some(stream);
+                        failovers.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(e) => log::error!("Mic failover failed: {:?}", e),
+                }
+            });
         }
     }
 
     struct OutputCallback {
-        peers: HashMap<u32, PeerStream>,
-        packet_rx: Receiver<(u32, u16, Vec<u8>)>,
+        receive: ReceivePipeline<Decoder>,
+        packet_rx: Receiver<IncomingPacket>,
         sample_rate: i32,
-        max_jitter_packets: usize,
-        error_callback: Arc<Box<dyn AudioErrorCallback>>,
+        far_end: Option<Arc<Mutex<FarEndBuffer>>>, // Reference for the echo canceller
+        shared_far_end: Arc<Mutex<FarEndBuffer>>,    // Kept so AEC can be toggled at runtime
+        secondary_mix: Option<Arc<Mutex<SidetoneBuffer>>>, // Feeds the second output, if there is one
+        shared_secondary_mix: Arc<Mutex<SidetoneBuffer>>,
+        duck_rx: Receiver<DuckCommand>,
+        flush_rx: Receiver<u32>,
+        peer_settings_rx: Receiver<(u32, PeerSetting)>,
+        ducker: Ducker,
+        limiter: Limiter,
+        params: Arc<DspParams>,
+        output_clock: Arc<AtomicU64>,
+        stall_count: Arc<AtomicU64>, // Mid-transmission underruns (stability metric)
+        alert_rx: Receiver<Arc<[i16]>>,
+        alerts: Vec<(Arc<[i16]>, usize)>, // Playing clips and their read position
+        recording_tx: Arc<RecordingSlot>,
+        archive_tx: Arc<RecordingSlot<ArchiveInput>>,
+        replay_history: Arc<Mutex<MixHistory>>,
+        privacy_mode: Arc<AtomicBool>,
+        config_rx: Receiver<AudioConfig>,
+        position_callback: CallbackSlot<dyn PeerPositionCallback>,
+        scan_callback: CallbackSlot<dyn ScanCallback>,
+        level_callback: CallbackSlot<dyn AudioLevelCallback>,
+        peer_levels: Arc<Mutex<Vec<PeerLevel>>>,
+        level_interval: u64, // Samples between level publishes
+        levels_published_at: u64,
+        _tracked: Tracked,
+        output_volume: Arc<AtomicU32>,
+        sidetone: Arc<Mutex<SidetoneBuffer>>,
+        sidetone_gain: Arc<AtomicU32>,
+        receive_muted: Arc<AtomicBool>,
+        volume_gain: f32,  // Gain applied at the end of the last buffer (ramps towards output_volume)
+        receive_gain: f32, // Same, for the receive mute
+        comfort_noise: Option<ComfortNoise>,
+        peer_event_callback: CallbackSlot<dyn PeerEventCallback>,
+        dispatcher: Arc<CallbackDispatcher>,
+        traffic: Arc<TrafficCounters>,
+        peer_stats: Arc<Mutex<Vec<PeerStats>>>,
+        talk_stats: Arc<Mutex<BTreeMap<u32, TalkTally>>>,
+        stats_interval: u64,      // Samples between stats publishes
+        stats_published_at: u64,
+        peer_delays: Arc<Mutex<HashMap<u32, u32>>>,
+        delayed: Vec<(u64, IncomingPacket)>, // Debug-delayed packets and their release time
+    }
+
+    impl RenderHandler for OutputCallback {
+        fn on_render(&mut self, frames: &mut [i16]) {
+            self.render(frames);
+        }
     }
 
-    impl AudioOutputCallback for OutputCallback {
-        type FrameType = (i16, Mono);
+    impl OutputCallback {
+        fn apply_config(&mut self, config: AudioConfig) {
+            self.receive.apply_config(&config);
+            self.far_end = config.echo_cancellation.then(|| self.shared_far_end.clone());
+            self.secondary_mix = config.secondary_output.is_some().then(|| self.shared_secondary_mix.clone());
+            match config.comfort_noise_dbfs {
+                Some(level) => self.comfort_noise.get_or_insert_with(|| ComfortNoise::new(level)).set_level(level),
+                None => self.comfort_noise = None,
+            }
+        }
+
+        /// Hands a packet to the receive pipeline.
+        fn receive_packet(&mut self, packet: IncomingPacket, now: u64) {
+            let bytes = packet.wire_len as u64;
+            self.traffic.packets_received.fetch_add(1, Ordering::Relaxed);
+            self.traffic.bytes_received.fetch_add(bytes, Ordering::Relaxed);
+            if self.receive.receive(packet, now) {
+                self.stall_count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        fn flush_peer(&mut self, id: u32) {
+            self.delayed.retain(|(_, packet)| packet.origin_id != id);
+            self.receive.flush_peer(id);
+        }
+
+        /// Hands a talk edge to a running archive.
+        fn archive(archive_tx: &RecordingSlot<ArchiveInput>, input: ArchiveInput) {
+            if let Ok(slot) = archive_tx.try_lock()
+                && let Some(tx) = &*slot {
+                let _ = tx.try_send(input);
+            }
+        }
+
+        /// Passes what the peers did on to the app's callbacks.
+        fn dispatch_peer_events(&mut self) {
+            for event in self.receive.drain_events() {
+                match event {
+                    PeerEvent::StartedTalking(id) => {
+                        Self::archive(&self.archive_tx, ArchiveInput::Started(id));
+                        self.dispatcher.notify(&self.peer_event_callback, move |callback| callback.on_peer_started_talking(id));
+                    }
+                    PeerEvent::StoppedTalking(id) => {
+                        Self::archive(&self.archive_tx, ArchiveInput::Stopped(id));
+                        self.dispatcher.notify(&self.peer_event_callback, move |callback| callback.on_peer_stopped_talking(id));
+                    }
+                    PeerEvent::TimedOut(id) => {
+                        self.dispatcher.notify(&self.peer_event_callback, move |callback| callback.on_peer_timed_out(id));
+                    }
+                    PeerEvent::Position(id, position) => {
+                        self.dispatcher.notify(&self.position_callback, move |callback| callback.on_peer_position(id, position));
+                    }
+                    PeerEvent::ScanChannel(channel) => {
+                        self.dispatcher.notify(&self.scan_callback, move |callback| callback.on_scan_channel_changed(channel));
+                    }
+                }
+            }
+        }
+
+        fn render(&mut self, frames: &mut [i16]) {
+            while let Ok(config) = self.config_rx.try_recv() {
+                self.apply_config(config);
+            }
+
+            let now = self.output_clock.load(Ordering::Relaxed);
 
-        fn on_audio_ready(&mut self, _stream: &mut dyn AudioOutputStreamSafe, frames: &mut [i16]) -> DataCallbackResult {
             // 1. Drain Channel (Lock-Free)
-            while let Ok((id, seq, data)) = self.packet_rx.try_recv() {
-                let rate = self.sample_rate;
-                let peer = self.peers.entry(id).or_insert_with(|| PeerStream::new(rate));
-                peer.jitter_buffer.insert(seq, data);
-                peer.silence_counter = 0;
+            while let Ok(packet) = self.packet_rx.try_recv() {
+                // Debug delays: try_lock, so the audio thread never waits on the setter
+                let delay_ms = self.peer_delays.try_lock().ok()
+                    .and_then(|delays| delays.get(&packet.origin_id).copied())
+                    .unwrap_or(0);
+                if delay_ms > 0 {
+                    let release_at = now + (self.sample_rate as u64 / 1000) * delay_ms as u64;
+                    self.delayed.push((release_at, packet));
+                } else {
+                    self.receive_packet(packet, now);
+                }
+            }
+            let mut i = 0;
+            while i < self.delayed.len() {
+                if self.delayed[i].0 <= now {
+                    let (_, packet) = self.delayed.remove(i);
+                    self.receive_packet(packet, now);
+                } else {
+                    i += 1;
+                }
+            }
+            // After the drain, so packets queued before the request go too
+            while let Ok(id) = self.flush_rx.try_recv() {
+                self.flush_peer(id);
+            }
+            while let Ok((id, setting)) = self.peer_settings_rx.try_recv() {
+                self.receive.apply_peer_setting(id, setting);
             }
 
+            // 2. Process Peers (Local ownership, no mutex!)
             let samples_needed = frames.len();
             let mut mix_buffer = vec![0i32; samples_needed];
-            let mut dead_peers = Vec::new();
+            // Privacy mode: no talk history, not even in memory
+            let private = self.privacy_mode.load(Ordering::Relaxed);
+            self.receive.keep_talk = !private;
+            if private {
+                self.receive.talk.clear();
+            }
+            self.receive.mix(&mut mix_buffer, now);
+            self.dispatch_peer_events();
 
-            // 2. Process Peers (Local ownership, no mutex!)
-            for (&node_id, peer) in self.peers.iter_mut() {
-                peer.silence_counter += 1;
-                if peer.silence_counter > PEER_TIMEOUT_FRAMES * 5 {
-                     dead_peers.push(node_id);
-                     continue;
+            if now - self.stats_published_at >= self.stats_interval
+                && let Ok(mut stats) = self.peer_stats.try_lock() {
+                stats.clear();
+                stats.extend(self.receive.peer_stats());
+                self.stats_published_at = now;
+                if let Ok(mut talk) = self.talk_stats.try_lock() {
+                    talk.clone_from(&self.receive.talk);
+                }
+            }
+            if now - self.levels_published_at >= self.level_interval {
+                self.levels_published_at = now;
+                let levels = self.receive.take_levels();
+                if let Ok(mut published) = self.peer_levels.try_lock() {
+                    published.clone_from(&levels);
                 }
+                if !levels.is_empty() {
+                    self.dispatcher.notify(&self.level_callback, move |callback| callback.on_peer_levels(levels));
+                }
+            }
 
-                let mut peer_samples_produced = 0;
+            // The recording gets the peers as received, whatever happens locally
+            if let Ok(slot) = self.recording_tx.try_lock()
+                && let Some(tx) = &*slot {
+                PcmBlock::send(tx, mix_buffer.iter().map(|&s| s.clamp(i16::MIN as i32, i16::MAX as i32) as i16));
+            }
+            if let Ok(slot) = self.archive_tx.try_lock()
+                && let Some(tx) = &*slot {
+                PcmBlock::send(tx, mix_buffer.iter().map(|&s| s.clamp(i16::MIN as i32, i16::MAX as i32) as i16));
+            }
+            if !self.privacy_mode.load(Ordering::Relaxed)
+                && let Ok(mut history) = self.replay_history.try_lock() {
+                history.extend(mix_buffer.iter().map(|&s| s.clamp(i16::MIN as i32, i16::MAX as i32) as i16));
+            }
 
-                while peer_samples_produced < samples_needed {
-                    // A. Use leftover decoded audio
-                    if peer.buffer_len > 0 {
-                        let to_copy = std::cmp::min(samples_needed - peer_samples_produced, peer.buffer_len);
-                        for i in 0..to_copy {
-                            mix_buffer[peer_samples_produced + i] += peer.buffer[i] as i32;
-                        }
+            // Receive mute only silences the peers, after they have been decoded
+            let receive_target = if self.receive_muted.load(Ordering::Relaxed) { 0.0 } else { 1.0 };
+            apply_gain_ramp(&mut mix_buffer, &mut self.receive_gain, receive_target);
+            if let Some(noise) = &mut self.comfort_noise {
+                noise.fill(&mut mix_buffer, receive_target > 0.0 && self.receive.in_gap());
+            }
 
-                        let remaining = peer.buffer_len - to_copy;
-                        peer.buffer.copy_within(to_copy..peer.buffer_len, 0);
-                        peer.buffer_len = remaining;
-                        peer_samples_produced += to_copy;
-                        continue;
-                    }
+            // 3. Attenuation windows (sample-accurate ducking)
+            while let Ok(command) = self.duck_rx.try_recv() {
+                self.ducker.apply_command(command);
+            }
+            self.ducker.process(&mut mix_buffer);
 
-                    // B. Jitter Buffer Maintenance
-                    while peer.jitter_buffer.len() > self.max_jitter_packets {
-                        if let Some(&first) = peer.jitter_buffer.keys().next() {
-                            peer.jitter_buffer.remove(&first);
-                            peer.next_expected_seq = Some(first.wrapping_add(1));
-                        }
-                    }
+            // Alerts are local notifications: mixed after ducking so they stay audible
+            while let Ok(clip) = self.alert_rx.try_recv() {
+                self.alerts.push((clip, 0));
+            }
+            for (clip, pos) in self.alerts.iter_mut() {
+                let to_copy = std::cmp::min(samples_needed, clip.len() - *pos);
+                for (mixed, &sample) in mix_buffer.iter_mut().zip(&clip[*pos..*pos + to_copy]) {
+                    *mixed += sample as i32;
+                }
+                *pos += to_copy;
+            }
+            self.alerts.retain(|(clip, pos)| *pos < clip.len());
 
-                    // C. Buffering Logic
-                    if peer.buffering {
-                        if peer.jitter_buffer.len() >= JITTER_BUFFER_START_THRESHOLD {
-                            peer.buffering = false;
-                            if let Some(&first) = peer.jitter_buffer.keys().next() {
-                                peer.next_expected_seq = Some(first);
-                            }
-                        } else {
-                            break; // Still buffering
-                        }
-                    }
+            // The second output takes the mix from here and applies its own gain
+            if let Some(secondary) = &self.secondary_mix
+                && let Ok(mut secondary) = secondary.try_lock() {
+                let mix = mix_buffer.iter().map(|&s| s.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+                secondary.push(mix, (self.sample_rate / 1000 * SECONDARY_OUTPUT_MAX_LATENCY_MS) as usize);
+            }
 
-                    // D. Fetch/Loss Logic
-                    let mut packet_to_decode: Option<Option<Vec<u8>>> = None;
-
-                    if let Some(expected) = peer.next_expected_seq {
-                        if let Some(data) = peer.jitter_buffer.remove(&expected) {
-                            // Happy Path
-                            peer.next_expected_seq = Some(expected.wrapping_add(1));
-                            packet_to_decode = Some(Some(data));
-                        } else {
-                            // Miss - Check lookahead window using constant
-                            let has_future = peer.jitter_buffer.keys().any(|&k| {
-                                let delta = k.wrapping_sub(expected);
-                                delta > 0 && delta < JITTER_LOOKAHEAD_WINDOW
-                            });
-
-                            if has_future {
-                                // Lost -> PLC
-                                peer.next_expected_seq = Some(expected.wrapping_add(1));
-                                packet_to_decode = Some(None);
-                            } else if peer.jitter_buffer.is_empty() {
-                                // Underrun
-                                peer.buffering = true;
-                                break;
-                            } else {
-                                // Gap -> Resync
-                                if let Some(&next_avail) = peer.jitter_buffer.keys().next() {
-                                    peer.next_expected_seq = Some(next_avail.wrapping_add(1));
-                                    packet_to_decode = Some(Some(peer.jitter_buffer.remove(&next_avail).unwrap()));
-                                }
-                            }
-                        }
-                    }
+            let volume = f32::from_bits(self.output_volume.load(Ordering::Relaxed));
+            apply_gain_ramp(&mut mix_buffer, &mut self.volume_gain, volume);
 
-                    // E. Decode
-                    if let Some(maybe_data) = packet_to_decode {
-                        let mut decoded_chunk = [0i16; MAX_BUFFER_SIZE];
-                        let len = match maybe_data {
-                            Some(data) => peer.decoder.decode(&data, &mut decoded_chunk, false).unwrap_or(0),
-                            None => peer.decoder.decode(&[], &mut decoded_chunk, true).unwrap_or(0),
-                        };
-
-                        if len > 0 {
-                            let space_left = samples_needed - peer_samples_produced;
-                            let to_take = std::cmp::min(len, space_left);
-                            for i in 0..to_take {
-                                mix_buffer[peer_samples_produced + i] += decoded_chunk[i] as i32;
-                            }
-                            peer_samples_produced += to_take;
-                            if len > to_take {
-                                let remainder = len - to_take;
-                                for i in 0..remainder {
-                                    peer.buffer[i] = decoded_chunk[to_take + i];
-                                }
-                                peer.buffer_len = remainder;
-                            }
-                        }
-                    } else {
-                        break;
-                    }
-                }
+            // Sidetone after the volume: its own gain sets how loud we hear ourselves
+            let sidetone_gain = f32::from_bits(self.sidetone_gain.load(Ordering::Relaxed));
+            if sidetone_gain > 0.0
+                && let Ok(mut sidetone) = self.sidetone.try_lock() {
+                sidetone.mix_into(&mut mix_buffer, sidetone_gain);
             }
 
-            for id in dead_peers {
-                self.peers.remove(&id);
-            }
+            // 4. Limiter (instead of hard clipping when several peers overlap)
+            self.limiter.process(&mut mix_buffer, self.params.get(DspParam::LimiterThresholdDbfs));
 
-            for i in 0..samples_needed {
-                frames[i] = mix_buffer[i].clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+            simd::saturate(&mix_buffer, frames);
+
+            if let Some(far_end) = &self.far_end {
+                far_end.lock().unwrap().push(frames);
             }
+        }
+    }
+
+    /// Plays the main output's mix on a second device (`AudioConfig.secondary_output`).
+    /// The two streams run on their own clocks: it plays whatever the main output
+    /// has handed over, and silence where that is behind.
+    struct SecondaryOutputCallback {
+        mix: Arc<Mutex<SidetoneBuffer>>,
+        gain: Arc<AtomicU32>, // f32 bits
+        current_gain: f32,    // Gain applied at the end of the last buffer
+        limiter: Limiter,
+        params: Arc<DspParams>,
+        mix_buffer: Vec<i32>, // Reused, so rendering doesn't allocate
+        _tracked: Tracked,
+    }
 
-            DataCallbackResult::Continue
+    impl RenderHandler for SecondaryOutputCallback {
+        fn on_render(&mut self, frames: &mut [i16]) {
+            self.mix_buffer.clear();
+            self.mix_buffer.resize(frames.len(), 0);
+            let mix_buffer = &mut self.mix_buffer;
+            if let Ok(mut mix) = self.mix.try_lock() {
+                mix.mix_into(mix_buffer, 1.0);
+            }
+            let gain = f32::from_bits(self.gain.load(Ordering::Relaxed));
+            apply_gain_ramp(mix_buffer, &mut self.current_gain, gain);
+            self.limiter.process(mix_buffer, self.params.get(DspParam::LimiterThresholdDbfs));
+            simd::saturate(mix_buffer, frames);
         }
+    }
+
+    /// Plays one test tone on a temporary output stream, then silence until it is closed.
+    struct ToneCallback {
+        tone: Vec<i16>,
+        pos: usize,
+    }
 
-        fn on_error_before_close(&mut self, _stream: &mut dyn AudioOutputStreamSafe, error: oboe::Error) {
-            self.error_callback.on_engine_error(error as i32);
+    impl RenderHandler for ToneCallback {
+        fn on_render(&mut self, frames: &mut [i16]) {
+            let to_copy = std::cmp::min(frames.len(), self.tone.len() - self.pos);
+            frames[..to_copy].copy_from_slice(&self.tone[self.pos..self.pos + to_copy]);
+            frames[to_copy..].fill(0);
+            self.pos += to_copy;
         }
     }
 
     #[uniffi::export]
     pub fn init_logger() {
-        android_logger::init_once(
-            android_logger::Config::default().with_max_level(log::LevelFilter::Debug),
-        );
+        device::init_logger();
     }
 }
 
 // ===========================================================================
-// STUB IMPLEMENTATION (NON-ANDROID)
+// STUB IMPLEMENTATION (NO AUDIO BACKEND)
 // ===========================================================================
 
-#[cfg(not(target_os = "android"))]
+#[cfg(not(audio_backend))]
 mod stub_impl {
     use super::*;
+    use std::sync::Arc;
     #[derive(uniffi::Object)]
     pub struct AudioEngine;
     #[uniffi::export]
     impl AudioEngine {
         #[uniffi::constructor]
-        pub fn new(_c: AudioConfig, _t: Box<dyn PacketTransport>, _cb: Box<dyn AudioErrorCallback>, _id: u32) -> Self { Self }
+        pub fn new(_c: AudioConfig, _t: Box<dyn PacketTransport>, _cb: Box<dyn AudioErrorCallback>, _id: u32) -> Result<Self, AudioError> { Ok(Self) }
         pub fn start_session(&self) -> Result<(), AudioError> { Ok(()) }
+        pub fn start_pull_session(&self) -> Result<(), AudioError> { Ok(()) }
+        pub fn submit_capture_frames(&self, _f: Vec<i16>) {}
+        pub fn read_render_frames(&self, n: u32) -> Vec<i16> { vec![0; n as usize] }
+        pub fn add_attenuation_window(&self, _d: f32, _s: u32, _ms: u32) -> u32 { 0 }
+        pub fn remove_attenuation_window(&self, _id: u32) {}
+        pub fn set_dsp_param(&self, _n: String, _v: f32) -> Result<(), AudioError> { Ok(()) }
+        pub fn get_dsp_param(&self, _n: String) -> Option<f32> { None }
+        pub fn apply_param_snapshot(&self, _s: HashMap<String, f32>) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_dsp_param_callback(&self, _cb: Box<dyn DspParamCallback>) {}
+        pub fn preload_clip(&self, _id: String, _b: Vec<u8>) -> Result<(), AudioError> { Ok(()) }
+        pub fn play_alert(&self, _id: String) -> Result<(), AudioError> { Ok(()) }
+        pub fn get_replay(&self, _s: u32) -> Vec<i16> { Vec::new() }
+        pub fn play_replay(&self, _s: u32) -> Result<(), AudioError> { Ok(()) }
+        pub fn unload_clip(&self, _id: String) {}
+        pub fn clip_cache_bytes(&self) -> u64 { 0 }
+        pub fn set_voice_activity_callback(&self, _cb: Box<dyn VoiceActivityCallback>) {}
+        pub fn set_transmit_callback(&self, _cb: Box<dyn TransmitCallback>) {}
+        pub fn set_transmit_gate(&self, _g: Box<dyn TransmitGate>) {}
+        pub fn clear_transmit_gate(&self) {}
+        pub fn update_config(&self, _c: AudioConfig) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_encoder_bitrate(&self, _b: i32) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_route_preference(&self, _o: Vec<AudioRoute>) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_output_device(&self, _id: i32) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_input_device(&self, _id: i32) -> Result<(), AudioError> { Ok(()) }
+        pub fn notify_route_changed(&self) -> Result<(), AudioError> { Ok(()) }
+        pub fn notify_network_change(&self, _k: NetworkKind) {}
+        pub fn set_audio_route_callback(&self, _cb: Box<dyn AudioRouteCallback>) {}
+        pub fn set_bluetooth_sco_callback(&self, _cb: Box<dyn BluetoothScoCallback>) {}
+        pub fn play_test_tone(&self, _r: Option<AudioRoute>, _f: f32, _d: u32) -> Result<Option<AudioRoute>, AudioError> { Ok(None) }
+        pub fn set_output_health_callback(&self, _cb: Box<dyn OutputHealthCallback>) {}
+        pub fn set_transport_health_callback(&self, _cb: Box<dyn TransportHealthCallback>) {}
+        pub fn set_output_volume(&self, _v: f32) {}
+        pub fn set_input_gain(&self, _db: f32) {}
+        pub fn set_sidetone_gain(&self, _g: f32) {}
+        pub fn get_input_gain_report(&self) -> InputGainReport {
+            InputGainReport { software_gain_db: 0.0, software_agc: false, platform_preset: None, platform_processing: false }
+        }
+        pub fn set_receive_muted(&self, _m: bool) {}
+        pub fn set_privacy_mode(&self, _e: bool) {}
+        pub fn is_privacy_mode(&self) -> bool { false }
+        pub fn start_recording(&self, _p: String) -> Result<(), AudioError> { Ok(()) }
+        pub fn stop_recording(&self) {}
+        pub fn start_archive(&self, _d: String) -> Result<(), AudioError> { Ok(()) }
+        pub fn stop_archive(&self) {}
+        pub fn search_archive(&self, _p: Option<u32>, _f: u64, _t: u64) -> Vec<ArchiveSegment> { Vec::new() }
+        pub fn export_archive_clip(&self, _s: ArchiveSegment) -> Result<Vec<u8>, AudioError> { Ok(Vec::new()) }
+        pub fn start_mic_recording(&self, _p: String) -> Result<(), AudioError> { Ok(()) }
+        pub fn stop_mic_recording(&self) {}
+        pub fn start_voice_message(&self) -> Result<(), AudioError> { Ok(()) }
+        pub fn finish_voice_message(&self) -> Result<Vec<u8>, AudioError> { Ok(Vec::new()) }
+        pub fn play_voice_message(&self, _b: Vec<u8>) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_local_position(&self, _p: Option<PeerPosition>) {}
+        pub fn set_peer_event_callback(&self, _cb: Box<dyn PeerEventCallback>) {}
+        pub fn set_group_key(&self, _k: Vec<u8>) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_identity_key(&self, _k: Vec<u8>) -> Result<Vec<u8>, AudioError> { Ok(Vec::new()) }
+        pub fn set_peer_key_callback(&self, _cb: Box<dyn PeerKeyCallback>) {}
+        pub fn clear_keys(&self) {}
+        pub fn set_roster_admin(&self, _k: Vec<u8>) -> Result<(), AudioError> { Ok(()) }
+        pub fn publish_roster(&self, _v: u32, _k: Vec<Vec<u8>>) -> Result<(), AudioError> { Ok(()) }
+        pub fn get_roster(&self) -> PeerRoster { PeerRoster { version: 0, keys: Vec::new() } }
+        pub fn set_roster_callback(&self, _cb: Box<dyn RosterCallback>) {}
+        pub fn get_floor_holder(&self) -> Option<u32> { None }
+        pub fn set_floor_callback(&self, _cb: Box<dyn FloorCallback>) {}
+        pub fn set_emergency_callback(&self, _cb: Box<dyn EmergencyCallback>) {}
+        pub fn set_audio_loop_callback(&self, _cb: Box<dyn AudioLoopCallback>) {}
+        pub fn set_scan_callback(&self, _cb: Box<dyn ScanCallback>) {}
+        pub fn export_session_token(&self) -> Result<Vec<u8>, AudioError> { Ok(Vec::new()) }
+        pub fn resume_session(&self, _t: Vec<u8>) -> Result<(), AudioError> { Ok(()) }
+        pub fn get_stats(&self) -> EngineStats {
+            EngineStats { peers: Vec::new(), talk: Vec::new(), packets_sent: 0, bytes_sent: 0, packets_received: 0, bytes_received: 0, playout_stalls: 0, input_failovers: 0, callbacks_dropped: 0, packets_replayed: 0, packets_corrupted: 0, packets_looped: 0, packets_blocked: 0, packets_oversized: 0, packets_malformed: 0, packets_legacy: 0, packets_dropped_stale: 0, frames_gated: 0, frames_dtx: 0, session_transmit_seconds: 0.0, session_transmit_bytes: 0, transmit_quota_remaining_seconds: None, privacy_mode: false }
+        }
+        pub fn set_peer_position_callback(&self, _cb: Box<dyn PeerPositionCallback>) {}
+        pub fn set_audio_level_callback(&self, _cb: Box<dyn AudioLevelCallback>) {}
+        pub fn get_peer_levels(&self) -> Vec<PeerLevel> { Vec::new() }
+        pub fn get_mic_level(&self) -> Option<MicLevel> { None }
+        pub fn get_peer_capabilities(&self) -> Vec<PeerCapabilities> { Vec::new() }
+        pub fn run_benchmark(&self, _p: u32, _s: u32) -> Result<BenchmarkReport, AudioError> {
+            Ok(BenchmarkReport { peers: 0, sample_rate: 0, frame_size_ms: 0, audio_seconds: 0.0, processing_seconds: 0.0, realtime_factor: 0.0, stages: Vec::new() })
+        }
+        pub fn describe_effective_config(&self) -> EffectiveConfig {
+            EffectiveConfig { profile: None, settings: HashMap::new(), issue: None, messages: Vec::new() }
+        }
+        pub fn describe_pipeline(&self) -> PipelineGraph {
+            PipelineGraph {
+                mode: "stopped".to_string(),
+                sample_rate: 0,
+                capture: Vec::new(),
+                playback: Vec::new(),
+                cpu: crate::dsp::simd::cpu().features(),
+            }
+        }
+        pub fn debug_set_peer_delay(&self, _id: u32, _ms: u32) {}
+        pub fn flush_peer(&self, _id: u32) {}
+        pub fn set_peer_concealment(&self, _id: u32, _m: ConcealmentMode) {}
+        pub fn set_local_priority(&self, _p: u8) {}
+        pub fn set_peer_priority(&self, _id: u32, _p: Option<u8>) {}
+        pub fn mute_peer(&self, _id: u32) {}
+        pub fn unmute_peer(&self, _id: u32) {}
+        pub fn block_peer(&self, _id: u32) {}
+        pub fn unblock_peer(&self, _id: u32) {}
         pub fn stop_session(&self) -> Result<(), AudioError> { Ok(()) }
+        pub fn shutdown(&self) {}
+        pub fn get_resource_report(&self) -> ResourceReport {
+            ResourceReport { engines: 0, pipelines: 0, peer_streams: 0, encoders: 0, threads: 0 }
+        }
         pub fn is_session_active(&self) -> bool { false }
-        pub fn set_mic_enabled(&self, _e: bool) {}
+        pub fn set_mic_enabled(&self, _e: bool) -> Result<(), AudioError> { Ok(()) }
+        pub fn on_accessory_ptt(self: Arc<Self>, _p: bool) -> Result<(), AudioError> { Ok(()) }
+        pub fn start_emergency_transmission(&self) {}
+        pub fn stop_emergency_transmission(&self) -> Result<(), AudioError> { Ok(()) }
+        pub fn set_transmit_quota(&self, _s: u32) {}
+        pub fn set_bandwidth_budget_kbps(&self, _k: u32) {}
+        pub fn get_bandwidth_usage(&self) -> BandwidthUsage {
+            BandwidthUsage { budget_kbps: None, send_kbps: 0.0, receive_kbps: 0.0, bitrate_bps: 0, redundancy: false, fec: true, capped: false }
+        }
+        pub fn set_loopback_enabled(&self, _e: bool) {}
+        pub fn set_active_channel(&self, _c: u16) {}
+        pub fn set_scan_channels(&self, _c: Vec<u16>) {}
+        pub fn set_priority_channel(&self, _c: Option<u16>) {}
         pub fn push_incoming_packet(&self, _d: Vec<u8>) {}
     }
+    #[cfg(feature = "udp-transport")]
+    #[uniffi::export]
+    impl AudioEngine {
+        #[uniffi::constructor]
+        pub fn new_with_udp_multicast(_c: AudioConfig, _u: UdpMulticastConfig, _cb: Box<dyn AudioErrorCallback>, _id: u32) -> Result<Self, AudioError> { Ok(Self) }
+    }
+    #[cfg(feature = "net-sim")]
+    #[uniffi::export]
+    impl AudioEngine {
+        pub fn set_network_impairment(&self, _i: Option<NetworkImpairment>) {}
+    }
     #[uniffi::export]
     pub fn init_logger() {}
 }
 
-#[cfg(target_os = "android")]
+#[cfg(audio_backend)]
 pub use real_impl::{AudioEngine, init_logger};
-#[cfg(not(target_os = "android"))]
+#[cfg(not(audio_backend))]
 pub use stub_impl::{AudioEngine, init_logger};
\ No newline at end of file
@@ -0,0 +1,157 @@
+// ===========================================================================
+// LOCALIZATION
+// ===========================================================================
+// Text for users (`Message`) is an ID plus arguments, so apps can word it in
+// any language. The engine renders it too, in the language set with
+// `set_locale`: English or German, anything else falls back to English.
+// Numbers follow the locale's separators either way. The locale is
+// process-wide, like the log level, since `ConfigBuilder` has no engine.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{Message, MessageId};
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Language {
+    English,
+    German,
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(Language::English as u8);
+
+/// Sets the language of `Message.text` and the number format of `Message.args`,
+/// from a BCP 47 tag such as "de-AT". Languages without a catalog get English.
+#[uniffi::export]
+pub fn set_locale(tag: String) {
+    let language = match tag.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase().as_str() {
+        "de" => Language::German,
+        _ => Language::English,
+    };
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+fn language() -> Language {
+    if LANGUAGE.load(Ordering::Relaxed) == Language::German as u8 { Language::German } else { Language::English }
+}
+
+/// A message argument, formatted when the message is built.
+pub(crate) enum Arg {
+    Int(i64),
+    Float(f32),
+    Ints(Vec<i32>),
+}
+
+pub(crate) fn message(id: MessageId, args: &[Arg]) -> Message {
+    let language = language();
+    let args: Vec<String> = args.iter().map(|arg| format_arg(arg, language)).collect();
+    let mut text = template(id, language).to_string();
+    for (i, arg) in args.iter().enumerate() {
+        text = text.replace(&format!("{{{}}}", i), arg);
+    }
+    Message { id, args, text }
+}
+
+fn format_arg(arg: &Arg, language: Language) -> String {
+    match arg {
+        Arg::Int(value) => format_int(*value, language),
+        Arg::Float(value) => format_float(*value, language),
+        Arg::Ints(values) => values.iter().map(|&value| format_int(value as i64, language)).collect::<Vec<_>>().join(", "),
+    }
+}
+
+/// Digits grouped by thousands: "48,000" or "48.000".
+fn format_int(value: i64, language: Language) -> String {
+    let separator = if language == Language::German { '.' } else { ',' };
+    let digits = value.unsigned_abs().to_string();
+    let mut out = String::new();
+    if value < 0 { out.push('-'); }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(separator);
+        }
+        out.push(digit);
+    }
+    out
+}
+
+/// Up to two decimals, without trailing zeros: "0.5" or "0,5".
+fn format_float(value: f32, language: Language) -> String {
+    let rounded = (value * 100.0).round() as i64;
+    let (whole, hundredths) = (rounded / 100, (rounded % 100).unsigned_abs());
+    let sign = if rounded < 0 && whole == 0 { "-" } else { "" };
+    let mut out = format!("{}{}", sign, format_int(whole, language));
+    if hundredths != 0 {
+        out.push(if language == Language::German { ',' } else { '.' });
+        out.push_str(format!("{:02}", hundredths).trim_end_matches('0'));
+    }
+    out
+}
+
+fn template(id: MessageId, language: Language) -> &'static str {
+    use MessageId::*;
+    match language {
+        Language::English => match id {
+            SampleRateUnsupported => "The sample rate must be one of {0} Hz.",
+            FrameSizeUnsupported => "The frame size must be one of {0} ms.",
+            JitterBufferTooShort => "The jitter buffer must be at least {0} ms.",
+            BitrateOutOfRange => "The bitrate must be automatic or between {0} and {1} bps.",
+            NegativeValue => "The reorder wait and the AGC levels can't be negative.",
+            NoiseSuppressionNeedsSampleRate => "Noise suppression needs a sample rate of {0} Hz.",
+            VadGateNeedsVad => "Voice-gated transmit needs voice activity detection.",
+            MaxPacketBytesTooSmall => "The packet size limit must be at least {0} bytes.",
+            NegativeDuck => "Ducking can't be negative.",
+            RogerBeepOutOfRange => "The roger beep can last at most {0} ms and must stay below {1} Hz.",
+            ComfortNoiseOutOfRange => "Comfort noise must be between {0} and {1} dBFS.",
+            PttPreRollTooLong => "The push-to-talk pre-roll can be at most {0} ms.",
+            SecondaryOutputInvalid => "The second output needs a device and a gain of at most {0}.",
+            OpusComplexityClamped => "Encoder complexity {0} is out of range, {1} is used.",
+            ResyncGapClamped => "A resync gap of {0} packets is out of range, {1} is used.",
+            ReplayBufferCapped => "The replay buffer is capped at {0} s.",
+            RogerBeepLevelCapped => "The roger beep is capped at {0} dBFS.",
+        },
+        Language::German => match id {
+            SampleRateUnsupported => "Die Abtastrate muss {0} Hz betragen.",
+            FrameSizeUnsupported => "Die Framegröße muss {0} ms betragen.",
+            JitterBufferTooShort => "Der Jitter-Puffer muss mindestens {0} ms lang sein.",
+            BitrateOutOfRange => "Die Bitrate muss automatisch sein oder zwischen {0} und {1} bit/s liegen.",
+            NegativeValue => "Die Umordnungswartezeit und die AGC-Pegel dürfen nicht negativ sein.",
+            NoiseSuppressionNeedsSampleRate => "Die Rauschunterdrückung braucht eine Abtastrate von {0} Hz.",
+            VadGateNeedsVad => "Sprachgesteuertes Senden braucht die Sprachaktivitätserkennung.",
+            MaxPacketBytesTooSmall => "Die Paketgrößengrenze muss mindestens {0} Byte betragen.",
+            NegativeDuck => "Die Absenkung darf nicht negativ sein.",
+            RogerBeepOutOfRange => "Der Roger-Piep darf höchstens {0} ms dauern und muss unter {1} Hz bleiben.",
+            ComfortNoiseOutOfRange => "Das Komfortrauschen muss zwischen {0} und {1} dBFS liegen.",
+            PttPreRollTooLong => "Der Push-to-Talk-Vorlauf darf höchstens {0} ms betragen.",
+            SecondaryOutputInvalid => "Die zweite Ausgabe braucht ein Gerät und eine Verstärkung von höchstens {0}.",
+            OpusComplexityClamped => "Encoder-Komplexität {0} liegt außerhalb des Bereichs, verwendet wird {1}.",
+            ResyncGapClamped => "Eine Resync-Lücke von {0} Paketen liegt außerhalb des Bereichs, verwendet wird {1}.",
+            ReplayBufferCapped => "Der Wiedergabepuffer ist auf {0} s begrenzt.",
+            RogerBeepLevelCapped => "Der Roger-Piep ist auf {0} dBFS begrenzt.",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_follow_the_locale() {
+        assert_eq!(format_int(48000, Language::English), "48,000");
+        assert_eq!(format_int(-1275, Language::German), "-1.275");
+        assert_eq!(format_int(512, Language::German), "512");
+        assert_eq!(format_float(-20.0, Language::English), "-20");
+        assert_eq!(format_float(0.5, Language::German), "0,5");
+        assert_eq!(format_float(-0.25, Language::English), "-0.25");
+        assert_eq!(format_float(1234.5, Language::German), "1.234,5");
+    }
+
+    #[test]
+    fn arguments_fill_the_template() {
+        // No test calls set_locale, so this is English
+        let message = message(MessageId::BitrateOutOfRange, &[Arg::Int(500), Arg::Int(512_000)]);
+        assert_eq!(message.args, ["500", "512,000"]);
+        assert_eq!(message.text, "The bitrate must be automatic or between 500 and 512,000 bps.");
+    }
+}
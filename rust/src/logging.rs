@@ -0,0 +1,32 @@
+// ===========================================================================
+// ENGINE LOGGING
+// ===========================================================================
+// Privacy mode keeps the engine's logs to warnings and errors. The `log` max
+// level is process-wide and belongs to the app, so it is left alone: the
+// engine's info and debug lines go through `info!`/`debug!` (lib.rs), which
+// stay silent while any engine in the process is in privacy mode.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static QUIET_ENGINES: AtomicU32 = AtomicU32::new(0);
+
+/// Held by an engine while it is in privacy mode; dropping it lets logging resume.
+pub(crate) struct Quiet(());
+
+impl Quiet {
+    pub(crate) fn new() -> Self {
+        QUIET_ENGINES.fetch_add(1, Ordering::Relaxed);
+        Self(())
+    }
+}
+
+impl Drop for Quiet {
+    fn drop(&mut self) {
+        QUIET_ENGINES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Whether info and debug lines are suppressed.
+pub(crate) fn is_quiet() -> bool {
+    QUIET_ENGINES.load(Ordering::Relaxed) > 0
+}
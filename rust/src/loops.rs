@@ -0,0 +1,82 @@
+// ===========================================================================
+// LOOP DETECTION
+// ===========================================================================
+// Two gateways bridging the same channel send audio around in a loop: we hear
+// our own frames again, or a peer's frames twice, under a gateway's origin ID.
+// Bridges forward the Opus frames unchanged, so every frame we send or play is
+// fingerprinted (its length and CRC-32) for LOOP_WINDOW_MS. A frame seen again
+// from another origin is a looped copy and is dropped; the first origin wins.
+// Frames below LOOP_FINGERPRINT_MIN_BYTES (DTX, silence) are too alike across
+// speakers to tell apart, so they are left alone.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::protocol::crc32;
+use crate::{LOOP_FINGERPRINT_MIN_BYTES, LOOP_REPORT_INTERVAL_MS, LOOP_WINDOW_MS};
+
+/// A looped copy of `original_id`'s frame.
+pub(crate) struct LoopedFrame {
+    pub(crate) original_id: u32,
+    /// First copy from this origin since LOOP_REPORT_INTERVAL_MS: tell the app.
+    pub(crate) report: bool,
+}
+
+pub(crate) struct LoopDetector {
+    seen: HashMap<u64, u32>,         // Fingerprint -> origin it came from first
+    expiry: VecDeque<(u64, Instant)>, // Fingerprints in the order they were seen
+    reported: HashMap<(u32, u32), Instant>, // (Looped origin, original) -> last report
+}
+
+impl LoopDetector {
+    pub(crate) fn new() -> Self {
+        Self { seen: HashMap::new(), expiry: VecDeque::new(), reported: HashMap::new() }
+    }
+
+    /// Remembers a frame we sent ourselves.
+    pub(crate) fn record(&mut self, own_id: u32, frame: &[u8]) {
+        if frame.len() < LOOP_FINGERPRINT_MIN_BYTES { return; }
+        self.expire();
+        let fingerprint = fingerprint(frame);
+        if !self.seen.contains_key(&fingerprint) {
+            self.remember(fingerprint, own_id);
+        }
+    }
+
+    /// Checks a received frame. `Some` if it already came from another origin.
+    pub(crate) fn check(&mut self, origin_id: u32, frame: &[u8]) -> Option<LoopedFrame> {
+        if frame.len() < LOOP_FINGERPRINT_MIN_BYTES { return None; }
+        self.expire();
+        let fingerprint = fingerprint(frame);
+        let Some(&original_id) = self.seen.get(&fingerprint) else {
+            self.remember(fingerprint, origin_id);
+            return None;
+        };
+        if original_id == origin_id { return None; }
+
+        let last = self.reported.get(&(origin_id, original_id));
+        let report = last.is_none_or(|last| last.elapsed() > Duration::from_millis(LOOP_REPORT_INTERVAL_MS));
+        if report {
+            self.reported.insert((origin_id, original_id), Instant::now());
+        }
+        Some(LoopedFrame { original_id, report })
+    }
+
+    fn remember(&mut self, fingerprint: u64, origin_id: u32) {
+        self.seen.insert(fingerprint, origin_id);
+        self.expiry.push_back((fingerprint, Instant::now()));
+    }
+
+    fn expire(&mut self) {
+        let window = Duration::from_millis(LOOP_WINDOW_MS);
+        while let Some(&(fingerprint, at)) = self.expiry.front() {
+            if at.elapsed() <= window { break; }
+            self.expiry.pop_front();
+            self.seen.remove(&fingerprint);
+        }
+    }
+}
+
+fn fingerprint(frame: &[u8]) -> u64 {
+    (frame.len() as u64) << 32 | crc32(frame) as u64
+}
@@ -0,0 +1,162 @@
+// ===========================================================================
+// NETWORK IMPAIRMENT SIMULATOR (feature = "net-sim")
+// ===========================================================================
+// QA only. Wraps the engine's transport and makes the link worse on purpose:
+// packets are lost, duplicated, delayed with jitter, or held back so later
+// ones overtake them. The random decisions come from a seeded generator, so
+// one seed and one packet stream always give the same impairment, and a
+// jitter-buffer change can be compared against the same bad link.
+//
+// Delayed packets are sent by a scheduler thread in due order. Without an
+// impairment set, packets go straight through.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{unbounded, Receiver, RecvTimeoutError, Sender};
+
+use crate::resources;
+use crate::{NetworkImpairment, PacketTransport, NETSIM_MAX_DELAY_MS, NETSIM_REORDER_HOLD_MS};
+
+/// Where `AudioEngine::set_network_impairment` reaches the running simulator.
+pub(crate) type ImpairmentSlot = Arc<Mutex<Option<ImpairedLink>>>;
+
+/// An impairment in effect, with its random state.
+pub(crate) struct ImpairedLink {
+    impairment: NetworkImpairment,
+    rng: u64,
+}
+
+impl ImpairedLink {
+    pub(crate) fn new(mut impairment: NetworkImpairment) -> Self {
+        for percent in [&mut impairment.loss_percent, &mut impairment.duplicate_percent, &mut impairment.reorder_percent] {
+            *percent = percent.clamp(0.0, 100.0);
+        }
+        impairment.delay_ms = impairment.delay_ms.min(NETSIM_MAX_DELAY_MS);
+        impairment.jitter_ms = impairment.jitter_ms.min(NETSIM_MAX_DELAY_MS);
+        Self { rng: impairment.seed, impairment }
+    }
+
+    /// SplitMix64: tiny, and the same on every platform.
+    fn next(&mut self) -> u64 {
+        self.rng = self.rng.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn chance(&mut self, percent: f32) -> bool {
+        percent > 0.0 && (self.next() % 10_000) < (percent * 100.0) as u64
+    }
+
+    /// Base delay plus up to `jitter_ms`.
+    fn delay(&mut self) -> Duration {
+        let jitter = match self.impairment.jitter_ms {
+            0 => 0,
+            jitter => self.next() % (jitter as u64 + 1),
+        };
+        Duration::from_millis(self.impairment.delay_ms as u64 + jitter)
+    }
+
+    /// When each copy of the next packet should go out (empty if it is lost).
+    fn schedule(&mut self) -> Vec<Duration> {
+        if self.chance(self.impairment.loss_percent) {
+            return Vec::new();
+        }
+        let mut delays = vec![self.delay()];
+        if self.chance(self.impairment.reorder_percent) {
+            delays[0] += Duration::from_millis(NETSIM_REORDER_HOLD_MS);
+        }
+        if self.chance(self.impairment.duplicate_percent) {
+            delays.push(self.delay());
+        }
+        delays
+    }
+}
+
+struct Scheduled {
+    due: Instant,
+    order: u64, // Ties go out in the order they were scheduled
+    data: Vec<u8>,
+}
+
+impl PartialEq for Scheduled {
+    fn eq(&self, other: &Self) -> bool {
+        (self.due, self.order) == (other.due, other.order)
+    }
+}
+
+impl Eq for Scheduled {}
+
+impl PartialOrd for Scheduled {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scheduled {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.due, self.order).cmp(&(other.due, other.order))
+    }
+}
+
+/// Sits between the engine and the real transport.
+pub(crate) struct NetworkSimulator {
+    inner: Arc<dyn PacketTransport>,
+    link: ImpairmentSlot,
+    scheduler_tx: Sender<(Duration, Vec<u8>)>,
+}
+
+impl NetworkSimulator {
+    pub(crate) fn new(inner: Box<dyn PacketTransport>) -> Self {
+        let inner: Arc<dyn PacketTransport> = Arc::from(inner);
+        let (scheduler_tx, scheduler_rx) = unbounded();
+        let scheduled = inner.clone();
+        resources::spawn(move || run_scheduler(scheduler_rx, scheduled.as_ref()));
+        Self { inner, link: Arc::new(Mutex::new(None)), scheduler_tx }
+    }
+
+    pub(crate) fn link(&self) -> ImpairmentSlot {
+        self.link.clone()
+    }
+}
+
+impl PacketTransport for NetworkSimulator {
+    fn send_packet(&self, data: Vec<u8>) {
+        let delays = match &mut *self.link.lock().unwrap() {
+            Some(link) => link.schedule(),
+            None => return self.inner.send_packet(data),
+        };
+        for delay in delays {
+            let _ = self.scheduler_tx.send((delay, data.clone()));
+        }
+    }
+}
+
+/// Sends each packet when it is due. Ends when the simulator is dropped.
+fn run_scheduler(rx: Receiver<(Duration, Vec<u8>)>, transport: &dyn PacketTransport) {
+    let mut queue: BinaryHeap<Reverse<Scheduled>> = BinaryHeap::new();
+    let mut order = 0u64;
+    loop {
+        let received = match queue.peek() {
+            Some(Reverse(next)) => rx.recv_deadline(next.due),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok((delay, data)) => {
+                queue.push(Reverse(Scheduled { due: Instant::now() + delay, order, data }));
+                order += 1;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+        let now = Instant::now();
+        while queue.peek().is_some_and(|Reverse(next)| next.due <= now) {
+            let Reverse(next) = queue.pop().unwrap();
+            transport.send_packet(next.data);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// ===========================================================================
+// HOT-RELOADABLE DSP PARAMETERS
+// ===========================================================================
+// A small key/value registry of tunables shared between the API thread and the
+// audio callbacks. Values are stored as f32 bits in atomics so the real-time
+// side can read them every frame without taking a lock.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::{AudioConfig, JITTER_BUFFER_START_THRESHOLD};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DspParam {
+    AgcTargetLevelDb,
+    AgcMaxGainDb,
+    NsStrength,
+    LimiterThresholdDbfs,
+    JitterMinPackets,
+    JitterMaxPackets,
+}
+
+struct ParamSpec {
+    param: DspParam,
+    name: &'static str,
+    min: f32,
+    max: f32,
+}
+
+// Order must match the `DspParam` discriminants (used as array index).
+const PARAM_SPECS: [ParamSpec; 6] = [
+    ParamSpec { param: DspParam::AgcTargetLevelDb, name: "agc.target_level_db", min: 0.0, max: 40.0 },
+    ParamSpec { param: DspParam::AgcMaxGainDb, name: "agc.max_gain_db", min: 0.0, max: 40.0 },
+    ParamSpec { param: DspParam::NsStrength, name: "ns.strength", min: 0.0, max: 1.0 },
+    ParamSpec { param: DspParam::LimiterThresholdDbfs, name: "limiter.threshold_dbfs", min: -20.0, max: 0.0 },
+    ParamSpec { param: DspParam::JitterMinPackets, name: "jitter.min_packets", min: 1.0, max: 50.0 },
+    ParamSpec { param: DspParam::JitterMaxPackets, name: "jitter.max_packets", min: 1.0, max: 100.0 },
+];
+
+pub(crate) struct DspParams {
+    values: [AtomicU32; PARAM_SPECS.len()],
+}
+
+impl DspParams {
+    /// Seeds the registry from the static session config.
+    pub(crate) fn new(config: &AudioConfig) -> Self {
+        let params = Self { values: std::array::from_fn(|_| AtomicU32::new(0)) };
+        params.store(DspParam::AgcTargetLevelDb, config.agc_target_level_db as f32);
+        params.store(DspParam::AgcMaxGainDb, config.agc_max_gain_db as f32);
+        params.store(DspParam::NsStrength, 1.0);
+        params.store(DspParam::LimiterThresholdDbfs, 0.0);
+        params.seed_jitter(config);
+        params
+    }
+
+    /// Re-seeds the values derived from config fields that `update_config` changed.
+    /// Runtime overrides of everything else are kept.
+    pub(crate) fn apply_config(&self, old: &AudioConfig, new: &AudioConfig) {
+        if new.agc_target_level_db != old.agc_target_level_db {
+            self.store(DspParam::AgcTargetLevelDb, new.agc_target_level_db as f32);
+        }
+        if new.agc_max_gain_db != old.agc_max_gain_db {
+            self.store(DspParam::AgcMaxGainDb, new.agc_max_gain_db as f32);
+        }
+        if new.jitter_buffer_ms != old.jitter_buffer_ms || new.frame_size_ms != old.frame_size_ms {
+            self.seed_jitter(new);
+        }
+    }
+
+    fn seed_jitter(&self, config: &AudioConfig) {
+        let max_packets = (config.jitter_buffer_ms / config.frame_size_ms.max(1)).max(1) as f32;
+        self.store(DspParam::JitterMinPackets, (JITTER_BUFFER_START_THRESHOLD as f32).min(max_packets));
+        self.store(DspParam::JitterMaxPackets, max_packets);
+    }
+
+    pub(crate) fn lookup(name: &str) -> Option<DspParam> {
+        PARAM_SPECS.iter().find(|spec| spec.name == name).map(|spec| spec.param)
+    }
+
+    pub(crate) fn name(param: DspParam) -> &'static str {
+        PARAM_SPECS[param as usize].name
+    }
+
+    pub(crate) fn get(&self, param: DspParam) -> f32 {
+        f32::from_bits(self.values[param as usize].load(Ordering::Relaxed))
+    }
+
+    /// Validates and stores a new value. Returns `false` if it is out of bounds
+    /// or would leave the jitter limits inverted.
+    pub(crate) fn set(&self, param: DspParam, value: f32) -> bool {
+        self.apply_snapshot(&[(param, value)]).is_some()
+    }
+
+    /// Validates a whole set of values against each other and applies all or none.
+    /// Returns the values that were replaced (for rollback), or `None` if rejected.
+    pub(crate) fn apply_snapshot(&self, values: &[(DspParam, f32)]) -> Option<Vec<(DspParam, f32)>> {
+        let mut merged: [f32; PARAM_SPECS.len()] = std::array::from_fn(|i| self.get(PARAM_SPECS[i].param));
+        for &(param, value) in values {
+            let spec = &PARAM_SPECS[param as usize];
+            if !value.is_finite() || value < spec.min || value > spec.max {
+                return None;
+            }
+            merged[param as usize] = value;
+        }
+        if merged[DspParam::JitterMinPackets as usize] > merged[DspParam::JitterMaxPackets as usize] {
+            return None;
+        }
+
+        let previous = values.iter().map(|&(param, _)| (param, self.get(param))).collect();
+        for &(param, value) in values {
+            self.store(param, value);
+        }
+        Some(previous)
+    }
+
+    fn store(&self, param: DspParam, value: f32) {
+        self.values[param as usize].store(value.to_bits(), Ordering::Relaxed);
+    }
+}
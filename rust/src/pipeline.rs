@@ -0,0 +1,1227 @@
+// ===========================================================================
+// SEND & RECEIVE PIPELINES
+// ===========================================================================
+// The platform-agnostic core of the engine. `SendPipeline` turns captured PCM
+// into packets (frame assembly, encoding, packetization); `ReceivePipeline`
+// turns packets back into PCM (jitter buffers, loss handling, decoding and
+// the peer mix). Neither knows about audio devices or threads: the Oboe
+// callbacks drive them with push/pull calls, and so can anything else that
+// has PCM and packets, such as a test harness or another platform's backend.
+//
+// The codec is a parameter (`FrameEncoder` / `FrameDecoder`), implemented
+// for Opus where it is available.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::crypto::{GroupCipher, random_sequence};
+use crate::dsp::meter::LevelMeter;
+use crate::dsp::resample::Resampler;
+use crate::dsp::simd;
+use crate::dsp::tone::talker_chirp;
+use crate::dsp::tsm::TimeStretcher;
+use crate::params::{DspParam, DspParams};
+use crate::resources::{Resource, Tracked};
+use crate::protocol::{
+    IncomingPacket, PacketOptions, native_decode_rate, parse_opus_toc, unwrap_own_packet, wall_clock_ms, wrap_packet, wrap_rtp,
+};
+use crate::{
+    AudioConfig, CAPABILITY_CHECKSUM, CAPABILITY_PRIORITY, CONCEAL_REPEAT_DECAY, ConcealmentMode, CAPABILITY_PADDING, CAPABILITY_REDUNDANCY, END_TO_END_DELAY_SMOOTHING,
+    LOCAL_CAPABILITIES, MAX_BUFFER_SIZE, MIX_CHEAP_CONCEAL_PERCENT, MIX_DECODE_BUDGET_PERCENT, OPUS_DTX_MAX_BYTES, OPUS_OUT_BUFFER_SIZE, PEER_FADE_MS, PEER_TALK_END_MS, PEER_TIMEOUT_FRAMES,
+    PeerLevel, PeerPosition, PeerStats, PeerTalkStats, PreemptionPolicy, RTP_CLOCK_RATE, STALL_DETECT_WINDOW_MS, TSM_ACCELERATE_MARGIN,
+    TSM_EXPAND_DEPTH,
+};
+
+/// The codec a `SendPipeline` encodes with.
+pub(crate) trait FrameEncoder: Send {
+    type Error: Display;
+
+    /// Encodes one frame into `out`, returning the encoded length.
+    fn encode_frame(&mut self, pcm: &[i16], out: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+/// The codec a `ReceivePipeline` decodes with (one per peer).
+pub(crate) trait FrameDecoder: Send + Sized {
+    /// A mono decoder producing `sample_rate`.
+    fn with_rate(sample_rate: i32) -> Option<Self>;
+
+    /// Decodes one packet into `out`, returning the sample count. An empty packet
+    /// conceals a lost frame; `fec` decodes the copy of the previous frame instead.
+    fn decode_frame(&mut self, data: &[u8], out: &mut [i16], fec: bool) -> Option<usize>;
+
+    fn packet_samples(&self, data: &[u8]) -> Option<usize>;
+
+    fn reset(&mut self);
+}
+
+/// Linear fade-in over the start and/or fade-out over the end of `buf`, at most
+/// `ramp` samples each (shorter if the buffer is).
+fn fade_edges(buf: &mut [i32], fade_in: bool, fade_out: bool, ramp: usize) {
+    let len = buf.len();
+    let ramp = ramp.min(len).max(1);
+    for (i, sample) in buf.iter_mut().enumerate() {
+        let mut gain = 1.0f32;
+        if fade_in && i < ramp {
+            gain *= (i + 1) as f32 / ramp as f32;
+        }
+        if fade_out && len - i <= ramp {
+            gain *= (len - i - 1) as f32 / ramp as f32;
+        }
+        if gain < 1.0 {
+            *sample = (*sample as f32 * gain) as i32;
+        }
+    }
+}
+
+// --- Send ---
+
+/// Captured PCM in, packets out. The caller runs its own processing on each
+/// frame (`frame_mut`) before encoding it, and decides what gets sent.
+pub(crate) struct SendPipeline<E> {
+    pub(crate) encoder: E,
+    config: AudioConfig,
+    own_node_id: u32,
+    buffer: [i16; MAX_BUFFER_SIZE],
+    buffer_pos: usize,
+    samples_per_frame: usize,
+    encoded: [u8; OPUS_OUT_BUFFER_SIZE],
+    encoded_len: usize,
+    previous_frame: Option<Vec<u8>>, // Last frame sent in this transmission (redundancy)
+    rtp_timestamp: u32, // Capture clock at 48kHz, advanced every frame (sent or not)
+    burst_start: bool,  // The next packet starts a transmission (RTP marker bit)
+    in_dtx: bool,       // The last frame was a DTX frame (see `skip_dtx_frame`)
+    pub(crate) emergency: bool, // Flag packets as an emergency, with redundancy forced on
+    pub(crate) redundancy_allowed: bool, // False while the bandwidth budget can't afford it
+    _tracked: Tracked,
+}
+
+impl<E: FrameEncoder> SendPipeline<E> {
+    pub(crate) fn new(encoder: E, config: AudioConfig, own_node_id: u32) -> Self {
+        Self {
+            encoder,
+            config,
+            own_node_id,
+            buffer: [0i16; MAX_BUFFER_SIZE],
+            buffer_pos: 0,
+            samples_per_frame: (config.sample_rate / 1000 * config.frame_size_ms) as usize,
+            encoded: [0u8; OPUS_OUT_BUFFER_SIZE],
+            encoded_len: 0,
+            previous_frame: None,
+            rtp_timestamp: random_sequence(), // Random start, like the sequence (RFC 3550 §5.1)
+            burst_start: true,
+            in_dtx: false,
+            emergency: false,
+            redundancy_allowed: true,
+            _tracked: Tracked::new(Resource::Encoder),
+        }
+    }
+
+    /// Picks up a new frame size and packetization options (the encoder's own
+    /// settings are the caller's).
+    pub(crate) fn apply_config(&mut self, config: AudioConfig) {
+        self.samples_per_frame = (config.sample_rate / 1000 * config.frame_size_ms) as usize;
+        self.config = config;
+    }
+
+    pub(crate) fn frame_samples(&self) -> usize {
+        self.samples_per_frame
+    }
+
+    pub(crate) fn is_burst_start(&self) -> bool {
+        self.burst_start
+    }
+
+    /// Queues captured PCM (what doesn't fit in the frame buffer is dropped).
+    pub(crate) fn push(&mut self, pcm: &[i16]) {
+        let to_copy = pcm.len().min(MAX_BUFFER_SIZE - self.buffer_pos);
+        self.buffer[self.buffer_pos..self.buffer_pos + to_copy].copy_from_slice(&pcm[..to_copy]);
+        self.buffer_pos += to_copy;
+    }
+
+    /// Fades the last `ramp` samples queued down to silence, ahead of a gap in
+    /// the capture (the mic went away).
+    pub(crate) fn fade_out_queued(&mut self, ramp: usize) {
+        let ramp = ramp.min(self.buffer_pos);
+        let start = self.buffer_pos - ramp;
+        for (i, sample) in self.buffer[start..self.buffer_pos].iter_mut().enumerate() {
+            *sample = (*sample as f32 * (ramp - i - 1) as f32 / ramp as f32) as i16;
+        }
+    }
+
+    pub(crate) fn frame_ready(&self) -> bool {
+        self.buffer_pos >= self.samples_per_frame
+    }
+
+    /// The oldest complete frame, for processing in place.
+    pub(crate) fn frame_mut(&mut self) -> &mut [i16] {
+        &mut self.buffer[..self.samples_per_frame]
+    }
+
+    pub(crate) fn frame(&self) -> &[i16] {
+        &self.buffer[..self.samples_per_frame]
+    }
+
+    /// Done with the current frame, sent or not.
+    pub(crate) fn next_frame(&mut self) {
+        let frame_ticks = self.samples_per_frame as i64 * RTP_CLOCK_RATE as i64 / self.config.sample_rate.max(1) as i64;
+        self.rtp_timestamp = self.rtp_timestamp.wrapping_add(frame_ticks as u32);
+        self.buffer.copy_within(self.samples_per_frame..self.buffer_pos, 0);
+        self.buffer_pos -= self.samples_per_frame;
+    }
+
+    pub(crate) fn own_node_id(&self) -> u32 {
+        self.own_node_id
+    }
+
+    /// The last encoded frame.
+    pub(crate) fn encoded(&self) -> &[u8] {
+        &self.encoded[..self.encoded_len]
+    }
+
+    /// Encodes the current frame, returning the encoded size.
+    pub(crate) fn encode(&mut self) -> Result<usize, E::Error> {
+        let len = self.encoder.encode_frame(&self.buffer[..self.samples_per_frame], &mut self.encoded)?;
+        self.encoded_len = len;
+        Ok(len)
+    }
+
+    /// Encodes `pcm`, a frame kept from earlier, instead of the current one.
+    pub(crate) fn encode_pcm(&mut self, pcm: &[i16]) -> Result<usize, E::Error> {
+        let len = self.encoder.encode_frame(pcm, &mut self.encoded)?;
+        self.encoded_len = len;
+        Ok(len)
+    }
+
+    /// Wraps the last encoded frame as packet `seq`, RTP if so configured and
+    /// there is no cipher. `None` only if sealing fails. Optional fields are only
+    /// used once every peer supports them (`common_capabilities`).
+    pub(crate) fn packetize(
+        &mut self,
+        seq: u32,
+        cipher: Option<(&GroupCipher, u32)>,
+        position: Option<PeerPosition>,
+        priority: u8,
+        channel: u16,
+        common_capabilities: u32,
+    ) -> Option<Vec<u8>> {
+        let frame = &self.encoded[..self.encoded_len];
+        // RTP has no room for a channel: only channel 0 goes out as RTP
+        let packet = if self.config.rtp_packetization && cipher.is_none() && channel == 0 {
+            Some(wrap_rtp(self.own_node_id, seq as u16, self.rtp_timestamp, self.burst_start, frame))
+        } else {
+            let redundant = self.previous_frame.as_deref()
+                .filter(|_| self.sends_redundancy() && common_capabilities & CAPABILITY_REDUNDANCY != 0);
+            wrap_packet(
+                self.own_node_id, seq, wall_clock_ms(), frame, cipher,
+                PacketOptions {
+                    position,
+                    redundant,
+                    priority: (priority > 0 && common_capabilities & CAPABILITY_PRIORITY != 0).then_some(priority),
+                    emergency: self.emergency,
+                    channel,
+                    checksum: common_capabilities & CAPABILITY_CHECKSUM != 0,
+                    padded: self.config.pad_packets && common_capabilities & CAPABILITY_PADDING != 0,
+                },
+            )
+        };
+        if self.sends_redundancy() {
+            self.previous_frame = Some(frame.to_vec());
+        }
+        self.burst_start = false;
+        packet
+    }
+
+    /// Redundant copies ride along if configured and affordable, or always in an emergency.
+    fn sends_redundancy(&self) -> bool {
+        (self.config.redundancy && self.redundancy_allowed) || self.emergency
+    }
+
+    /// Whether to hold back a frame `encoded_len` bytes long. During a pause,
+    /// Opus DTX turns out tiny frames that only say the pause goes on. The first
+    /// is sent, so peers fill the pause with comfort noise instead of concealing
+    /// losses; the rest stay here without using up a sequence number, which
+    /// keeps the sequence unbroken. RTP timestamps still advance.
+    pub(crate) fn skip_dtx_frame(&mut self, encoded_len: usize) -> bool {
+        let dtx = encoded_len <= OPUS_DTX_MAX_BYTES;
+        let skip = dtx && self.in_dtx;
+        self.in_dtx = dtx;
+        skip
+    }
+
+    /// The next packet starts a new transmission, with no redundant frame.
+    pub(crate) fn end_burst(&mut self) {
+        self.burst_start = true;
+        self.previous_frame = None;
+        self.in_dtx = false;
+    }
+}
+
+#[allow(dead_code)]
+impl<E: FrameEncoder> SendPipeline<E> {
+    /// Encodes every complete frame of `pcm` (plus what was queued) as
+    /// one transmission from `*seq` on, as if every peer had our capabilities.
+    pub(crate) fn transmit(&mut self, pcm: &[i16], seq: &mut u32, cipher: Option<(&GroupCipher, u32)>) -> Vec<Vec<u8>> {
+        self.push(pcm);
+        let mut packets = Vec::new();
+        while self.frame_ready() {
+            match self.encode() {
+                Ok(_) => {
+                    if let Some(packet) = self.packetize(*seq, cipher, None, 0, 0, LOCAL_CAPABILITIES) {
+                        packets.push(packet);
+                    }
+                    *seq = seq.wrapping_add(1);
+                }
+                Err(e) => log::error!("Encode failed: {}", e),
+            }
+            self.next_frame();
+        }
+        packets
+    }
+}
+
+// --- Receive ---
+
+/// A packet waiting in a jitter buffer.
+struct BufferedPacket {
+    opus_data: Vec<u8>,
+    position: Option<PeerPosition>,
+    captured_at_ms: Option<u32>,
+    redundant: bool, // A copy carried by the next packet; the original was lost
+}
+
+/// Running talk-time totals for one peer (see `PeerTalkStats`).
+#[derive(Clone, Default)]
+pub(crate) struct TalkTally {
+    seconds: f64,
+    energy: f64, // Sum of squared samples (full scale = 1.0)
+    samples: u64,
+    seconds_by_hour: [f64; 24],
+}
+
+impl TalkTally {
+    fn add(&mut self, pcm: &[i16], sample_rate: i32) {
+        let seconds = pcm.len() as f64 / sample_rate.max(1) as f64;
+        let hour = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs() / 3600 % 24);
+        self.seconds += seconds;
+        self.seconds_by_hour[hour as usize] += seconds;
+        self.samples += pcm.len() as u64;
+        self.energy += pcm.iter().map(|&s| {
+            let x = s as f64 / i16::MAX as f64;
+            x * x
+        }).sum::<f64>();
+    }
+
+    pub(crate) fn stats(&self, peer_id: u32) -> PeerTalkStats {
+        PeerTalkStats {
+            peer_id,
+            talk_seconds: self.seconds as f32,
+            average_level_dbfs: (10.0 * (self.energy / self.samples.max(1) as f64 + 1e-10).log10()) as f32,
+            talk_seconds_by_hour: self.seconds_by_hour.iter().map(|&s| s as f32).collect(),
+        }
+    }
+}
+
+/// What a peer's next frame is decoded from.
+enum FrameSource {
+    Packet(BufferedPacket),
+    /// Lost, but the next packet carries a copy of it (Opus in-band FEC).
+    Fec(Vec<u8>),
+    /// Lost for good: Opus packet loss concealment.
+    Conceal,
+    /// Lost for good, filled in without the decoder (`ConcealmentMode::Repeat`).
+    Repeat,
+    /// Lost for good, left silent (`ConcealmentMode::Silence`).
+    Silence,
+    /// Nothing was sent: the peer paused under DTX. Its last DTX frame again.
+    Dtx(Vec<u8>),
+}
+
+struct PeerStream<D> {
+    decoder: D,
+    jitter_buffer: BTreeMap<u16, BufferedPacket>, // Keyed by the low 16 bits of the sequence
+    next_expected_seq: Option<u16>,
+    buffering: bool,
+    buffer: [i16; MAX_BUFFER_SIZE], // Internal scratch buffer for decoding
+    buffer_len: usize,              // How much valid data is in buffer
+    silence_counter: usize,         // For garbage collection
+    underrun_at: Option<u64>,       // Output clock when the jitter buffer last ran dry
+    missing_since: Option<u64>,     // Output clock when we started waiting for next_expected_seq
+    decode_rate: i32,               // Native rate of this peer's stream
+    packet_samples: usize,          // Duration of its latest packet at 48kHz (from the TOC)
+    resampler: Resampler,           // decode_rate -> mixer rate
+    talking: bool,                  // Between started/stopped talking events
+    producing: bool,                // Filled the whole of the last mixer callback
+    last_packet_at: u64,            // Output clock when the last packet arrived
+    end_to_end_delay_ms: Option<f32>,
+    last_frame: Vec<i16>, // Last frame decoded, at decode_rate (for cheap concealment)
+    repeat_gain: f32,     // Applied to the next repeat of `last_frame`
+    dtx_frame: Option<Vec<u8>>, // The peer is pausing under DTX: its last frame, replayed until it resumes
+    announced_priority: u8, // From the peer's latest packet (0 without the field)
+    preemption_gain: f32,   // Applied at the end of the last callback
+    channel: u16,           // Its latest packet came on
+    scan_rank: u32,         // Of that channel (see `set_scan_channels`)
+    chirp: Vec<i16>,        // Talker ID chirp at the mixer rate (made on first use)
+    chirp_pos: usize,       // Next chirp sample to play; `chirp.len()` when not playing
+    level: LevelMeter,      // What it played since `take_levels`
+    _tracked: Tracked,
+    // Stats
+    packets_received: u64,
+    bytes_received: u64,
+    packets_lost: u64,
+    packets_concealed: u64,
+    packets_concealed_cheaply: u64,
+    packets_recovered: u64,
+    decode_errors: u64,
+    callbacks_deferred: u64,
+}
+
+impl<D: FrameDecoder> PeerStream<D> {
+    fn new(decode_rate: i32, mixer_rate: i32, packet_samples: usize) -> Self {
+        let decoder = D::with_rate(decode_rate).unwrap();
+        Self {
+            decoder,
+            jitter_buffer: BTreeMap::new(),
+            next_expected_seq: None,
+            buffering: true,
+            buffer: [0i16; MAX_BUFFER_SIZE],
+            buffer_len: 0,
+            silence_counter: 0,
+            underrun_at: None,
+            missing_since: None,
+            decode_rate,
+            packet_samples,
+            resampler: Resampler::new(decode_rate, mixer_rate),
+            talking: false,
+            producing: false,
+            last_packet_at: 0,
+            end_to_end_delay_ms: None,
+            last_frame: Vec::with_capacity(MAX_BUFFER_SIZE),
+            repeat_gain: 1.0,
+            dtx_frame: None,
+            announced_priority: 0,
+            channel: 0,
+            scan_rank: 0,
+            chirp: Vec::new(),
+            chirp_pos: 0,
+            level: LevelMeter::new(),
+            _tracked: Tracked::new(Resource::PeerStream),
+            preemption_gain: 1.0,
+            packets_received: 0,
+            bytes_received: 0,
+            packets_lost: 0,
+            packets_concealed: 0,
+            packets_concealed_cheaply: 0,
+            packets_recovered: 0,
+            decode_errors: 0,
+            callbacks_deferred: 0,
+        }
+    }
+
+    /// Drops everything buffered and starts playout over as for a new peer.
+    /// Stats are kept.
+    fn flush(&mut self, mixer_rate: i32) {
+        self.jitter_buffer.clear();
+        self.next_expected_seq = None;
+        self.buffering = true;
+        self.buffer_len = 0;
+        self.underrun_at = None;
+        self.missing_since = None;
+        self.producing = false;
+        self.last_frame.clear();
+        self.dtx_frame = None;
+        self.resampler = Resampler::new(self.decode_rate, mixer_rate);
+        self.decoder.reset();
+    }
+
+    fn stats(&self, peer_id: u32, priority: u8) -> PeerStats {
+        let expected = self.packets_received + self.packets_lost;
+        PeerStats {
+            peer_id,
+            packets_received: self.packets_received,
+            packets_lost: self.packets_lost,
+            packet_loss_percent: if expected > 0 { self.packets_lost as f32 * 100.0 / expected as f32 } else { 0.0 },
+            jitter_buffer_depth: self.jitter_buffer.len() as u32,
+            packets_concealed: self.packets_concealed,
+            packets_concealed_cheaply: self.packets_concealed_cheaply,
+            packets_recovered: self.packets_recovered,
+            decode_errors: self.decode_errors,
+            bytes_received: self.bytes_received,
+            talking: self.talking,
+            end_to_end_delay_ms: self.end_to_end_delay_ms.map(|delay| delay.round() as i32),
+            callbacks_deferred: self.callbacks_deferred,
+            priority,
+            frame_size_ms: self.packet_samples as f32 * 1000.0 / RTP_CLOCK_RATE as f32,
+        }
+    }
+
+    /// Mix order, highest first: whoever is mid-speech, then peers starting or
+    /// finishing a burst, then the rest; the most recently heard first within each.
+    fn mix_priority(&self) -> (bool, bool, u64) {
+        (self.talking && self.producing, self.talking, self.last_packet_at)
+    }
+
+    /// A jitter limit counted in packets of our own length, as a count of this
+    /// peer's packets: a peer sending 20ms frames needs three times as many to
+    /// hold the same time as our 60ms. At least 1.
+    fn packets_for(&self, packets: usize, own_packet_samples: usize) -> usize {
+        (packets * own_packet_samples).div_ceil(self.packet_samples.max(1)).max(1)
+    }
+
+    /// One of this peer's packets in samples at its decode rate: how much a lost
+    /// one is concealed with.
+    fn frame_samples(&self) -> usize {
+        (self.packet_samples * self.decode_rate.max(0) as usize / RTP_CLOCK_RATE as usize).clamp(1, MAX_BUFFER_SIZE)
+    }
+
+    /// Drops the oldest packets past `max_packets`.
+    fn trim_jitter_buffer(&mut self, max_packets: usize) {
+        while self.jitter_buffer.len() > max_packets {
+            if let Some(first) = oldest_seq(&self.jitter_buffer) {
+                self.jitter_buffer.remove(&first);
+                self.next_expected_seq = Some(first.wrapping_add(1));
+                self.missing_since = None;
+            }
+        }
+    }
+
+    /// Whether this callback would decode or play anything for the peer.
+    fn has_audio(&self) -> bool {
+        self.buffer_len > 0 || !self.jitter_buffer.is_empty()
+    }
+
+    /// Fills `out` for a lost frame without the decoder: the last frame again,
+    /// quieter with every repeat, or silence of the same length.
+    fn conceal_cheaply(&mut self, out: &mut [i16], repeat: bool) -> usize {
+        let len = self.last_frame.len().min(out.len());
+        if repeat {
+            self.repeat_gain *= CONCEAL_REPEAT_DECAY;
+            for (sample, &last) in out.iter_mut().zip(&self.last_frame) {
+                *sample = (last as f32 * self.repeat_gain) as i16;
+            }
+        } else {
+            out[..len].fill(0);
+        }
+        len
+    }
+
+    /// Switches to a new native rate. Only done between talk spurts, since the
+    /// decoder state is lost.
+    fn set_decode_rate(&mut self, decode_rate: i32, mixer_rate: i32) {
+        if let Some(decoder) = D::with_rate(decode_rate) {
+            debug!("Peer decode rate {} -> {} Hz", self.decode_rate, decode_rate);
+            self.decoder = decoder;
+            self.decode_rate = decode_rate;
+            self.last_frame.clear();
+            self.resampler = Resampler::new(decode_rate, mixer_rate);
+        }
+    }
+}
+
+/// The earliest sequence number in a jitter buffer. Sequences start at random, so
+/// the buffer regularly straddles the wrap: then the keys past the gap come first.
+fn oldest_seq<T>(jitter_buffer: &BTreeMap<u16, T>) -> Option<u16> {
+    let (&lowest, &highest) = (jitter_buffer.keys().next()?, jitter_buffer.keys().next_back()?);
+    if highest - lowest > u16::MAX / 2 {
+        jitter_buffer.range(u16::MAX / 2..).next().map(|(&seq, _)| seq)
+    } else {
+        Some(lowest)
+    }
+}
+
+/// Something a peer did while being mixed, for the app's callbacks.
+pub(crate) enum PeerEvent {
+    StartedTalking(u32),
+    StoppedTalking(u32),
+    TimedOut(u32),
+    /// The position sent with the frame about to be heard.
+    Position(u32, PeerPosition),
+    /// The mixer locked onto another channel, or none is active any more.
+    ScanChannel(Option<u16>),
+}
+
+/// A per-peer setting from the app, for the running pipeline.
+#[derive(Clone, Copy)]
+pub(crate) enum PeerSetting {
+    Concealment(ConcealmentMode),
+    /// Replaces the priority the peer announces; `None` goes back to it.
+    Priority(Option<u8>),
+    /// Decoded but left out of the mix.
+    Muted(bool),
+}
+
+/// Packets in, mixed PCM out. Times (`now`) are in samples on the caller's
+/// output clock.
+pub(crate) struct ReceivePipeline<D> {
+    peers: BTreeMap<u32, PeerStream<D>>, // Ordered, so mixing and stats run the same way every time
+    sample_rate: i32,
+    params: Arc<DspParams>,
+    stretcher: TimeStretcher,
+    reorder_wait_samples: u64,
+    resync_gap_packets: u16,
+    talk_end_samples: u64,
+    peer_fade_samples: usize,
+    stall_window: u64,
+    packet_samples: usize, // Our own packets' duration at 48kHz, which the jitter limits count in
+    pub(crate) talk: BTreeMap<u32, TalkTally>,
+    pub(crate) keep_talk: bool, // Off in privacy mode
+    pub(crate) concealment: BTreeMap<u32, ConcealmentMode>, // Peers not on `Auto`
+    pub(crate) priorities: BTreeMap<u32, u8>, // Overrides of what peers announce
+    pub(crate) muted: BTreeSet<u32>,
+    preemption: Option<PreemptionPolicy>,
+    preemption_gain: f32, // For `PreemptionPolicy::Duck`
+    talker_id_chirp: bool,
+    max_speakers: usize, // 0 = no limit
+    scan_lock: Option<u16>, // Channel the mixer plays while scanning
+    events: Vec<PeerEvent>,
+}
+
+impl<D: FrameDecoder> ReceivePipeline<D> {
+    pub(crate) fn new(config: &AudioConfig, params: Arc<DspParams>) -> Self {
+        let rate = config.sample_rate;
+        let mut pipeline = Self {
+            peers: BTreeMap::new(),
+            sample_rate: rate,
+            params,
+            stretcher: TimeStretcher::new(rate),
+            reorder_wait_samples: 0,
+            resync_gap_packets: 0,
+            talk_end_samples: (rate / 1000 * PEER_TALK_END_MS) as u64,
+            peer_fade_samples: (rate / 1000 * PEER_FADE_MS) as usize,
+            stall_window: (rate / 1000 * STALL_DETECT_WINDOW_MS) as u64,
+            packet_samples: 0,
+            talk: BTreeMap::new(),
+            keep_talk: true,
+            concealment: BTreeMap::new(),
+            priorities: BTreeMap::new(),
+            muted: BTreeSet::new(),
+            preemption: None,
+            preemption_gain: 1.0,
+            talker_id_chirp: false,
+            max_speakers: 0,
+            scan_lock: None,
+            events: Vec::new(),
+        };
+        pipeline.apply_config(config);
+        pipeline
+    }
+
+    /// Jitter targets live in the shared params (re-seeded by `update_config`).
+    pub(crate) fn apply_config(&mut self, config: &AudioConfig) {
+        self.reorder_wait_samples = (config.sample_rate / 1000 * config.reorder_wait_ms.max(0)) as u64;
+        self.resync_gap_packets = config.resync_gap_packets.clamp(2, u16::MAX as i32) as u16;
+        self.preemption = config.preemption;
+        self.preemption_gain = 10f32.powf(-config.preemption_duck_db.max(0.0) / 20.0);
+        self.talker_id_chirp = config.talker_id_chirp;
+        self.max_speakers = config.max_concurrent_speakers as usize;
+        self.packet_samples = (RTP_CLOCK_RATE / 1000 * config.frame_size_ms.max(1)) as usize;
+    }
+
+    /// Hands a packet to its peer's jitter buffer. True if it ends a stall: the
+    /// peer's buffer had run dry mid-transmission.
+    pub(crate) fn receive(&mut self, packet: IncomingPacket, now: u64) -> bool {
+        let IncomingPacket { origin_id: id, seq, captured_at_ms, position, opus_data: data, redundant, priority, emergency, channel, scan_rank, wire_len, .. } = packet;
+        let rate = self.sample_rate;
+        let packet_samples = parse_opus_toc(&data).map_or(self.packet_samples, |toc| toc.packet_samples() as usize);
+        let peer = self.peers.entry(id).or_insert_with(|| PeerStream::new(native_decode_rate(&data, rate), rate, packet_samples));
+        peer.packet_samples = packet_samples;
+        peer.packets_received += 1;
+        peer.bytes_received += wire_len as u64;
+        // An emergency outranks every priority
+        peer.announced_priority = if emergency { u8::MAX } else { priority.unwrap_or(0) };
+        peer.channel = channel;
+        peer.scan_rank = scan_rank;
+        peer.jitter_buffer.insert(seq as u16, BufferedPacket { opus_data: data, position, captured_at_ms, redundant: false });
+
+        // The previous frame's copy fills its slot, unless it arrived or was already played.
+        // A late original still replaces the copy.
+        let previous = (seq as u16).wrapping_sub(1);
+        let still_ahead = peer.next_expected_seq.is_none_or(|expected| previous.wrapping_sub(expected) < u16::MAX / 2);
+        if let Some(frame) = redundant
+            && still_ahead
+            && !peer.jitter_buffer.contains_key(&previous) {
+            peer.jitter_buffer.insert(previous, BufferedPacket { opus_data: frame, position: None, captured_at_ms: None, redundant: true });
+        }
+        peer.silence_counter = 0;
+        peer.last_packet_at = now;
+        peer.underrun_at.take().is_some_and(|underrun_at| now - underrun_at < self.stall_window)
+    }
+
+    /// Starts a peer's playout over, dropping what is buffered.
+    pub(crate) fn flush_peer(&mut self, id: u32) {
+        let Some(peer) = self.peers.get_mut(&id) else { return };
+        peer.flush(self.sample_rate);
+        if std::mem::take(&mut peer.talking) {
+            self.events.push(PeerEvent::StoppedTalking(id));
+        }
+    }
+
+    pub(crate) fn apply_peer_setting(&mut self, id: u32, setting: PeerSetting) {
+        match setting {
+            PeerSetting::Concealment(ConcealmentMode::Auto) => { self.concealment.remove(&id); }
+            PeerSetting::Concealment(mode) => { self.concealment.insert(id, mode); }
+            PeerSetting::Priority(None) => { self.priorities.remove(&id); }
+            PeerSetting::Priority(Some(priority)) => { self.priorities.insert(id, priority); }
+            PeerSetting::Muted(true) => { self.muted.insert(id); }
+            PeerSetting::Muted(false) => { self.muted.remove(&id); }
+        }
+    }
+
+    /// Someone is mid-transmission but nobody filled the last callback: an underrun
+    /// or a stall (see `AudioConfig.comfort_noise_dbfs`).
+    pub(crate) fn in_gap(&self) -> bool {
+        self.peers.values().any(|peer| peer.talking)
+            && !self.peers.values().any(|peer| peer.talking && peer.producing)
+    }
+
+    /// Every peer's level since the last call (see `PeerLevel`).
+    pub(crate) fn take_levels(&mut self) -> Vec<PeerLevel> {
+        self.peers.iter_mut().map(|(&peer_id, peer)| {
+            let (rms_dbfs, peak_dbfs) = peer.level.take();
+            PeerLevel { peer_id, rms_dbfs, peak_dbfs }
+        }).collect()
+    }
+
+    /// Events since the last call, in order.
+    pub(crate) fn drain_events(&mut self) -> std::vec::Drain<'_, PeerEvent> {
+        self.events.drain(..)
+    }
+
+    pub(crate) fn peer_stats(&self) -> impl Iterator<Item = PeerStats> + '_ {
+        self.peers.iter().map(|(&id, peer)| peer.stats(id, self.priority(id, peer)))
+    }
+
+    fn priority(&self, id: u32, peer: &PeerStream<D>) -> u8 {
+        self.priorities.get(&id).copied().unwrap_or(peer.announced_priority)
+    }
+
+    /// Speakers over `max_speakers`. Priority decides, then peers already talking
+    /// keep their turn over new ones; the node ID breaks ties so the choice is stable.
+    fn held_speakers(&self) -> BTreeSet<u32> {
+        if self.max_speakers == 0 { return BTreeSet::new(); }
+        let mut speakers: Vec<_> = self.peers.iter()
+            .filter(|&(id, peer)| (peer.talking || peer.has_audio()) && !self.muted.contains(id))
+            .map(|(&id, peer)| (self.priority(id, peer), peer.talking, id))
+            .collect();
+        speakers.sort_unstable_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)).then(a.2.cmp(&b.2)));
+        speakers.into_iter().skip(self.max_speakers).map(|(_, _, id)| id).collect()
+    }
+
+    /// The channel to play while scanning: the one we are locked onto while someone
+    /// there is still talking and no channel with someone talking outranks it, else
+    /// the best-ranked such channel. Equal ranks (see `set_priority_channel`) keep the
+    /// lock, so a burst is heard to the end. `None` while nobody talks.
+    fn scan_channel(&self) -> Option<u16> {
+        let talking = || self.peers.values().filter(|peer| peer.talking);
+        let top = talking().map(|peer| peer.scan_rank).min()?;
+        let candidates = talking().filter(|peer| peer.scan_rank == top).map(|peer| peer.channel);
+        match self.scan_lock {
+            Some(locked) if candidates.clone().any(|channel| channel == locked) => Some(locked),
+            _ => candidates.min(),
+        }
+    }
+
+    /// Adds every peer's next `mix_buffer.len()` samples to `mix_buffer`.
+    ///
+    /// Peers are decoded in priority order (see `PeerStream::mix_priority`). The
+    /// first always is; once the decode budget for this callback is spent, the
+    /// others keep their packets buffered and catch up in a later callback (the
+    /// time-stretcher absorbs the extra depth), so on a slow CPU the current
+    /// speaker is never the one who breaks up. Before that, past
+    /// MIX_CHEAP_CONCEAL_PERCENT, peers on `ConcealmentMode::Auto` other than the
+    /// first stop spending decoder time on lost frames and repeat the last one.
+    ///
+    /// With `AudioConfig.preemption`, peers below the highest priority among those
+    /// talking are ducked or muted (ramped over one callback). While scanning, only the
+    /// channel locked onto (see `scan_channel`) is heard; the others are muted the same way,
+    /// as are peers in `muted`. Speakers over `max_speakers` (see `held_speakers`) sit
+    /// the callback out like deferred ones.
+    pub(crate) fn mix(&mut self, mix_buffer: &mut [i32], now: u64) {
+        // Jitter limits are hot-reloadable, so sample them once per callback
+        let min_jitter_packets = self.params.get(DspParam::JitterMinPackets) as usize;
+        let max_jitter_packets = self.params.get(DspParam::JitterMaxPackets) as usize;
+
+        let samples_needed = mix_buffer.len();
+        let mut peer_buffer = vec![0i32; samples_needed]; // One peer's share, faded before mixing
+        let mut dead_peers = Vec::new();
+
+        let started = Instant::now();
+        let callback = Duration::from_secs_f64(samples_needed as f64 / self.sample_rate.max(1) as f64);
+        let budget = callback.mul_f64(MIX_DECODE_BUDGET_PERCENT as f64 / 100.0);
+        let cheap_conceal_after = callback.mul_f64(MIX_CHEAP_CONCEAL_PERCENT as f64 / 100.0);
+        let mut order: Vec<_> = self.peers.iter().map(|(&id, peer)| (peer.mix_priority(), id)).collect();
+        order.sort_unstable_by(|a, b| b.cmp(a));
+        let top_priority = self.peers.iter()
+            .filter(|(_, peer)| peer.talking)
+            .map(|(&id, peer)| self.priority(id, peer))
+            .max();
+        let held = self.held_speakers();
+        let heard_channel = self.scan_channel();
+        if heard_channel != self.scan_lock {
+            self.scan_lock = heard_channel;
+            self.events.push(PeerEvent::ScanChannel(heard_channel));
+        }
+
+        for (rank, &(_, node_id)) in order.iter().enumerate() {
+            let scanned_over = self.peers.get(&node_id)
+                .is_some_and(|peer| heard_channel.is_some_and(|heard| peer.channel != heard));
+            let preemption_gain = match (self.preemption, self.peers.get(&node_id), top_priority) {
+                _ if scanned_over || self.muted.contains(&node_id) => 0.0,
+                (Some(policy), Some(peer), Some(top)) if self.priority(node_id, peer) < top => match policy {
+                    PreemptionPolicy::Duck => self.preemption_gain,
+                    PreemptionPolicy::Suppress => 0.0,
+                },
+                _ => 1.0,
+            };
+            let Some(peer) = self.peers.get_mut(&node_id) else { continue };
+            // The limits count our own packets; this peer's may be shorter or longer
+            let min_packets = peer.packets_for(min_jitter_packets, self.packet_samples);
+            let max_packets = peer.packets_for(max_jitter_packets, self.packet_samples);
+            let accelerate_depth = peer.packets_for(min_jitter_packets + TSM_ACCELERATE_MARGIN, self.packet_samples);
+            let expand_depth = peer.packets_for(TSM_EXPAND_DEPTH, self.packet_samples);
+            let resync_gap = peer.packets_for(self.resync_gap_packets as usize, self.packet_samples).clamp(2, u16::MAX as usize) as u16;
+            peer.silence_counter += 1;
+            if peer.silence_counter > PEER_TIMEOUT_FRAMES * 5 {
+                 dead_peers.push(node_id);
+                 continue;
+            }
+            if held.contains(&node_id) {
+                // Over max_concurrent_speakers: buffered until a turn frees up
+                peer.trim_jitter_buffer(max_packets);
+                peer.callbacks_deferred += 1;
+                peer.producing = false;
+                continue;
+            }
+            if rank > 0 && peer.has_audio() && started.elapsed() > budget {
+                // Over budget: this peer fades back in when it next gets a turn
+                peer.callbacks_deferred += 1;
+                peer.producing = false;
+                continue;
+            }
+
+            let mut peer_samples_produced = 0;
+            peer_buffer.fill(0);
+
+            while peer_samples_produced < samples_needed {
+                // The talker ID chirp goes ahead of the burst, which waits in the jitter buffer
+                if peer.chirp_pos < peer.chirp.len() {
+                    let to_copy = std::cmp::min(samples_needed - peer_samples_produced, peer.chirp.len() - peer.chirp_pos);
+                    for i in 0..to_copy {
+                        peer_buffer[peer_samples_produced + i] = peer.chirp[peer.chirp_pos + i] as i32;
+                    }
+                    peer.chirp_pos += to_copy;
+                    peer_samples_produced += to_copy;
+                    continue;
+                }
+
+                // A. Use leftover decoded audio
+                if peer.buffer_len > 0 {
+                    let to_copy = std::cmp::min(samples_needed - peer_samples_produced, peer.buffer_len);
+                    for i in 0..to_copy {
+                        peer_buffer[peer_samples_produced + i] = peer.buffer[i] as i32;
+                    }
+
+                    let remaining = peer.buffer_len - to_copy;
+                    peer.buffer.copy_within(to_copy..peer.buffer_len, 0);
+                    peer.buffer_len = remaining;
+                    peer_samples_produced += to_copy;
+                    continue;
+                }
+
+                // B. Jitter Buffer Maintenance (last resort; time-stretching normally keeps us below max)
+                peer.trim_jitter_buffer(max_packets);
+
+                // C. Buffering Logic
+                if peer.buffering {
+                    if peer.jitter_buffer.len() >= min_packets {
+                        peer.buffering = false;
+                        if let Some(first) = oldest_seq(&peer.jitter_buffer) {
+                            let data = &peer.jitter_buffer[&first].opus_data;
+                            peer.next_expected_seq = Some(first);
+                            // New talk spurt: follow the sender if its bandwidth changed
+                            let native_rate = native_decode_rate(data, self.sample_rate);
+                            if native_rate != peer.decode_rate {
+                                peer.set_decode_rate(native_rate, self.sample_rate);
+                            }
+                        }
+                        if !peer.talking {
+                            peer.talking = true;
+                            self.events.push(PeerEvent::StartedTalking(node_id));
+                            if self.talker_id_chirp {
+                                if peer.chirp.is_empty() {
+                                    peer.chirp = talker_chirp(node_id, self.sample_rate);
+                                }
+                                peer.chirp_pos = 0;
+                                continue;
+                            }
+                        }
+                    } else {
+                        break; // Still buffering
+                    }
+                }
+
+                // D. Fetch/Loss Logic
+                let mut packet_to_decode: Option<FrameSource> = None;
+
+                if let Some(frame) = &peer.dtx_frame
+                    && peer.talking
+                    && peer.jitter_buffer.len() < min_packets {
+                    // DTX pause: nothing is missing. Comfort noise until the peer resumes,
+                    // and until the jitter buffer has refilled, which sets the playout
+                    // point afresh at no cost.
+                    packet_to_decode = Some(FrameSource::Dtx(frame.clone()));
+                } else if let Some(expected) = peer.next_expected_seq {
+                    if let Some(entry) = peer.jitter_buffer.remove(&expected) {
+                        // Happy Path
+                        peer.next_expected_seq = Some(expected.wrapping_add(1));
+                        peer.missing_since = None;
+                        packet_to_decode = Some(FrameSource::Packet(entry));
+                    } else {
+                        // Miss - Check lookahead window
+                        let has_future = peer.jitter_buffer.keys().any(|&k| {
+                            let delta = k.wrapping_sub(expected);
+                            delta > 0 && delta < resync_gap
+                        });
+
+                        let waited = now - *peer.missing_since.get_or_insert(now);
+                        if has_future && waited < self.reorder_wait_samples {
+                            // Might just be reordered: hold this peer's playout a little longer
+                            break;
+                        } else if has_future {
+                            // Lost -> FEC from the next packet if it's here, else PLC
+                            peer.next_expected_seq = Some(expected.wrapping_add(1));
+                            peer.packets_lost += 1;
+                            peer.missing_since = None;
+                            packet_to_decode = Some(match peer.jitter_buffer.get(&expected.wrapping_add(1)) {
+                                Some(next) => {
+                                    peer.packets_recovered += 1;
+                                    FrameSource::Fec(next.opus_data.clone())
+                                }
+                                None => {
+                                    peer.packets_concealed += 1;
+                                    let mode = match self.concealment.get(&node_id) {
+                                        Some(&mode) => mode,
+                                        None if rank > 0 && started.elapsed() > cheap_conceal_after => ConcealmentMode::Repeat,
+                                        None => ConcealmentMode::Full,
+                                    };
+                                    match mode {
+                                        ConcealmentMode::Auto | ConcealmentMode::Full => FrameSource::Conceal,
+                                        ConcealmentMode::Repeat => FrameSource::Repeat,
+                                        ConcealmentMode::Silence => FrameSource::Silence,
+                                    }
+                                }
+                            });
+                        } else if peer.jitter_buffer.is_empty() {
+                            // Underrun
+                            peer.buffering = true;
+                            peer.missing_since = None;
+                            peer.underrun_at = Some(now);
+                            break;
+                        } else {
+                            // Gap -> Resync
+                            // (also where a restarted peer's new baseline is picked up)
+                            if let Some(next_avail) = oldest_seq(&peer.jitter_buffer) {
+                                let skipped = next_avail.wrapping_sub(expected);
+                                if skipped < u16::MAX / 2 {
+                                    peer.packets_lost += skipped as u64;
+                                }
+                                peer.next_expected_seq = Some(next_avail.wrapping_add(1));
+                                peer.missing_since = None;
+                                packet_to_decode = Some(FrameSource::Packet(peer.jitter_buffer.remove(&next_avail).unwrap()));
+                            }
+                        }
+                    }
+                }
+
+                // E. Decode
+                if let Some(source) = packet_to_decode {
+                    let mut native_chunk = [0i16; MAX_BUFFER_SIZE];
+                    let concealed = !matches!(source, FrameSource::Packet(_));
+                    let dtx = matches!(source, FrameSource::Dtx(_));
+                    let cheap = matches!(source, FrameSource::Repeat | FrameSource::Silence);
+                    let native_len = match source {
+                        FrameSource::Packet(packet) => {
+                            if packet.redundant {
+                                peer.packets_lost += 1;
+                                peer.packets_recovered += 1;
+                            }
+                            peer.dtx_frame = (packet.opus_data.len() <= OPUS_DTX_MAX_BYTES).then(|| packet.opus_data.clone());
+                            // This packet is about to be heard: publish its position now
+                            if let Some(position) = packet.position {
+                                self.events.push(PeerEvent::Position(node_id, position));
+                            }
+                            if let Some(captured_at_ms) = packet.captured_at_ms {
+                                let delay = wall_clock_ms().wrapping_sub(captured_at_ms) as i32 as f32;
+                                peer.end_to_end_delay_ms = Some(match peer.end_to_end_delay_ms {
+                                    Some(smoothed) => smoothed + END_TO_END_DELAY_SMOOTHING * (delay - smoothed),
+                                    None => delay,
+                                });
+                            }
+                            peer.decoder.decode_frame(&packet.opus_data, &mut native_chunk, false)
+                        }
+                        FrameSource::Fec(next) => {
+                            // FEC decodes exactly one frame's worth: the lost one is as long as the next
+                            let samples = peer.decoder.packet_samples(&next).unwrap_or(0).min(MAX_BUFFER_SIZE);
+                            if samples > 0 {
+                                peer.decoder.decode_frame(&next, &mut native_chunk[..samples], true)
+                            } else {
+                                peer.decoder.decode_frame(&[], &mut native_chunk[..peer.frame_samples()], true)
+                            }
+                        }
+                        // PLC fills whatever it is given: one packet of this peer's, however long
+                        FrameSource::Conceal => peer.decoder.decode_frame(&[], &mut native_chunk[..peer.frame_samples()], true),
+                        FrameSource::Repeat => Some(peer.conceal_cheaply(&mut native_chunk, true)),
+                        FrameSource::Silence => Some(peer.conceal_cheaply(&mut native_chunk, false)),
+                        FrameSource::Dtx(frame) => peer.decoder.decode_frame(&frame, &mut native_chunk, false),
+                    };
+                    let native_len = native_len.unwrap_or_else(|| {
+                        peer.decode_errors += 1;
+                        0
+                    });
+                    if cheap {
+                        peer.packets_concealed_cheaply += 1;
+                    } else if native_len > 0 && !dtx {
+                        peer.last_frame.clear();
+                        peer.last_frame.extend_from_slice(&native_chunk[..native_len]);
+                        peer.repeat_gain = 1.0;
+                    }
+
+                    let mut decoded_chunk = [0i16; MAX_BUFFER_SIZE];
+                    let mut len = peer.resampler.process(&native_chunk[..native_len], &mut decoded_chunk);
+                    if !concealed && len > 0 && self.keep_talk {
+                        // Counted before time-stretching: this is how long the peer actually spoke
+                        self.talk.entry(node_id).or_default().add(&decoded_chunk[..len], self.sample_rate);
+                    }
+
+                    // Time-stretch towards the target depth instead of skipping/starving
+                    let depth = peer.jitter_buffer.len();
+                    if depth > accelerate_depth {
+                        len = self.stretcher.accelerate(&mut decoded_chunk, len);
+                    } else if depth <= expand_depth {
+                        len = self.stretcher.expand(&mut decoded_chunk, len);
+                    }
+
+                    if len > 0 {
+                        let space_left = samples_needed - peer_samples_produced;
+                        let to_take = std::cmp::min(len, space_left);
+                        for i in 0..to_take {
+                            peer_buffer[peer_samples_produced + i] = decoded_chunk[i] as i32;
+                        }
+                        peer_samples_produced += to_take;
+                        if len > to_take {
+                            let remainder = len - to_take;
+                            peer.buffer[..remainder].copy_from_slice(&decoded_chunk[to_take..len]);
+                            peer.buffer_len = remainder;
+                        }
+                    }
+                } else {
+                    break;
+                }
+            }
+
+            let produced = &mut peer_buffer[..peer_samples_produced];
+            fade_edges(produced, !peer.producing, peer_samples_produced < samples_needed, self.peer_fade_samples);
+            peer.level.add(produced.iter().copied());
+            if peer.preemption_gain != 1.0 || preemption_gain != 1.0 {
+                simd::gain_ramp(produced, peer.preemption_gain, preemption_gain);
+                peer.preemption_gain = preemption_gain;
+            }
+            simd::mix(mix_buffer, produced);
+            peer.producing = peer_samples_produced == samples_needed;
+
+            // Talk burst over: everything played out and the sender went quiet
+            if peer.talking && peer.buffer_len == 0 && peer.jitter_buffer.is_empty()
+                && now - peer.last_packet_at >= self.talk_end_samples {
+                peer.talking = false;
+                self.events.push(PeerEvent::StoppedTalking(node_id));
+            }
+        }
+
+        for id in dead_peers {
+            if let Some(peer) = self.peers.remove(&id) {
+                if peer.talking {
+                    self.events.push(PeerEvent::StoppedTalking(id));
+                }
+                self.events.push(PeerEvent::TimedOut(id));
+            }
+        }
+    }
+}
+
+// Push/pull entry points for driving a pipeline without an audio device (tests,
+// other platforms). The Oboe callbacks use the finer-grained calls above.
+#[allow(dead_code)]
+impl<D: FrameDecoder> ReceivePipeline<D> {
+    /// Parses (and, with the sender's key, decrypts) a packet and buffers it. No
+    /// replay protection; false if the packet didn't parse.
+    pub(crate) fn push_packet(&mut self, data: &[u8], cipher: Option<&GroupCipher>, now: u64) -> bool {
+        let Some(packet) = unwrap_own_packet(data, cipher) else { return false };
+        self.receive(packet, now);
+        true
+    }
+
+    /// The next `frames.len()` samples of the peer mix, clamped (no limiter or
+    /// volume: those belong to the output side). The caller advances `now`.
+    pub(crate) fn pull(&mut self, frames: &mut [i16], now: u64) {
+        let mut mix_buffer = vec![0i32; frames.len()];
+        self.mix(&mut mix_buffer, now);
+        simd::saturate(&mix_buffer, frames);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 20ms fullband CELT frame (RFC 6716 §3.1), so the pipelines size it like Opus
+    const TOC: u8 = 31 << 3;
+    const FRAME: usize = 960;
+    // What the fake decoder plays for a frame it didn't get
+    const CONCEALED: i16 = -1;
+    const RECOVERED: i16 = -2;
+
+    /// Stands in for Opus: a TOC byte, then the frame's first sample.
+    struct FakeEncoder;
+
+    impl FrameEncoder for FakeEncoder {
+        type Error = &'static str;
+
+        fn encode_frame(&mut self, pcm: &[i16], out: &mut [u8]) -> Result<usize, Self::Error> {
+            if pcm.len() != FRAME { return Err("wrong frame size"); }
+            out[0] = TOC;
+            out[1..3].copy_from_slice(&pcm[0].to_le_bytes());
+            Ok(3)
+        }
+    }
+
+    /// Plays a frame's sample for the whole frame.
+    struct FakeDecoder;
+
+    impl FrameDecoder for FakeDecoder {
+        fn with_rate(sample_rate: i32) -> Option<Self> {
+            (sample_rate == 48000).then_some(FakeDecoder)
+        }
+
+        fn decode_frame(&mut self, data: &[u8], out: &mut [i16], fec: bool) -> Option<usize> {
+            let value = match data {
+                [] => CONCEALED,
+                _ if fec => RECOVERED,
+                [TOC, low, high] => i16::from_le_bytes([*low, *high]),
+                _ => return None,
+            };
+            let len = if data.is_empty() { out.len() } else { FRAME };
+            out[..len].fill(value);
+            Some(len)
+        }
+
+        fn packet_samples(&self, _data: &[u8]) -> Option<usize> {
+            Some(FRAME)
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    fn config() -> AudioConfig {
+        AudioConfig { frame_size_ms: 20, jitter_buffer_ms: 200, ..AudioConfig::default() }
+    }
+
+    /// One packet per value, each frame a constant signal, captured a frame at a time.
+    fn send(config: AudioConfig, values: &[i16], seq: u32, cipher: Option<(&GroupCipher, u32)>) -> Vec<Vec<u8>> {
+        let mut pipeline = SendPipeline::new(FakeEncoder, config, 7);
+        let mut seq = seq;
+        values.iter().flat_map(|&value| pipeline.transmit(&[value; FRAME], &mut seq, cipher)).collect()
+    }
+
+    /// Delivers `packets` one per frame, as they were sent, except those in `lost`,
+    /// and pulls until all of it has played. Returns each frame value heard, once,
+    /// in order (ramps and silence left out).
+    fn play(pipeline: &mut ReceivePipeline<FakeDecoder>, packets: &[Vec<u8>], lost: &[usize], cipher: Option<&GroupCipher>) -> Vec<i16> {
+        let mut heard = Vec::new();
+        let mut frames = [0i16; FRAME];
+        let mut now = 0;
+        for i in 0..packets.len() * 3 {
+            if let Some(packet) = packets.get(i).filter(|_| !lost.contains(&i)) {
+                assert!(pipeline.push_packet(packet, cipher, now));
+            }
+            pipeline.pull(&mut frames, now);
+            now += FRAME as u64;
+            for &sample in &frames {
+                let whole = sample % 100 == 0 || sample == CONCEALED || sample == RECOVERED;
+                if sample != 0 && whole && heard.last() != Some(&sample) {
+                    heard.push(sample);
+                }
+            }
+        }
+        heard
+    }
+
+    fn receiver(config: &AudioConfig) -> ReceivePipeline<FakeDecoder> {
+        ReceivePipeline::new(config, Arc::new(DspParams::new(config)))
+    }
+
+    #[test]
+    fn frames_are_sent_whole() {
+        let mut pipeline = SendPipeline::new(FakeEncoder, config(), 7);
+        let mut seq = 0;
+        let pcm: Vec<i16> = [100, 200, 300].iter().flat_map(|&value| [value; FRAME]).collect();
+        assert_eq!(pipeline.transmit(&pcm[..FRAME * 5 / 2], &mut seq, None).len(), 2);
+        assert_eq!(pipeline.transmit(&pcm[FRAME * 5 / 2..], &mut seq, None).len(), 1);
+        assert_eq!(seq, 3);
+    }
+
+    #[test]
+    fn round_trip_plays_in_order() {
+        let config = config();
+        let values = [100, 200, 300, 400, 500, 600, 700, 800];
+        let mut receive = receiver(&config);
+        assert_eq!(play(&mut receive, &send(config, &values, 1000, None), &[], None), values);
+        let stats = receive.peer_stats().next().unwrap();
+        assert_eq!((stats.peer_id, stats.packets_received, stats.packets_lost), (7, 8, 0));
+    }
+
+    #[test]
+    fn sequence_wrap_keeps_order() {
+        let config = config();
+        let values = [100, 200, 300, 400, 500, 600, 700, 800];
+        let mut receive = receiver(&config);
+        assert_eq!(play(&mut receive, &send(config, &values, u16::MAX as u32 - 3, None), &[], None), values);
+    }
+
+    #[test]
+    fn losses_are_recovered_or_concealed() {
+        let config = config();
+        let values = [100, 200, 300, 400, 500, 600, 700, 800, 900, 1000];
+        let mut receive = receiver(&config);
+        // 400 has no packet after it to take FEC from, 500 does
+        assert_eq!(
+            play(&mut receive, &send(config, &values, 0, None), &[3, 4], None),
+            [100, 200, 300, CONCEALED, RECOVERED, 600, 700, 800, 900, 1000],
+        );
+        let stats = receive.peer_stats().next().unwrap();
+        assert_eq!((stats.packets_lost, stats.packets_concealed, stats.packets_recovered), (2, 1, 1));
+    }
+
+    #[test]
+    fn redundant_copy_fills_a_loss() {
+        let config = AudioConfig { redundancy: true, ..config() };
+        let values = [100, 200, 300, 400, 500, 600, 700, 800];
+        let mut receive = receiver(&config);
+        assert_eq!(play(&mut receive, &send(config, &values, 0, None), &[2], None), values);
+        let stats = receive.peer_stats().next().unwrap();
+        assert_eq!((stats.packets_lost, stats.packets_concealed, stats.packets_recovered), (1, 0, 1));
+    }
+
+    #[test]
+    fn encrypted_packets_need_the_key() {
+        let config = config();
+        let values = [100, 200, 300, 400, 500, 600, 700, 800];
+        let cipher = GroupCipher::new(&[7; 32]).unwrap();
+        let other = GroupCipher::new(&[8; 32]).unwrap();
+        let packets = send(config, &values, 0, Some((&cipher, 1)));
+        let mut receive = receiver(&config);
+        assert!(!receive.push_packet(&packets[0], None, 0));
+        assert!(!receive.push_packet(&packets[0], Some(&other), 0));
+        assert_eq!(play(&mut receive, &packets, &[], Some(&cipher)), values);
+    }
+}
@@ -0,0 +1,569 @@
+// ===========================================================================
+// PACKET FORMAT
+// ===========================================================================
+// Building and parsing the packets on the wire: our own audio packets (v1 to
+// v3 headers, see the Protocol Layout constants), control packets, plain RTP,
+// and the unversioned packets of builds before any of these (v0). Pure
+// functions over byte slices, so the send and receive pipelines (see
+// pipeline.rs) use them on every target.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::crypto::GroupCipher;
+use crate::{
+    PACKET_CHECKSUM_SIZE, PACKET_EPOCH_SIZE, PACKET_FLAG_CHECKSUM, PACKET_FLAG_CONTROL, PACKET_FLAG_EMERGENCY, PACKET_FLAG_ENCRYPTED,
+    PACKET_FLAG_PADDED,
+    PACKET_FLAG_POSITION, PACKET_FLAG_PRIORITY, PACKET_FLAG_REDUNDANT, PACKET_HEADER_SIZE, PACKET_HEADER_SIZE_V0, PACKET_HEADER_SIZE_V1, PACKET_HEADER_SIZE_V2, PACKET_PADDING_BUCKET,
+    PACKET_PADDING_LEN_SIZE, PACKET_POSITION_SIZE, PACKET_PRIORITY_SIZE, PACKET_REDUNDANT_LEN_SIZE, PROTOCOL_MIN_VERSION, PROTOCOL_VERSION,
+    OPUS_MAX_FRAME_BYTES, OPUS_MAX_PACKET_SAMPLES, PeerPosition, RTP_DYNAMIC_PAYLOAD_TYPES, RTP_HEADER_SIZE, RTP_PAYLOAD_TYPE, RTP_VERSION,
+};
+
+/// An audio packet as received, parsed (and decrypted).
+pub(crate) struct IncomingPacket {
+    pub(crate) origin_id: u32,
+    pub(crate) seq: u32,
+    pub(crate) seq_bits: u32,                  // 16 for v1 senders
+    pub(crate) epoch: Option<u32>,             // Set if the packet was encrypted
+    pub(crate) captured_at_ms: Option<u32>,
+    pub(crate) position: Option<PeerPosition>,
+    pub(crate) opus_data: Vec<u8>,
+    pub(crate) redundant: Option<Vec<u8>>, // Copy of the previous frame (seq - 1)
+    pub(crate) priority: Option<u8>,
+    pub(crate) emergency: bool,
+    pub(crate) channel: u16,
+    pub(crate) scan_rank: u32, // 0 for the active channel, then the scan list's order
+    pub(crate) wire_len: usize,                // Size as received (for stats)
+}
+
+/// Lowest decode rate that keeps the full bandwidth of this Opus packet (from its TOC byte),
+/// capped at the mixer rate.
+pub(crate) fn native_decode_rate(opus_data: &[u8], mixer_rate: i32) -> i32 {
+    opus_data.first().map_or(mixer_rate, |&toc| bandwidth_rate(toc).min(mixer_rate))
+}
+
+fn bandwidth_rate(toc: u8) -> i32 {
+    match toc >> 3 {
+        0..=3 | 16..=19 => 8000,          // Narrowband
+        4..=7 => 12000,                   // Mediumband
+        8..=11 | 20..=23 => 16000,        // Wideband
+        12..=13 | 24..=27 => 24000,       // Super-wideband
+        _ => 48000,                       // Fullband
+    }
+}
+
+/// How long an Opus packet plays, from its TOC byte (RFC 6716 §3.1).
+pub(crate) struct OpusToc {
+    pub(crate) frame_samples: u32, // One frame, at 48kHz
+    pub(crate) frames: u32,
+}
+
+impl OpusToc {
+    /// The whole packet, at 48kHz.
+    pub(crate) fn packet_samples(&self) -> u32 {
+        self.frame_samples * self.frames
+    }
+}
+
+/// Parses an Opus packet's TOC byte and checks its framing against RFC 6716
+/// §3.4. `None` for anything a decoder would reject, so such payloads are
+/// dropped before they reach a jitter buffer.
+pub(crate) fn parse_opus_toc(opus_data: &[u8]) -> Option<OpusToc> {
+    let (&toc, rest) = opus_data.split_first()?;
+    let config = (toc >> 3) as usize;
+    let frame_samples = match config {
+        0..=11 => [480, 960, 1920, 2880][config % 4], // SILK: 10-60ms
+        12..=15 => [480, 960][config % 2],            // Hybrid: 10-20ms
+        _ => [120, 240, 480, 960][config % 4],        // CELT: 2.5-20ms
+    };
+    let frames = match toc & 0x03 {
+        // One frame
+        0 => {
+            if rest.len() > OPUS_MAX_FRAME_BYTES { return None; }
+            1
+        }
+        // Two frames of equal size
+        1 => {
+            if rest.len() % 2 != 0 || rest.len() / 2 > OPUS_MAX_FRAME_BYTES { return None; }
+            2
+        }
+        // Two frames, the first one's length given
+        2 => {
+            let (first, used) = opus_frame_length(rest)?;
+            let second = rest.len().checked_sub(used + first)?;
+            if first > OPUS_MAX_FRAME_BYTES || second > OPUS_MAX_FRAME_BYTES { return None; }
+            2
+        }
+        // Any number of frames, with padding and CBR or VBR sizes
+        _ => {
+            let (&count, mut rest) = rest.split_first()?;
+            let frames = (count & 0x3F) as u32;
+            if frames == 0 || frames * frame_samples > OPUS_MAX_PACKET_SAMPLES { return None; }
+            let mut padding = 0;
+            if count & 0x40 != 0 {
+                loop {
+                    let (&byte, after) = rest.split_first()?;
+                    rest = after;
+                    padding += if byte == 255 { 254 } else { byte as usize };
+                    if byte != 255 { break; }
+                }
+            }
+            let payload = rest.len().checked_sub(padding)?;
+            if count & 0x80 != 0 {
+                // VBR: every frame's length but the last comes first
+                let (mut lengths, mut sized) = (0, 0);
+                for _ in 1..frames {
+                    let (len, used) = opus_frame_length(&rest[lengths..])?;
+                    if len > OPUS_MAX_FRAME_BYTES { return None; }
+                    lengths += used;
+                    sized += len;
+                }
+                let last = payload.checked_sub(lengths)?.checked_sub(sized)?;
+                if last > OPUS_MAX_FRAME_BYTES { return None; }
+            } else if payload % frames as usize != 0 || payload / frames as usize > OPUS_MAX_FRAME_BYTES {
+                return None;
+            }
+            frames
+        }
+    };
+    (frames * frame_samples <= OPUS_MAX_PACKET_SAMPLES).then_some(OpusToc { frame_samples, frames })
+}
+
+/// A frame length as coded in Opus packets (one or two bytes), and the bytes it took.
+fn opus_frame_length(data: &[u8]) -> Option<(usize, usize)> {
+    match *data.first()? {
+        len @ 0..=251 => Some((len as usize, 1)),
+        low => Some((*data.get(1)? as usize * 4 + low as usize, 2)),
+    }
+}
+
+/// Optional fields of an outgoing audio packet.
+#[derive(Default)]
+pub(crate) struct PacketOptions<'a> {
+    pub(crate) position: Option<PeerPosition>,
+    pub(crate) redundant: Option<&'a [u8]>, // The previous frame
+    pub(crate) priority: Option<u8>,
+    pub(crate) emergency: bool,
+    pub(crate) channel: u16,                // 0 is sent with the v2 header
+    pub(crate) checksum: bool,              // Ignored for encrypted packets
+    pub(crate) padded: bool,                // Ignored for cleartext packets
+}
+
+/// Builds a packet. With a cipher (and the sender's current epoch) the body is
+/// encrypted; returns `None` only if sealing fails (never send cleartext instead).
+pub(crate) fn wrap_packet(
+    origin_id: u32,
+    seq: u32,
+    captured_at_ms: u32,
+    opus_data: &[u8],
+    cipher: Option<(&GroupCipher, u32)>,
+    options: PacketOptions,
+) -> Option<Vec<u8>> {
+    let PacketOptions { position, redundant, priority, emergency, channel, checksum, padded } = options;
+    let redundant = redundant.filter(|frame| frame.len() <= u16::MAX as usize);
+    let checksum = checksum && cipher.is_none();
+    let padded = padded && cipher.is_some();
+    let mut flags = 0;
+    if position.is_some() { flags |= PACKET_FLAG_POSITION; }
+    if cipher.is_some() { flags |= PACKET_FLAG_ENCRYPTED; }
+    if checksum { flags |= PACKET_FLAG_CHECKSUM; }
+    if redundant.is_some() { flags |= PACKET_FLAG_REDUNDANT; }
+    if padded { flags |= PACKET_FLAG_PADDED; }
+    if priority.is_some() { flags |= PACKET_FLAG_PRIORITY; }
+    if emergency { flags |= PACKET_FLAG_EMERGENCY; }
+
+    let mut packet = Vec::with_capacity(
+        PACKET_HEADER_SIZE + PACKET_EPOCH_SIZE + PACKET_POSITION_SIZE + PACKET_REDUNDANT_LEN_SIZE
+            + redundant.map_or(0, <[u8]>::len) + PACKET_PRIORITY_SIZE + opus_data.len() + PACKET_CHECKSUM_SIZE,
+    );
+    write_header(&mut packet, origin_id, seq, captured_at_ms, flags, channel);
+    if let Some((_, epoch)) = cipher {
+        let mut epoch_buf = [0u8; PACKET_EPOCH_SIZE];
+        LittleEndian::write_u32(&mut epoch_buf, epoch);
+        packet.extend_from_slice(&epoch_buf);
+    }
+    let body_start = packet.len();
+
+    if let Some(position) = position {
+        let mut pos_buf = [0u8; PACKET_POSITION_SIZE];
+        LittleEndian::write_f64(&mut pos_buf[0..8], position.latitude_deg);
+        LittleEndian::write_f64(&mut pos_buf[8..16], position.longitude_deg);
+        LittleEndian::write_f32(&mut pos_buf[16..20], position.altitude_m);
+        LittleEndian::write_f32(&mut pos_buf[20..24], position.heading_deg);
+        packet.extend_from_slice(&pos_buf);
+    }
+    if let Some(frame) = redundant {
+        packet.extend_from_slice(&(frame.len() as u16).to_le_bytes());
+        packet.extend_from_slice(frame);
+    }
+    if let Some(priority) = priority {
+        packet.push(priority);
+    }
+    packet.extend_from_slice(opus_data);
+    if padded {
+        let unpadded = packet.len() - body_start + PACKET_PADDING_LEN_SIZE;
+        let padding = unpadded.next_multiple_of(PACKET_PADDING_BUCKET) - unpadded + PACKET_PADDING_LEN_SIZE;
+        packet.resize(packet.len() + padding - PACKET_PADDING_LEN_SIZE, 0);
+        packet.extend_from_slice(&(padding as u16).to_le_bytes());
+    }
+
+    if let Some((cipher, epoch)) = cipher {
+        // The header stays readable (routing needs it) but is authenticated
+        let sealed = cipher.seal(origin_id, epoch, seq, &packet[..body_start], &packet[body_start..])?;
+        packet.truncate(body_start);
+        packet.extend_from_slice(&sealed);
+    }
+    if checksum {
+        let crc = crc32(&packet);
+        packet.extend_from_slice(&crc.to_le_bytes());
+    }
+    Some(packet)
+}
+
+/// Checks and strips the checksum trailer, if the packet has one.
+/// `None` if it doesn't match.
+pub(crate) fn verify_checksum<'a>(data: &'a [u8], header: &PacketHeader) -> Option<&'a [u8]> {
+    if header.flags & PACKET_FLAG_CHECKSUM == 0 { return Some(data); }
+    let (body, trailer) = data.split_at_checked(data.len().checked_sub(PACKET_CHECKSUM_SIZE)?)?;
+    if body.len() < header.size || LittleEndian::read_u32(trailer) != crc32(body) { return None; }
+    Some(body)
+}
+
+/// CRC-32 (IEEE 802.3, as in zip/PNG). Bitwise: packets are small and this
+/// runs once per packet, so a lookup table isn't worth it.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Parses (and, given the sender's key, decrypts) a packet. While a key is in
+/// use, cleartext packets are rejected so they can't be injected.
+pub(crate) fn unwrap_packet(data: &[u8], cipher: Option<&GroupCipher>) -> Option<IncomingPacket> {
+    let header = read_header(data)?;
+    let (origin_id, seq, flags) = (header.origin_id, header.seq, header.flags);
+
+    let mut epoch = None;
+    let body = match (flags & PACKET_FLAG_ENCRYPTED != 0, cipher) {
+        (true, Some(cipher)) => {
+            let body_start = header.size + PACKET_EPOCH_SIZE;
+            if data.len() < body_start { return None; }
+            let packet_epoch = LittleEndian::read_u32(&data[header.size..body_start]);
+            epoch = Some(packet_epoch);
+            cipher.open(origin_id, packet_epoch, seq, &data[..body_start], &data[body_start..])?
+        }
+        (false, None) => data[header.size..].to_vec(),
+        _ => return None, // Encrypted without a key, or cleartext while encryption is on
+    };
+    let mut payload = &body[..];
+
+    if flags & PACKET_FLAG_PADDED != 0 {
+        let len_start = payload.len().checked_sub(PACKET_PADDING_LEN_SIZE)?;
+        let padding = LittleEndian::read_u16(&payload[len_start..]) as usize;
+        if padding < PACKET_PADDING_LEN_SIZE { return None; }
+        payload = &payload[..payload.len().checked_sub(padding)?];
+    }
+
+    let mut position = None;
+    if flags & PACKET_FLAG_POSITION != 0 {
+        if payload.len() < PACKET_POSITION_SIZE { return None; }
+        position = Some(PeerPosition {
+            latitude_deg: LittleEndian::read_f64(&payload[0..8]),
+            longitude_deg: LittleEndian::read_f64(&payload[8..16]),
+            altitude_m: LittleEndian::read_f32(&payload[16..20]),
+            heading_deg: LittleEndian::read_f32(&payload[20..24]),
+        });
+        payload = &payload[PACKET_POSITION_SIZE..];
+    }
+
+    let mut redundant = None;
+    if flags & PACKET_FLAG_REDUNDANT != 0 {
+        if payload.len() < PACKET_REDUNDANT_LEN_SIZE { return None; }
+        let len = LittleEndian::read_u16(&payload[..PACKET_REDUNDANT_LEN_SIZE]) as usize;
+        let end = PACKET_REDUNDANT_LEN_SIZE + len;
+        if payload.len() < end { return None; }
+        redundant = Some(payload[PACKET_REDUNDANT_LEN_SIZE..end].to_vec());
+        payload = &payload[end..];
+    }
+
+    let mut priority = None;
+    if flags & PACKET_FLAG_PRIORITY != 0 {
+        let (&value, rest) = payload.split_first()?;
+        priority = Some(value);
+        payload = rest;
+    }
+    Some(IncomingPacket {
+        origin_id,
+        seq,
+        seq_bits: header.seq_bits,
+        epoch,
+        captured_at_ms: header.captured_at_ms,
+        position,
+        opus_data: payload.to_vec(),
+        redundant,
+        priority,
+        emergency: flags & PACKET_FLAG_EMERGENCY != 0,
+        channel: header.channel,
+        scan_rank: 0,
+        wire_len: data.len(),
+    })
+}
+
+/// Whether a versioned packet is laid out as its header says, Opus payload
+/// included. Encrypted and checksummed packets are checked later, so they pass.
+/// A v0 packet can start with a valid version byte; this is what tells it apart.
+pub(crate) fn parses_as_versioned(data: &[u8], header: &PacketHeader) -> bool {
+    header.flags & (PACKET_FLAG_ENCRYPTED | PACKET_FLAG_CHECKSUM) != 0
+        || unwrap_packet(data, None).is_some_and(|packet| parse_opus_toc(&packet.opus_data).is_some())
+}
+
+/// Parses a packet from a build before protocol versions (v0, see
+/// PACKET_HEADER_SIZE_V0). Those builds only send mono Opus, so anything else
+/// isn't one.
+pub(crate) fn unwrap_legacy(data: &[u8]) -> Option<IncomingPacket> {
+    let opus_data = data.get(PACKET_HEADER_SIZE_V0..)?;
+    if opus_data.first()? & 0x04 != 0 || parse_opus_toc(opus_data).is_none() { return None; }
+    Some(IncomingPacket {
+        origin_id: LittleEndian::read_u32(&data[0..4]),
+        seq: LittleEndian::read_u16(&data[4..6]) as u32,
+        seq_bits: 16,
+        epoch: None,
+        captured_at_ms: None,
+        position: None,
+        opus_data: opus_data.to_vec(),
+        redundant: None,
+        priority: None,
+        emergency: false,
+        channel: 0,
+        scan_rank: 0,
+        wire_len: data.len(),
+    })
+}
+
+/// RTP packet (RFC 3550) carrying one Opus frame (RFC 7587).
+pub(crate) fn wrap_rtp(ssrc: u32, seq: u16, timestamp: u32, marker: bool, opus_data: &[u8]) -> Vec<u8> {
+    let mut packet = vec![0u8; RTP_HEADER_SIZE];
+    packet[0] = RTP_VERSION << 6;
+    packet[1] = (marker as u8) << 7 | RTP_PAYLOAD_TYPE;
+    BigEndian::write_u16(&mut packet[2..4], seq);
+    BigEndian::write_u32(&mut packet[4..8], timestamp);
+    BigEndian::write_u32(&mut packet[8..12], ssrc);
+    packet.extend_from_slice(opus_data);
+    packet
+}
+
+/// Reads back one of our own audio packets for the self-monitor, the same way a
+/// peer would (minus replay protection: the sequence is ours).
+pub(crate) fn unwrap_own_packet(data: &[u8], cipher: Option<&GroupCipher>) -> Option<IncomingPacket> {
+    if is_rtp(data) {
+        return unwrap_rtp(data);
+    }
+    let header = read_header(data)?;
+    let mut packet = unwrap_packet(verify_checksum(data, &header)?, cipher)?;
+    packet.wire_len = data.len();
+    Some(packet)
+}
+
+pub(crate) fn is_rtp(data: &[u8]) -> bool {
+    data.first().is_some_and(|&byte| byte >> 6 == RTP_VERSION)
+}
+
+/// Parses an RTP packet from any sender, skipping CSRCs, header extensions and padding.
+pub(crate) fn unwrap_rtp(data: &[u8]) -> Option<IncomingPacket> {
+    if data.len() < RTP_HEADER_SIZE || !is_rtp(data) { return None; }
+    if !RTP_DYNAMIC_PAYLOAD_TYPES.contains(&(data[1] & 0x7F)) { return None; } // Not Opus (e.g. comfort noise)
+    let csrc_count = (data[0] & 0x0F) as usize;
+    let mut start = RTP_HEADER_SIZE + 4 * csrc_count;
+    if data[0] & 0x10 != 0 {
+        // Extension: [Profile (2)] + [Length in 32-bit words (2)] + data
+        let words = BigEndian::read_u16(data.get(start + 2..start + 4)?) as usize;
+        start += 4 + 4 * words;
+    }
+    let mut end = data.len();
+    if data[0] & 0x20 != 0 {
+        end = end.checked_sub(*data.last()? as usize)?;
+    }
+    if start >= end { return None; }
+
+    Some(IncomingPacket {
+        origin_id: BigEndian::read_u32(&data[8..12]),
+        seq: BigEndian::read_u16(&data[2..4]) as u32,
+        seq_bits: 16,
+        epoch: None,
+        captured_at_ms: None,
+        position: None,
+        opus_data: data[start..end].to_vec(),
+        redundant: None,
+        priority: None,
+        emergency: false,
+        channel: 0,
+        scan_rank: 0,
+        wire_len: data.len(),
+    })
+}
+
+/// Control packets always use the (frozen) v1 header.
+pub(crate) fn wrap_control(origin_id: u32, msg_type: u8, payload: &[u8]) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(PACKET_HEADER_SIZE_V1 + 1 + payload.len());
+    let mut header = [0u8; PACKET_HEADER_SIZE_V1];
+    header[0] = 1;
+    LittleEndian::write_u32(&mut header[1..5], origin_id);
+    header[7] = PACKET_FLAG_CONTROL;
+    packet.extend_from_slice(&header);
+    packet.push(msg_type);
+    packet.extend_from_slice(payload);
+    packet
+}
+
+/// Channel 0 gets the v2 header, so builds without channels still hear it.
+pub(crate) fn write_header(packet: &mut Vec<u8>, origin_id: u32, seq: u32, captured_at_ms: u32, flags: u8, channel: u16) {
+    let mut header = [0u8; PACKET_HEADER_SIZE];
+    header[0] = if channel == 0 { 2 } else { PROTOCOL_VERSION };
+    LittleEndian::write_u32(&mut header[1..5], origin_id);
+    LittleEndian::write_u32(&mut header[5..9], seq);
+    LittleEndian::write_u32(&mut header[9..13], captured_at_ms);
+    header[13] = flags;
+    LittleEndian::write_u16(&mut header[14..16], channel);
+    let size = if channel == 0 { PACKET_HEADER_SIZE_V2 } else { PACKET_HEADER_SIZE };
+    packet.extend_from_slice(&header[..size]);
+}
+
+pub(crate) struct PacketHeader {
+    pub(crate) version: u8,
+    pub(crate) origin_id: u32,
+    pub(crate) seq: u32,
+    pub(crate) seq_bits: u32,
+    pub(crate) captured_at_ms: Option<u32>,
+    pub(crate) flags: u8,
+    pub(crate) channel: u16, // 0 before v3
+    pub(crate) size: usize,
+}
+
+/// Parses any version we understand (`None` for others, or if truncated).
+pub(crate) fn read_header(data: &[u8]) -> Option<PacketHeader> {
+    let version = *data.first()?;
+    if !(PROTOCOL_MIN_VERSION..=PROTOCOL_VERSION).contains(&version) { return None; }
+    let size = match version {
+        1 => PACKET_HEADER_SIZE_V1,
+        2 => PACKET_HEADER_SIZE_V2,
+        _ => PACKET_HEADER_SIZE,
+    };
+    if data.len() < size { return None; }
+    let origin_id = LittleEndian::read_u32(&data[1..5]);
+    Some(if version == 1 {
+        PacketHeader {
+            version, origin_id, seq: LittleEndian::read_u16(&data[5..7]) as u32, seq_bits: 16,
+            captured_at_ms: None, flags: data[7], channel: 0, size,
+        }
+    } else {
+        PacketHeader {
+            version, origin_id, seq: LittleEndian::read_u32(&data[5..9]), seq_bits: 32,
+            captured_at_ms: Some(LittleEndian::read_u32(&data[9..13])), flags: data[13],
+            channel: if version == 2 { 0 } else { LittleEndian::read_u16(&data[14..16]) }, size,
+        }
+    })
+}
+
+/// Wall clock for capture timestamps: Unix time in ms, modulo 2^32.
+pub(crate) fn wall_clock_ms() -> u32 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headers_round_trip_in_every_version() {
+        let mut v1 = vec![1, 0, 0, 0, 0, 0, 0, PACKET_FLAG_POSITION];
+        LittleEndian::write_u32(&mut v1[1..5], 0xAABB_CCDD);
+        LittleEndian::write_u16(&mut v1[5..7], 0xBEEF);
+        let mut v2 = Vec::new();
+        write_header(&mut v2, 0xAABB_CCDD, 0x1234_5678, 99, PACKET_FLAG_ENCRYPTED, 0);
+        let mut v3 = Vec::new();
+        write_header(&mut v3, 0xAABB_CCDD, 0x1234_5678, 99, PACKET_FLAG_ENCRYPTED, 7);
+
+        let header = read_header(&v1).unwrap();
+        assert_eq!((header.version, header.size, header.seq, header.seq_bits), (1, PACKET_HEADER_SIZE_V1, 0xBEEF, 16));
+        assert_eq!((header.origin_id, header.captured_at_ms, header.flags, header.channel), (0xAABB_CCDD, None, PACKET_FLAG_POSITION, 0));
+
+        let header = read_header(&v2).unwrap();
+        assert_eq!((header.version, header.size, header.seq, header.seq_bits), (2, PACKET_HEADER_SIZE_V2, 0x1234_5678, 32));
+        assert_eq!((header.origin_id, header.captured_at_ms, header.flags, header.channel), (0xAABB_CCDD, Some(99), PACKET_FLAG_ENCRYPTED, 0));
+
+        let header = read_header(&v3).unwrap();
+        assert_eq!((header.version, header.size, header.seq, header.seq_bits), (PROTOCOL_VERSION, PACKET_HEADER_SIZE, 0x1234_5678, 32));
+        assert_eq!((header.origin_id, header.captured_at_ms, header.flags, header.channel), (0xAABB_CCDD, Some(99), PACKET_FLAG_ENCRYPTED, 7));
+
+        let control = wrap_control(5, 0x01, b"hi");
+        let header = read_header(&control).unwrap();
+        assert_eq!((header.version, header.origin_id, header.flags), (1, 5, PACKET_FLAG_CONTROL));
+        assert_eq!(&control[header.size..], [0x01, b'h', b'i']);
+    }
+
+    #[test]
+    fn truncated_headers_are_rejected() {
+        let mut v1 = vec![0u8; PACKET_HEADER_SIZE_V1];
+        v1[0] = 1;
+        let mut v2 = Vec::new();
+        write_header(&mut v2, 1, 1, 1, 0, 0);
+        let mut v3 = Vec::new();
+        write_header(&mut v3, 1, 1, 1, 0, 7);
+        for header in [v1, v2, v3] {
+            assert!(read_header(&header).is_some());
+            for len in 0..header.len() {
+                assert!(read_header(&header[..len]).is_none(), "v{} cut to {} bytes", header[0], len);
+            }
+        }
+        assert!(read_header(&[PROTOCOL_VERSION + 1; PACKET_HEADER_SIZE]).is_none());
+    }
+
+    #[test]
+    fn crc32_matches_the_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn rtp_payload_skips_csrcs_extension_and_padding() {
+        let opus = [0xF8, 0x01, 0x02, 0x03];
+        let mut packet = wrap_rtp(0x0102_0304, 4242, 960, true, &[]);
+        packet[0] |= 0x20 | 0x10 | 2; // Padding, extension, two CSRCs
+        packet.extend_from_slice(&[0x11; 8]);
+        packet.extend_from_slice(&[0xBE, 0xDE, 0x00, 0x01, 0x22, 0x22, 0x22, 0x22]);
+        packet.extend_from_slice(&opus);
+        packet.extend_from_slice(&[0x00, 0x00, 0x03]);
+
+        let parsed = unwrap_rtp(&packet).unwrap();
+        assert_eq!(parsed.opus_data, opus);
+        assert_eq!((parsed.origin_id, parsed.seq, parsed.seq_bits), (0x0102_0304, 4242, 16));
+        assert_eq!(parsed.wire_len, packet.len());
+
+        // Padding that eats into the header is malformed
+        let last = packet.len() - 1;
+        packet[last] = 200;
+        assert!(unwrap_rtp(&packet).is_none());
+    }
+
+    #[test]
+    fn tampered_encrypted_packets_are_dropped() {
+        let cipher = GroupCipher::new(&[3; 32]).unwrap();
+        let options = PacketOptions { channel: 4, padded: true, ..Default::default() };
+        let packet = wrap_packet(9, 77, 1000, &[0xF8, 0xAA], Some((&cipher, 5)), options).unwrap();
+        let parsed = unwrap_packet(&packet, Some(&cipher)).unwrap();
+        assert_eq!((parsed.origin_id, parsed.seq, parsed.epoch, parsed.channel), (9, 77, Some(5), 4));
+        assert_eq!(parsed.opus_data, [0xF8, 0xAA]);
+
+        // The header is authenticated too
+        let mut tampered = packet.clone();
+        tampered[14] ^= 0x01;
+        assert!(unwrap_packet(&tampered, Some(&cipher)).is_none());
+        assert!(unwrap_packet(&packet, None).is_none());
+    }
+}
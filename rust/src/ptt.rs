@@ -0,0 +1,101 @@
+// ===========================================================================
+// PTT BUTTON
+// ===========================================================================
+// Key events from accessories (wired headset buttons, Bluetooth remotes) and the
+// app's own button all go through `on_accessory_ptt`, so every entry point means
+// the same thing. Contacts bounce and keys auto-repeat: repeats of the current
+// state are ignored, and an edge within PTT_DEBOUNCE_MS of the last one only
+// takes effect once that window has passed, if the key is still in that state.
+// So bounces cancel out, but a release right after its press (remotes may send
+// both at once) still turns the mic off.
+//
+// - Momentary (default): the mic is on while the key is held.
+// - Toggle (`AudioConfig.ptt_toggle`): a tap turns the mic on and the next tap
+//   turns it off. A press held for PTT_TOGGLE_HOLD_MS still works as momentary,
+//   so releasing it turns the mic off again.
+
+use std::time::{Duration, Instant};
+
+use crate::{PTT_DEBOUNCE_MS, PTT_TOGGLE_HOLD_MS};
+
+pub(crate) struct PttButton {
+    pressed: bool,  // As last applied
+    key_down: bool, // As last reported, applied once the debounce window allows
+    last_edge: Option<Instant>,
+    opened_at: Option<Instant>, // This press turned the mic on (toggle mode)
+}
+
+impl PttButton {
+    pub(crate) fn new() -> Self {
+        Self { pressed: false, key_down: false, last_edge: None, opened_at: None }
+    }
+
+    /// Takes a key edge; `mic_on` is the mic's current state (floor control or the
+    /// quota may have turned it off). Returns the state to switch the mic to, or
+    /// `None` to leave it. An edge inside the debounce window waits: see `settle_in`.
+    pub(crate) fn event(&mut self, pressed: bool, toggle: bool, mic_on: bool) -> Option<bool> {
+        self.key_down = pressed;
+        self.settle(toggle, mic_on)
+    }
+
+    /// How long until a waiting edge can be applied with `settle`, if one is waiting.
+    pub(crate) fn settle_in(&self) -> Option<Duration> {
+        if self.key_down == self.pressed { return None; }
+        let elapsed = self.last_edge.map_or(Duration::MAX, |last| last.elapsed());
+        Some(Duration::from_millis(PTT_DEBOUNCE_MS).saturating_sub(elapsed))
+    }
+
+    /// Applies the key's state if it changed and the debounce window has passed;
+    /// returns what `event` would.
+    pub(crate) fn settle(&mut self, toggle: bool, mic_on: bool) -> Option<bool> {
+        let pressed = self.key_down;
+        if pressed == self.pressed { return None; }
+        let now = Instant::now();
+        if self.last_edge.is_some_and(|last| now.duration_since(last) < Duration::from_millis(PTT_DEBOUNCE_MS)) {
+            return None;
+        }
+        self.pressed = pressed;
+        self.last_edge = Some(now);
+
+        if !toggle {
+            self.opened_at = None;
+            return Some(pressed);
+        }
+        if pressed {
+            self.opened_at = (!mic_on).then_some(now);
+            return Some(!mic_on);
+        }
+        // Released: a long press was momentary, a tap leaves the mic latched
+        let held = self.opened_at.take()
+            .is_some_and(|opened| now.duration_since(opened) >= Duration::from_millis(PTT_TOGGLE_HOLD_MS));
+        held.then_some(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_release_inside_the_debounce_window_still_lands() {
+        let mut button = PttButton::new();
+        assert_eq!(button.event(true, false, false), Some(true));
+        assert_eq!(button.event(false, false, true), None);
+        let wait = button.settle_in().unwrap();
+        assert!(wait <= Duration::from_millis(PTT_DEBOUNCE_MS));
+        std::thread::sleep(wait);
+        assert_eq!(button.settle(false, true), Some(false));
+        assert_eq!(button.settle_in(), None);
+    }
+
+    #[test]
+    fn bounces_cancel_out() {
+        let mut button = PttButton::new();
+        assert_eq!(button.event(true, false, false), Some(true));
+        assert_eq!(button.event(false, false, true), None);
+        assert_eq!(button.event(true, false, true), None);
+        assert_eq!(button.settle_in(), None);
+        std::thread::sleep(Duration::from_millis(PTT_DEBOUNCE_MS));
+        assert_eq!(button.settle(false, true), None);
+    }
+}
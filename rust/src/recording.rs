@@ -0,0 +1,205 @@
+// ===========================================================================
+// RECORDING
+// ===========================================================================
+// `start_recording` archives the received mix as an Ogg/Opus file (RFC 7845),
+// `start_mic_recording` our own outgoing audio as WAV, and `start_voice_message`
+// captures a message as an in-memory Ogg/Opus blob. The audio callbacks only
+// hand PCM over; writer threads do the encoding and the I/O with these.
+// `MixHistory` keeps the last seconds of the mix in memory for instant replay.
+
+use std::collections::VecDeque;
+use std::io::{self, Seek, SeekFrom, Write};
+
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+
+use crate::RECORDING_PAGE_PACKETS;
+
+// Granule positions count 48kHz samples whatever rate the stream was encoded at
+const GRANULE_RATE: u64 = 48000;
+const VENDOR: &[u8] = b"walkie-talkie";
+
+pub(crate) struct OggOpusWriter<W: Write> {
+    writer: PacketWriter<'static, W>,
+    serial: u32,
+    granule: u64,
+    pending: Option<(Vec<u8>, u64)>, // Held back one packet, so the last one can end the stream
+    packets_in_page: u32,
+}
+
+impl<W: Write> OggOpusWriter<W> {
+    /// Writes the identification and comment headers of a mono stream. `pre_skip`
+    /// is the encoder lookahead in 48kHz samples.
+    pub(crate) fn new(inner: W, serial: u32, input_rate: u32, pre_skip: u16) -> io::Result<Self> {
+        let mut writer = PacketWriter::new(inner);
+
+        let mut head = Vec::with_capacity(19);
+        head.extend_from_slice(b"OpusHead");
+        head.push(1); // Version
+        head.push(1); // Channels
+        head.extend_from_slice(&pre_skip.to_le_bytes());
+        head.extend_from_slice(&input_rate.to_le_bytes());
+        head.extend_from_slice(&0i16.to_le_bytes()); // Output gain
+        head.push(0); // Channel mapping family
+        writer.write_packet(head, serial, PacketWriteEndInfo::EndPage, 0)?;
+
+        let mut tags = Vec::with_capacity(16 + VENDOR.len());
+        tags.extend_from_slice(b"OpusTags");
+        tags.extend_from_slice(&(VENDOR.len() as u32).to_le_bytes());
+        tags.extend_from_slice(VENDOR);
+        tags.extend_from_slice(&0u32.to_le_bytes()); // No user comments
+        writer.write_packet(tags, serial, PacketWriteEndInfo::EndPage, 0)?;
+
+        Ok(Self { writer, serial, granule: pre_skip as u64, pending: None, packets_in_page: 0 })
+    }
+
+    /// Adds one Opus packet holding `samples` at `rate` Hz. Pages are closed every
+    /// RECORDING_PAGE_PACKETS packets, so a crash loses at most that much audio.
+    pub(crate) fn write(&mut self, packet: Vec<u8>, samples: usize, rate: u32) -> io::Result<()> {
+        self.granule += samples as u64 * GRANULE_RATE / rate as u64;
+        let Some((previous, granule)) = self.pending.replace((packet, self.granule)) else { return Ok(()) };
+        self.packets_in_page += 1;
+        let end = if self.packets_in_page >= RECORDING_PAGE_PACKETS {
+            self.packets_in_page = 0;
+            PacketWriteEndInfo::EndPage
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+        self.writer.write_packet(previous, self.serial, end, granule)
+    }
+
+    /// Ends the stream. The last `padding` samples (at `rate`) were silence added to
+    /// fill the final frame; the final granule position trims them on playback.
+    /// Hands back the inner writer.
+    pub(crate) fn finish(mut self, padding: usize, rate: u32) -> io::Result<W> {
+        if let Some((last, granule)) = self.pending.take() {
+            let granule = granule.saturating_sub(padding as u64 * GRANULE_RATE / rate as u64);
+            self.writer.write_packet(last, self.serial, PacketWriteEndInfo::EndStream, granule)?;
+        }
+        let mut inner = self.writer.into_inner();
+        inner.flush()?;
+        Ok(inner)
+    }
+}
+
+const WAV_HEADER_SIZE: u32 = 44;
+
+/// 16-bit mono PCM WAV. The sizes in the header are filled in by `finish`.
+pub(crate) struct WavWriter<W: Write + Seek> {
+    inner: W,
+    data_bytes: u32,
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    pub(crate) fn new(mut inner: W, sample_rate: u32) -> io::Result<Self> {
+        let mut header = Vec::with_capacity(WAV_HEADER_SIZE as usize);
+        header.extend_from_slice(b"RIFF");
+        header.extend_from_slice(&0u32.to_le_bytes()); // RIFF size, see `finish`
+        header.extend_from_slice(b"WAVEfmt ");
+        header.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+        header.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        header.extend_from_slice(&1u16.to_le_bytes()); // Channels
+        header.extend_from_slice(&sample_rate.to_le_bytes());
+        header.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // Byte rate
+        header.extend_from_slice(&2u16.to_le_bytes()); // Block align
+        header.extend_from_slice(&16u16.to_le_bytes()); // Bits per sample
+        header.extend_from_slice(b"data");
+        header.extend_from_slice(&0u32.to_le_bytes()); // Data size, see `finish`
+        inner.write_all(&header)?;
+        Ok(Self { inner, data_bytes: 0 })
+    }
+
+    /// Appends samples. Beyond the format's 4GB limit (12 hours at 48kHz) they are dropped.
+    pub(crate) fn write(&mut self, pcm: &[i16]) -> io::Result<()> {
+        let bytes = pcm.len() as u32 * 2;
+        if self.data_bytes.checked_add(bytes + WAV_HEADER_SIZE).is_none() { return Ok(()); }
+        let mut buf = Vec::with_capacity(bytes as usize);
+        for sample in pcm {
+            buf.extend_from_slice(&sample.to_le_bytes());
+        }
+        self.inner.write_all(&buf)?;
+        self.data_bytes += bytes;
+        Ok(())
+    }
+
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.inner.seek(SeekFrom::Start(4))?;
+        self.inner.write_all(&(self.data_bytes + WAV_HEADER_SIZE - 8).to_le_bytes())?;
+        self.inner.seek(SeekFrom::Start(40))?;
+        self.inner.write_all(&self.data_bytes.to_le_bytes())?;
+        self.inner.flush()
+    }
+}
+
+/// The most recent samples of the received mix, up to a fixed length.
+pub(crate) struct MixHistory {
+    samples: VecDeque<i16>,
+    capacity: usize,
+}
+
+impl MixHistory {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self { samples: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    /// Changes how much is kept, dropping the oldest samples if it shrinks.
+    /// Memory is reserved up front, so the audio thread never allocates.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.trim();
+        self.samples.shrink_to(capacity);
+        self.samples.reserve_exact(capacity - self.samples.len());
+    }
+
+    pub(crate) fn extend<I>(&mut self, pcm: I)
+    where
+        I: IntoIterator<Item = i16>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        if self.capacity == 0 { return; }
+        // Make room first: going past the reserved capacity, even briefly, reallocates
+        let pcm = pcm.into_iter();
+        let incoming = pcm.len().min(self.capacity);
+        let skip = pcm.len() - incoming;
+        let excess = (self.samples.len() + incoming).saturating_sub(self.capacity);
+        self.samples.drain(..excess);
+        self.samples.extend(pcm.skip(skip));
+    }
+
+    /// Up to the last `len` samples, oldest first.
+    pub(crate) fn latest(&self, len: usize) -> Vec<i16> {
+        let skip = self.samples.len().saturating_sub(len);
+        self.samples.range(skip..).copied().collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    fn trim(&mut self) {
+        let excess = self.samples.len().saturating_sub(self.capacity);
+        self.samples.drain(..excess);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_full_history_stays_in_its_reservation() {
+        let mut history = MixHistory::new(0);
+        history.set_capacity(1000);
+        let reserved = history.samples.capacity();
+        for block in 0..50 {
+            history.extend((0..96).map(|i| (block * 96 + i) as i16));
+            assert_eq!(history.samples.capacity(), reserved);
+        }
+        assert_eq!(history.latest(2000).len(), 1000);
+        assert_eq!(history.latest(2), [4798, 4799]);
+
+        // A block longer than the whole history keeps its end
+        history.extend((0..1500).map(|i| i as i16));
+        assert_eq!(history.samples.capacity(), reserved);
+        assert_eq!(history.latest(1000)[0], 500);
+    }
+}
@@ -0,0 +1,163 @@
+// ===========================================================================
+// REPLAY PROTECTION
+// ===========================================================================
+// A sliding window per sender (as in SRTP, RFC 3711 §3.3.2): the highest
+// packet index seen plus a bitmap of the REPLAY_WINDOW_PACKETS before it.
+// Anything already marked, or older than the window, is rejected.
+//
+// Encrypted packets are indexed by (epoch, seq), which is authenticated and
+// only moves forward (the epoch is bumped whenever the sequence wraps). The
+// sequence is 32 bits wide, or 16 for v1 senders. For
+// cleartext the rollover is estimated from the highest index seen; there is
+// nothing to protect there, so this only drops duplicates, and the window
+// resets when the sender goes quiet or jumps back (it may have restarted).
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::{REPLAY_RESET_MS, REPLAY_WINDOW_PACKETS};
+
+struct ReplayWindow {
+    highest: u64,
+    seen: u64, // Bit i set = index `highest - i` was accepted
+    last_accepted: Instant,
+}
+
+impl ReplayWindow {
+    fn new(index: u64) -> Self {
+        Self { highest: index, seen: 1, last_accepted: Instant::now() }
+    }
+
+    /// The rollover count that puts `seq` closest to the highest index seen.
+    fn cleartext_index(&self, seq: u32, seq_bits: u32) -> u64 {
+        let rollover = self.highest >> seq_bits;
+        [rollover.wrapping_sub(1), rollover, rollover + 1]
+            .into_iter()
+            .filter(|&candidate| candidate <= u64::MAX >> seq_bits)
+            .map(|candidate| (candidate << seq_bits) | seq as u64)
+            .min_by_key(|&index| index.abs_diff(self.highest))
+            .unwrap()
+    }
+
+    fn accept(&mut self, index: u64) -> bool {
+        if index > self.highest {
+            let shift = index - self.highest;
+            self.seen = if shift >= REPLAY_WINDOW_PACKETS { 0 } else { self.seen << shift };
+            self.seen |= 1;
+            self.highest = index;
+        } else {
+            let age = self.highest - index;
+            if age >= REPLAY_WINDOW_PACKETS || self.seen & (1 << age) != 0 {
+                return false;
+            }
+            self.seen |= 1 << age;
+        }
+        self.last_accepted = Instant::now();
+        true
+    }
+}
+
+/// Replay windows for every sender.
+pub(crate) struct ReplayGuard {
+    windows: HashMap<u32, ReplayWindow>,
+}
+
+impl ReplayGuard {
+    pub(crate) fn new() -> Self {
+        Self { windows: HashMap::new() }
+    }
+
+    /// Records a packet; `false` if it's a replay (or too old to tell).
+    /// `epoch` is set for encrypted packets.
+    pub(crate) fn accept(&mut self, origin_id: u32, epoch: Option<u32>, seq: u32, seq_bits: u32) -> bool {
+        let Some(window) = self.windows.get_mut(&origin_id) else {
+            let index = epoch.map_or(seq as u64, |epoch| encrypted_index(epoch, seq, seq_bits));
+            self.windows.insert(origin_id, ReplayWindow::new(index));
+            return true;
+        };
+        match epoch {
+            Some(epoch) => window.accept(encrypted_index(epoch, seq, seq_bits)),
+            None if window.last_accepted.elapsed() >= Duration::from_millis(REPLAY_RESET_MS) => {
+                *window = ReplayWindow::new(seq as u64);
+                true
+            }
+            None => {
+                let index = window.cleartext_index(seq, seq_bits);
+                if index + REPLAY_WINDOW_PACKETS <= window.highest {
+                    // Far behind: the sender restarted with a new baseline
+                    *window = ReplayWindow::new(index);
+                    return true;
+                }
+                window.accept(index)
+            }
+        }
+    }
+
+    /// Forgets a sender (it switched keys, so its indices start over).
+    pub(crate) fn reset(&mut self, origin_id: u32) {
+        self.windows.remove(&origin_id);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.windows.clear();
+    }
+}
+
+fn encrypted_index(epoch: u32, seq: u32, seq_bits: u32) -> u64 {
+    ((epoch as u64) << seq_bits) | seq as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_window_slides_and_rejects_duplicates() {
+        let mut guard = ReplayGuard::new();
+        assert!(guard.accept(1, Some(7), 100, 32));
+        assert!(!guard.accept(1, Some(7), 100, 32));
+        // Out of order within the window is fine, once
+        assert!(guard.accept(1, Some(7), 102, 32));
+        assert!(guard.accept(1, Some(7), 101, 32));
+        assert!(!guard.accept(1, Some(7), 101, 32));
+
+        // Sliding forward keeps what was seen inside the window
+        assert!(guard.accept(1, Some(7), 150, 32));
+        assert!(!guard.accept(1, Some(7), 102, 32));
+        assert!(guard.accept(1, Some(7), 103, 32));
+        // A new epoch is ahead of any sequence in the old one
+        assert!(guard.accept(1, Some(8), 0, 32));
+        assert!(!guard.accept(1, Some(7), 151, 32));
+        // Other senders have their own window
+        assert!(guard.accept(2, Some(7), 100, 32));
+    }
+
+    #[test]
+    fn packets_older_than_the_window_are_rejected() {
+        let mut guard = ReplayGuard::new();
+        let newest = 1000;
+        assert!(guard.accept(1, Some(1), newest, 32));
+        let oldest = newest - REPLAY_WINDOW_PACKETS as u32 + 1;
+        assert!(guard.accept(1, Some(1), oldest, 32));
+        assert!(!guard.accept(1, Some(1), oldest - 1, 32));
+        // After a jump the window moves on: even indices never seen fall out of it
+        assert!(guard.accept(1, Some(1), newest + REPLAY_WINDOW_PACKETS as u32, 32));
+        assert!(!guard.accept(1, Some(1), newest - 2, 32));
+        assert!(guard.accept(1, Some(1), newest + 1, 32));
+
+        // Forgetting the sender starts over
+        guard.reset(1);
+        assert!(guard.accept(1, Some(1), 5, 32));
+    }
+
+    #[test]
+    fn cleartext_sequences_roll_over() {
+        let mut guard = ReplayGuard::new();
+        assert!(guard.accept(1, None, 65_534, 16));
+        assert!(guard.accept(1, None, 65_535, 16));
+        assert!(guard.accept(1, None, 0, 16));
+        assert!(!guard.accept(1, None, 65_535, 16));
+        assert!(!guard.accept(1, None, 0, 16));
+        assert!(guard.accept(1, None, 1, 16));
+    }
+}
@@ -0,0 +1,79 @@
+// ===========================================================================
+// RESOURCE ACCOUNTING
+// ===========================================================================
+// Counts of what the engine holds, so soak tests can check that thousands of
+// sessions don't leak (`get_resource_report`). Every counted object owns a
+// `Tracked` guard, counted while it lives, so the numbers can't drift from the
+// objects themselves. Counts are process-wide: all engines in the process add up.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread::{self, JoinHandle};
+
+use crate::ResourceReport;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Resource {
+    Engine,
+    Pipeline,   // An input or output callback (audio stream or pull mode)
+    PeerStream, // A peer's decoder, jitter buffer and scratch buffers
+    Encoder,    // A send pipeline
+    Thread,
+}
+
+static COUNTS: [AtomicU32; 5] = [const { AtomicU32::new(0) }; 5];
+
+/// Counts one `Resource` for as long as it lives.
+pub(crate) struct Tracked(Resource);
+
+impl Tracked {
+    pub(crate) fn new(resource: Resource) -> Self {
+        COUNTS[resource as usize].fetch_add(1, Ordering::Relaxed);
+        Self(resource)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        COUNTS[self.0 as usize].fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn count(resource: Resource) -> u32 {
+    COUNTS[resource as usize].load(Ordering::Relaxed)
+}
+
+/// `thread::spawn`, counted until the thread's function returns.
+pub(crate) fn spawn<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let tracked = Tracked::new(Resource::Thread);
+    thread::spawn(move || {
+        let _tracked = tracked;
+        f()
+    })
+}
+
+pub(crate) fn report() -> ResourceReport {
+    ResourceReport {
+        engines: count(Resource::Engine),
+        pipelines: count(Resource::Pipeline),
+        peer_streams: count(Resource::PeerStream),
+        encoders: count(Resource::Encoder),
+        threads: count(Resource::Thread),
+    }
+}
+
+/// Debug builds: once a session has stopped, the pipelines, peer streams and
+/// encoders must all be gone. Only checked while a single engine exists, since
+/// the counts cover every engine in the process.
+pub(crate) fn debug_assert_session_released() {
+    if cfg!(debug_assertions) && count(Resource::Engine) == 1 {
+        let report = report();
+        debug_assert!(
+            report.pipelines == 0 && report.peer_streams == 0 && report.encoders == 0,
+            "Session resources left after stop_session: {:?}", report,
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// ===========================================================================
+// ROSTER SYNC
+// ===========================================================================
+// The trusted-peer roster (the identity keys the app should accept) is owned by
+// one admin node, whose identity key every node is told. The admin publishes
+// numbered versions and hands each peer with an agreed key its own copy, sealed
+// under their pairwise key (see handshake.rs). Only the holder of the admin
+// identity key can produce a copy that opens, so no separate signature is
+// needed. A newer version replaces the current roster; anything else is ignored.
+// Peers that complete key agreement later get the current version right away.
+//
+// ROSTER: [Target ID (4)] + [Version (4)] + sealed [Count (2)] + [Keys (32 each)] + [Tag (16)]
+
+use crate::handshake::{KEY_SIZE, KeyAgreement};
+
+pub(crate) const CONTROL_ROSTER: u8 = 0x04;
+// Keeps a roster message within one 1500-byte datagram
+pub(crate) const MAX_ROSTER_KEYS: usize = 40;
+const ROSTER_HEADER_SIZE: usize = 8;
+const ROSTER_COUNT_SIZE: usize = 2;
+// Nonce sequence for roster messages (sender keys use 0); the version is the epoch
+const ROSTER_NONCE_SEQ: u32 = 1;
+
+pub(crate) struct RosterSync {
+    admin: Option<[u8; KEY_SIZE]>,
+    version: u32, // 0 = no roster yet
+    keys: Vec<[u8; KEY_SIZE]>,
+}
+
+impl RosterSync {
+    pub(crate) fn new() -> Self {
+        Self { admin: None, version: 0, keys: Vec::new() }
+    }
+
+    /// A different admin starts over: its versions have nothing to do with the old one's.
+    pub(crate) fn set_admin(&mut self, admin: Option<[u8; KEY_SIZE]>) {
+        if self.admin != admin {
+            self.version = 0;
+            self.keys.clear();
+        }
+        self.admin = admin;
+    }
+
+    /// Forgets the admin and the roster.
+    pub(crate) fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    pub(crate) fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub(crate) fn keys(&self) -> &[[u8; KEY_SIZE]] {
+        &self.keys
+    }
+
+    fn is_admin(&self, key_agreement: &KeyAgreement) -> bool {
+        self.admin.is_some() && key_agreement.identity_public() == self.admin
+    }
+
+    /// Replaces the roster with a newer version. Only the admin may publish, and the
+    /// version must move forward (it is part of the nonce, so it must never repeat).
+    pub(crate) fn publish(&mut self, key_agreement: &KeyAgreement, version: u32, keys: Vec<[u8; KEY_SIZE]>) -> bool {
+        if !self.is_admin(key_agreement) || version <= self.version || keys.len() > MAX_ROSTER_KEYS {
+            return false;
+        }
+        self.version = version;
+        self.keys = keys;
+        true
+    }
+
+    /// The current roster sealed for `target`, if we are the admin and have one.
+    pub(crate) fn message_for(&self, key_agreement: &KeyAgreement, own_id: u32, target: u32) -> Option<Vec<u8>> {
+        if self.version == 0 || !self.is_admin(key_agreement) { return None; }
+        let mut payload = Vec::with_capacity(ROSTER_HEADER_SIZE + ROSTER_COUNT_SIZE + self.keys.len() * KEY_SIZE + 16);
+        payload.extend_from_slice(&target.to_le_bytes());
+        payload.extend_from_slice(&self.version.to_le_bytes());
+        let mut plaintext = Vec::with_capacity(ROSTER_COUNT_SIZE + self.keys.len() * KEY_SIZE);
+        plaintext.extend_from_slice(&(self.keys.len() as u16).to_le_bytes());
+        for key in &self.keys {
+            plaintext.extend_from_slice(key);
+        }
+        let sealed = key_agreement.seal_for_peer(own_id, target, self.version, ROSTER_NONCE_SEQ, &payload, &plaintext)?;
+        payload.extend_from_slice(&sealed);
+        Some(payload)
+    }
+
+    /// Handles a roster message; `true` if it was a newer version from the admin.
+    pub(crate) fn handle(&mut self, key_agreement: &KeyAgreement, own_id: u32, origin_id: u32, payload: &[u8]) -> bool {
+        if payload.len() < ROSTER_HEADER_SIZE { return false; }
+        let target = u32::from_le_bytes(payload[0..4].try_into().unwrap());
+        if target != own_id { return false; } // Addressed to someone else
+        let version = u32::from_le_bytes(payload[4..8].try_into().unwrap());
+        if version <= self.version { return false; } // Already have it (or a resend)
+        if self.admin.is_none() || key_agreement.peer_identity(origin_id) != self.admin { return false; }
+
+        let header = &payload[..ROSTER_HEADER_SIZE];
+        let Some(plaintext) = key_agreement.open_from_peer(origin_id, version, ROSTER_NONCE_SEQ, header, &payload[ROSTER_HEADER_SIZE..]) else {
+            log::warn!("Roster from peer {} failed to verify", origin_id);
+            return false;
+        };
+        if plaintext.len() < ROSTER_COUNT_SIZE { return false; }
+        let count = u16::from_le_bytes(plaintext[..ROSTER_COUNT_SIZE].try_into().unwrap()) as usize;
+        let keys = &plaintext[ROSTER_COUNT_SIZE..];
+        if count > MAX_ROSTER_KEYS || keys.len() != count * KEY_SIZE { return false; }
+
+        self.version = version;
+        self.keys = keys.chunks_exact(KEY_SIZE).map(|key| key.try_into().unwrap()).collect();
+        true
+    }
+}
@@ -0,0 +1,472 @@
+// ===========================================================================
+// CENTRALIZED CONFIGURATION
+// ===========================================================================
+// Every tuning constant and wire-format size, in one place. The crate root
+// imports them all, so the rest of the crate names them as `crate::NAME`.
+
+use crate::AudioRoute;
+
+// --- Buffer & Channel Limits ---
+// Buffer size for 120ms of audio at 48kHz (48000Hz * 0.120s = 5760 samples).
+// We need enough room to hold data while processing.
+pub(crate) const MAX_BUFFER_SIZE: usize = 5760;
+
+// Maximum size of a raw Opus encoded packet. 512 bytes is plenty for voice.
+pub(crate) const OPUS_OUT_BUFFER_SIZE: usize = 512;
+// Loss rate the encoder plans for. Opus only embeds in-band FEC (a low-bitrate copy
+// of the previous frame) when this is nonzero; higher values spend more bits on it.
+pub(crate) const OPUS_EXPECTED_LOSS_PERCENT: i32 = 10;
+// With DTX on, Opus encodes a pause as frames of at most this size ("still silent",
+// nothing to decode but comfort noise). Larger frames carry audio or a noise update.
+pub(crate) const OPUS_DTX_MAX_BYTES: usize = 2;
+// Limits of the Opus packet format (RFC 6716 §3.4): one frame's bytes, and a
+// whole packet's duration (120ms) in samples at 48kHz.
+pub(crate) const OPUS_MAX_FRAME_BYTES: usize = 1275;
+pub(crate) const OPUS_MAX_PACKET_SAMPLES: u32 = 5760;
+// Bitrates Opus accepts (bits per second); 0 means "let the encoder choose".
+pub(crate) const OPUS_BITRATE_RANGE: std::ops::RangeInclusive<i32> = 500..=512_000;
+
+// --- Protocol Layout ---
+// Header: [Version (1 byte)] + [OriginID (4 bytes)] + [Sequence (4 bytes)]
+//       + [Capture Time (4 bytes, Unix ms mod 2^32)] + [Flags (1 byte)] + [Channel (2 bytes)]
+// Optional fields follow the header in flag-bit order, then the Opus data.
+pub(crate) const PACKET_HEADER_SIZE: usize = 16;
+// v2 header: the same without the channel. Still sent for channel 0 (the default),
+// so builds without channels keep hearing it.
+pub(crate) const PACKET_HEADER_SIZE_V2: usize = 14;
+// v1 header: [Version (1 byte)] + [OriginID (4 bytes)] + [Sequence (2 bytes)] + [Flags (1 byte)]
+// Still parsed, and always used for control packets: the v1 header and the
+// capabilities message are frozen, so any two versioned builds can find out what the
+// other speaks.
+pub(crate) const PACKET_HEADER_SIZE_V1: usize = 8;
+// v0 (builds before protocol versions): [OriginID (4 bytes)] + [Sequence (2 bytes)],
+// then the Opus data. No version byte, see legacy.rs for how it is recognized. Played
+// while no key is set; v0 builds can't parse anything we send.
+pub(crate) const PACKET_HEADER_SIZE_V0: usize = 6;
+// Packets in sequence (each at most LEGACY_SEQ_GAP after the last, within
+// LEGACY_CONFIRM_MS) before a sender counts as v0.
+pub(crate) const LEGACY_CONFIRM_PACKETS: u32 = 2;
+pub(crate) const LEGACY_SEQ_GAP: u16 = 16;
+pub(crate) const LEGACY_CONFIRM_MS: u64 = 2000;
+// A v0 sender counts as unconfirmed again once none of its packets has continued
+// its sequence for this long
+pub(crate) const LEGACY_EXPIRE_MS: u64 = 10_000;
+pub(crate) const PROTOCOL_VERSION: u8 = 3;
+// Oldest version we still parse; audio outside [MIN, VERSION] is dropped.
+pub(crate) const PROTOCOL_MIN_VERSION: u8 = 1;
+pub(crate) const PACKET_FLAG_POSITION: u8 = 0x01;
+// Encrypted: [Epoch (4 bytes)] follows the header, then everything else is
+// ChaCha20-Poly1305 ciphertext (optional fields + Opus data) plus a 16-byte tag.
+pub(crate) const PACKET_FLAG_ENCRYPTED: u8 = 0x02;
+pub(crate) const PACKET_EPOCH_SIZE: usize = 4;
+// Position: [Latitude (f64)] + [Longitude (f64)] + [Altitude (f32)] + [Heading (f32)]
+pub(crate) const PACKET_POSITION_SIZE: usize = 24;
+// Checksum: a CRC-32 (IEEE) of everything before it, as a 4-byte trailer, so damaged
+// datagrams are dropped instead of decoded. Only added to cleartext (encrypted packets
+// carry an AEAD tag), and only once every peer announced CAPABILITY_CHECKSUM, since
+// older builds would decode the trailer as audio.
+pub(crate) const PACKET_FLAG_CHECKSUM: u8 = 0x04;
+pub(crate) const PACKET_CHECKSUM_SIZE: usize = 4;
+// Redundancy (`AudioConfig.redundancy`): [Length (2 bytes)] + [Previous Opus Frame],
+// after the position. Fills a single lost packet from the one after it (RED-style).
+// Like the checksum, only sent once every peer announced CAPABILITY_REDUNDANCY.
+pub(crate) const PACKET_FLAG_REDUNDANT: u8 = 0x08;
+pub(crate) const PACKET_REDUNDANT_LEN_SIZE: usize = 2;
+// Padding (`AudioConfig.pad_packets`): [Zeros] + [Padding Length (2 bytes, includes
+// itself)] after the Opus data, so the encrypted body is a multiple of the bucket
+// and packet sizes don't reveal speech activity. Encrypted packets only, and only
+// once every peer announced CAPABILITY_PADDING.
+pub(crate) const PACKET_FLAG_PADDED: u8 = 0x10;
+pub(crate) const PACKET_PADDING_LEN_SIZE: usize = 2;
+pub(crate) const PACKET_PADDING_BUCKET: usize = 128;
+// Priority (`set_local_priority`): [Priority (1)] after the redundant frame, for
+// receivers that duck or mute lower-priority speakers (`AudioConfig.preemption`).
+// Left out at priority 0 (the default), and only sent once every peer announced
+// CAPABILITY_PRIORITY.
+pub(crate) const PACKET_FLAG_PRIORITY: u8 = 0x20;
+pub(crate) const PACKET_PRIORITY_SIZE: usize = 1;
+// Emergency (`start_emergency_transmission`): no payload. Always sent; builds that
+// don't know the flag ignore it and play the audio as usual.
+pub(crate) const PACKET_FLAG_EMERGENCY: u8 = 0x40;
+// Control: the header is followed by [Type (1 byte)] + payload instead of audio.
+// Types 0x01-0x02 are key agreement (see handshake.rs), 0x04 is the roster
+// (see roster.rs), 0x05-0x07 are floor control (see floor.rs). Never encrypted
+// at this layer.
+pub(crate) const PACKET_FLAG_CONTROL: u8 = 0x80;
+// Capabilities: [Min Version (1)] + [Max Version (1)] + [Capabilities (4)] + [Reply Wanted (1)]
+pub(crate) const CONTROL_CAPABILITIES: u8 = 0x03;
+pub(crate) const CAPABILITIES_SIZE: usize = 7;
+// A peer not heard from this long is forgotten (roughly PEER_TIMEOUT_FRAMES), and
+// asked for its capabilities again once it is back.
+pub(crate) const CAPABILITIES_TIMEOUT_MS: u64 = 3000;
+// Capability bits (`PeerCapabilities.capabilities`)
+pub(crate) const CAPABILITY_POSITION: u32 = 1 << 0;
+pub(crate) const CAPABILITY_ENCRYPTION: u32 = 1 << 1;
+pub(crate) const CAPABILITY_KEY_AGREEMENT: u32 = 1 << 2;
+pub(crate) const CAPABILITY_CHECKSUM: u32 = 1 << 3;
+pub(crate) const CAPABILITY_REDUNDANCY: u32 = 1 << 4;
+pub(crate) const CAPABILITY_PADDING: u32 = 1 << 5;
+pub(crate) const CAPABILITY_PRIORITY: u32 = 1 << 6;
+pub(crate) const LOCAL_CAPABILITIES: u32 = CAPABILITY_POSITION | CAPABILITY_ENCRYPTION | CAPABILITY_KEY_AGREEMENT
+    | CAPABILITY_CHECKSUM | CAPABILITY_REDUNDANCY | CAPABILITY_PADDING | CAPABILITY_PRIORITY;
+// RTP mode (`AudioConfig.rtp_packetization`): a plain 12-byte RTP header (RFC 3550)
+// with SSRC = node ID and a 48kHz timestamp (RFC 7587), then the Opus data. No
+// position or checksum, and never used while a key is set (no SRTP). Always parsed:
+// its first byte (0x80-0xBF) can't be mistaken for one of our version bytes.
+pub(crate) const RTP_HEADER_SIZE: usize = 12;
+pub(crate) const RTP_VERSION: u8 = 2;
+pub(crate) const RTP_PAYLOAD_TYPE: u8 = 111; // Dynamic; the usual choice for Opus
+pub(crate) const RTP_DYNAMIC_PAYLOAD_TYPES: std::ops::RangeInclusive<u8> = 96..=127;
+pub(crate) const RTP_CLOCK_RATE: i32 = 48000; // Fixed for Opus whatever the actual rate
+// Position is sent on the first packet of a transmission, then every N packets.
+pub(crate) const POSITION_REFRESH_PACKETS: u32 = 25;
+// Default for `AudioConfig.max_packet_bytes`: a typical MTU. Our largest packets
+// stay below it: a redundant audio packet (two Opus frames, every optional field,
+// padding, the tag) and a full roster (MAX_ROSTER_KEYS keys).
+pub(crate) const MAX_PACKET_BYTES_DEFAULT: u32 = 1500;
+// Lowest accepted limit (the IPv4 minimum datagram size), so a config can't
+// reject ordinary audio packets.
+pub(crate) const MAX_PACKET_BYTES_MIN: u32 = 576;
+
+// --- Network Handover ---
+// After `notify_network_change`, for this long the jitter buffer aims deeper and
+// the encoder plans for more loss (more in-band FEC); then both go back.
+pub(crate) const HANDOVER_GRACE_MS: u64 = 8000;
+pub(crate) const HANDOVER_JITTER_EXTRA_MS: i32 = 120;
+pub(crate) const HANDOVER_EXPECTED_LOSS_PERCENT: i32 = 30;
+
+// --- Emergency Transmission ---
+// An emergency goes out at a bitrate as high as mono speech benefits from, planned
+// for heavy loss (the most in-band FEC), with redundant frames where peers take them.
+pub(crate) const EMERGENCY_BITRATE_BPS: i32 = 64_000;
+pub(crate) const EMERGENCY_EXPECTED_LOSS_PERCENT: i32 = 50;
+// A sender whose emergency packets stop for this long starts a new emergency with the next one
+pub(crate) const EMERGENCY_TIMEOUT_MS: u64 = 2000;
+
+// --- Bandwidth Budget ---
+// Estimated bytes each audio packet adds to its payload (our header, cipher tag,
+// checksum, plus UDP/IP), counted against `set_bandwidth_budget_kbps`.
+pub(crate) const BUDGET_PACKET_OVERHEAD_BYTES: u32 = 40;
+// The budget never squeezes the bitrate below this (narrowband speech stays intelligible)
+pub(crate) const BUDGET_MIN_BITRATE_BPS: i32 = 6000;
+// Below this, in-band FEC costs speech more than it saves, so it is turned off
+pub(crate) const BUDGET_FEC_MIN_BITRATE_BPS: i32 = 12_000;
+// `get_bandwidth_usage` reports rates averaged over this long
+pub(crate) const BUDGET_METER_WINDOW_MS: u64 = 5000;
+
+// --- Session Resumption ---
+// Token: [Version (1 byte)] + [NodeID (4 bytes)] + [Sequence (4 bytes)] + [Key Epoch (4 bytes)]
+//      + [Active Channel (2 bytes)] + [Has Priority (1 byte)] + [Priority Channel (2 bytes)]
+//      + [Scan Count (2 bytes)] + [Scan Channels (2 bytes each)]
+pub(crate) const SESSION_TOKEN_VERSION: u8 = 3;
+pub(crate) const SESSION_TOKEN_SIZE: usize = 20; // Without the scan list
+// v2 tokens (no channels) and v1 tokens (2-byte sequence) from older builds are
+// still accepted, and leave the channels as they are.
+pub(crate) const SESSION_TOKEN_SIZE_V2: usize = 13;
+pub(crate) const SESSION_TOKEN_SIZE_V1: usize = 11;
+// Packets may have been sent after the token was exported, so resume a bit further on.
+pub(crate) const SESSION_RESUME_SEQ_ADVANCE: u32 = 64;
+
+// --- Key Agreement ---
+// Minimum gap between handshake requests triggered by audio we have no key for.
+pub(crate) const HANDSHAKE_RETRY_MS: u64 = 1000;
+
+// --- Replay Protection ---
+// Packets this far behind the newest one from the same sender are rejected.
+pub(crate) const REPLAY_WINDOW_PACKETS: u64 = 64;
+// Cleartext senders may restart from any sequence number, so their window
+// starts over after this much silence (roughly PEER_TIMEOUT_FRAMES).
+pub(crate) const REPLAY_RESET_MS: u64 = 3000;
+
+// --- Loop Detection ---
+// Frames we sent or heard are remembered this long, to spot copies coming back
+// through bridged gateways under another origin ID.
+pub(crate) const LOOP_WINDOW_MS: u64 = 2000;
+// Smaller frames (DTX, silence) are too alike across speakers to fingerprint.
+pub(crate) const LOOP_FINGERPRINT_MIN_BYTES: usize = 16;
+// A loop that persists is reported again after this long.
+pub(crate) const LOOP_REPORT_INTERVAL_MS: u64 = 30_000;
+
+// --- Channel Scan ---
+// A scanned channel silent for this long reports its next packet as new activity.
+pub(crate) const SCAN_ACTIVITY_GAP_MS: u64 = 2000;
+
+// --- Tuning Parameters ---
+// How many frames of silence (missing packets) before we delete a peer?
+// 50 frames * 60ms = ~3 seconds.
+pub(crate) const PEER_TIMEOUT_FRAMES: usize = 50;
+
+// Weight of each new sample in the smoothed end-to-end delay (PeerStats).
+pub(crate) const END_TO_END_DELAY_SMOOTHING: f32 = 0.1;
+
+// A talk burst is over once a peer's audio has played out and nothing new
+// arrived for this long (covers normal packet spacing on BLE).
+pub(crate) const PEER_TALK_END_MS: i32 = 500;
+
+// Fade applied when a peer's audio starts or stops inside a mixer callback
+// (underrun, talk start/end), so the edge doesn't click.
+pub(crate) const PEER_FADE_MS: i32 = 5;
+
+// Share of a mixer callback's duration the peer decodes may take. Once it is
+// used up, peers that aren't the current speaker wait for the next callback.
+pub(crate) const MIX_DECODE_BUDGET_PERCENT: u32 = 50;
+// Share after which peers on `ConcealmentMode::Auto` that aren't the current
+// speaker conceal lost frames by repeating the last one instead of Opus PLC.
+pub(crate) const MIX_CHEAP_CONCEAL_PERCENT: u32 = 25;
+// Gain applied on every repeat of a peer's last frame, so a long gap fades out.
+pub(crate) const CONCEAL_REPEAT_DECAY: f32 = 0.5;
+
+// Repeats of the same engine error within this window are coalesced into one callback.
+pub(crate) const ERROR_AGGREGATION_WINDOW_MS: u64 = 1000;
+
+// Pending callbacks when `dedicated_callback_thread` is on; beyond this, events are dropped.
+pub(crate) const CALLBACK_QUEUE_CAPACITY: usize = 256;
+
+// How often the output callback publishes per-peer stats for `get_stats`.
+pub(crate) const STATS_PUBLISH_MS: i32 = 250;
+// How often it publishes per-peer levels (`AudioLevelCallback`), fast enough to animate.
+pub(crate) const LEVEL_PUBLISH_MS: i32 = 50;
+
+// Jitter Buffer: How many packets to buffer before STARTING playback?
+// 6 packets * 60ms = 360ms latency.
+// Higher = smoother audio, Lower = faster conversation.
+pub(crate) const JITTER_BUFFER_START_THRESHOLD: usize = 6;
+
+// Jitter Buffer: How far ahead to check for a "future" packet if the expected one is missing?
+// If we expect Seq 10, but have Seq 15, we treat 11-14 as lost and skip to 15.
+// Default for `AudioConfig.resync_gap_packets`.
+pub(crate) const JITTER_LOOKAHEAD_WINDOW: u16 = 10;
+
+// --- Time-Stretch Playout ---
+// Instead of dropping packets when the jitter buffer runs deep (or starving when
+// it runs shallow), playout is sped up / slowed down by one pitch period per frame.
+// Accelerate once this many packets above the start threshold...
+pub(crate) const TSM_ACCELERATE_MARGIN: usize = 2;
+// ...and expand when only this many packets are left.
+pub(crate) const TSM_EXPAND_DEPTH: usize = 1;
+// Pitch search range (covers ~67Hz to 400Hz voices).
+pub(crate) const TSM_MIN_PITCH_MS: i32 = 2;
+pub(crate) const TSM_MAX_PITCH_MS: i32 = 15;
+// Only cut/insert where the signal repeats itself this well (normalized correlation)...
+pub(crate) const TSM_MIN_CORRELATION: f32 = 0.6;
+// ...unless the frame is this quiet (RMS, full scale = 1.0).
+pub(crate) const TSM_SILENCE_RMS: f32 = 0.003;
+
+// --- Per-Peer Resampling ---
+// Peers are decoded at their native Opus bandwidth and resampled to the mixer rate.
+// Filter half-length in input samples (latency is this many samples at the peer's rate).
+pub(crate) const RESAMPLER_HALF_TAPS: usize = 8;
+
+// --- Echo Cancellation ---
+// Length of the adaptive filter. 512 taps @ 48kHz covers ~10ms of echo tail
+// after the far-end FIFO and the delay estimator have absorbed the bulk latency.
+pub(crate) const AEC_FILTER_TAPS: usize = 512;
+
+// NLMS step size (0..2). Smaller = slower but more stable convergence.
+pub(crate) const AEC_STEP_SIZE: f32 = 0.3;
+
+// Geigel double-talk detector: if the mic is louder than this fraction of the
+// recent far-end peak, we assume the local user is talking and freeze adaptation.
+pub(crate) const AEC_DOUBLE_TALK_THRESHOLD: f32 = 0.5;
+
+// Upper bound for the far-end reference FIFO (500ms @ 48kHz). Protects against
+// unbounded growth if the input stream stops draining it.
+pub(crate) const AEC_FAR_END_MAX_SAMPLES: usize = 24000;
+// How far the reference may run ahead of the mic once the input callback took its
+// samples. The delay estimator can only hold the reference back, so echo that comes
+// back sooner than this after playout (a round trip under 20ms) isn't cancelled.
+pub(crate) const AEC_FAR_END_MAX_LEAD_MS: usize = 20;
+
+// Bulk delay estimation: envelopes of the reference and the mic (mean magnitude per
+// block) are correlated over the last window, at every lag up to the maximum, and the
+// reference is delayed by the best lag if it correlates at least this well.
+pub(crate) const AEC_MAX_DELAY_MS: usize = 250;
+pub(crate) const AEC_DELAY_BLOCK_SAMPLES: usize = 64;
+pub(crate) const AEC_DELAY_WINDOW_MS: usize = 1000;
+pub(crate) const AEC_DELAY_UPDATE_MS: usize = 250;
+pub(crate) const AEC_DELAY_MIN_CORRELATION: f32 = 0.5;
+
+// --- Automatic Gain Control ---
+// Per-frame smoothing factors. Attack (gain going down) is fast to stop clipping,
+// release (gain going up) is slow so short pauses don't pump up the noise floor.
+pub(crate) const AGC_ATTACK: f32 = 0.5;
+pub(crate) const AGC_RELEASE: f32 = 0.05;
+
+// Frames quieter than this are treated as silence and leave the gain untouched.
+pub(crate) const AGC_NOISE_GATE_DBFS: f32 = -50.0;
+
+// --- Attenuation Windows (Ducking) ---
+// Fade time when entering/leaving an attenuation window. Long enough to be
+// click-free, short enough that the prompt isn't masked at its start.
+pub(crate) const ATTENUATION_RAMP_MS: i32 = 30;
+
+// --- Output Limiter ---
+// How quickly the limiter recovers to unity gain after a peak.
+pub(crate) const LIMITER_RELEASE_MS: i32 = 50;
+
+// --- Output Volume ---
+// Upper bound for `set_output_volume` (2.0 = +6dB; the limiter catches the peaks).
+pub(crate) const MAX_OUTPUT_VOLUME: f32 = 2.0;
+
+// --- Secondary Output ---
+// Mix waiting for the second output stream; older samples are dropped, so it
+// never lags further behind the main output than this.
+pub(crate) const SECONDARY_OUTPUT_MAX_LATENCY_MS: i32 = 60;
+
+// --- Input Gain ---
+// Range for `set_input_gain`. Boosts beyond what a quiet mic needs only add noise.
+pub(crate) const MIN_INPUT_GAIN_DB: f32 = -30.0;
+pub(crate) const MAX_INPUT_GAIN_DB: f32 = 30.0;
+
+// --- Sidetone ---
+// Upper bound for `set_sidetone_gain` (1.0 = the mic at its captured level).
+pub(crate) const MAX_SIDETONE_GAIN: f32 = 1.0;
+// Mic audio waiting for the output stream; older samples are dropped, so the
+// sidetone never lags further behind than this.
+pub(crate) const SIDETONE_MAX_LATENCY_MS: i32 = 40;
+
+// --- Mic Failover ---
+// When the mic disappears mid-session, the tail of the old mic's audio fades out
+// and the replacement mic's first audio fades in over this long.
+pub(crate) const INPUT_FAILOVER_FADE_MS: i32 = 10;
+
+// --- Voice Activity Detection ---
+// A frame is speech if it is this much louder than the tracked noise floor...
+pub(crate) const VAD_SPEECH_MARGIN_DB: f32 = 9.0;
+// ...and louder than this absolute level (ignores near-silent rooms).
+pub(crate) const VAD_MIN_SPEECH_DBFS: f32 = -55.0;
+// How fast the noise floor estimate may creep up during non-quiet frames.
+pub(crate) const VAD_FLOOR_RISE_DB_PER_SEC: f32 = 3.0;
+// Keep reporting speech this long after the last speech frame (word gaps, trailing consonants).
+pub(crate) const VAD_HANGOVER_MS: i32 = 300;
+
+// --- Parameter Snapshots (Remote Config) ---
+// After a snapshot is applied, watch playout stability for this long...
+pub(crate) const PARAM_PROBATION_MS: u64 = 10_000;
+pub(crate) const PARAM_PROBATION_POLL_MS: u64 = 250;
+// ...and roll back if more than this many mid-transmission stalls occur.
+pub(crate) const PARAM_PROBATION_MAX_STALLS: u64 = 3;
+// A peer whose jitter buffer ran dry and then receives audio again within this
+// window stalled mid-transmission (as opposed to simply finishing a talk burst).
+pub(crate) const STALL_DETECT_WINDOW_MS: i32 = 1000;
+
+// --- Alert Clips ---
+// Memory budget for pre-decoded alert PCM (4 MB = ~43s of 48kHz mono).
+pub(crate) const CLIP_CACHE_MAX_BYTES: usize = 4 * 1024 * 1024;
+
+// --- UDP Multicast Transport ---
+// Receive buffer: the largest UDP payload, so nothing arrives truncated and
+// `AudioConfig.max_packet_bytes` decides what is too big.
+#[cfg(feature = "udp-transport")]
+pub(crate) const UDP_MAX_DATAGRAM_SIZE: usize = 65_507;
+// Receive timeout, so the loop can notice shutdown.
+#[cfg(feature = "udp-transport")]
+pub(crate) const UDP_RECEIVE_POLL_MS: u64 = 200;
+
+// --- Transport Send Watchdog ---
+// A `send_packet` call that blocks this long is reported through TransportHealthCallback.
+pub(crate) const TRANSPORT_STALL_MS: u64 = 250;
+
+// --- Engine Shutdown ---
+// `shutdown` (and dropping the engine) waits this long for each engine thread;
+// one still stuck in a foreign call after that is left to finish on its own.
+pub(crate) const THREAD_JOIN_TIMEOUT_MS: u64 = 1000;
+pub(crate) const THREAD_JOIN_POLL_MS: u64 = 5;
+
+// --- Output Watchdog ---
+// A running output stream whose presented position stops moving (or that under-runs
+// on every poll) for OUTPUT_WATCHDOG_STALL_MS is suspected of playing nothing.
+pub(crate) const OUTPUT_WATCHDOG_POLL_MS: u64 = 500;
+pub(crate) const OUTPUT_WATCHDOG_STALL_MS: u64 = 3000;
+// Confirmation: a short tone above most adults' hearing, listened for on the mic.
+// Platform echo cancellation may hide it, so hearing it clears a suspicion but
+// not hearing it proves nothing on its own.
+pub(crate) const LOOPBACK_TONE_HZ: f32 = 18_500.0;
+pub(crate) const LOOPBACK_TONE_MS: u32 = 300;
+pub(crate) const LOOPBACK_TONE_FADE_MS: u32 = 30;
+pub(crate) const LOOPBACK_TONE_LEVEL_DBFS: f32 = -24.0;
+pub(crate) const LOOPBACK_LISTEN_MS: u64 = 800;
+pub(crate) const LOOPBACK_DETECT_RATIO: f32 = 0.05; // Share of a mic block's energy at the tone
+pub(crate) const LOOPBACK_MIN_SAMPLE_RATE: i32 = 44_100; // The tone needs this much bandwidth
+
+// --- Audio Routing ---
+// Walked in order at session start and on `notify_route_changed`; the first route
+// with a connected device wins. Replaced by `set_route_preference`.
+pub(crate) const DEFAULT_ROUTE_PREFERENCE: [AudioRoute; 4] =
+    [AudioRoute::BluetoothSco, AudioRoute::Wired, AudioRoute::Earpiece, AudioRoute::Speaker];
+// `play_test_tone`, for settings screens to check which device we play through.
+pub(crate) const TEST_TONE_LEVEL_DBFS: f32 = -12.0;
+pub(crate) const TEST_TONE_FADE_MS: u32 = 10;
+pub(crate) const TEST_TONE_MAX_MS: u32 = 5000;
+// Left for the device to drain the tone before a temporary stream is closed.
+pub(crate) const TEST_TONE_DRAIN_MS: u64 = 250;
+
+// --- Roger Beep ---
+// `AudioConfig.roger_beep`: longer beeps are cut to this.
+pub(crate) const ROGER_BEEP_MAX_MS: u32 = 1000;
+pub(crate) const ROGER_BEEP_FADE_MS: u32 = 5;
+
+// --- Talk Permit Tone ---
+// `AudioConfig.talk_permit_tone`: played locally on PTT press; the mic opens when it ends.
+pub(crate) const TALK_PERMIT_TONE_HZ: f32 = 1500.0;
+pub(crate) const TALK_PERMIT_TONE_MS: u32 = 120;
+pub(crate) const TALK_PERMIT_TONE_LEVEL_DBFS: f32 = -12.0;
+pub(crate) const TALK_PERMIT_TONE_FADE_MS: u32 = 5;
+
+// --- PTT Pre-Roll ---
+// `AudioConfig.ptt_pre_roll_ms`: mic audio kept while PTT is up goes out in one burst
+// ahead of the transmission, so receivers need a jitter buffer at least this deep.
+pub(crate) const PTT_PRE_ROLL_MAX_MS: u32 = 1000;
+
+// --- PTT Button ---
+// `on_accessory_ptt`: key edges closer together than this are contact bounce.
+pub(crate) const PTT_DEBOUNCE_MS: u64 = 40;
+// Under `AudioConfig.ptt_toggle`, a press held this long is momentary.
+pub(crate) const PTT_TOGGLE_HOLD_MS: u64 = 600;
+
+// --- Talker ID Chirp ---
+// `AudioConfig.talker_id_chirp`: three notes picked from the scale by the peer's
+// node ID (512 combinations), ahead of each of its transmissions.
+pub(crate) const TALKER_CHIRP_NOTES_HZ: [f32; 8] = [660.0, 740.0, 880.0, 990.0, 1100.0, 1320.0, 1480.0, 1760.0];
+pub(crate) const TALKER_CHIRP_NOTE_MS: u32 = 40;
+pub(crate) const TALKER_CHIRP_GAP_MS: u32 = 15;
+pub(crate) const TALKER_CHIRP_LEVEL_DBFS: f32 = -18.0;
+pub(crate) const TALKER_CHIRP_FADE_MS: u32 = 5;
+
+// --- Floor Control ---
+// `AudioConfig.floor_control` (see floor.rs). A request takes a free floor once
+// nobody objected for this long (a round trip or two).
+pub(crate) const FLOOR_REQUEST_WAIT_MS: u64 = 200;
+// The holder repeats its GRANT this often, so peers can tell it is still there.
+pub(crate) const FLOOR_REFRESH_MS: u64 = 1000;
+// A holder not heard from for this long has gone; the floor is free.
+pub(crate) const FLOOR_HOLD_TIMEOUT_MS: u64 = 3000;
+
+// --- Recording ---
+// The received mix is re-encoded in frames of this size on the writer thread.
+pub(crate) const RECORDING_FRAME_MS: i32 = 20;
+pub(crate) const RECORDING_BITRATE_BPS: i32 = 24_000;
+// Mix buffers waiting for the writer; beyond this they are dropped (a gap in the file).
+pub(crate) const RECORDING_QUEUE_BUFFERS: usize = 64;
+// Packets per Ogg page (one second at 20ms), so a crash loses at most that much.
+pub(crate) const RECORDING_PAGE_PACKETS: u32 = 50;
+// Longest voice message; anything captured beyond it is dropped.
+pub(crate) const VOICE_MESSAGE_MAX_MS: u32 = 120_000;
+// Upper bound for `AudioConfig.replay_buffer_secs` (kept in memory, 94KB/s at 48kHz).
+pub(crate) const REPLAY_MAX_SECS: u32 = 60;
+
+// --- Benchmark ---
+// Limits for `run_benchmark`, which blocks its caller for the whole run.
+pub(crate) const BENCHMARK_MAX_PEERS: u32 = 64;
+pub(crate) const BENCHMARK_MAX_SECS: u32 = 60;
+
+// --- Debugging ---
+// Upper bound for `debug_set_peer_delay` (held packets are kept in memory).
+pub(crate) const DEBUG_MAX_PEER_DELAY_MS: u32 = 10_000;
+// Same for the network simulator's delay and jitter.
+#[cfg(feature = "net-sim")]
+pub(crate) const NETSIM_MAX_DELAY_MS: u32 = 10_000;
+// Extra hold for a packet picked for reordering: longer than a frame, so the
+// next packet overtakes it.
+#[cfg(feature = "net-sim")]
+pub(crate) const NETSIM_REORDER_HOLD_MS: u64 = 80;
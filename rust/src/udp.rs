@@ -0,0 +1,97 @@
+// ===========================================================================
+// UDP MULTICAST TRANSPORT (feature = "udp-transport")
+// ===========================================================================
+// A ready-made transport for LAN deployments: every node sends its packets to
+// one multicast group and listens on the same group. Apps that use it don't
+// have to implement `PacketTransport` themselves.
+
+use std::net::{Ipv4Addr, SocketAddrV4, UdpSocket};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::resources;
+use crate::{AudioError, PacketTransport, UdpMulticastConfig, UDP_MAX_DATAGRAM_SIZE, UDP_RECEIVE_POLL_MS};
+
+/// Sending half. Handed to the engine as its `PacketTransport`.
+pub(crate) struct UdpMulticastTransport {
+    socket: UdpSocket,
+    group: SocketAddrV4,
+}
+
+impl UdpMulticastTransport {
+    /// Binds the group port, joins the group and returns the sender plus a
+    /// second handle to the same socket for the receive loop.
+    pub(crate) fn open(config: &UdpMulticastConfig) -> Result<(Self, UdpSocket), AudioError> {
+        let group: Ipv4Addr = config.group_address.parse().map_err(|_| AudioError::InvalidParameter)?;
+        let interface: Ipv4Addr = config.interface_address.parse().map_err(|_| AudioError::InvalidParameter)?;
+        if !group.is_multicast() {
+            return Err(AudioError::InvalidParameter);
+        }
+
+        let open = || -> std::io::Result<(UdpSocket, UdpSocket)> {
+            let socket = UdpSocket::bind(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, config.port))?;
+            socket.join_multicast_v4(&group, &interface)?;
+            socket.set_multicast_ttl_v4(config.ttl)?;
+            // We never want to hear ourselves through the network
+            socket.set_multicast_loop_v4(false)?;
+            let receiver = socket.try_clone()?;
+            receiver.set_read_timeout(Some(Duration::from_millis(UDP_RECEIVE_POLL_MS)))?;
+            Ok((socket, receiver))
+        };
+        let (socket, receiver) = open().map_err(|e| {
+            log::error!("UDP multicast setup failed ({}:{}): {}", group, config.port, e);
+            AudioError::TransportError
+        })?;
+
+        info!("UDP multicast joined {}:{} (TTL {})", group, config.port, config.ttl);
+        Ok((Self { socket, group: SocketAddrV4::new(group, config.port) }, receiver))
+    }
+}
+
+impl PacketTransport for UdpMulticastTransport {
+    fn send_packet(&self, data: Vec<u8>) {
+        if let Err(e) = self.socket.send_to(&data, self.group) {
+            log::warn!("UDP send failed: {}", e);
+        }
+    }
+}
+
+/// Receiving half: a thread that feeds every datagram into the engine.
+/// Stops (and is joined) when dropped.
+pub(crate) struct UdpReceiver {
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl UdpReceiver {
+    pub(crate) fn spawn(socket: UdpSocket, deliver: impl Fn(&[u8]) + Send + 'static) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+        let flag = running.clone();
+        let thread = resources::spawn(move || {
+            let mut buf = vec![0u8; UDP_MAX_DATAGRAM_SIZE];
+            while flag.load(Ordering::Relaxed) {
+                match socket.recv_from(&mut buf) {
+                    Ok((len, _)) => deliver(&buf[..len]),
+                    // Timeouts just give us a chance to check the running flag
+                    Err(e) if matches!(e.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut) => {}
+                    Err(e) => {
+                        log::error!("UDP receive failed: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
+        Self { running, thread: Some(thread) }
+    }
+}
+
+impl Drop for UdpReceiver {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}